@@ -0,0 +1,322 @@
+// benches/hot_paths.rs
+// DOCUMENTATION: Criterion benchmarks for the service hot paths
+// PURPOSE: Put numbers behind grid generation, Google result transformation,
+// cache contention, search SQL assembly, and bulk place upserts
+//
+// Run all benchmarks:          cargo bench --bench hot_paths
+// CI smoke run (one iteration): cargo bench --bench hot_paths -- --test
+// With TEST_DATABASE_URL set, bulk_upsert also compares the per-row and bulk writes
+
+use auphere_places::db::{BulkUpsertColumns, PlaceRepository};
+use auphere_places::models::{CreatePlaceRequest, SearchQuery};
+use auphere_places::services::google_places_client::GooglePlace;
+use auphere_places::services::{
+    CityBounds, GooglePlacesClient, GridGenerator, PlaceService, PlacesCache, DEFAULT_CELL_SIZE_KM,
+    DEFAULT_MAX_ENTRIES, DEFAULT_RADIUS_M,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Zaragoza-sized bounding box used for grid benchmarks
+fn zaragoza_bounds() -> CityBounds {
+    CityBounds {
+        name: "Zaragoza".to_string(),
+        min_lat: 41.60,
+        max_lat: 41.70,
+        min_lng: -0.95,
+        max_lng: -0.82,
+        cell_size_km: DEFAULT_CELL_SIZE_KM,
+        radius_m: DEFAULT_RADIUS_M,
+        timezone: None,
+    }
+}
+
+/// Build a synthetic Google result set with photos, reviews, and address components
+fn synthetic_places(count: usize) -> Vec<GooglePlace> {
+    (0..count)
+        .map(|i| {
+            let offset = (i % 1000) as f64 * 0.0001;
+            serde_json::from_value(serde_json::json!({
+                "place_id": format!("place_{}", i),
+                "name": format!("Place {}", i),
+                "types": ["restaurant", "food", "point_of_interest"],
+                "geometry": { "location": { "lat": 41.65 + offset, "lng": -0.88 - offset } },
+                "vicinity": "Calle Alfonso I, Zaragoza",
+                "address_components": [
+                    { "long_name": "Zaragoza", "short_name": "Zaragoza", "types": ["locality", "political"] },
+                    { "long_name": "Casco Antiguo", "short_name": "Casco Antiguo", "types": ["sublocality", "political"] }
+                ],
+                "rating": 4.5,
+                "user_ratings_total": 120,
+                "price_level": 2,
+                "opening_hours": { "open_now": true },
+                "photos": [
+                    { "photo_reference": format!("ref_{}_a", i), "width": 1024, "height": 768 },
+                    { "photo_reference": format!("ref_{}_b", i), "width": 800, "height": 600 }
+                ],
+                "reviews": [
+                    { "author_name": "Ana", "rating": 5, "text": "Muy bueno", "time": 1700000000 }
+                ]
+            }))
+            .expect("synthetic place must deserialize")
+        })
+        .collect()
+}
+
+/// Search query exercising every WHERE clause branch
+fn full_search_query() -> SearchQuery {
+    SearchQuery {
+        q: Some("tapas o'clock".to_string()),
+        city: Some("Zaragoza".to_string()),
+        district: Some("Casco Antiguo".to_string()),
+        type_: Some("restaurant".to_string()),
+        lat: Some(41.65),
+        lon: Some(-0.88),
+        radius_km: Some(2.5),
+        min_rating: Some(4.0),
+        ..Default::default()
+    }
+}
+
+fn bench_grid_generation(c: &mut Criterion) {
+    let bounds = zaragoza_bounds();
+    let mut group = c.benchmark_group("grid_generate");
+
+    for cell_size_km in [0.5, 1.0, 1.5, 3.0] {
+        let cells = GridGenerator::generate_grid(&bounds, cell_size_km, 1000).len();
+        group.throughput(Throughput::Elements(cells as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(cell_size_km),
+            &cell_size_km,
+            |b, &size| b.iter(|| GridGenerator::generate_grid(black_box(&bounds), size, 1000)),
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_transform(c: &mut Criterion) {
+    let places = synthetic_places(5_000);
+    let client = GooglePlacesClient::new("bench_key".to_string());
+    let mut group = c.benchmark_group("google_transform");
+    group.throughput(Throughput::Elements(places.len() as u64));
+
+    group.bench_function("transform_google_place_to_frontend", |b| {
+        b.iter(|| {
+            places
+                .iter()
+                .map(|place| {
+                    PlaceService::transform_google_place_to_frontend(
+                        black_box(place),
+                        &client,
+                        Some(41.65),
+                        Some(-0.88),
+                        Some("Zaragoza"),
+                    )
+                })
+                .filter(Result::is_ok)
+                .count()
+        })
+    });
+
+    group.bench_function("calculate_distance", |b| {
+        b.iter(|| {
+            places
+                .iter()
+                .map(|place| {
+                    PlaceService::calculate_distance(
+                        black_box(41.65),
+                        black_box(-0.88),
+                        place.geometry.location.lat,
+                        place.geometry.location.lng,
+                    )
+                })
+                .sum::<f64>()
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_cache_contention(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("cache_contention");
+
+    for tasks in [1usize, 8, 32] {
+        group.bench_with_input(BenchmarkId::from_parameter(tasks), &tasks, |b, &tasks| {
+            b.to_async(&runtime).iter(|| async move {
+                let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+                let handles: Vec<_> = (0..tasks)
+                    .map(|t| {
+                        let cache = cache.clone();
+                        tokio::spawn(async move {
+                            for i in 0..100 {
+                                let key = format!("key_{}", (t * 100 + i) % 256);
+                                if cache.get(&key).await.is_none() {
+                                    cache.set(key, "{\"results\":[]}".to_string()).await;
+                                }
+                            }
+                        })
+                    })
+                    .collect();
+                for handle in handles {
+                    handle.await.expect("cache task panicked");
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_search_sql(c: &mut Criterion) {
+    let full = full_search_query();
+    let empty = SearchQuery::default();
+    let mut group = c.benchmark_group("search_sql");
+
+    group.bench_function("page_query_all_filters", |b| {
+        b.iter(|| PlaceRepository::search_page_query(black_box(&full), 20, 0).sql().len())
+    });
+    group.bench_function("page_query_no_filters", |b| {
+        b.iter(|| PlaceRepository::search_page_query(black_box(&empty), 20, 0).sql().len())
+    });
+    group.bench_function("normalized_cache_key", |b| b.iter(|| black_box(&full).normalized_key()));
+
+    group.finish();
+}
+
+/// Create requests for `count` synthetic places under Google ids starting with `prefix`
+fn synthetic_requests(count: usize, prefix: &str) -> Vec<CreatePlaceRequest> {
+    synthetic_places(count)
+        .iter()
+        .map(|place| {
+            let mut request = GooglePlacesClient::to_create_request(place, "Zaragoza");
+            request.google_place_id = Some(format!("{}_{}", prefix, place.place_id));
+            request
+        })
+        .collect()
+}
+
+fn bench_bulk_upsert(c: &mut Criterion) {
+    let requests = synthetic_requests(3_000, "bench");
+    let mut group = c.benchmark_group("bulk_upsert");
+    group.throughput(Throughput::Elements(requests.len() as u64));
+    group.bench_function("column_arrays", |b| {
+        b.iter(|| BulkUpsertColumns::from_requests(black_box(&requests)).map(|columns| columns.len()))
+    });
+    group.finish();
+
+    // Round trips dominate the write path, so the database comparison needs a real server
+    let Ok(database_url) = std::env::var("TEST_DATABASE_URL") else {
+        return;
+    };
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let pool = runtime
+        .block_on(sqlx::PgPool::connect(&database_url))
+        .expect("connect to TEST_DATABASE_URL");
+    let run = uuid::Uuid::new_v4();
+    let per_row = synthetic_requests(300, &format!("bench_row_{}", run));
+    let bulk = synthetic_requests(300, &format!("bench_bulk_{}", run));
+
+    // Both paths must agree on created/updated before their timings mean anything
+    let (per_row_created, bulk_created) = runtime.block_on(async {
+        let mut per_row_created = 0;
+        for request in &per_row {
+            per_row_created += PlaceRepository::upsert_google_place(&pool, request)
+                .await
+                .expect("per-row upsert")
+                .created as usize;
+        }
+        let bulk_created = PlaceRepository::bulk_upsert(&pool, &bulk)
+            .await
+            .expect("bulk upsert")
+            .iter()
+            .filter(|row| row.created)
+            .count();
+        (per_row_created, bulk_created)
+    });
+    assert_eq!(per_row_created, bulk_created, "bulk and per-row upserts must create the same rows");
+
+    let mut group = c.benchmark_group("bulk_upsert_db");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(per_row.len() as u64));
+    group.bench_function("per_row", |b| {
+        b.to_async(&runtime).iter(|| async {
+            for request in &per_row {
+                PlaceRepository::upsert_google_place(&pool, request).await.expect("per-row upsert");
+            }
+        })
+    });
+    group.bench_function("bulk", |b| {
+        b.to_async(&runtime)
+            .iter(|| async { PlaceRepository::bulk_upsert(&pool, &bulk).await.expect("bulk upsert") })
+    });
+    group.finish();
+
+    runtime
+        .block_on(
+            sqlx::query("DELETE FROM places WHERE google_place_id LIKE $1")
+                .bind(format!("bench_%_{}_%", run))
+                .execute(&pool),
+        )
+        .expect("remove benchmark places");
+}
+
+/// Regression guard: correctness and a generous time budget on the hot paths
+/// DOCUMENTATION: Runs before the benchmarks (including `-- --test` in CI) and panics
+/// when output shape changes or when a pass becomes an order of magnitude slower
+fn regression_guard(_c: &mut Criterion) {
+    let bounds = zaragoza_bounds();
+    let cells = GridGenerator::generate_grid(&bounds, 1.5, 1000);
+    assert!(!cells.is_empty(), "grid generation produced no cells");
+    assert!(
+        GridGenerator::generate_grid(&bounds, 0.5, 1000).len() > cells.len(),
+        "smaller cells must produce a denser grid"
+    );
+
+    let places = synthetic_places(10_000);
+    let client = GooglePlacesClient::new("bench_key".to_string());
+    let started = Instant::now();
+    let transformed = places
+        .iter()
+        .filter_map(|place| {
+            PlaceService::transform_google_place_to_frontend(
+                place,
+                &client,
+                Some(41.65),
+                Some(-0.88),
+                Some("Zaragoza"),
+            )
+            .ok()
+        })
+        .count();
+    let elapsed = started.elapsed();
+    assert_eq!(transformed, places.len(), "every synthetic place must transform");
+    assert!(
+        elapsed < Duration::from_secs(5),
+        "transforming 10k places took {:?} (budget 5s)",
+        elapsed
+    );
+
+    let full = full_search_query();
+    let count_query = PlaceRepository::search_count_query(&full);
+    assert!(count_query.sql().contains("WHERE p.is_active = true"));
+    assert!(!count_query.sql().contains("o'clock"), "search input must be bound, not inlined");
+
+    let requests = synthetic_requests(1_000, "guard");
+    let columns = BulkUpsertColumns::from_requests(&requests).expect("synthetic requests have unique ids");
+    assert_eq!(columns.len(), requests.len(), "bulk upsert needs one array entry per place");
+    assert_eq!(columns.google_place_id[999], "guard_place_999", "bulk upsert arrays must keep request order");
+}
+
+criterion_group!(
+    benches,
+    regression_guard,
+    bench_grid_generation,
+    bench_transform,
+    bench_cache_contention,
+    bench_search_sql,
+    bench_bulk_upsert
+);
+criterion_main!(benches);
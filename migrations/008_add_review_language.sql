@@ -0,0 +1,10 @@
+-- migrations/008_add_review_language.sql
+-- DOCUMENTATION: Records the language each review was written in
+-- PURPOSE: Reviews are now fetched untranslated; keep their language for sentiment analysis
+-- DEPENDENCIES: 007_fix_review_rating_type.sql
+
+-- Nullable: rows synced before this migration keep NULL until the review is re-synced
+ALTER TABLE place_reviews
+    ADD COLUMN IF NOT EXISTS language VARCHAR(16);
+
+COMMENT ON COLUMN place_reviews.language IS 'Language the review was written in (e.g. es, en), from Google original_language';
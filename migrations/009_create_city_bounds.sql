@@ -0,0 +1,24 @@
+-- migrations/009_create_city_bounds.sql
+-- DOCUMENTATION: Geographic bounds of the cities that can be synced
+-- PURPOSE: Add cities without a code change; managed via PUT /admin/cities/{name}/bounds
+-- DEPENDENCIES: 001_create_places.sql
+
+CREATE TABLE IF NOT EXISTS city_bounds (
+    name VARCHAR(100) PRIMARY KEY,
+    min_lat DOUBLE PRECISION NOT NULL CHECK (min_lat >= -90 AND min_lat <= 90),
+    max_lat DOUBLE PRECISION NOT NULL CHECK (max_lat >= -90 AND max_lat <= 90),
+    min_lng DOUBLE PRECISION NOT NULL CHECK (min_lng >= -180 AND min_lng <= 180),
+    max_lng DOUBLE PRECISION NOT NULL CHECK (max_lng >= -180 AND max_lng <= 180),
+    cell_size_km DOUBLE PRECISION NOT NULL DEFAULT 1.5 CHECK (cell_size_km > 0),
+    radius_m INT NOT NULL DEFAULT 1000 CHECK (radius_m > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (min_lat < max_lat AND min_lng < max_lng)
+);
+
+-- City names are matched case-insensitively
+CREATE UNIQUE INDEX IF NOT EXISTS idx_city_bounds_name_lower ON city_bounds (LOWER(name));
+
+COMMENT ON TABLE city_bounds IS 'Sync grid bounds per city; built-in bounds in GridGenerator apply when a city has no row';
+COMMENT ON COLUMN city_bounds.cell_size_km IS 'Default grid cell size for this city (km)';
+COMMENT ON COLUMN city_bounds.radius_m IS 'Default Nearby Search radius per cell (m)';
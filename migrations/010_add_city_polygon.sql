@@ -0,0 +1,11 @@
+-- migrations/010_add_city_polygon.sql
+-- DOCUMENTATION: Optional municipality outline per city
+-- PURPOSE: Skip sync grid cells outside the city (farmland, rivers); managed via
+-- PUT /admin/cities/{name}/polygon
+-- DEPENDENCIES: 009_create_city_bounds.sql
+
+-- Nullable: cities without an outline sync their whole bounding box
+ALTER TABLE city_bounds
+    ADD COLUMN IF NOT EXISTS polygon JSONB;
+
+COMMENT ON COLUMN city_bounds.polygon IS 'GeoJSON Polygon or MultiPolygon ([lng, lat] positions); grid cells centered outside it are skipped';
@@ -0,0 +1,24 @@
+-- migrations/011_create_sync_cell_progress.sql
+-- DOCUMENTATION: Grid cells completed by city syncs
+-- PURPOSE: Let an interrupted sync resume (SyncRequest.resume) without re-querying
+-- cells it already searched
+-- DEPENDENCIES: none
+
+CREATE TABLE IF NOT EXISTS sync_cell_progress (
+    -- Sync label: the city name, or bbox:... for bounding-box syncs
+    city VARCHAR(200) NOT NULL,
+    cell_id VARCHAR(200) NOT NULL,
+    -- Empty string when the sync had no place type filter
+    place_type VARCHAR(100) NOT NULL DEFAULT '',
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    radius_m INT NOT NULL,
+    results_count INT NOT NULL DEFAULT 0,
+    completed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (city, place_type, cell_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sync_cell_progress_completed_at ON sync_cell_progress (completed_at);
+
+COMMENT ON TABLE sync_cell_progress IS 'Last completed search of each sync grid cell; cells are matched on id, center and radius';
+COMMENT ON COLUMN sync_cell_progress.results_count IS 'Places Google returned for the cell (60 means it was subdivided)';
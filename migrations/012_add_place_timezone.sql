@@ -0,0 +1,99 @@
+-- migrations/012_add_place_timezone.sql
+-- DOCUMENTATION: Place time zone and opening hours evaluation in SQL
+-- PURPOSE: Let the open_now search filter evaluate the stored opening_hours periods
+-- at query time instead of trusting the is_open_now flag captured at sync time
+-- DEPENDENCIES: 006_enrich_places_fields.sql
+
+ALTER TABLE places
+    ADD COLUMN IF NOT EXISTS timezone VARCHAR(64);
+
+COMMENT ON COLUMN places.timezone IS 'UTC offset of the place as +HH:MM, taken from Google utc_offset_minutes';
+
+-- Format an offset in minutes as +HH:MM (castable to INTERVAL)
+CREATE OR REPLACE FUNCTION utc_offset_label(minutes INT)
+RETURNS TEXT AS $$
+    SELECT CASE WHEN minutes < 0 THEN '-' ELSE '+' END
+        || lpad((abs(minutes) / 60)::TEXT, 2, '0') || ':'
+        || lpad((abs(minutes) % 60)::TEXT, 2, '0')
+$$ LANGUAGE sql IMMUTABLE STRICT;
+
+-- Keep timezone in step with opening_hours; hours without an offset keep the
+-- previous value
+CREATE OR REPLACE FUNCTION update_places_timezone()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.opening_hours->>'utc_offset_minutes' ~ '^-?[0-9]{1,4}$' THEN
+        NEW.timezone := utc_offset_label((NEW.opening_hours->>'utc_offset_minutes')::INT);
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_update_places_timezone ON places;
+CREATE TRIGGER trg_update_places_timezone
+BEFORE INSERT OR UPDATE OF opening_hours ON places
+FOR EACH ROW
+EXECUTE FUNCTION update_places_timezone();
+
+UPDATE places
+SET timezone = utc_offset_label((opening_hours->>'utc_offset_minutes')::INT)
+WHERE opening_hours->>'utc_offset_minutes' ~ '^-?[0-9]{1,4}$';
+
+-- Whether Google opening hours are open at `at`, mirroring OpeningHours::is_open_at_utc:
+-- minutes since Sunday 00:00 local time, closes at or before the open wrap into the
+-- next week, a period without close is open around the clock, invalid periods are
+-- skipped. Returns false without usable periods and NULL when the offset is unknown
+CREATE OR REPLACE FUNCTION place_is_open_at(hours JSONB, tz TEXT, at TIMESTAMPTZ)
+RETURNS BOOLEAN AS $$
+    WITH periods AS (
+        SELECT
+            (period #>> '{open,day}')::INT * 1440
+                + substr(period #>> '{open,time}', 1, 2)::INT * 60
+                + substr(period #>> '{open,time}', 3, 2)::INT AS open_minute,
+            CASE WHEN jsonb_typeof(period->'close') = 'object' THEN
+                (period #>> '{close,day}')::INT * 1440
+                    + substr(period #>> '{close,time}', 1, 2)::INT * 60
+                    + substr(period #>> '{close,time}', 3, 2)::INT
+            END AS close_minute
+        FROM jsonb_array_elements(
+            CASE WHEN jsonb_typeof(hours->'periods') = 'array' THEN hours->'periods' ELSE '[]'::JSONB END
+        ) AS period
+        WHERE period #>> '{open,day}' ~ '^[0-6]$'
+            AND period #>> '{open,time}' ~ '^(([01][0-9]|2[0-3])[0-5][0-9]|2400)$'
+            AND (
+                jsonb_typeof(period->'close') IS DISTINCT FROM 'object'
+                OR (
+                    period #>> '{close,day}' ~ '^[0-6]$'
+                    AND period #>> '{close,time}' ~ '^(([01][0-9]|2[0-3])[0-5][0-9]|2400)$'
+                )
+            )
+    ),
+    spans AS (
+        SELECT
+            open_minute,
+            close_minute + CASE WHEN close_minute <= open_minute THEN 10080 ELSE 0 END AS end_minute,
+            close_minute IS NULL AS always
+        FROM periods
+    ),
+    local_time AS (
+        SELECT (at AT TIME ZONE 'UTC') + tz::INTERVAL AS ts
+    ),
+    now_minute AS (
+        SELECT EXTRACT(DOW FROM ts)::INT * 1440
+            + EXTRACT(HOUR FROM ts)::INT * 60
+            + EXTRACT(MINUTE FROM ts)::INT AS minute
+        FROM local_time
+    )
+    SELECT CASE
+        WHEN NOT EXISTS (SELECT 1 FROM periods) THEN false
+        WHEN tz IS NULL THEN NULL
+        ELSE (
+            SELECT bool_or(
+                always
+                OR (minute >= open_minute AND minute < end_minute)
+                OR (minute + 10080 >= open_minute AND minute + 10080 < end_minute)
+            )
+            FROM spans, now_minute
+        )
+    END
+$$ LANGUAGE sql STABLE;
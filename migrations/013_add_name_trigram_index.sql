@@ -0,0 +1,9 @@
+-- migrations/013_add_name_trigram_index.sql
+-- DOCUMENTATION: Trigram index on place names
+-- PURPOSE: Support fuzzy name search (similarity on p.name) for misspelled queries
+-- that full-text search cannot match
+-- DEPENDENCIES: 001_create_places.sql
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_places_name_trgm ON places USING GIN (name gin_trgm_ops);
@@ -0,0 +1,77 @@
+-- migrations/014_spanish_search_config.sql
+-- DOCUMENTATION: Configurable, accent-insensitive full-text search
+-- PURPOSE: Build search_vector with the text search configuration chosen by
+-- SEARCH_LANGUAGE (default spanish) over unaccented text, so "restaurantes" matches
+-- "restaurante" and "café" matches "cafe"
+-- DEPENDENCIES: 006_enrich_places_fields.sql
+
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+-- unaccent() is only STABLE; pinning the dictionary makes it safe to treat as immutable
+CREATE OR REPLACE FUNCTION places_unaccent(input TEXT)
+RETURNS TEXT AS $$
+    SELECT public.unaccent('public.unaccent'::REGDICTIONARY, input)
+$$ LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT;
+
+-- Text search configuration search_vector is built with (single row). Written by
+-- POST /admin/search/rebuild from SEARCH_LANGUAGE
+CREATE TABLE IF NOT EXISTS search_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    language REGCONFIG NOT NULL DEFAULT 'spanish'
+);
+
+INSERT INTO search_settings (id) VALUES (TRUE) ON CONFLICT (id) DO NOTHING;
+
+CREATE OR REPLACE FUNCTION update_places_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector := to_tsvector(
+        COALESCE((SELECT language FROM search_settings), 'spanish'::REGCONFIG),
+        places_unaccent(
+            NEW.name || ' ' ||
+            COALESCE(NEW.description, '') || ' ' ||
+            array_to_string(COALESCE(NEW.main_categories, ARRAY[]::TEXT[]), ' ') || ' ' ||
+            array_to_string(COALESCE(NEW.cuisine_types, ARRAY[]::TEXT[]), ' ')
+        )
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Rebuilding search vectors is not an edit: sessions that set auphere.search_rebuild
+-- keep updated_at as it was and write no audit rows
+CREATE OR REPLACE FUNCTION update_places_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF current_setting('auphere.search_rebuild', true) = 'on' THEN
+        RETURN NEW;
+    END IF;
+    NEW.updated_at = CURRENT_TIMESTAMP;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION audit_places_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'UPDATE' AND current_setting('auphere.search_rebuild', true) = 'on' THEN
+        RETURN NEW;
+    END IF;
+
+    INSERT INTO places_audit (place_id, action, old_data, new_data, changed_at)
+    VALUES (
+        CASE WHEN TG_OP = 'DELETE' THEN OLD.id ELSE NEW.id END,
+        TG_OP,
+        CASE WHEN TG_OP = 'INSERT' THEN NULL ELSE row_to_json(OLD) END,
+        CASE WHEN TG_OP = 'DELETE' THEN NULL ELSE row_to_json(NEW) END,
+        CURRENT_TIMESTAMP
+    );
+
+    RETURN CASE WHEN TG_OP = 'DELETE' THEN OLD ELSE NEW END;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Rebuild existing vectors through the trigger
+SET auphere.search_rebuild = 'on';
+UPDATE places SET search_vector = NULL;
+RESET auphere.search_rebuild;
@@ -0,0 +1,30 @@
+-- migrations/015_skip_place_delete_audit.sql
+-- DOCUMENTATION: Allow hard deletes of places
+-- PURPOSE: places_audit.place_id cascades from places, so the audit row written
+-- after a DELETE referenced a place that no longer existed and the foreign key
+-- aborted every hard delete. Deletes are no longer audited; the cascade already
+-- removes the place's earlier audit rows (wanted for GDPR erasure)
+-- DEPENDENCIES: 014_spanish_search_config.sql
+
+CREATE OR REPLACE FUNCTION audit_places_changes()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        RETURN OLD;
+    END IF;
+    IF TG_OP = 'UPDATE' AND current_setting('auphere.search_rebuild', true) = 'on' THEN
+        RETURN NEW;
+    END IF;
+
+    INSERT INTO places_audit (place_id, action, old_data, new_data, changed_at)
+    VALUES (
+        NEW.id,
+        TG_OP,
+        CASE WHEN TG_OP = 'INSERT' THEN NULL ELSE row_to_json(OLD) END,
+        row_to_json(NEW),
+        CURRENT_TIMESTAMP
+    );
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,21 @@
+-- migrations/016_add_name_suggest_index.sql
+-- DOCUMENTATION: Accent-insensitive name prefix indexes
+-- PURPOSE: Serve GET /places/suggest (typeahead over stored places) from an index:
+-- "cafe bo" must find "Café Botánico" and "bota" must find it by its second word
+-- DEPENDENCIES: 013_add_name_trigram_index.sql, 014_spanish_search_config.sql
+
+-- Lowercased, unaccented name; the expression both indexes and the query use
+CREATE OR REPLACE FUNCTION places_name_key(name TEXT)
+RETURNS TEXT AS $$
+    SELECT lower(places_unaccent(name))
+$$ LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT;
+
+-- Whole-name prefixes (LIKE 'ca%'), usable from the second character
+CREATE INDEX IF NOT EXISTS idx_places_name_key_prefix
+    ON places (places_name_key(name) text_pattern_ops)
+    WHERE is_active = TRUE;
+
+-- Word prefixes (LIKE '% bo%'), usable from the third character
+CREATE INDEX IF NOT EXISTS idx_places_name_key_trgm
+    ON places USING GIN (places_name_key(name) gin_trgm_ops)
+    WHERE is_active = TRUE;
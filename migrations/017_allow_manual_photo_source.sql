@@ -0,0 +1,10 @@
+-- migrations/017_allow_manual_photo_source.sql
+-- DOCUMENTATION: Manual photo source
+-- PURPOSE: Photos attached by URL through POST /places/{id}/photos are stored with
+-- source 'manual', which the original source constraint rejected
+-- DEPENDENCIES: 004_create_photos_table.sql
+
+ALTER TABLE place_photos DROP CONSTRAINT IF EXISTS valid_photo_source;
+ALTER TABLE place_photos ADD CONSTRAINT valid_photo_source CHECK (source IN (
+    'google', 'trustpilot', 'yelp', 'tripadvisor', 'instagram', 'user_upload', 'owner_upload', 'manual'
+));
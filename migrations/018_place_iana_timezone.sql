@@ -0,0 +1,122 @@
+-- migrations/018_place_iana_timezone.sql
+-- DOCUMENTATION: IANA time zones for places and city bounds
+-- PURPOSE: Evaluate opening hours with daylight saving time. 012 stored the fixed
+-- UTC offset Google reported at sync time, which is an hour off for half the year
+-- DEPENDENCIES: 009_create_city_bounds.sql, 012_add_place_timezone.sql
+
+-- Whether `tz` names a zone the server knows; fixed offsets and abbreviations
+-- ("+01:00", "CET") are refused since they ignore daylight saving time
+CREATE OR REPLACE FUNCTION is_iana_timezone(tz TEXT)
+RETURNS BOOLEAN AS $$
+BEGIN
+    IF tz IS DISTINCT FROM 'UTC' AND tz !~ '^[A-Za-z][A-Za-z0-9_+-]*(/[A-Za-z][A-Za-z0-9_+-]*)+$' THEN
+        RETURN false;
+    END IF;
+    PERFORM NOW() AT TIME ZONE tz;
+    RETURN true;
+EXCEPTION WHEN invalid_parameter_value THEN
+    RETURN false;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE STRICT;
+
+ALTER TABLE city_bounds
+    ADD COLUMN IF NOT EXISTS timezone VARCHAR(64)
+    CONSTRAINT city_bounds_timezone_check CHECK (timezone IS NULL OR is_iana_timezone(timezone));
+
+COMMENT ON COLUMN city_bounds.timezone IS 'IANA time zone of the city (e.g. Europe/Madrid), copied to its places';
+
+-- The service writes the zone of registered cities; a stored city zone wins.
+-- Anything that is not an IANA zone is dropped
+DROP TRIGGER IF EXISTS trg_update_places_timezone ON places;
+
+CREATE OR REPLACE FUNCTION update_places_timezone()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.timezone := COALESCE(
+        (SELECT timezone FROM city_bounds WHERE LOWER(name) = LOWER(NEW.city) AND timezone IS NOT NULL),
+        NEW.timezone
+    );
+    IF NEW.timezone IS NOT NULL AND NOT is_iana_timezone(NEW.timezone) THEN
+        NEW.timezone := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_update_places_timezone
+BEFORE INSERT OR UPDATE OF city, timezone ON places
+FOR EACH ROW
+EXECUTE FUNCTION update_places_timezone();
+
+DROP FUNCTION IF EXISTS utc_offset_label(INT);
+
+COMMENT ON COLUMN places.timezone IS 'IANA time zone of the place (e.g. Europe/Madrid), taken from its city; NULL when unknown';
+
+-- Offsets written by 012 are replaced: built-in cities (GridGenerator) are in
+-- Spain's peninsular zone, stored cities bring their own
+UPDATE places SET timezone = NULL WHERE timezone IS NOT NULL AND NOT is_iana_timezone(timezone);
+UPDATE places
+SET timezone = 'Europe/Madrid'
+WHERE LOWER(city) IN ('zaragoza', 'madrid', 'barcelona', 'valencia', 'sevilla', 'seville', 'bilbao', 'málaga', 'malaga');
+UPDATE places p
+SET timezone = c.timezone
+FROM city_bounds c
+WHERE LOWER(c.name) = LOWER(p.city) AND c.timezone IS NOT NULL;
+
+-- Same evaluation as 012, but local time comes from the IANA zone at `at`.
+-- Returns false without usable periods and NULL when the zone is unknown
+CREATE OR REPLACE FUNCTION place_is_open_at(hours JSONB, tz TEXT, at TIMESTAMPTZ)
+RETURNS BOOLEAN AS $$
+    WITH periods AS (
+        SELECT
+            (period #>> '{open,day}')::INT * 1440
+                + substr(period #>> '{open,time}', 1, 2)::INT * 60
+                + substr(period #>> '{open,time}', 3, 2)::INT AS open_minute,
+            CASE WHEN jsonb_typeof(period->'close') = 'object' THEN
+                (period #>> '{close,day}')::INT * 1440
+                    + substr(period #>> '{close,time}', 1, 2)::INT * 60
+                    + substr(period #>> '{close,time}', 3, 2)::INT
+            END AS close_minute
+        FROM jsonb_array_elements(
+            CASE WHEN jsonb_typeof(hours->'periods') = 'array' THEN hours->'periods' ELSE '[]'::JSONB END
+        ) AS period
+        WHERE period #>> '{open,day}' ~ '^[0-6]$'
+            AND period #>> '{open,time}' ~ '^(([01][0-9]|2[0-3])[0-5][0-9]|2400)$'
+            AND (
+                jsonb_typeof(period->'close') IS DISTINCT FROM 'object'
+                OR (
+                    period #>> '{close,day}' ~ '^[0-6]$'
+                    AND period #>> '{close,time}' ~ '^(([01][0-9]|2[0-3])[0-5][0-9]|2400)$'
+                )
+            )
+    ),
+    spans AS (
+        SELECT
+            open_minute,
+            close_minute + CASE WHEN close_minute <= open_minute THEN 10080 ELSE 0 END AS end_minute,
+            close_minute IS NULL AS always
+        FROM periods
+    ),
+    local_time AS (
+        SELECT at AT TIME ZONE tz AS ts
+        WHERE is_iana_timezone(tz)
+    ),
+    now_minute AS (
+        SELECT EXTRACT(DOW FROM ts)::INT * 1440
+            + EXTRACT(HOUR FROM ts)::INT * 60
+            + EXTRACT(MINUTE FROM ts)::INT AS minute
+        FROM local_time
+    )
+    SELECT CASE
+        WHEN NOT EXISTS (SELECT 1 FROM periods) THEN false
+        WHEN NOT EXISTS (SELECT 1 FROM local_time) THEN NULL
+        ELSE (
+            SELECT bool_or(
+                always
+                OR (minute >= open_minute AND minute < end_minute)
+                OR (minute + 10080 >= open_minute AND minute + 10080 < end_minute)
+            )
+            FROM spans, now_minute
+        )
+    END
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,10 @@
+-- migrations/019_record_sync_runs.sql
+-- DOCUMENTATION: Sync run history in data_sync_log
+-- PURPOSE: Every background sync job (city, bbox or batch) writes one data_sync_log
+-- row whose id is the job id; the outcome of its webhook notification is kept there
+-- DEPENDENCIES: 002_create_search_index.sql
+
+ALTER TABLE data_sync_log
+    ADD COLUMN IF NOT EXISTS webhook JSONB;
+
+COMMENT ON COLUMN data_sync_log.webhook IS 'Webhook notification of the run: url, delivered, attempts and the last error';
@@ -0,0 +1,12 @@
+-- migrations/020_sync_run_breakdown.sql
+-- DOCUMENTATION: Per-type and per-cell breakdown of sync runs
+-- PURPOSE: Keep the `by_type` and `cells` stats of each run in its data_sync_log row,
+-- so the breakdown outlives the job status
+-- DEPENDENCIES: 019_record_sync_runs.sql
+
+ALTER TABLE data_sync_log
+    ADD COLUMN IF NOT EXISTS by_type JSONB,
+    ADD COLUMN IF NOT EXISTS cells JSONB;
+
+COMMENT ON COLUMN data_sync_log.by_type IS 'Places created per internal type, e.g. {"restaurant": 12, "bar": 4}';
+COMMENT ON COLUMN data_sync_log.cells IS 'Counts per searched cell (cell_id, retrieved, created, skipped, failed), at most SYNC_MAX_CELL_STATS entries';
@@ -107,6 +107,19 @@ MIGRATIONS=(
     "005_adjust_google_rating_type.sql"
     "006_enrich_places_fields.sql"
     "007_fix_review_rating_type.sql"
+    "008_add_review_language.sql"
+    "009_create_city_bounds.sql"
+    "010_add_city_polygon.sql"
+    "011_create_sync_cell_progress.sql"
+    "012_add_place_timezone.sql"
+    "013_add_name_trigram_index.sql"
+    "014_spanish_search_config.sql"
+    "015_skip_place_delete_audit.sql"
+    "016_add_name_suggest_index.sql"
+    "017_allow_manual_photo_source.sql"
+    "018_place_iana_timezone.sql"
+    "019_record_sync_runs.sql"
+    "020_sync_run_breakdown.sql"
 )
 
 print_info "Found ${#MIGRATIONS[@]} migration(s) to apply"
@@ -42,6 +42,10 @@ struct SyncResponse {
     #[serde(default)]
     places_retrieved: u32,
     #[serde(default)]
+    cells_processed: u32,
+    #[serde(default)]
+    cells_skipped_resume: u32,
+    #[serde(default)]
     places_created: u32,
     #[serde(default)]
     places_skipped: u32,
@@ -52,6 +56,8 @@ struct SyncResponse {
     #[serde(default)]
     photos_created: u32,
     #[serde(default)]
+    by_type: HashMap<String, u32>,
+    #[serde(default)]
     errors: Vec<String>,
     #[serde(default)]
     duration_seconds: u64,
@@ -61,6 +67,22 @@ struct SyncResponse {
     completed_at: Option<String>,
 }
 
+/// Respuesta 202 de POST /admin/sync/{city}
+#[derive(Deserialize, Debug)]
+struct SyncJobAccepted {
+    job_id: String,
+}
+
+/// Estado de GET /admin/sync/jobs/{job_id}
+#[derive(Deserialize, Debug)]
+struct SyncJobStatus {
+    state: String,
+    #[serde(default)]
+    stats: SyncResponse,
+    #[serde(default)]
+    error: Option<String>,
+}
+
 #[derive(Debug)]
 struct SyncResult {
     place_type: String,
@@ -73,6 +95,9 @@ struct SyncResult {
     duration_secs: f64,
 }
 
+// Intervalo entre consultas al estado de un trabajo de sync
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 // --- Configuración de Tipos de Lugares ---
 // Implementado como una función que devuelve el HashMap para facilitar el manejo
 
@@ -153,7 +178,7 @@ struct PlacesSyncManager {
 impl PlacesSyncManager {
     fn new(base_url: String, admin_token: String) -> Self {
         let client = Client::builder()
-            .timeout(Duration::from_secs(300))
+            .timeout(Duration::from_secs(30))
             .build()
             .expect("Failed to create HTTP client");
 
@@ -189,15 +214,61 @@ impl PlacesSyncManager {
             .await
             .map_err(|e| e.to_string())?;
 
-        if response.status().is_success() {
-            response
-                .json::<SyncResponse>()
-                .await
-                .map_err(|e| format!("Failed to parse response JSON: {}", e))
-        } else {
+        if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-            Err(format!("HTTP {} - {}", status, body))
+            return Err(format!("HTTP {} - {}", status, body));
+        }
+
+        let job = response
+            .json::<SyncJobAccepted>()
+            .await
+            .map_err(|e| format!("Failed to parse response JSON: {}", e))?;
+        self.wait_for_job(&job.job_id).await
+    }
+
+    /// The server runs the sync in the background; poll its job until it finishes
+    async fn wait_for_job(&self, job_id: &str) -> Result<SyncResponse, String> {
+        let url = format!("{}/admin/sync/jobs/{}", self.base_url, job_id);
+        let mut last_cells = None;
+
+        loop {
+            tokio::time::sleep(JOB_POLL_INTERVAL).await;
+
+            let response = self
+                .client
+                .get(&url)
+                .header("X-Admin-Token", &self.admin_token)
+                .send()
+                .await
+                .map_err(|e| e.to_string())?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(format!("Job {}: HTTP {} - {}", job_id, status, body));
+            }
+
+            let job = response
+                .json::<SyncJobStatus>()
+                .await
+                .map_err(|e| format!("Failed to parse job status JSON: {}", e))?;
+
+            match job.state.as_str() {
+                "completed" => return Ok(job.stats),
+                "failed" => {
+                    return Err(job.error.unwrap_or_else(|| "Sync job failed".to_string()));
+                }
+                state => {
+                    let cells = job.stats.cells_processed + job.stats.cells_skipped_resume;
+                    if state == "running" && last_cells != Some(cells) {
+                        println!(
+                            "   {}… {} cells, {} new places{}",
+                            BLUE, cells, job.stats.places_created, RESET
+                        );
+                        last_cells = Some(cells);
+                    }
+                }
+            }
         }
     }
 
@@ -241,6 +312,13 @@ impl PlacesSyncManager {
                         resp.places_created, resp.places_skipped, duration,
                         RESET
                     );
+                    if !resp.by_type.is_empty() {
+                        let mut by_type: Vec<_> = resp.by_type.iter().collect();
+                        by_type.sort();
+                        let breakdown: Vec<String> =
+                            by_type.iter().map(|(place_type, created)| format!("{} {}", created, place_type)).collect();
+                        println!("   {}↳ {}{}", BLUE, breakdown.join(", "), RESET);
+                    }
                     if !resp.errors.is_empty() {
                         println!(
                             "{}⚠️  {} warnings ({}).{}",
@@ -0,0 +1,239 @@
+// src/db/city_bounds_repository.rs
+// DOCUMENTATION: City bounds database operations
+// PURPOSE: Store the sync grid bounds of each city in the `city_bounds` table
+
+use crate::errors::PlacesError;
+use crate::services::CityBounds;
+use sqlx::PgPool;
+
+pub struct CityBoundsRepository;
+
+/// Row of the `city_bounds` table
+#[derive(Debug, sqlx::FromRow)]
+struct CityBoundsRow {
+    name: String,
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+    cell_size_km: f64,
+    radius_m: i32,
+    timezone: Option<String>,
+}
+
+impl From<CityBoundsRow> for CityBounds {
+    fn from(row: CityBoundsRow) -> Self {
+        CityBounds {
+            name: row.name,
+            min_lat: row.min_lat,
+            max_lat: row.max_lat,
+            min_lng: row.min_lng,
+            max_lng: row.max_lng,
+            cell_size_km: row.cell_size_km,
+            radius_m: u32::try_from(row.radius_m).unwrap_or_default(),
+            timezone: row.timezone,
+        }
+    }
+}
+
+impl CityBoundsRepository {
+    /// Get the bounds of a city (case-insensitive name match)
+    pub async fn get_by_name(pool: &PgPool, name: &str) -> Result<Option<CityBounds>, PlacesError> {
+        let row = sqlx::query_as::<_, CityBoundsRow>(
+            r#"
+            SELECT name, min_lat, max_lat, min_lng, max_lng, cell_size_km, radius_m, timezone
+            FROM city_bounds
+            WHERE LOWER(name) = LOWER($1)
+            "#,
+        )
+        .bind(name.trim())
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to fetch bounds for city {}: {}", name, e);
+            PlacesError::DatabaseError(format!("Fetch city bounds failed: {}", e))
+        })?;
+
+        Ok(row.map(Into::into))
+    }
+
+    /// Names of all cities with stored bounds, alphabetically
+    pub async fn list_names(pool: &PgPool) -> Result<Vec<String>, PlacesError> {
+        let names = sqlx::query_scalar::<_, String>("SELECT name FROM city_bounds ORDER BY name")
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to list city bounds: {}", e);
+                PlacesError::DatabaseError(format!("List city bounds failed: {}", e))
+            })?;
+
+        Ok(names)
+    }
+
+    /// Bounds of every stored city, alphabetically
+    pub async fn list(pool: &PgPool) -> Result<Vec<CityBounds>, PlacesError> {
+        let rows = sqlx::query_as::<_, CityBoundsRow>(
+            r#"
+            SELECT name, min_lat, max_lat, min_lng, max_lng, cell_size_km, radius_m, timezone
+            FROM city_bounds
+            ORDER BY name
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to list city bounds: {}", e);
+            PlacesError::DatabaseError(format!("List city bounds failed: {}", e))
+        })?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Create or replace the bounds of a city
+    /// DOCUMENTATION: Matching is case-insensitive; the stored name takes the new spelling
+    pub async fn upsert(pool: &PgPool, bounds: &CityBounds) -> Result<CityBounds, PlacesError> {
+        let row = sqlx::query_as::<_, CityBoundsRow>(
+            r#"
+            INSERT INTO city_bounds (name, min_lat, max_lat, min_lng, max_lng, cell_size_km, radius_m, timezone)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT ((LOWER(name))) DO UPDATE
+            SET name = EXCLUDED.name,
+                min_lat = EXCLUDED.min_lat,
+                max_lat = EXCLUDED.max_lat,
+                min_lng = EXCLUDED.min_lng,
+                max_lng = EXCLUDED.max_lng,
+                cell_size_km = EXCLUDED.cell_size_km,
+                radius_m = EXCLUDED.radius_m,
+                timezone = EXCLUDED.timezone,
+                updated_at = NOW()
+            RETURNING name, min_lat, max_lat, min_lng, max_lng, cell_size_km, radius_m, timezone
+            "#,
+        )
+        .bind(&bounds.name)
+        .bind(bounds.min_lat)
+        .bind(bounds.max_lat)
+        .bind(bounds.min_lng)
+        .bind(bounds.max_lng)
+        .bind(bounds.cell_size_km)
+        .bind(i32::try_from(bounds.radius_m).unwrap_or(i32::MAX))
+        .bind(&bounds.timezone)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            // The timezone CHECK only fails for zones the server does not know
+            if e.as_database_error().and_then(|db| db.constraint()) == Some("city_bounds_timezone_check") {
+                return PlacesError::InvalidInput(format!(
+                    "Unknown timezone {}",
+                    bounds.timezone.as_deref().unwrap_or_default()
+                ));
+            }
+            log::error!("Failed to save bounds for city {}: {}", bounds.name, e);
+            PlacesError::DatabaseError(format!("Save city bounds failed: {}", e))
+        })?;
+
+        Ok(row.into())
+    }
+
+    /// Get the GeoJSON outline of a city, if one was stored
+    pub async fn get_polygon(pool: &PgPool, name: &str) -> Result<Option<serde_json::Value>, PlacesError> {
+        let polygon = sqlx::query_scalar::<_, Option<serde_json::Value>>(
+            "SELECT polygon FROM city_bounds WHERE LOWER(name) = LOWER($1)",
+        )
+        .bind(name.trim())
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to fetch polygon for city {}: {}", name, e);
+            PlacesError::DatabaseError(format!("Fetch city polygon failed: {}", e))
+        })?;
+
+        Ok(polygon.flatten())
+    }
+
+    /// Store (or clear with None) the outline of a city with stored bounds
+    /// DOCUMENTATION: Returns false when the city has no row in `city_bounds`
+    pub async fn set_polygon(
+        pool: &PgPool,
+        name: &str,
+        polygon: Option<&serde_json::Value>,
+    ) -> Result<bool, PlacesError> {
+        let result = sqlx::query(
+            "UPDATE city_bounds SET polygon = $2, updated_at = NOW() WHERE LOWER(name) = LOWER($1)",
+        )
+        .bind(name.trim())
+        .bind(polygon)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to save polygon for city {}: {}", name, e);
+            PlacesError::DatabaseError(format!("Save city polygon failed: {}", e))
+        })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_pool;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_upsert_and_case_insensitive_lookup() {
+        let pool = test_pool().await;
+        let name = format!("Huesca-{}", Uuid::new_v4());
+        let bounds = CityBounds {
+            name: name.clone(),
+            min_lat: 42.12,
+            max_lat: 42.16,
+            min_lng: -0.43,
+            max_lng: -0.38,
+            cell_size_km: 1.0,
+            radius_m: 800,
+            timezone: Some("Europe/Madrid".to_string()),
+        };
+
+        CityBoundsRepository::upsert(&pool, &bounds).await.unwrap();
+        let updated = CityBounds { name: name.to_uppercase(), radius_m: 900, ..bounds };
+        CityBoundsRepository::upsert(&pool, &updated).await.unwrap();
+
+        let stored = CityBoundsRepository::get_by_name(&pool, &name).await.unwrap().unwrap();
+        assert_eq!(stored.name, name.to_uppercase());
+        assert_eq!(stored.radius_m, 900);
+        assert_eq!(stored.timezone.as_deref(), Some("Europe/Madrid"));
+        assert!(CityBoundsRepository::list_names(&pool).await.unwrap().contains(&stored.name));
+        assert!(CityBoundsRepository::list(&pool).await.unwrap().contains(&stored));
+
+        let polygon = serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [[[-0.43, 42.12], [-0.38, 42.12], [-0.38, 42.16], [-0.43, 42.12]]]
+        });
+        assert!(CityBoundsRepository::set_polygon(&pool, &name, Some(&polygon)).await.unwrap());
+        assert_eq!(CityBoundsRepository::get_polygon(&pool, &name).await.unwrap(), Some(polygon));
+        assert!(CityBoundsRepository::set_polygon(&pool, &name, None).await.unwrap());
+        assert!(CityBoundsRepository::get_polygon(&pool, &name).await.unwrap().is_none());
+        assert!(!CityBoundsRepository::set_polygon(&pool, "Nowhere-City", None).await.unwrap());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_upsert_rejects_unknown_timezone() {
+        let pool = test_pool().await;
+        let bounds = CityBounds {
+            name: format!("Jaca-{}", Uuid::new_v4()),
+            min_lat: 42.56,
+            max_lat: 42.58,
+            min_lng: -0.56,
+            max_lng: -0.53,
+            cell_size_km: 1.0,
+            radius_m: 800,
+            timezone: Some("Europe/Atlantis".to_string()),
+        };
+
+        let result = CityBoundsRepository::upsert(&pool, &bounds).await;
+        assert!(matches!(result, Err(PlacesError::InvalidInput(_))), "{:?}", result);
+        assert!(CityBoundsRepository::get_by_name(&pool, &bounds.name).await.unwrap().is_none());
+    }
+}
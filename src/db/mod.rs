@@ -2,10 +2,16 @@
 // DOCUMENTATION: Database module organization
 // PURPOSE: Re-export database components
 
+pub mod city_bounds_repository;
 pub mod photo_repository;
 pub mod repository;
 pub mod review_repository;
+pub mod sync_log_repository;
+pub mod sync_progress_repository;
 
+pub use city_bounds_repository::*;
 pub use photo_repository::*;
 pub use repository::*;
 pub use review_repository::*;
+pub use sync_log_repository::*;
+pub use sync_progress_repository::*;
@@ -4,7 +4,8 @@
 
 use crate::errors::PlacesError;
 use crate::models::{CreatePhotoRequest, Photo};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 pub struct PhotoRepository;
@@ -15,6 +16,52 @@ impl PhotoRepository {
     pub async fn create_photo(
         pool: &PgPool,
         req: &CreatePhotoRequest,
+    ) -> Result<Photo, PlacesError> {
+        Self::insert_photo(pool, req).await
+    }
+
+    /// Attach a photo to a place
+    /// DOCUMENTATION: create_photo for a photo added by hand. A primary photo demotes
+    /// the place's other primaries in the same transaction, so a failed insert keeps
+    /// the current primary and a place never shows two
+    pub async fn add_photo_to_place(
+        pool: &PgPool,
+        req: &CreatePhotoRequest,
+    ) -> Result<Photo, PlacesError> {
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin add photo failed: {}", e))
+        })?;
+
+        if req.is_primary.unwrap_or(false) {
+            sqlx::query(
+                r#"
+                UPDATE place_photos
+                SET is_primary = FALSE, updated_at = NOW()
+                WHERE place_id = $1 AND is_primary
+                "#,
+            )
+            .bind(&req.place_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to unset primary photos: {}", e);
+                PlacesError::DatabaseError(format!("Unset primary failed: {}", e))
+            })?;
+        }
+
+        let photo = Self::insert_photo(&mut *tx, req).await?;
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit add photo failed: {}", e))
+        })?;
+
+        Ok(photo)
+    }
+
+    /// INSERT behind create_photo and add_photo_to_place
+    async fn insert_photo<'e>(
+        executor: impl PgExecutor<'e>,
+        req: &CreatePhotoRequest,
     ) -> Result<Photo, PlacesError> {
         let photo = sqlx::query_as::<_, Photo>(
             r#"
@@ -46,7 +93,7 @@ impl PhotoRepository {
         .bind(&req.attribution)
         .bind(req.is_primary.unwrap_or(false))
         .bind(req.display_order.unwrap_or(0))
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await
         .map_err(|e| {
             log::error!("Failed to create photo: {}", e);
@@ -91,6 +138,97 @@ impl PhotoRepository {
         Ok(photos)
     }
 
+    /// Count the photos of a place
+    /// DOCUMENTATION: Total behind get_photo_page, optionally limited to one source
+    pub async fn count_photos_by_place(
+        pool: &PgPool,
+        place_id: &Uuid,
+        source: Option<&str>,
+    ) -> Result<i64, PlacesError> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM place_photos
+            WHERE place_id = $1 AND ($2::text IS NULL OR source = $2)
+            "#,
+        )
+        .bind(place_id)
+        .bind(source)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to count photos for place {}: {}", place_id, e);
+            PlacesError::DatabaseError(format!("Count photos failed: {}", e))
+        })
+    }
+
+    /// Get one page of the photos of a place
+    /// DOCUMENTATION: get_photos_by_place order, with created_at and id as tiebreaks so
+    /// pages never overlap
+    pub async fn get_photo_page(
+        pool: &PgPool,
+        place_id: &Uuid,
+        source: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Photo>, PlacesError> {
+        sqlx::query_as::<_, Photo>(
+            r#"
+            SELECT * FROM place_photos
+            WHERE place_id = $1 AND ($2::text IS NULL OR source = $2)
+            ORDER BY is_primary DESC, display_order ASC, created_at ASC, id ASC
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(place_id)
+        .bind(source)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to fetch photo page for place {}: {}", place_id, e);
+            PlacesError::DatabaseError(format!("Fetch photos failed: {}", e))
+        })
+    }
+
+    /// Get the first photos of several places in one query
+    /// DOCUMENTATION: Up to `per_place` photos of each place, in get_photos_by_place
+    /// order, grouped by place id. Places without photos are absent from the map
+    pub async fn get_photos_by_places(
+        pool: &PgPool,
+        place_ids: &[Uuid],
+        per_place: i64,
+    ) -> Result<HashMap<Uuid, Vec<Photo>>, PlacesError> {
+        let photos = sqlx::query_as::<_, Photo>(
+            r#"
+            SELECT * FROM (
+                SELECT *, ROW_NUMBER() OVER (
+                    PARTITION BY place_id
+                    ORDER BY is_primary DESC, display_order ASC, created_at ASC
+                ) AS photo_rank
+                FROM place_photos
+                WHERE place_id = ANY($1)
+            ) ranked
+            WHERE photo_rank <= $2
+            ORDER BY place_id, photo_rank
+            "#,
+        )
+        .bind(place_ids)
+        .bind(per_place)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to fetch photos for {} places: {}", place_ids.len(), e);
+            PlacesError::DatabaseError(format!("Fetch photos failed: {}", e))
+        })?;
+
+        let mut by_place: HashMap<Uuid, Vec<Photo>> = HashMap::new();
+        for photo in photos {
+            by_place.entry(photo.place_id).or_default().push(photo);
+        }
+        Ok(by_place)
+    }
+
     /// Delete all photos for a place from a specific source
     /// DOCUMENTATION: Remove all photos from a specific source (useful for re-sync)
     #[allow(dead_code)]
@@ -117,14 +255,150 @@ impl PhotoRepository {
         Ok(result.rows_affected())
     }
 
+    /// Delete one photo of a place
+    /// DOCUMENTATION: NotFound unless `photo_id` is a photo of `place_id`. When the
+    /// photo was primary, the first remaining photo by display_order becomes primary
+    /// in the same transaction; its id is returned
+    pub async fn delete_photo(
+        pool: &PgPool,
+        place_id: &Uuid,
+        photo_id: &Uuid,
+    ) -> Result<Option<Uuid>, PlacesError> {
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin delete photo failed: {}", e))
+        })?;
+
+        let deleted: Option<(Option<bool>,)> = sqlx::query_as(
+            "DELETE FROM place_photos WHERE id = $1 AND place_id = $2 RETURNING is_primary",
+        )
+        .bind(photo_id)
+        .bind(place_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to delete photo {} of place {}: {}", photo_id, place_id, e);
+            PlacesError::DatabaseError(format!("Delete photo failed: {}", e))
+        })?;
+        let (was_primary,) =
+            deleted.ok_or_else(|| PlacesError::NotFound(format!("Photo {} of place {}", photo_id, place_id)))?;
+
+        let promoted = if was_primary == Some(true) {
+            sqlx::query_scalar(
+                r#"
+                UPDATE place_photos
+                SET is_primary = TRUE, updated_at = NOW()
+                WHERE id = (
+                    SELECT id FROM place_photos
+                    WHERE place_id = $1
+                    ORDER BY display_order ASC, created_at ASC, id ASC
+                    LIMIT 1
+                )
+                RETURNING id
+                "#,
+            )
+            .bind(place_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to promote a photo of place {}: {}", place_id, e);
+                PlacesError::DatabaseError(format!("Promote photo failed: {}", e))
+            })?
+        } else {
+            None
+        };
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit delete photo failed: {}", e))
+        })?;
+
+        Ok(promoted)
+    }
+
+    /// Reorder the photos of a place
+    /// DOCUMENTATION: `photo_ids` come first in the given order, the place's other
+    /// photos after them in their current order; display_order becomes 0, 1, 2...
+    /// through one UNNEST WITH ORDINALITY update inside a transaction. Empty lists,
+    /// duplicates and ids that are not photos of the place are a ValidationError.
+    /// Returns the place's photos in their new order
+    pub async fn reorder_photos(
+        pool: &PgPool,
+        place_id: &Uuid,
+        photo_ids: &[Uuid],
+    ) -> Result<Vec<Photo>, PlacesError> {
+        if photo_ids.is_empty() {
+            return Err(PlacesError::ValidationError("photo_ids must not be empty".to_string()));
+        }
+
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin reorder photos failed: {}", e))
+        })?;
+
+        let current: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM place_photos
+            WHERE place_id = $1
+            ORDER BY display_order ASC, created_at ASC, id ASC
+            FOR UPDATE
+            "#,
+        )
+        .bind(place_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to lock photos of place {}: {}", place_id, e);
+            PlacesError::DatabaseError(format!("Fetch photos failed: {}", e))
+        })?;
+        let order = full_photo_order(&current, photo_ids)?;
+
+        sqlx::query(
+            r#"
+            UPDATE place_photos p
+            SET display_order = (o.position - 1)::INT, updated_at = NOW()
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(id, position)
+            WHERE p.id = o.id AND p.place_id = $1
+            "#,
+        )
+        .bind(place_id)
+        .bind(&order)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to reorder photos of place {}: {}", place_id, e);
+            PlacesError::DatabaseError(format!("Reorder photos failed: {}", e))
+        })?;
+
+        let photos = sqlx::query_as::<_, Photo>(
+            r#"
+            SELECT * FROM place_photos
+            WHERE place_id = $1
+            ORDER BY display_order ASC, created_at ASC, id ASC
+            "#,
+        )
+        .bind(place_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| PlacesError::DatabaseError(format!("Fetch photos failed: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit reorder photos failed: {}", e))
+        })?;
+
+        Ok(photos)
+    }
+
     /// Set primary photo
-    /// DOCUMENTATION: Mark a specific photo as primary (unsets other primaries for the place)
-    #[allow(dead_code)]
+    /// DOCUMENTATION: Mark a specific photo as primary (unsets other primaries for the place).
+    /// Both updates run in one transaction, and a photo that is not one of the
+    /// place's is NotFound with the current primary left in place
     pub async fn set_primary_photo(
         pool: &PgPool,
         place_id: &Uuid,
         photo_id: &Uuid,
     ) -> Result<(), PlacesError> {
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin set primary failed: {}", e))
+        })?;
+
         // First, unset all primaries for this place
         sqlx::query(
             r#"
@@ -134,7 +408,7 @@ impl PhotoRepository {
             "#,
         )
         .bind(place_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             log::error!("Failed to unset primary photos: {}", e);
@@ -142,22 +416,199 @@ impl PhotoRepository {
         })?;
 
         // Then set the new primary
-        sqlx::query(
+        let updated = sqlx::query(
             r#"
             UPDATE place_photos
-            SET is_primary = TRUE
+            SET is_primary = TRUE, updated_at = NOW()
             WHERE id = $1 AND place_id = $2
             "#,
         )
         .bind(photo_id)
         .bind(place_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             log::error!("Failed to set primary photo: {}", e);
             PlacesError::DatabaseError(format!("Set primary failed: {}", e))
+        })?
+        .rows_affected();
+
+        // Dropping the transaction rolls the unset back
+        if updated == 0 {
+            return Err(PlacesError::NotFound(format!("Photo {} of place {}", photo_id, place_id)));
+        }
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit set primary failed: {}", e))
         })?;
 
         Ok(())
     }
 }
+
+/// Full display order of a place's photos: `requested` followed by the rest of
+/// `current`. Duplicated ids and ids missing from `current` are rejected
+fn full_photo_order(current: &[Uuid], requested: &[Uuid]) -> Result<Vec<Uuid>, PlacesError> {
+    let mut seen = HashSet::with_capacity(requested.len());
+    if let Some(duplicate) = requested.iter().find(|id| !seen.insert(**id)) {
+        return Err(PlacesError::ValidationError(format!("Photo {} is listed more than once", duplicate)));
+    }
+
+    let foreign: Vec<String> = requested
+        .iter()
+        .filter(|id| !current.contains(id))
+        .map(|id| id.to_string())
+        .collect();
+    if !foreign.is_empty() {
+        return Err(PlacesError::ValidationError(format!(
+            "Photos {} do not belong to this place",
+            foreign.join(", ")
+        )));
+    }
+
+    let mut order = requested.to_vec();
+    order.extend(current.iter().filter(|id| !seen.contains(*id)));
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_test_place, test_pool};
+
+    async fn add_photo(pool: &PgPool, place_id: Uuid, display_order: i32, is_primary: bool) -> Uuid {
+        let photo = CreatePhotoRequest {
+            place_id,
+            source: "manual".to_string(),
+            source_photo_reference: None,
+            photo_url: format!("https://cdn.example.com/{}/{}.jpg", place_id, display_order),
+            thumbnail_url: None,
+            width: None,
+            height: None,
+            attribution: None,
+            is_primary: Some(is_primary),
+            display_order: Some(display_order),
+        };
+        PhotoRepository::create_photo(pool, &photo).await.unwrap().id
+    }
+
+    #[test]
+    fn test_full_photo_order_appends_unmentioned_photos() {
+        let current: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+
+        let order = full_photo_order(&current, &[current[2], current[0]]).unwrap();
+        assert_eq!(order, [current[2], current[0], current[1], current[3]]);
+        let reversed: Vec<Uuid> = current.iter().rev().copied().collect();
+        assert_eq!(full_photo_order(&current, &reversed).unwrap(), reversed);
+
+        for requested in [vec![current[1], current[1]], vec![current[0], Uuid::new_v4()]] {
+            let err = full_photo_order(&current, &requested).unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}", requested);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_deleting_the_primary_photo_promotes_the_next_one() {
+        let pool = test_pool().await;
+        let place_id = insert_test_place(&pool, &format!("delete_photo_{}", Uuid::new_v4())).await;
+        let primary = add_photo(&pool, place_id, 2, true).await;
+        let first = add_photo(&pool, place_id, 0, false).await;
+        let second = add_photo(&pool, place_id, 1, false).await;
+
+        // A non-primary photo goes without promotion
+        assert_eq!(PhotoRepository::delete_photo(&pool, &place_id, &second).await.unwrap(), None);
+
+        assert_eq!(PhotoRepository::delete_photo(&pool, &place_id, &primary).await.unwrap(), Some(first));
+        let photos = PhotoRepository::get_photos_by_place(&pool, &place_id, None).await.unwrap();
+        assert_eq!(photos.len(), 1);
+        assert_eq!((photos[0].id, photos[0].is_primary), (first, Some(true)));
+
+        // The last photo leaves nothing to promote
+        assert_eq!(PhotoRepository::delete_photo(&pool, &place_id, &first).await.unwrap(), None);
+        let again = PhotoRepository::delete_photo(&pool, &place_id, &first).await;
+        assert!(matches!(again, Err(PlacesError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_adding_a_primary_photo_demotes_the_others() {
+        let pool = test_pool().await;
+        let place_id = insert_test_place(&pool, &format!("add_photo_{}", Uuid::new_v4())).await;
+        add_photo(&pool, place_id, 0, true).await;
+        let request = |display_order: i32, is_primary: bool| CreatePhotoRequest {
+            place_id,
+            source: "manual".to_string(),
+            source_photo_reference: None,
+            photo_url: format!("https://cdn.example.com/{}/added-{}.jpg", place_id, display_order),
+            thumbnail_url: None,
+            width: None,
+            height: None,
+            attribution: None,
+            is_primary: Some(is_primary),
+            display_order: Some(display_order),
+        };
+        let primaries = |photos: Vec<Photo>| -> Vec<Uuid> {
+            photos.into_iter().filter(|photo| photo.is_primary == Some(true)).map(|photo| photo.id).collect()
+        };
+
+        let secondary = PhotoRepository::add_photo_to_place(&pool, &request(1, false)).await.unwrap();
+        assert_eq!(secondary.is_primary, Some(false));
+        let primary = PhotoRepository::add_photo_to_place(&pool, &request(2, true)).await.unwrap();
+        let photos = PhotoRepository::get_photos_by_place(&pool, &place_id, None).await.unwrap();
+        assert_eq!(photos.len(), 3);
+        assert_eq!(primaries(photos), [primary.id]);
+
+        // A rejected insert rolls the demotion back
+        let invalid = CreatePhotoRequest {
+            source: "unknown".to_string(),
+            ..request(3, true)
+        };
+        assert!(PhotoRepository::add_photo_to_place(&pool, &invalid).await.is_err());
+        let photos = PhotoRepository::get_photos_by_place(&pool, &place_id, None).await.unwrap();
+        assert_eq!(primaries(photos), [primary.id]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_photo_of_another_place_is_not_deleted() {
+        let pool = test_pool().await;
+        let owner = insert_test_place(&pool, &format!("photo_owner_{}", Uuid::new_v4())).await;
+        let other = insert_test_place(&pool, &format!("photo_other_{}", Uuid::new_v4())).await;
+        let photo = add_photo(&pool, owner, 0, true).await;
+
+        let result = PhotoRepository::delete_photo(&pool, &other, &photo).await;
+        assert!(matches!(result, Err(PlacesError::NotFound(_))));
+        assert_eq!(PhotoRepository::get_photos_by_place(&pool, &owner, None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_reorder_photos_puts_unmentioned_photos_last() {
+        let pool = test_pool().await;
+        let place_id = insert_test_place(&pool, &format!("reorder_{}", Uuid::new_v4())).await;
+        let other = insert_test_place(&pool, &format!("reorder_other_{}", Uuid::new_v4())).await;
+        let mut photos = Vec::new();
+        for order in 0..4 {
+            photos.push(add_photo(&pool, place_id, order, order == 0).await);
+        }
+        let foreign = add_photo(&pool, other, 0, true).await;
+        let listed = |photos: &[Photo]| -> Vec<(Uuid, Option<i32>)> {
+            photos.iter().map(|photo| (photo.id, photo.display_order)).collect()
+        };
+
+        let reordered = PhotoRepository::reorder_photos(&pool, &place_id, &[photos[3], photos[1]]).await.unwrap();
+        assert_eq!(
+            listed(&reordered),
+            [(photos[3], Some(0)), (photos[1], Some(1)), (photos[0], Some(2)), (photos[2], Some(3))]
+        );
+
+        // A foreign id rejects the whole request and leaves the order as it was
+        let err = PhotoRepository::reorder_photos(&pool, &place_id, &[photos[0], foreign]).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
+        let stored = PhotoRepository::get_photos_by_place(&pool, &place_id, None).await.unwrap();
+        let mut orders: Vec<(Uuid, Option<i32>)> = listed(&stored);
+        orders.sort_by_key(|(_, order)| *order);
+        assert_eq!(orders, listed(&reordered));
+    }
+}
@@ -4,13 +4,21 @@
 
 use crate::errors::PlacesError;
 use crate::models::*;
+use crate::services::GridGenerator;
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use serde_json::Value;
-use sqlx::{FromRow, PgPool};
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Text search configuration used when SEARCH_LANGUAGE is not set
+pub const DEFAULT_SEARCH_LANGUAGE: &str = "spanish";
+
 /// Internal struct for mapping database rows to Place struct
-/// DOCUMENTATION: Handles PostGIS POINT extraction via ST_X() and ST_Y()
+/// DOCUMENTATION: Handles PostGIS POINT extraction via ST_X() and ST_Y(). The photo
+/// and distance columns default to None, so write statements can map their
+/// RETURNING clause without the photo join
 #[derive(Debug, FromRow)]
 struct PlaceRow {
     pub id: Uuid,
@@ -53,6 +61,9 @@ struct PlaceRow {
     pub primary_photo_url: Option<String>,
     #[sqlx(default)]
     pub primary_photo_thumbnail_url: Option<String>,
+    /// Only selected by searches with lat/lon
+    #[sqlx(default)]
+    pub distance_km: Option<f64>,
 }
 
 impl PlaceRow {
@@ -96,10 +107,20 @@ impl PlaceRow {
             last_verified_at: self.last_verified_at,
             primary_photo_url: self.primary_photo_url,
             primary_photo_thumbnail_url: self.primary_photo_thumbnail_url,
+            distance_km: self.distance_km,
         }
     }
 }
 
+/// Row returned by the upsert statements: the written place and how it got there
+#[derive(Debug, FromRow)]
+struct UpsertRow {
+    #[sqlx(flatten)]
+    place: PlaceRow,
+    /// xmax = 0: the row was inserted rather than updated
+    inserted: bool,
+}
+
 /// PlaceRepository: All database operations for places
 /// DOCUMENTATION: Uses query_as for type-safe SQL queries with PostGIS support
 pub struct PlaceRepository;
@@ -112,7 +133,7 @@ impl PlaceRepository {
         pool: &PgPool,
         req: &CreatePlaceRequest,
     ) -> Result<Place, PlacesError> {
-        let inserted: (Uuid,) = sqlx::query_as(
+        let inserted = sqlx::query_as::<_, PlaceRow>(
             r#"
             INSERT INTO places (
                 name, description, type, location, address,
@@ -120,7 +141,7 @@ impl PlaceRepository {
                 google_place_id, google_place_url, google_rating, google_rating_count, price_level,
                 main_categories, secondary_categories, cuisine_types,
                 opening_hours, is_open_now, business_status, suitable_for,
-                created_at, updated_at
+                tags, is_active, timezone, created_at, updated_at
             )
             VALUES (
                 $1, $2, $3, 
@@ -129,9 +150,25 @@ impl PlaceRepository {
                 $12, $13, $14, $15, $16,
                 $17, $18, $19,
                 $20, $21, $22, $23,
+                jsonb_strip_nulls(jsonb_build_object('language', $24::text))
+                    || COALESCE($25::jsonb, '{}'::jsonb),
+                $22::text IS DISTINCT FROM 'CLOSED_PERMANENTLY',
+                $26,
                 NOW(), NOW()
             )
-            RETURNING id
+            RETURNING
+                id, name, description, type,
+                ST_X(location) as longitude, ST_Y(location) as latitude,
+                address, city, district, postal_code,
+                phone, email, website,
+                google_place_id, google_place_url,
+                google_rating, google_rating_count, price_level,
+                main_categories, secondary_categories, cuisine_types,
+                tags, vibe_descriptor, suitable_for,
+                opening_hours, is_open_now,
+                is_subscribed, subscription_tier, subscription_expires_at, owner_id,
+                is_active, business_status,
+                created_at, updated_at, last_verified_at
             "#,
         )
         .bind(&req.name) // $1
@@ -157,6 +194,9 @@ impl PlaceRepository {
         .bind(&req.is_open_now) // $21
         .bind(&req.business_status) // $22
         .bind(&req.suitable_for) // $23
+        .bind(&req.language) // $24
+        .bind(&req.attributes) // $25
+        .bind(GridGenerator::city_timezone(&req.city)) // $26
         .fetch_one(pool)
         .await
         .map_err(|e| {
@@ -164,30 +204,42 @@ impl PlaceRepository {
             PlacesError::DatabaseError(e.to_string())
         })?;
 
-        let place = Self::get_by_id(pool, inserted.0).await?;
+        let place = inserted.to_place();
         log::info!("Created place with id: {}", place.id);
         Ok(place)
     }
 
     /// Upsert a place identified by Google Place ID
     /// Inserts new rows or updates existing ones with latest Google metadata
+    /// DOCUMENTATION: Places Google reports as CLOSED_PERMANENTLY are stored inactive;
+    /// any other status (re)activates the place. The returned place may be inactive.
+    /// Details-only fields (phone, website, district, postal code, hours periods) are
+    /// kept when the request lacks them, so a Nearby Search result can refresh a place
     pub async fn upsert_google_place(
         pool: &PgPool,
         req: &CreatePlaceRequest,
-    ) -> Result<(Place, bool), PlacesError> {
+    ) -> Result<UpsertedPlace, PlacesError> {
         let google_id = req.google_place_id.as_ref().ok_or_else(|| {
             PlacesError::InvalidInput("google_place_id is required for upsert".into())
         })?;
 
-        // Try insert first - on conflict do nothing so we can detect creation
-        let insert_sql = r#"
+        // Snapshot of the stored row for the change report and the activation flags. A
+        // concurrent upsert of the same place between this read and the update only
+        // widens the reported diff
+        let previous = Self::fetch_by_google_ids(pool, std::slice::from_ref(google_id))
+            .await?
+            .remove(google_id);
+
+        // Single atomic statement: xmax = 0 only for freshly inserted rows, so creation
+        // detection cannot race with a concurrent upsert of the same Google place
+        let upsert_sql = r#"
             INSERT INTO places (
                 name, description, type, location, address,
                 city, district, postal_code, phone, website, 
                 google_place_id, google_place_url, google_rating, google_rating_count, price_level,
                 main_categories, secondary_categories, cuisine_types,
                 opening_hours, is_open_now, business_status, suitable_for,
-                created_at, updated_at
+                tags, is_active, timezone, created_at, updated_at
             )
             VALUES (
                 $1, $2, $3,
@@ -196,13 +248,67 @@ impl PlaceRepository {
                 $12, $13, $14, $15, $16,
                 $17, $18, $19,
                 $20, $21, $22, $23,
+                jsonb_strip_nulls(jsonb_build_object('language', $24::text))
+                    || COALESCE($25::jsonb, '{}'::jsonb),
+                $22::text IS DISTINCT FROM 'CLOSED_PERMANENTLY',
+                $26,
                 NOW(), NOW()
             )
-            ON CONFLICT (google_place_id) DO NOTHING
-            RETURNING id
+            ON CONFLICT (google_place_id) DO UPDATE
+            SET name = EXCLUDED.name,
+                -- Google's editorial summary only fills an empty description
+                description = COALESCE(NULLIF(places.description, ''), EXCLUDED.description),
+                type = EXCLUDED.type,
+                location = EXCLUDED.location,
+                address = EXCLUDED.address,
+                city = EXCLUDED.city,
+                -- Only Place Details reports these; a Nearby Search refresh keeps them
+                district = COALESCE(EXCLUDED.district, places.district),
+                postal_code = COALESCE(EXCLUDED.postal_code, places.postal_code),
+                phone = COALESCE(EXCLUDED.phone, places.phone),
+                website = COALESCE(EXCLUDED.website, places.website),
+                google_place_url = EXCLUDED.google_place_url,
+                google_rating = EXCLUDED.google_rating,
+                google_rating_count = EXCLUDED.google_rating_count,
+                price_level = EXCLUDED.price_level,
+                main_categories = EXCLUDED.main_categories,
+                secondary_categories = EXCLUDED.secondary_categories,
+                cuisine_types = EXCLUDED.cuisine_types,
+                -- Nearby Search hours only carry open_now: keep the stored periods then
+                opening_hours = CASE
+                    WHEN jsonb_typeof(EXCLUDED.opening_hours -> 'periods') = 'array'
+                        THEN EXCLUDED.opening_hours
+                    ELSE NULLIF(
+                        COALESCE(places.opening_hours, '{}'::jsonb)
+                            || jsonb_strip_nulls(COALESCE(EXCLUDED.opening_hours, '{}'::jsonb)),
+                        '{}'::jsonb
+                    )
+                END,
+                is_open_now = EXCLUDED.is_open_now,
+                business_status = EXCLUDED.business_status,
+                suitable_for = EXCLUDED.suitable_for,
+                -- Keep other tags; language and reported attributes are replaced
+                tags = COALESCE(places.tags, '{}'::jsonb) || EXCLUDED.tags,
+                is_active = EXCLUDED.is_active,
+                timezone = EXCLUDED.timezone,
+                updated_at = NOW()
+            RETURNING
+                id, name, description, type,
+                ST_X(location) as longitude, ST_Y(location) as latitude,
+                address, city, district, postal_code,
+                phone, email, website,
+                google_place_id, google_place_url,
+                google_rating, google_rating_count, price_level,
+                main_categories, secondary_categories, cuisine_types,
+                tags, vibe_descriptor, suitable_for,
+                opening_hours, is_open_now,
+                is_subscribed, subscription_tier, subscription_expires_at, owner_id,
+                is_active, business_status,
+                created_at, updated_at, last_verified_at,
+                (xmax = 0) AS inserted
         "#;
 
-        let inserted = sqlx::query_as::<_, (Uuid,)>(insert_sql)
+        let row = sqlx::query_as::<_, UpsertRow>(upsert_sql)
             .bind(&req.name)
             .bind(&req.description)
             .bind(&req.type_)
@@ -214,7 +320,7 @@ impl PlaceRepository {
             .bind(&req.postal_code)
             .bind(&req.phone)
             .bind(&req.website)
-            .bind(&req.google_place_id)
+            .bind(google_id)
             .bind(&req.google_place_url)
             .bind(&req.google_rating)
             .bind(&req.google_rating_count)
@@ -226,81 +332,273 @@ impl PlaceRepository {
             .bind(&req.is_open_now)
             .bind(&req.business_status)
             .bind(&req.suitable_for)
-            .fetch_optional(pool)
+            .bind(&req.language)
+            .bind(&req.attributes)
+            .bind(GridGenerator::city_timezone(&req.city))
+            .fetch_one(pool)
             .await
             .map_err(|e| {
                 log::error!("Failed to upsert place {}: {}", google_id, e);
                 PlacesError::DatabaseError(e.to_string())
             })?;
 
-        if let Some((id,)) = inserted {
-            let place = Self::get_by_id(pool, id).await?;
-            return Ok((place, true));
+        let (place, created) = (row.place.to_place(), row.inserted);
+        let is_active = place.is_active.unwrap_or(true);
+        let was_active = previous.as_ref().map(|previous| previous.is_active.unwrap_or(true));
+        let changes = match (&previous, created) {
+            (Some(previous), false) => previous.changes_to(&place),
+            _ => Vec::new(),
+        };
+        let upserted = UpsertedPlace {
+            changes,
+            created,
+            deactivated: !created && was_active == Some(true) && !is_active,
+            reactivated: !created && was_active == Some(false) && is_active,
+            place,
+        };
+        if upserted.deactivated {
+            log::info!("Deactivated {} ({}): permanently closed", upserted.place.name, google_id);
+        } else if upserted.reactivated {
+            log::info!("Reactivated {} ({})", upserted.place.name, google_id);
         }
+        Ok(upserted)
+    }
 
-        // Update existing record
-        let updated_sql = r#"
-            UPDATE places
-            SET name = $1,
-                description = $2,
-                type = $3,
-                location = ST_SetSRID(ST_MakePoint($4, $5), 4326),
-                address = $6,
-                city = $7,
-                district = $8,
-                postal_code = $9,
-                phone = $10,
-                website = $11,
-                google_place_url = $12,
-                google_rating = $13,
-                google_rating_count = $14,
-                price_level = $15,
-                main_categories = $16,
-                secondary_categories = $17,
-                cuisine_types = $18,
-                opening_hours = $19,
-                is_open_now = $20,
-                business_status = $21,
-                suitable_for = $22,
-                is_active = true,
+    /// Upsert many Google places in one statement
+    /// DOCUMENTATION: Same semantics as `upsert_google_place` (field updates, tags
+    /// merge, CLOSED_PERMANENTLY deactivation, change report) but with two round
+    /// trips per batch instead of two per place: one read of the stored rows, which
+    /// drives both the change report and the activation flags, and one
+    /// INSERT ... SELECT FROM UNNEST(...) ON CONFLICT returning the written rows.
+    /// Results are in request order. Every request needs a google_place_id and ids
+    /// must be unique within the batch (one statement cannot update a row twice);
+    /// a database error fails the whole batch
+    pub async fn bulk_upsert(
+        pool: &PgPool,
+        places: &[CreatePlaceRequest],
+    ) -> Result<Vec<UpsertedPlace>, PlacesError> {
+        if places.is_empty() {
+            return Ok(Vec::new());
+        }
+        let columns = BulkUpsertColumns::from_requests(places)?;
+
+        let previous = Self::fetch_by_google_ids(pool, &columns.google_place_id).await?;
+
+        let upsert_sql = r#"
+            WITH input AS (
+                SELECT * FROM UNNEST(
+                    $1::text[], $2::text[], $3::text[], $4::float8[], $5::float8[],
+                    $6::text[], $7::text[], $8::text[], $9::text[], $10::text[], $11::text[],
+                    $12::text[], $13::text[], $14::float4[], $15::int4[], $16::int4[],
+                    $17::jsonb[], $18::jsonb[], $19::jsonb[],
+                    $20::jsonb[], $21::bool[], $22::text[], $23::jsonb[],
+                    $24::text[], $25::jsonb[], $26::text[]
+                ) WITH ORDINALITY AS t(
+                    name, description, type, longitude, latitude,
+                    address, city, district, postal_code, phone, website,
+                    google_place_id, google_place_url, google_rating, google_rating_count, price_level,
+                    main_categories, secondary_categories, cuisine_types,
+                    opening_hours, is_open_now, business_status, suitable_for,
+                    language, attributes, timezone, ordinal
+                )
+            )
+            INSERT INTO places (
+                name, description, type, location, address,
+                city, district, postal_code, phone, website,
+                google_place_id, google_place_url, google_rating, google_rating_count, price_level,
+                main_categories, secondary_categories, cuisine_types,
+                opening_hours, is_open_now, business_status, suitable_for,
+                tags, is_active, timezone, created_at, updated_at
+            )
+            SELECT
+                name, description, type,
+                ST_SetSRID(ST_MakePoint(longitude, latitude), 4326),
+                address, city, district, postal_code, phone, website,
+                google_place_id, google_place_url, google_rating, google_rating_count, price_level,
+                ARRAY(SELECT jsonb_array_elements_text(main_categories)),
+                ARRAY(SELECT jsonb_array_elements_text(secondary_categories)),
+                ARRAY(SELECT jsonb_array_elements_text(cuisine_types)),
+                opening_hours, is_open_now, business_status,
+                ARRAY(SELECT jsonb_array_elements_text(suitable_for)),
+                jsonb_strip_nulls(jsonb_build_object('language', language))
+                    || COALESCE(attributes, '{}'::jsonb),
+                business_status IS DISTINCT FROM 'CLOSED_PERMANENTLY',
+                timezone,
+                NOW(), NOW()
+            FROM input
+            ORDER BY ordinal
+            ON CONFLICT (google_place_id) DO UPDATE
+            SET name = EXCLUDED.name,
+                -- Google's editorial summary only fills an empty description
+                description = COALESCE(NULLIF(places.description, ''), EXCLUDED.description),
+                type = EXCLUDED.type,
+                location = EXCLUDED.location,
+                address = EXCLUDED.address,
+                city = EXCLUDED.city,
+                -- Only Place Details reports these; a Nearby Search refresh keeps them
+                district = COALESCE(EXCLUDED.district, places.district),
+                postal_code = COALESCE(EXCLUDED.postal_code, places.postal_code),
+                phone = COALESCE(EXCLUDED.phone, places.phone),
+                website = COALESCE(EXCLUDED.website, places.website),
+                google_place_url = EXCLUDED.google_place_url,
+                google_rating = EXCLUDED.google_rating,
+                google_rating_count = EXCLUDED.google_rating_count,
+                price_level = EXCLUDED.price_level,
+                main_categories = EXCLUDED.main_categories,
+                secondary_categories = EXCLUDED.secondary_categories,
+                cuisine_types = EXCLUDED.cuisine_types,
+                -- Nearby Search hours only carry open_now: keep the stored periods then
+                opening_hours = CASE
+                    WHEN jsonb_typeof(EXCLUDED.opening_hours -> 'periods') = 'array'
+                        THEN EXCLUDED.opening_hours
+                    ELSE NULLIF(
+                        COALESCE(places.opening_hours, '{}'::jsonb)
+                            || jsonb_strip_nulls(COALESCE(EXCLUDED.opening_hours, '{}'::jsonb)),
+                        '{}'::jsonb
+                    )
+                END,
+                is_open_now = EXCLUDED.is_open_now,
+                business_status = EXCLUDED.business_status,
+                suitable_for = EXCLUDED.suitable_for,
+                -- Keep other tags; language and reported attributes are replaced
+                tags = COALESCE(places.tags, '{}'::jsonb) || EXCLUDED.tags,
+                is_active = EXCLUDED.is_active,
+                timezone = EXCLUDED.timezone,
                 updated_at = NOW()
-            WHERE google_place_id = $23
-            RETURNING id
+            RETURNING
+                id, name, description, type,
+                ST_X(location) as longitude, ST_Y(location) as latitude,
+                address, city, district, postal_code,
+                phone, email, website,
+                google_place_id, google_place_url,
+                google_rating, google_rating_count, price_level,
+                main_categories, secondary_categories, cuisine_types,
+                tags, vibe_descriptor, suitable_for,
+                opening_hours, is_open_now,
+                is_subscribed, subscription_tier, subscription_expires_at, owner_id,
+                is_active, business_status,
+                created_at, updated_at, last_verified_at,
+                (xmax = 0) AS inserted
         "#;
 
-        let updated = sqlx::query_as::<_, (Uuid,)>(updated_sql)
-            .bind(&req.name)
-            .bind(&req.description)
-            .bind(&req.type_)
-            .bind(req.location[0])
-            .bind(req.location[1])
-            .bind(&req.address)
-            .bind(&req.city)
-            .bind(&req.district)
-            .bind(&req.postal_code)
-            .bind(&req.phone)
-            .bind(&req.website)
-            .bind(&req.google_place_url)
-            .bind(&req.google_rating)
-            .bind(&req.google_rating_count)
-            .bind(&req.price_level)
-            .bind(&req.main_categories)
-            .bind(&req.secondary_categories)
-            .bind(&req.cuisine_types)
-            .bind(&req.opening_hours)
-            .bind(&req.is_open_now)
-            .bind(&req.business_status)
-            .bind(&req.suitable_for)
-            .bind(google_id)
-            .fetch_one(pool)
+        let rows = sqlx::query_as::<_, UpsertRow>(upsert_sql)
+            .bind(&columns.name)
+            .bind(&columns.description)
+            .bind(&columns.type_)
+            .bind(&columns.longitude)
+            .bind(&columns.latitude)
+            .bind(&columns.address)
+            .bind(&columns.city)
+            .bind(&columns.district)
+            .bind(&columns.postal_code)
+            .bind(&columns.phone)
+            .bind(&columns.website)
+            .bind(&columns.google_place_id)
+            .bind(&columns.google_place_url)
+            .bind(&columns.google_rating)
+            .bind(&columns.google_rating_count)
+            .bind(&columns.price_level)
+            .bind(&columns.main_categories)
+            .bind(&columns.secondary_categories)
+            .bind(&columns.cuisine_types)
+            .bind(&columns.opening_hours)
+            .bind(&columns.is_open_now)
+            .bind(&columns.business_status)
+            .bind(&columns.suitable_for)
+            .bind(&columns.language)
+            .bind(&columns.attributes)
+            .bind(&columns.timezone)
+            .fetch_all(pool)
             .await
             .map_err(|e| {
-                log::error!("Failed to update place {}: {}", google_id, e);
+                log::error!("Failed to bulk upsert {} places: {}", places.len(), e);
                 PlacesError::DatabaseError(e.to_string())
             })?;
 
-        let place = Self::get_by_id(pool, updated.0).await?;
-        Ok((place, false))
+        let mut outcomes: HashMap<String, UpsertRow> = rows
+            .into_iter()
+            .filter_map(|row| row.place.google_place_id.clone().map(|google_id| (google_id, row)))
+            .collect();
+
+        let mut upserted = Vec::with_capacity(places.len());
+        for google_id in &columns.google_place_id {
+            let row = outcomes.remove(google_id).ok_or_else(|| {
+                PlacesError::DatabaseError(format!("Bulk upsert returned no row for {}", google_id))
+            })?;
+            let (place, created) = (row.place.to_place(), row.inserted);
+            let is_active = place.is_active.unwrap_or(true);
+            let was_active = previous.get(google_id).map(|previous| previous.is_active.unwrap_or(true));
+            let changes = match (previous.get(google_id), created) {
+                (Some(previous), false) => previous.changes_to(&place),
+                _ => Vec::new(),
+            };
+            let row = UpsertedPlace {
+                changes,
+                created,
+                deactivated: !created && was_active == Some(true) && !is_active,
+                reactivated: !created && was_active == Some(false) && is_active,
+                place,
+            };
+            if row.deactivated {
+                log::info!("Deactivated {} ({}): permanently closed", row.place.name, google_id);
+            } else if row.reactivated {
+                log::info!("Reactivated {} ({})", row.place.name, google_id);
+            }
+            upserted.push(row);
+        }
+
+        log::debug!("Bulk upserted {} places", upserted.len());
+        Ok(upserted)
+    }
+
+    /// Stored places among `google_place_ids`, active or not, keyed by Google id
+    async fn fetch_by_google_ids(
+        pool: &PgPool,
+        google_place_ids: &[String],
+    ) -> Result<HashMap<String, Place>, PlacesError> {
+        let rows = sqlx::query_as::<_, PlaceRow>(
+            r#"
+            SELECT 
+                p.id, p.name, p.description, p.type,
+                ST_X(p.location) as longitude, ST_Y(p.location) as latitude,
+                p.address, p.city, p.district, p.postal_code,
+                p.phone, p.email, p.website, 
+                p.google_place_id, p.google_place_url,
+                p.google_rating, p.google_rating_count, p.price_level,
+                p.main_categories, p.secondary_categories, p.cuisine_types,
+                p.tags, p.vibe_descriptor, p.suitable_for,
+                p.opening_hours, p.is_open_now,
+                p.is_subscribed, p.subscription_tier, p.subscription_expires_at, p.owner_id,
+                p.is_active, p.business_status,
+                p.created_at, p.updated_at, p.last_verified_at,
+                photo.photo_url as primary_photo_url,
+                photo.thumbnail_url as primary_photo_thumbnail_url
+            FROM places p
+            LEFT JOIN LATERAL (
+                SELECT photo_url, thumbnail_url
+                FROM place_photos
+                WHERE place_id = p.id
+                ORDER BY is_primary DESC, display_order ASC, created_at ASC
+                LIMIT 1
+            ) photo ON true
+            WHERE p.google_place_id = ANY($1)
+            "#,
+        )
+        .bind(google_place_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Database error fetching places by Google id: {}", e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let place = row.to_place();
+                place.google_place_id.clone().map(|google_id| (google_id, place))
+            })
+            .collect())
     }
 
     /// Retrieve place by Google Place ID
@@ -308,6 +606,15 @@ impl PlaceRepository {
     pub async fn get_by_google_place_id(
         pool: &PgPool,
         google_place_id: &str,
+    ) -> Result<Place, PlacesError> {
+        Self::fetch_by_google_place_id(pool, google_place_id, false).await
+    }
+
+    /// Load a place by Google Place ID, optionally including deactivated places
+    pub async fn fetch_by_google_place_id(
+        pool: &PgPool,
+        google_place_id: &str,
+        include_inactive: bool,
     ) -> Result<Place, PlacesError> {
         let row = sqlx::query_as::<_, PlaceRow>(
             r#"
@@ -334,10 +641,11 @@ impl PlaceRepository {
                 ORDER BY is_primary DESC, display_order ASC, created_at ASC
                 LIMIT 1
             ) photo ON true
-            WHERE p.google_place_id = $1 AND p.is_active = true
+            WHERE p.google_place_id = $1 AND ($2 OR p.is_active = true)
             "#,
         )
         .bind(google_place_id)
+        .bind(include_inactive)
         .fetch_optional(pool)
         .await
         .map_err(|e| {
@@ -352,10 +660,16 @@ impl PlaceRepository {
         Ok(row.to_place())
     }
 
-    /// Retrieve place by ID
-    /// DOCUMENTATION: Used for GET /places/{id} endpoint
-    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Place, PlacesError> {
-        let row = sqlx::query_as::<_, PlaceRow>(
+    /// Retrieve active places by UUID or Google place id in one query
+    /// DOCUMENTATION: Used by POST /places/batch. The primary photo comes from the same
+    /// LATERAL join as single lookups, so no per-place photo query is needed. Rows come
+    /// back in no particular order
+    pub async fn get_many(
+        pool: &PgPool,
+        ids: &[Uuid],
+        google_place_ids: &[String],
+    ) -> Result<Vec<Place>, PlacesError> {
+        let rows = sqlx::query_as::<_, PlaceRow>(
             r#"
             SELECT 
                 p.id, p.name, p.description, p.type,
@@ -380,37 +694,31 @@ impl PlaceRepository {
                 ORDER BY is_primary DESC, display_order ASC, created_at ASC
                 LIMIT 1
             ) photo ON true
-            WHERE p.id = $1 AND p.is_active = true
+            WHERE (p.id = ANY($1) OR p.google_place_id = ANY($2)) AND p.is_active = true
             "#,
         )
-        .bind(id)
-        .fetch_optional(pool)
+        .bind(ids)
+        .bind(google_place_ids)
+        .fetch_all(pool)
         .await
         .map_err(|e| {
-            log::error!("Database error fetching place: {}", e);
+            log::error!("Failed to get places in batch: {}", e);
             PlacesError::DatabaseError(e.to_string())
-        })?
-        .ok_or_else(|| {
-            log::warn!("Place not found: {}", id);
-            PlacesError::NotFound(id.to_string())
         })?;
 
-        Ok(row.to_place())
+        Ok(rows.into_iter().map(|r| r.to_place()).collect())
     }
 
-    /// Search places with full-text and filters
-    /// DOCUMENTATION: Used for GET /places/search endpoint
-    /// Returns tuple: (results, total_count) for pagination
-    pub async fn search(
-        pool: &PgPool,
-        query: &SearchQuery,
-    ) -> Result<(Vec<Place>, i64), PlacesError> {
-        let limit = query.limit.unwrap_or(20).min(100);
-        let page = query.page.unwrap_or(1).max(1);
-        let offset = (page - 1) * limit;
+    /// Retrieve place by ID
+    /// DOCUMENTATION: Used for GET /places/{id} endpoint
+    pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Place, PlacesError> {
+        Self::fetch_by_id(pool, id, false).await
+    }
 
-        // Build base SELECT with PostGIS coordinate extraction
-        let select_clause = r#"
+    /// Load a place by ID, optionally including deactivated places
+    pub async fn fetch_by_id(pool: &PgPool, id: Uuid, include_inactive: bool) -> Result<Place, PlacesError> {
+        let row = sqlx::query_as::<_, PlaceRow>(
+            r#"
             SELECT 
                 p.id, p.name, p.description, p.type,
                 ST_X(p.location) as longitude, ST_Y(p.location) as latitude,
@@ -434,183 +742,2770 @@ impl PlaceRepository {
                 ORDER BY is_primary DESC, display_order ASC, created_at ASC
                 LIMIT 1
             ) photo ON true
-        "#;
+            WHERE p.id = $1 AND ($2 OR p.is_active = true)
+            "#,
+        )
+        .bind(id)
+        .bind(include_inactive)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Database error fetching place: {}", e);
+            PlacesError::DatabaseError(e.to_string())
+        })?
+        .ok_or_else(|| {
+            log::warn!("Place not found: {}", id);
+            PlacesError::NotFound(id.to_string())
+        })?;
+
+        Ok(row.to_place())
+    }
+
+    /// Append the WHERE clause of a search query to `builder`
+    /// DOCUMENTATION: Shared by the count and page queries of `search` so both always
+    /// filter the same rows. Every user-supplied value is sent as a bound parameter;
+    /// only fixed SQL fragments are pushed as text. Free of I/O so the assembly can be
+    /// benchmarked and tested without a database
+    pub fn push_search_filters<'q>(builder: &mut QueryBuilder<'q, Postgres>, query: &'q SearchQuery) {
+        Self::push_matching_filters(builder, query, NameMatch::default());
+    }
 
-        // Build dynamic query based on provided filters
-        let mut where_clauses = vec!["p.is_active = true".to_string()];
+    /// Append the WHERE clause of a search query, matching `q` as `name_match`
+    pub fn push_matching_filters<'q>(
+        builder: &mut QueryBuilder<'q, Postgres>,
+        query: &'q SearchQuery,
+        name_match: NameMatch<'_>,
+    ) {
+        // Admin searches (include_inactive) also see deactivated places
+        if query.include_inactive {
+            builder.push(" WHERE true");
+        } else {
+            builder.push(" WHERE p.is_active = true");
+        }
 
-        // Full-text search
+        // Full-text search, or trigram similarity of the name for misspelled queries
         if let Some(q) = &query.q {
-            where_clauses.push(format!(
-                "p.search_vector @@ plainto_tsquery('english', '{}')",
-                q.replace("'", "''")
-            ));
+            match name_match {
+                // Same configuration and unaccenting as the search_vector trigger (migration 014)
+                NameMatch::FullText { language } => {
+                    builder.push(" AND p.search_vector @@ plainto_tsquery(");
+                    builder.push_bind(language.to_string());
+                    builder.push("::regconfig, places_unaccent(");
+                    builder.push_bind(q.as_str());
+                    builder.push("))");
+                }
+                NameMatch::Fuzzy { threshold } => {
+                    builder.push(" AND similarity(p.name, ");
+                    builder.push_bind(q.as_str());
+                    builder.push(") > ");
+                    builder.push_bind(threshold);
+                }
+            }
         }
 
         // City filter
         if let Some(city) = &query.city {
-            where_clauses.push(format!("p.city ILIKE '%{}%'", city.replace("'", "''")));
+            builder.push(" AND p.city ILIKE '%' || ");
+            builder.push_bind(city.as_str());
+            builder.push(" || '%'");
         }
 
         // District filter
         if let Some(district) = &query.district {
-            where_clauses.push(format!(
-                "p.district ILIKE '%{}%'",
-                district.replace("'", "''")
-            ));
+            builder.push(" AND p.district ILIKE '%' || ");
+            builder.push_bind(district.as_str());
+            builder.push(" || '%'");
         }
 
         // Type filter
         if let Some(type_) = &query.type_ {
-            where_clauses.push(format!("p.type = '{}'", type_.replace("'", "''")));
+            builder.push(" AND p.type = ");
+            builder.push_bind(type_.as_str());
         }
 
-        // Geographic proximity
-        if let (Some(lat), Some(lon), Some(radius_km)) = (query.lat, query.lon, query.radius_km) {
-            where_clauses.push(format!(
-                "ST_DWithin(p.location::geography, ST_SetSRID(ST_MakePoint({}, {}), 4326)::geography, {})",
-                lon, lat, radius_km * 1000.0
-            ));
+        // Cuisine filter: any of the known cuisines (unknown ones were dropped)
+        let cuisines = query.known_cuisines();
+        if !cuisines.is_empty() {
+            builder.push(" AND p.cuisine_types && ");
+            builder.push_bind(cuisines);
         }
 
-        // Rating filter
-        if let Some(min_rating) = query.min_rating {
-            where_clauses.push(format!("p.google_rating >= {}", min_rating));
+        // Audience filter: suitable for any of the requested tags
+        let suitable_for = query.suitable_for_tags();
+        if !suitable_for.is_empty() {
+            builder.push(" AND p.suitable_for && ");
+            builder.push_bind(suitable_for);
         }
 
-        let where_clause = format!("WHERE {}", where_clauses.join(" AND "));
+        // Google category filters on either category array. Exclusion wins: a place
+        // with both an included and an excluded category is left out
+        let categories = query.included_categories();
+        if !categories.is_empty() {
+            builder.push(" AND (p.main_categories && ");
+            builder.push_bind(categories.clone());
+            builder.push(" OR p.secondary_categories && ");
+            builder.push_bind(categories);
+            builder.push(")");
+        }
 
-        // Get total count
-        let count_sql = format!("SELECT COUNT(*) FROM places p {}", where_clause);
-        let count_result: (i64,) =
-            sqlx::query_as(&count_sql)
-                .fetch_one(pool)
-                .await
-                .map_err(|e| {
-                    log::error!("Count query error: {}", e);
-                    PlacesError::DatabaseError(e.to_string())
-                })?;
+        // Tag containment, one check per expression (the service rejects invalid ones)
+        for tag in query.tag_filters().unwrap_or_default() {
+            builder.push(" AND p.tags @> ");
+            builder.push_bind(tag);
+            builder.push("::jsonb");
+        }
 
-        let total = count_result.0;
+        let excluded = query.excluded_categories();
+        if !excluded.is_empty() {
+            builder.push(" AND NOT (COALESCE(p.main_categories && ");
+            builder.push_bind(excluded.clone());
+            builder.push(", false) OR COALESCE(p.secondary_categories && ");
+            builder.push_bind(excluded);
+            builder.push(", false))");
+        }
 
-        // Build final query with ordering and pagination
-        let sql = format!(
-            "{} {} ORDER BY p.google_rating DESC NULLS LAST LIMIT {} OFFSET {}",
-            select_clause, where_clause, limit, offset
-        );
+        // Geographic proximity
+        if let (Some(lat), Some(lon), Some(radius_km)) = (query.lat, query.lon, query.radius_km) {
+            builder.push(" AND ST_DWithin(p.location::geography, ST_SetSRID(ST_MakePoint(");
+            builder.push_bind(lon);
+            builder.push(", ");
+            builder.push_bind(lat);
+            builder.push("), 4326)::geography, ");
+            builder.push_bind(radius_km * 1000.0);
+            builder.push(")");
+        }
 
-        log::debug!("Executing search query: {}", sql);
+        // Map viewport; the bounding-box operator can use the GIST index on location.
+        // The service rejects invalid boxes and boxes combined with a radius
+        if let Ok(Some(bbox)) = query.bounding_box() {
+            builder.push(" AND p.location && ST_MakeEnvelope(");
+            builder.push_bind(bbox.sw_lng);
+            builder.push(", ");
+            builder.push_bind(bbox.sw_lat);
+            builder.push(", ");
+            builder.push_bind(bbox.ne_lng);
+            builder.push(", ");
+            builder.push_bind(bbox.ne_lat);
+            builder.push(", 4326)");
+        }
 
-        let rows = sqlx::query_as::<_, PlaceRow>(&sql)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| {
-                log::error!("Search query error: {}", e);
-                PlacesError::DatabaseError(e.to_string())
-            })?;
+        // Rating filter
+        if let Some(min_rating) = query.min_rating {
+            builder.push(" AND p.google_rating >= ");
+            builder.push_bind(min_rating);
+        }
 
-        let places: Vec<Place> = rows.into_iter().map(|r| r.to_place()).collect();
+        if let Some(min_count) = query.min_rating_count {
+            builder.push(" AND p.google_rating_count >= ");
+            builder.push_bind(min_count);
+        }
 
-        log::info!(
-            "Search completed: {} results, {} total (page {}/{})",
-            places.len(),
-            total,
-            page,
-            (total + limit - 1) / limit
-        );
+        // Opening hours evaluated now in the place's IANA time zone (migration 018);
+        // a place without a known zone cannot be shown to be open, so it does not match
+        if query.open_now == Some(true) {
+            builder.push(" AND COALESCE(place_is_open_at(p.opening_hours, p.timezone, NOW()), false)");
+        }
 
-        Ok((places, total))
+        // A NULL price_level never compares true, so unpriced places drop out as soon
+        // as either bound is given
+        if let Some(min_price) = query.min_price {
+            builder.push(" AND p.price_level >= ");
+            builder.push_bind(min_price);
+        }
+
+        if let Some(max_price) = query.max_price {
+            builder.push(" AND p.price_level <= ");
+            builder.push_bind(max_price);
+        }
     }
 
-    /// Update existing place
-    /// DOCUMENTATION: Partial update - only provided fields are modified
-    pub async fn update_place(
-        pool: &PgPool,
-        id: Uuid,
-        req: &UpdatePlaceRequest,
-    ) -> Result<Place, PlacesError> {
-        // Verify place exists
-        let _ = Self::get_by_id(pool, id).await?;
+    /// Append the ORDER BY of a search page
+    /// DOCUMENTATION: Only these whitelisted fragments reach the SQL; distance orders
+    /// by the bound `distance_km` column of the page query. NULLs go last in both directions and `p.id` breaks ties so
+    /// pages stay stable. Without a sort (or for distance without coordinates, which
+    /// the service rejects) the highest rated places come first
+    pub fn push_search_order(builder: &mut QueryBuilder<'_, Postgres>, query: &SearchQuery) {
+        let sort = query.sort.as_deref().and_then(SearchSort::parse);
+        let order = query
+            .order
+            .as_deref()
+            .and_then(SortOrder::parse)
+            .unwrap_or_else(|| sort.map_or(SortOrder::Desc, SearchSort::default_order));
 
-        let updated: (Uuid,) = sqlx::query_as(
-            r#"
-            UPDATE places
-            SET name = COALESCE($1, name),
-                description = COALESCE($2, description),
-                tags = COALESCE($3, tags),
-                vibe_descriptor = COALESCE($4, vibe_descriptor),
-                opening_hours = COALESCE($5, opening_hours),
-                google_rating = COALESCE($6, google_rating),
-                business_status = COALESCE($7, business_status),
-                updated_at = NOW()
-            WHERE id = $8
-            RETURNING id
-            "#,
-        )
-        .bind(&req.name)
-        .bind(&req.description)
-        .bind(&req.tags)
-        .bind(&req.vibe_descriptor)
-        .bind(&req.opening_hours)
-        .bind(req.google_rating)
-        .bind(&req.business_status)
-        .bind(id)
-        .fetch_one(pool)
-        .await
-        .map_err(|e| {
-            log::error!("Update failed for place {}: {}", id, e);
-            PlacesError::DatabaseError(e.to_string())
-        })?;
+        builder.push(" ORDER BY ");
+        match (sort, query.lat, query.lon) {
+            // Column selected by search_page_query whenever lat/lon are present
+            (Some(SearchSort::Distance), Some(_), Some(_)) => {
+                builder.push("distance_km");
+            }
+            (Some(SearchSort::RatingCount), _, _) => {
+                builder.push("p.google_rating_count");
+            }
+            (Some(SearchSort::CreatedAt), _, _) => {
+                builder.push("p.created_at");
+            }
+            (Some(SearchSort::Name), _, _) => {
+                builder.push("lower(p.name)");
+            }
+            _ => {
+                builder.push("p.google_rating");
+            }
+        }
+        builder.push(" ");
+        builder.push(order.as_sql());
+        builder.push(" NULLS LAST, p.id ASC");
+    }
 
-        let place = Self::get_by_id(pool, updated.0).await?;
+    /// Query counting every row a search matches
+    pub fn search_count_query(query: &SearchQuery) -> QueryBuilder<'_, Postgres> {
+        Self::search_count_query_matching(query, NameMatch::default())
+    }
 
-        log::info!("Updated place: {}", id);
-        Ok(place)
+    /// Query counting every row a search matches with `q` matched as `name_match`
+    pub fn search_count_query_matching<'q>(
+        query: &'q SearchQuery,
+        name_match: NameMatch<'_>,
+    ) -> QueryBuilder<'q, Postgres> {
+        let mut builder = QueryBuilder::new("SELECT COUNT(*) FROM places p");
+        Self::push_matching_filters(&mut builder, query, name_match);
+        builder
     }
 
-    /// Soft delete place
-    /// DOCUMENTATION: Sets is_active=false instead of physical deletion
-    pub async fn delete_place(pool: &PgPool, id: Uuid) -> Result<(), PlacesError> {
-        let rows =
-            sqlx::query("UPDATE places SET is_active = false, updated_at = NOW() WHERE id = $1")
-                .bind(id)
-                .execute(pool)
+    /// Query counting search matches per value of `column`
+    /// DOCUMENTATION: Same WHERE clause as the count query; NULL values are left
+    /// out and only the `max_values` most frequent values are returned
+    pub fn search_facet_query<'q>(
+        query: &'q SearchQuery,
+        name_match: NameMatch<'_>,
+        column: FacetColumn,
+        max_values: i64,
+    ) -> QueryBuilder<'q, Postgres> {
+        let column = column.column();
+        let mut builder = QueryBuilder::new(format!("SELECT {}::text AS value, COUNT(*) AS count FROM places p", column));
+        Self::push_matching_filters(&mut builder, query, name_match);
+        builder.push(format!(" AND {} IS NOT NULL GROUP BY 1 ORDER BY 2 DESC, 1 ASC LIMIT ", column));
+        builder.push_bind(max_values);
+        builder
+    }
+
+    /// Facet counts of a search, `q` matched as `name_match`
+    /// DOCUMENTATION: Runs the three facet queries concurrently
+    pub async fn search_facets(
+        pool: &PgPool,
+        query: &SearchQuery,
+        name_match: NameMatch<'_>,
+        max_values: i64,
+    ) -> Result<SearchFacets, PlacesError> {
+        let count = |column: FacetColumn| async move {
+            Self::search_facet_query(query, name_match, column, max_values)
+                .build_query_as::<(String, i64)>()
+                .fetch_all(pool)
                 .await
+                .map(|rows| rows.into_iter().collect())
                 .map_err(|e| {
-                    log::error!("Delete failed for place {}: {}", id, e);
+                    log::error!("Facet query error: {}", e);
                     PlacesError::DatabaseError(e.to_string())
-                })?
-                .rows_affected();
+                })
+        };
+        let (types, districts, price_levels) = tokio::try_join!(
+            count(FacetColumn::Type),
+            count(FacetColumn::District),
+            count(FacetColumn::PriceLevel)
+        )?;
 
-        if rows == 0 {
-            return Err(PlacesError::NotFound(id.to_string()));
-        }
+        Ok(SearchFacets { types, districts, price_levels })
+    }
 
-        log::info!("Deleted place: {}", id);
-        Ok(())
+    /// Query for one page of search results, best rated first
+    pub fn search_page_query(query: &SearchQuery, limit: i64, offset: i64) -> QueryBuilder<'_, Postgres> {
+        Self::search_page_query_matching(query, NameMatch::default(), limit, offset)
     }
 
-    /// Bulk insert places (used by Google sync)
-    /// DOCUMENTATION: Efficiently insert multiple places
-    /// Returns count of successfully inserted places
-    #[allow(dead_code)]
-    pub async fn bulk_insert(
-        pool: &PgPool,
-        places: Vec<CreatePlaceRequest>,
-    ) -> Result<u64, PlacesError> {
-        let mut count = 0;
-
-        for place in places {
-            // In a real high-perf scenario, we'd use COPY or build a large VALUES statement.
-            // For now, loop inserts are safer for error handling per item.
-            match Self::create_place(pool, &place).await {
-                Ok(_) => count += 1,
-                Err(e) => {
-                    log::warn!("Failed to insert place {}: {}", place.name, e);
-                }
+    /// Query for one page of search results with `q` matched as `name_match`
+    /// DOCUMENTATION: Without an explicit sort, fuzzy pages put the closest names first
+    pub fn search_page_query_matching<'q>(
+        query: &'q SearchQuery,
+        name_match: NameMatch<'_>,
+        limit: i64,
+        offset: i64,
+    ) -> QueryBuilder<'q, Postgres> {
+        let mut builder = Self::place_page_select(query);
+        Self::push_matching_filters(&mut builder, query, name_match);
+        match (name_match, query.q.as_deref(), query.sort.as_deref()) {
+            (NameMatch::Fuzzy { .. }, Some(q), None) => {
+                builder.push(" ORDER BY similarity(p.name, ");
+                builder.push_bind(q);
+                builder.push(") DESC, p.google_rating DESC NULLS LAST, p.id ASC");
             }
+            _ => Self::push_search_order(&mut builder, query),
         }
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+        builder
+    }
+
+    /// SELECT and FROM of a page of places, up to the WHERE clause
+    /// DOCUMENTATION: Includes the primary photo, plus `distance_km` when the query has lat/lon
+    fn place_page_select(query: &SearchQuery) -> QueryBuilder<'_, Postgres> {
+        // Base SELECT with PostGIS coordinate extraction
+        let mut builder = QueryBuilder::new(
+            r#"
+            SELECT 
+                p.id, p.name, p.description, p.type,
+                ST_X(p.location) as longitude, ST_Y(p.location) as latitude,
+                p.address, p.city, p.district, p.postal_code,
+                p.phone, p.email, p.website, 
+                p.google_place_id, p.google_place_url,
+                p.google_rating, p.google_rating_count, p.price_level,
+                p.main_categories, p.secondary_categories, p.cuisine_types,
+                p.tags, p.vibe_descriptor, p.suitable_for,
+                p.opening_hours, p.is_open_now,
+                p.is_subscribed, p.subscription_tier, p.subscription_expires_at, p.owner_id,
+                p.is_active, p.business_status,
+                p.created_at, p.updated_at, p.last_verified_at,
+                photo.photo_url as primary_photo_url,
+                photo.thumbnail_url as primary_photo_thumbnail_url
+            "#,
+        );
+        // Distance from the search point, bound like the radius filter
+        if let (Some(lat), Some(lon)) = (query.lat, query.lon) {
+            builder.push("    , ST_Distance(p.location::geography, ST_SetSRID(ST_MakePoint(");
+            builder.push_bind(lon);
+            builder.push(", ");
+            builder.push_bind(lat);
+            builder.push("), 4326)::geography) / 1000.0 AS distance_km");
+        }
+        builder.push(
+            r#"
+            FROM places p
+            LEFT JOIN LATERAL (
+                SELECT photo_url, thumbnail_url
+                FROM place_photos
+                WHERE place_id = p.id
+                ORDER BY is_primary DESC, display_order ASC, created_at ASC
+                LIMIT 1
+            ) photo ON true
+            "#,
+        );
+        builder
+    }
+
+    /// WHERE clause of a city listing: active places in exactly `city`
+    /// DOCUMENTATION: Case-insensitive equality rather than the substring match of
+    /// search, so "Zaragoza" does not list "Zaragoza Norte". `query` contributes only
+    /// its type filter
+    fn push_city_filters<'q>(builder: &mut QueryBuilder<'q, Postgres>, city: &'q str, query: &'q SearchQuery) {
+        builder.push(" WHERE p.is_active = true AND lower(p.city) = lower(");
+        builder.push_bind(city);
+        builder.push(")");
+
+        if let Some(type_) = &query.type_ {
+            builder.push(" AND p.type = ");
+            builder.push_bind(type_.as_str());
+        }
+    }
+
+    /// Query counting the active places of a city
+    pub fn city_count_query<'q>(city: &'q str, query: &'q SearchQuery) -> QueryBuilder<'q, Postgres> {
+        let mut builder = QueryBuilder::new("SELECT COUNT(*) FROM places p");
+        Self::push_city_filters(&mut builder, city, query);
+        builder
+    }
+
+    /// Query for one page of a city listing, ordered like search
+    pub fn city_page_query<'q>(
+        city: &'q str,
+        query: &'q SearchQuery,
+        limit: i64,
+        offset: i64,
+    ) -> QueryBuilder<'q, Postgres> {
+        let mut builder = Self::place_page_select(query);
+        Self::push_city_filters(&mut builder, city, query);
+        Self::push_search_order(&mut builder, query);
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+        builder
+    }
+
+    /// Query grouping the places matching `query` into a grid of `cell_size` degrees
+    /// DOCUMENTATION: One row per non-empty cell (ST_SnapToGrid), largest first
+    pub fn cluster_query(query: &SearchQuery, cell_size: f64) -> QueryBuilder<'_, Postgres> {
+        let mut builder = QueryBuilder::new(
+            r#"
+            SELECT
+                AVG(ST_Y(p.location)) AS latitude, AVG(ST_X(p.location)) AS longitude,
+                COUNT(*) AS count,
+                CASE WHEN COUNT(*) = 1 THEN (array_agg(p.id))[1] END AS place_id
+            FROM places p"#,
+        );
+        Self::push_search_filters(&mut builder, query);
+        builder.push(" GROUP BY ST_SnapToGrid(p.location, ");
+        builder.push_bind(cell_size);
+        builder.push(") ORDER BY count DESC, latitude ASC, longitude ASC");
+        builder
+    }
+
+    /// Grid clusters of the places matching `query`
+    /// DOCUMENTATION: Used for GET /places/clusters
+    pub async fn clusters(pool: &PgPool, query: &SearchQuery, cell_size: f64) -> Result<Vec<ClusterRow>, PlacesError> {
+        Self::cluster_query(query, cell_size)
+            .build_query_as::<ClusterRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Cluster query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })
+    }
+
+    /// Query for one page of featured places in a seeded shuffle
+    /// DOCUMENTATION: Ordered by a hash of the place id and `seed`, so a seed always
+    /// gives the same order and consecutive pages never overlap. `query` carries the
+    /// type and quality filters; `city` is matched exactly, ignoring case
+    pub fn featured_query<'q>(
+        city: Option<&'q str>,
+        query: &'q SearchQuery,
+        seed: u32,
+        limit: i64,
+        offset: i64,
+    ) -> QueryBuilder<'q, Postgres> {
+        let mut builder = Self::place_page_select(query);
+        Self::push_search_filters(&mut builder, query);
+        if let Some(city) = city {
+            builder.push(" AND lower(p.city) = lower(");
+            builder.push_bind(city);
+            builder.push(")");
+        }
+        builder.push(" ORDER BY md5(p.id::text || ':' || ");
+        builder.push_bind(i64::from(seed));
+        builder.push("::text), p.id ASC LIMIT ");
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+        builder
+    }
+
+    /// One page of featured places
+    /// DOCUMENTATION: Used for GET /places/featured
+    pub async fn featured(
+        pool: &PgPool,
+        city: Option<&str>,
+        query: &SearchQuery,
+        seed: u32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Place>, PlacesError> {
+        let rows = Self::featured_query(city, query, seed, limit, offset)
+            .build_query_as::<PlaceRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Featured query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().map(|r| r.to_place()).collect())
+    }
+
+    /// Query for typeahead suggestions matching `q`
+    /// DOCUMENTATION: Matches the start of the name or of any word in it, ignoring
+    /// case and accents (places_name_key, migration 016). `q` is bound with its LIKE
+    /// wildcards escaped. Whole-name prefixes rank first, then busier places
+    pub fn suggest_query<'q>(q: &str, city: Option<&'q str>, limit: i64) -> QueryBuilder<'q, Postgres> {
+        let pattern = escape_like(q);
+        let mut builder = QueryBuilder::new(
+            r#"
+            SELECT p.id, p.name, p.type, p.district, photo.thumbnail_url
+            FROM places p
+            LEFT JOIN LATERAL (
+                SELECT COALESCE(thumbnail_url, photo_url) AS thumbnail_url
+                FROM place_photos
+                WHERE place_id = p.id
+                ORDER BY is_primary DESC, display_order ASC, created_at ASC
+                LIMIT 1
+            ) photo ON true
+            WHERE p.is_active = true"#,
+        );
+        builder.push(" AND (places_name_key(p.name) LIKE places_name_key(");
+        builder.push_bind(pattern.clone());
+        builder.push(") || '%' OR places_name_key(p.name) LIKE '% ' || places_name_key(");
+        builder.push_bind(pattern.clone());
+        builder.push(") || '%')");
+        if let Some(city) = city {
+            builder.push(" AND lower(p.city) = lower(");
+            builder.push_bind(city);
+            builder.push(")");
+        }
+        builder.push(" ORDER BY places_name_key(p.name) LIKE places_name_key(");
+        builder.push_bind(pattern);
+        builder.push(") || '%' DESC, p.google_rating_count DESC NULLS LAST, places_name_key(p.name) ASC, p.id ASC LIMIT ");
+        builder.push_bind(limit);
+        builder
+    }
+
+    /// Typeahead suggestions among active places
+    /// DOCUMENTATION: Used for GET /places/suggest
+    pub async fn suggest(
+        pool: &PgPool,
+        q: &str,
+        city: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<PlaceSuggestion>, PlacesError> {
+        Self::suggest_query(q, city, limit)
+            .build_query_as::<PlaceSuggestion>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Suggest query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })
+    }
+
+    /// Query for the places around `anchor_id`, ordered like search
+    /// DOCUMENTATION: `query` carries the anchor's coordinates, the radius, the type
+    /// filter and sort=distance; the anchor itself is excluded
+    pub fn nearby_query(anchor_id: Uuid, query: &SearchQuery, limit: i64) -> QueryBuilder<'_, Postgres> {
+        let mut builder = Self::place_page_select(query);
+        Self::push_search_filters(&mut builder, query);
+        builder.push(" AND p.id <> ");
+        builder.push_bind(anchor_id);
+        Self::push_search_order(&mut builder, query);
+        builder.push(" LIMIT ");
+        builder.push_bind(limit);
+        builder
+    }
+
+    /// Active places within `query.radius_km` of another place
+    /// DOCUMENTATION: Used for GET /places/{id}/nearby; `distance_km` is filled in
+    pub async fn nearby(
+        pool: &PgPool,
+        anchor_id: Uuid,
+        query: &SearchQuery,
+        limit: i64,
+    ) -> Result<Vec<Place>, PlacesError> {
+        let rows = Self::nearby_query(anchor_id, query, limit)
+            .build_query_as::<PlaceRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Nearby query error for {}: {}", anchor_id, e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().map(|r| r.to_place()).collect())
+    }
+
+    /// Query for the places most similar to `anchor`, best score first
+    /// DOCUMENTATION: `query` carries the anchor's coordinates, the radius and the
+    /// anchor's type, so candidates are active places of the same type nearby that
+    /// share at least one cuisine type or main category with the anchor; the anchor
+    /// itself is excluded. Candidates are ordered by the `weights` score, ties by id
+    pub fn similar_query<'q>(
+        anchor: &Place,
+        query: &'q SearchQuery,
+        weights: &SimilarityWeights,
+        limit: i64,
+    ) -> QueryBuilder<'q, Postgres> {
+        let mut builder = Self::place_page_select(query);
+        Self::push_search_filters(&mut builder, query);
+        builder.push(" AND p.id <> ");
+        builder.push_bind(anchor.id);
+        builder.push(" AND (p.cuisine_types && ");
+        builder.push_bind(anchor.cuisine_types.clone().unwrap_or_default());
+        builder.push("::text[] OR p.main_categories && ");
+        builder.push_bind(anchor.main_categories.clone().unwrap_or_default());
+        builder.push("::text[])");
+
+        builder.push(" ORDER BY (");
+        builder.push_bind(weights.shared_cuisine);
+        builder.push(" * cardinality(ARRAY(SELECT unnest(p.cuisine_types) INTERSECT SELECT unnest(");
+        builder.push_bind(anchor.cuisine_types.clone().unwrap_or_default());
+        builder.push("::text[]))) + ");
+        builder.push_bind(weights.shared_category);
+        builder.push(" * cardinality(ARRAY(SELECT unnest(p.main_categories) INTERSECT SELECT unnest(");
+        builder.push_bind(anchor.main_categories.clone().unwrap_or_default());
+        builder.push("::text[]))) + ");
+        builder.push_bind(weights.rating);
+        builder.push(" * COALESCE(p.google_rating, 0) + ");
+        builder.push_bind(weights.rating_count);
+        builder.push(" * ln(1 + COALESCE(p.google_rating_count, 0)::float8) - ");
+        builder.push_bind(weights.distance_km);
+        builder.push(" * ST_Distance(p.location::geography, ST_SetSRID(ST_MakePoint(");
+        builder.push_bind(anchor.longitude);
+        builder.push(", ");
+        builder.push_bind(anchor.latitude);
+        builder.push("), 4326)::geography) / 1000.0) DESC, p.id ASC LIMIT ");
+        builder.push_bind(limit);
+        builder
+    }
+
+    /// Active places similar to another place
+    /// DOCUMENTATION: Used for GET /places/{id}/similar; `distance_km` is filled in
+    pub async fn similar(
+        pool: &PgPool,
+        anchor: &Place,
+        query: &SearchQuery,
+        weights: &SimilarityWeights,
+        limit: i64,
+    ) -> Result<Vec<Place>, PlacesError> {
+        let rows = Self::similar_query(anchor, query, weights, limit)
+            .build_query_as::<PlaceRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Similar query error for {}: {}", anchor.id, e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(rows.into_iter().map(|r| r.to_place()).collect())
+    }
+
+    /// List the active places of a city
+    /// DOCUMENTATION: Used for GET /cities/{city}/places. Pagination and ordering
+    /// come from `query` (page, limit, sort, order) like search
+    /// Returns tuple: (results, total_count) for pagination
+    pub async fn list_by_city(
+        pool: &PgPool,
+        city: &str,
+        query: &SearchQuery,
+    ) -> Result<(Vec<Place>, i64), PlacesError> {
+        let limit = query.limit.unwrap_or(20).clamp(1, 100);
+        let offset = (query.page.unwrap_or(1).max(1) - 1) * limit;
+
+        let total: i64 = Self::city_count_query(city, query)
+            .build_query_scalar()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| {
+                log::error!("City count query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        let rows = Self::city_page_query(city, query, limit, offset)
+            .build_query_as::<PlaceRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("City listing query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        Ok((rows.into_iter().map(|r| r.to_place()).collect(), total))
+    }
+
+    /// Search places with full-text and filters
+    /// DOCUMENTATION: Used for GET /places/search endpoint
+    /// Returns tuple: (results, total_count) for pagination
+    pub async fn search(
+        pool: &PgPool,
+        query: &SearchQuery,
+    ) -> Result<(Vec<Place>, i64), PlacesError> {
+        Self::search_matching(pool, query, NameMatch::default()).await
+    }
+
+    /// Search places with `q` matched as `name_match`
+    /// DOCUMENTATION: NameMatch::Fuzzy needs the pg_trgm extension (migration 013)
+    pub async fn search_matching(
+        pool: &PgPool,
+        query: &SearchQuery,
+        name_match: NameMatch<'_>,
+    ) -> Result<(Vec<Place>, i64), PlacesError> {
+        let limit = query.limit.unwrap_or(20).min(100);
+        let page = query.page.unwrap_or(1).max(1);
+        let offset = (page - 1) * limit;
+
+        // Get total count
+        let total: i64 = Self::search_count_query_matching(query, name_match)
+            .build_query_scalar()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Count query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        let mut page_query = Self::search_page_query_matching(query, name_match, limit, offset);
+        log::debug!("Executing search query: {}", page_query.sql());
+
+        let rows = page_query
+            .build_query_as::<PlaceRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Search query error: {}", e);
+                PlacesError::DatabaseError(e.to_string())
+            })?;
+
+        let places: Vec<Place> = rows.into_iter().map(|r| r.to_place()).collect();
+
+        log::info!(
+            "Search completed: {} results, {} total (page {}/{})",
+            places.len(),
+            total,
+            page,
+            (total + limit - 1) / limit
+        );
+
+        Ok((places, total))
+    }
+
+    /// Rebuild every search_vector with the `language` text search configuration
+    /// DOCUMENTATION: Stores `language` in search_settings, which the search_vector
+    /// trigger reads, and recomputes all rows in one transaction. The
+    /// auphere.search_rebuild flag keeps updated_at and the audit log untouched
+    /// (migration 014). Returns the number of places rebuilt
+    pub async fn rebuild_search_vectors(pool: &PgPool, language: &str) -> Result<u64, PlacesError> {
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin search rebuild failed: {}", e))
+        })?;
+
+        sqlx::query("SELECT set_config('auphere.search_rebuild', 'on', true)")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Search rebuild failed: {}", e)))?;
+
+        sqlx::query("UPDATE search_settings SET language = $1::regconfig")
+            .bind(language)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to set search language {}: {}", language, e);
+                PlacesError::DatabaseError(format!("Set search language failed: {}", e))
+            })?;
+
+        let rebuilt = sqlx::query("UPDATE places SET search_vector = NULL")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Rebuild search vectors failed: {}", e)))?
+            .rows_affected();
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit search rebuild failed: {}", e))
+        })?;
+
+        log::info!("Rebuilt {} search vectors with the {} configuration", rebuilt, language);
+        Ok(rebuilt)
+    }
+
+    /// Update existing place
+    /// DOCUMENTATION: Partial update - only provided fields are modified
+    pub async fn update_place(
+        pool: &PgPool,
+        id: Uuid,
+        req: &UpdatePlaceRequest,
+    ) -> Result<Place, PlacesError> {
+        // Verify place exists
+        let _ = Self::get_by_id(pool, id).await?;
+
+        let updated: (Uuid,) = sqlx::query_as(
+            r#"
+            UPDATE places
+            SET name = COALESCE($1, name),
+                description = COALESCE($2, description),
+                tags = COALESCE($3, tags),
+                vibe_descriptor = COALESCE($4, vibe_descriptor),
+                opening_hours = COALESCE($5, opening_hours),
+                google_rating = COALESCE($6, google_rating),
+                business_status = COALESCE($7, business_status),
+                updated_at = NOW()
+            WHERE id = $8
+            RETURNING id
+            "#,
+        )
+        .bind(&req.name)
+        .bind(&req.description)
+        .bind(&req.tags)
+        .bind(&req.vibe_descriptor)
+        .bind(&req.opening_hours)
+        .bind(req.google_rating)
+        .bind(&req.business_status)
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Update failed for place {}: {}", id, e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        let place = Self::get_by_id(pool, updated.0).await?;
+
+        log::info!("Updated place: {}", id);
+        Ok(place)
+    }
+
+    /// Mark place as verified against Google
+    /// DOCUMENTATION: Sets last_verified_at to NOW() after a fresh Place Details fetch
+    pub async fn mark_verified(pool: &PgPool, id: Uuid) -> Result<DateTime<Utc>, PlacesError> {
+        let verified: (DateTime<Utc>,) = sqlx::query_as(
+            "UPDATE places SET last_verified_at = NOW() WHERE id = $1 RETURNING last_verified_at",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to mark place {} as verified: {}", id, e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(verified.0)
+    }
+
+    /// Last verification time of the stored places among `google_place_ids`
+    /// DOCUMENTATION: Ids with no stored place are absent from the map; stored places
+    /// never verified map to None. Lets a sync decide on Place Details without loading
+    /// whole records
+    pub async fn verified_at_by_google_ids(
+        pool: &PgPool,
+        google_place_ids: &[String],
+    ) -> Result<HashMap<String, Option<DateTime<Utc>>>, PlacesError> {
+        let rows: Vec<(String, Option<DateTime<Utc>>)> = sqlx::query_as(
+            "SELECT google_place_id, last_verified_at FROM places WHERE google_place_id = ANY($1)",
+        )
+        .bind(google_place_ids)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to look up places by Google id: {}", e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Active Google places due for a refresh, least recently verified first
+    /// DOCUMENTATION: Never-verified places come first. `verified_before` keeps only
+    /// places last verified before that instant; `city` matches case-insensitively
+    pub async fn list_for_refresh(
+        pool: &PgPool,
+        city: Option<&str>,
+        verified_before: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> Result<Vec<RefreshCandidate>, PlacesError> {
+        sqlx::query_as::<_, RefreshCandidate>(
+            r#"
+            SELECT id, google_place_id, name, city
+            FROM places
+            WHERE google_place_id IS NOT NULL
+              AND is_active = true
+              AND ($1::text IS NULL OR LOWER(city) = LOWER($1))
+              AND ($2::timestamptz IS NULL OR last_verified_at IS NULL OR last_verified_at < $2)
+            ORDER BY last_verified_at ASC NULLS FIRST, id
+            LIMIT $3
+            "#,
+        )
+        .bind(city)
+        .bind(verified_before)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to list places for refresh: {}", e);
+            PlacesError::DatabaseError(e.to_string())
+        })
+    }
+
+    /// Active Google places not verified since `verified_before` (or never verified)
+    /// DOCUMENTATION: Same selection as `list_for_refresh`, counted over every city
+    pub async fn count_stale(pool: &PgPool, verified_before: DateTime<Utc>) -> Result<i64, PlacesError> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM places
+            WHERE google_place_id IS NOT NULL
+              AND is_active = true
+              AND (last_verified_at IS NULL OR last_verified_at < $1)
+            "#,
+        )
+        .bind(verified_before)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to count stale places: {}", e);
+            PlacesError::DatabaseError(e.to_string())
+        })
+    }
+
+    /// Overwrite the fields Google keeps changing and mark the place verified
+    /// DOCUMENTATION: Rating, rating count, business status, opening hours, open-now,
+    /// phone and website come from `req` (built from fresh Place Details); everything
+    /// else, including descriptions and categories, is left alone. A CLOSED_PERMANENTLY
+    /// status deactivates the place; returns whether it is still active
+    pub async fn refresh_google_fields(
+        pool: &PgPool,
+        id: Uuid,
+        req: &CreatePlaceRequest,
+    ) -> Result<bool, PlacesError> {
+        let active: Option<(bool,)> = sqlx::query_as(
+            r#"
+            UPDATE places
+            SET google_rating = $1,
+                google_rating_count = $2,
+                business_status = $3,
+                opening_hours = $4,
+                is_open_now = $5,
+                phone = $6,
+                website = $7,
+                is_active = $3::text IS DISTINCT FROM 'CLOSED_PERMANENTLY',
+                last_verified_at = NOW(),
+                updated_at = NOW()
+            WHERE id = $8
+            RETURNING is_active
+            "#,
+        )
+        .bind(req.google_rating)
+        .bind(req.google_rating_count)
+        .bind(&req.business_status)
+        .bind(&req.opening_hours)
+        .bind(req.is_open_now)
+        .bind(&req.phone)
+        .bind(&req.website)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to refresh place {}: {}", id, e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        active
+            .map(|(active,)| active)
+            .ok_or_else(|| PlacesError::NotFound(id.to_string()))
+    }
+
+    /// Soft delete place
+    /// DOCUMENTATION: Sets is_active=false instead of physical deletion
+    /// Returns the city of the deleted place (used for search cache invalidation)
+    pub async fn delete_place(pool: &PgPool, id: Uuid) -> Result<String, PlacesError> {
+        let deleted: Option<(String,)> = sqlx::query_as(
+            "UPDATE places SET is_active = false, updated_at = NOW() WHERE id = $1 RETURNING city",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Delete failed for place {}: {}", id, e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        let (city,) = deleted.ok_or_else(|| PlacesError::NotFound(id.to_string()))?;
+
+        log::info!("Deleted place: {}", id);
+        Ok(city)
+    }
+
+    /// Permanently delete a place and its reviews and photos
+    /// DOCUMENTATION: Runs in one transaction with the place row locked. Works on
+    /// inactive places too. Subscribed places are refused with Conflict so a paying
+    /// customer is never removed by accident; metrics and audit rows go with the
+    /// place through ON DELETE CASCADE
+    pub async fn hard_delete_place(pool: &PgPool, id: Uuid) -> Result<HardDeleteReport, PlacesError> {
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin hard delete failed: {}", e))
+        })?;
+
+        let place: Option<(String, bool)> = sqlx::query_as(
+            "SELECT city, COALESCE(is_subscribed, false) FROM places WHERE id = $1 FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            log::error!("Hard delete lookup failed for place {}: {}", id, e);
+            PlacesError::DatabaseError(e.to_string())
+        })?;
+
+        let (city, is_subscribed) = place.ok_or_else(|| PlacesError::NotFound(id.to_string()))?;
+        if is_subscribed {
+            return Err(PlacesError::Conflict(format!(
+                "Place {} is subscribed and cannot be hard deleted",
+                id
+            )));
+        }
+
+        let reviews_deleted = sqlx::query("DELETE FROM place_reviews WHERE place_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Delete reviews failed: {}", e)))?
+            .rows_affected();
+
+        let photos_deleted = sqlx::query("DELETE FROM place_photos WHERE place_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Delete photos failed: {}", e)))?
+            .rows_affected();
+
+        sqlx::query("DELETE FROM places WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                log::error!("Hard delete failed for place {}: {}", id, e);
+                PlacesError::DatabaseError(format!("Delete place failed: {}", e))
+            })?;
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit hard delete failed: {}", e))
+        })?;
+
+        log::info!(
+            "Hard deleted place {} ({} reviews, {} photos)",
+            id,
+            reviews_deleted,
+            photos_deleted
+        );
+        Ok(HardDeleteReport {
+            place_id: id,
+            city,
+            reviews_deleted,
+            photos_deleted,
+        })
+    }
+}
+
+/// Result of `PlaceRepository::upsert_google_place`
+#[derive(Debug, Clone)]
+pub struct UpsertedPlace {
+    /// Stored record (inactive when Google reports it permanently closed)
+    pub place: Place,
+    /// Whether the row was inserted
+    pub created: bool,
+    /// An active place was deactivated by this upsert
+    pub deactivated: bool,
+    /// An inactive place was reactivated by this upsert
+    pub reactivated: bool,
+    /// Fields the upsert changed on an existing place (empty when created)
+    pub changes: Vec<FieldChange>,
+}
+
+/// Escape the LIKE wildcards (and the escape character) in `input`
+pub fn escape_like(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Column arrays bound by `PlaceRepository::bulk_upsert`, one entry per place
+/// DOCUMENTATION: Postgres arrays cannot be ragged, so list columns travel as JSONB
+/// arrays and are unpacked into TEXT[] in SQL. Free of I/O so the assembly can be
+/// tested and benchmarked without a database
+#[derive(Debug, Default)]
+pub struct BulkUpsertColumns {
+    pub name: Vec<String>,
+    pub description: Vec<Option<String>>,
+    pub type_: Vec<String>,
+    pub longitude: Vec<f64>,
+    pub latitude: Vec<f64>,
+    pub address: Vec<Option<String>>,
+    pub city: Vec<String>,
+    pub district: Vec<Option<String>>,
+    pub postal_code: Vec<Option<String>>,
+    pub phone: Vec<Option<String>>,
+    pub website: Vec<Option<String>>,
+    pub google_place_id: Vec<String>,
+    pub google_place_url: Vec<Option<String>>,
+    pub google_rating: Vec<Option<f32>>,
+    pub google_rating_count: Vec<Option<i32>>,
+    pub price_level: Vec<Option<i32>>,
+    pub main_categories: Vec<Value>,
+    pub secondary_categories: Vec<Value>,
+    pub cuisine_types: Vec<Value>,
+    pub opening_hours: Vec<Option<Value>>,
+    pub is_open_now: Vec<Option<bool>>,
+    pub business_status: Vec<Option<String>>,
+    pub suitable_for: Vec<Value>,
+    pub language: Vec<Option<String>>,
+    pub attributes: Vec<Option<Value>>,
+    pub timezone: Vec<Option<String>>,
+}
+
+impl BulkUpsertColumns {
+    /// Split `places` into column arrays
+    /// Fails with InvalidInput when a place has no google_place_id or an id repeats
+    pub fn from_requests(places: &[CreatePlaceRequest]) -> Result<Self, PlacesError> {
+        let mut columns = Self::default();
+        let mut seen = std::collections::HashSet::with_capacity(places.len());
+
+        for (index, req) in places.iter().enumerate() {
+            let google_id = req.google_place_id.clone().ok_or_else(|| {
+                PlacesError::InvalidInput(format!(
+                    "google_place_id is required for upsert (row {})",
+                    index
+                ))
+            })?;
+            if !seen.insert(google_id.clone()) {
+                return Err(PlacesError::InvalidInput(format!(
+                    "google_place_id {} appears more than once in the batch",
+                    google_id
+                )));
+            }
+
+            columns.name.push(req.name.clone());
+            columns.description.push(req.description.clone());
+            columns.type_.push(req.type_.clone());
+            columns.longitude.push(req.location[0]);
+            columns.latitude.push(req.location[1]);
+            columns.address.push(req.address.clone());
+            columns.city.push(req.city.clone());
+            columns.district.push(req.district.clone());
+            columns.postal_code.push(req.postal_code.clone());
+            columns.phone.push(req.phone.clone());
+            columns.website.push(req.website.clone());
+            columns.google_place_id.push(google_id);
+            columns.google_place_url.push(req.google_place_url.clone());
+            columns.google_rating.push(req.google_rating);
+            columns.google_rating_count.push(req.google_rating_count);
+            columns.price_level.push(req.price_level);
+            columns.main_categories.push(Value::from(req.main_categories.clone()));
+            columns.secondary_categories.push(Value::from(req.secondary_categories.clone()));
+            columns.cuisine_types.push(Value::from(req.cuisine_types.clone()));
+            columns.opening_hours.push(req.opening_hours.clone());
+            columns.is_open_now.push(req.is_open_now);
+            columns.business_status.push(req.business_status.clone());
+            columns.suitable_for.push(Value::from(req.suitable_for.clone()));
+            columns.language.push(req.language.clone());
+            columns.attributes.push(req.attributes.clone());
+            columns.timezone.push(GridGenerator::city_timezone(&req.city));
+        }
+
+        Ok(columns)
+    }
+
+    /// Number of places
+    pub fn len(&self) -> usize {
+        self.google_place_id.len()
+    }
+
+    /// Whether there are no places
+    pub fn is_empty(&self) -> bool {
+        self.google_place_id.is_empty()
+    }
+}
+
+/// Place selected by `PlaceRepository::list_for_refresh`
+#[derive(Debug, Clone, FromRow)]
+pub struct RefreshCandidate {
+    pub id: Uuid,
+    pub google_place_id: String,
+    pub name: String,
+    pub city: String,
+}
+
+/// One grid cell of a cluster query
+#[derive(Debug, Clone, FromRow)]
+pub struct ClusterRow {
+    /// Centroid of the cell's places
+    pub latitude: f64,
+    pub longitude: f64,
+    pub count: i64,
+    /// The place, when the cell holds exactly one
+    pub place_id: Option<Uuid>,
+}
+
+/// Column counted by a search facet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FacetColumn {
+    Type,
+    District,
+    PriceLevel,
+}
+
+impl FacetColumn {
+    fn column(self) -> &'static str {
+        match self {
+            FacetColumn::Type => "p.type",
+            FacetColumn::District => "p.district",
+            FacetColumn::PriceLevel => "p.price_level",
+        }
+    }
+}
+
+/// How the `q` search parameter matches places
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameMatch<'a> {
+    /// Full-text search on `search_vector` with the `language` text search
+    /// configuration, ignoring accents; needs matching stems
+    FullText { language: &'a str },
+    /// Trigram similarity between the name and `q` above `threshold` (0-1), which
+    /// tolerates typos
+    Fuzzy { threshold: f64 },
+}
+
+impl Default for NameMatch<'_> {
+    fn default() -> Self {
+        NameMatch::FullText { language: DEFAULT_SEARCH_LANGUAGE }
+    }
+}
+
+/// Weights of the similarity score of GET /places/{id}/similar
+/// DOCUMENTATION: The score of a candidate is the weighted sum of the cuisine types
+/// and main categories it shares with the place, its Google rating and
+/// ln(1 + rating count), minus its distance in kilometers times `distance_km`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarityWeights {
+    pub shared_cuisine: f64,
+    pub shared_category: f64,
+    pub rating: f64,
+    pub rating_count: f64,
+    pub distance_km: f64,
+}
+
+/// Outcome of `PlaceRepository::hard_delete_place`
+#[derive(Debug, Serialize)]
+pub struct HardDeleteReport {
+    pub place_id: Uuid,
+    /// City of the deleted place (used for search cache invalidation)
+    pub city: String,
+    pub reviews_deleted: u64,
+    pub photos_deleted: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_test_place, lazy_pool, test_pool};
+
+
+    /// The WHERE clause of a search query, without the parts around it
+    fn where_clause(sql: &str) -> &str {
+        let start = sql.find(" WHERE p.is_active").expect("search queries filter active places");
+        let end = sql.rfind(" ORDER BY ").unwrap_or(sql.len());
+        &sql[start..end]
+    }
+
+    /// `sql` with every `$n` placeholder replaced by `$?`
+    fn without_placeholder_numbers(sql: &str) -> String {
+        let mut out = String::with_capacity(sql.len());
+        let mut chars = sql.chars().peekable();
+        while let Some(c) = chars.next() {
+            out.push(c);
+            if c == '$' {
+                out.push('?');
+                while chars.next_if(char::is_ascii_digit).is_some() {}
+            }
+        }
+        out
+    }
+
+    fn hostile_search_query() -> SearchQuery {
+        SearchQuery {
+            q: Some("tapas o'clock'; DROP TABLE places; --".to_string()),
+            city: Some("Zaragoza' OR '1'='1".to_string()),
+            district: Some("--".to_string()),
+            type_: Some("bar'; DELETE FROM places; --".to_string()),
+            lat: Some(41.65),
+            lon: Some(-0.88),
+            radius_km: Some(2.5),
+            min_rating: Some(4.0),
+            min_rating_count: Some(10),
+            open_now: Some(true),
+            min_price: Some(1),
+            max_price: Some(3),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_search_input_is_bound_not_inlined() {
+        let query = hostile_search_query();
+        let page_query = PlaceRepository::search_page_query(&query, 20, 40);
+        let sql = page_query.sql();
+
+        for needle in ["o'clock", "DROP", "DELETE", "'1'='1", "--", ";"] {
+            assert!(!sql.contains(needle), "{:?} leaked into {}", needle, sql);
+        }
+        // distance lon/lat, language, q, city, district, type, lon, lat, radius, 2 ratings, 2 prices, limit, offset
+        assert!(sql.contains("$16"));
+        assert!(!sql.contains("$17"));
+    }
+
+    #[test]
+    fn test_search_count_and_page_queries_share_filters() {
+        for query in [SearchQuery::default(), hostile_search_query()] {
+            let count_query = PlaceRepository::search_count_query(&query);
+            let page_query = PlaceRepository::search_page_query(&query, 20, 0);
+
+            // The page query binds the distance point first, shifting its placeholders
+            assert_eq!(
+                without_placeholder_numbers(where_clause(count_query.sql())),
+                without_placeholder_numbers(where_clause(page_query.sql()))
+            );
+            assert!(count_query.sql().starts_with("SELECT COUNT(*) FROM places p WHERE"));
+        }
+    }
+
+    #[test]
+    fn test_search_price_filters_are_bound() {
+        let query = SearchQuery { min_price: Some(0), max_price: Some(4), ..Default::default() };
+        let count_query = PlaceRepository::search_count_query(&query);
+
+        assert!(count_query.sql().ends_with(" AND p.price_level >= $1 AND p.price_level <= $2"), "{}", count_query.sql());
+    }
+
+    #[test]
+    fn test_search_cuisine_filter_binds_known_cuisines() {
+        let query = SearchQuery {
+            cuisines: Some(vec!["Italian".to_string(), "klingon".to_string(), "japanese".to_string()]),
+            ..Default::default()
+        };
+        let count_query = PlaceRepository::search_count_query(&query);
+        assert!(count_query.sql().ends_with(" AND p.cuisine_types && $1"), "{}", count_query.sql());
+
+        let unknown_only = SearchQuery { cuisines: Some(vec!["klingon".to_string()]), ..Default::default() };
+        assert!(!PlaceRepository::search_count_query(&unknown_only).sql().contains("cuisine_types"));
+    }
+
+    #[test]
+    fn test_search_suitable_for_combines_with_type_and_radius() {
+        let query = SearchQuery {
+            type_: Some("restaurant".to_string()),
+            lat: Some(41.65),
+            lon: Some(-0.88),
+            radius_km: Some(1.0),
+            suitable_for: Some(vec!["families".to_string(), "Groups".to_string()]),
+            ..Default::default()
+        };
+        let sql = PlaceRepository::search_count_query(&query).into_sql();
+
+        assert!(sql.contains(" AND p.type = $1 AND p.suitable_for && $2 AND ST_DWithin("), "{}", sql);
+        assert!(sql.ends_with("$5)"), "{}", sql);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_suitable_for_filter() {
+        let pool = test_pool().await;
+        let city = format!("Suitable {}", Uuid::new_v4());
+        let places = [
+            ("family_restaurant", "restaurant", [-0.8891, 41.6488], vec!["families", "groups"]),
+            ("date_bar", "bar", [-0.8891, 41.6488], vec!["couples"]),
+            ("far_family_restaurant", "restaurant", [-0.5, 41.9], vec!["families"]),
+            ("untagged", "restaurant", [-0.8891, 41.6488], vec![]),
+        ];
+        for (name, place_type, location, suitable_for) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": place_type,
+                "location": location,
+                "city": city,
+                "main_categories": [],
+                "suitable_for": suitable_for
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |query: SearchQuery| {
+            let query = SearchQuery { city: Some(city.clone()), ..query };
+            let pool = pool.clone();
+            async move {
+                let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+                assert_eq!(total as usize, places.len());
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+        let tags = |tags: &[&str]| Some(tags.iter().map(|t| t.to_string()).collect());
+
+        assert_eq!(
+            names(SearchQuery { suitable_for: tags(&["families", "couples"]), ..Default::default() }).await,
+            ["date_bar", "family_restaurant", "far_family_restaurant"]
+        );
+        assert_eq!(
+            names(SearchQuery { suitable_for: tags(&["families"]), type_: Some("restaurant".to_string()), ..Default::default() }).await,
+            ["family_restaurant", "far_family_restaurant"]
+        );
+        let nearby = SearchQuery { lat: Some(41.6488), lon: Some(-0.8891), radius_km: Some(2.0), ..Default::default() };
+        assert_eq!(
+            names(SearchQuery { suitable_for: tags(&["families"]), ..nearby.clone() }).await,
+            ["family_restaurant"]
+        );
+        assert!(names(SearchQuery { suitable_for: tags(&["budget"]), ..nearby }).await.is_empty());
+    }
+
+    #[test]
+    fn test_search_category_filters_are_bound() {
+        let query = SearchQuery {
+            categories: Some(vec!["Bakery".to_string(), "cafe".to_string()]),
+            exclude_categories: Some(vec!["meal_delivery".to_string()]),
+            min_rating: Some(4.0),
+            ..Default::default()
+        };
+        let sql = PlaceRepository::search_count_query(&query).into_sql();
+
+        assert!(
+            sql.ends_with(
+                " AND (p.main_categories && $1 OR p.secondary_categories && $2) \
+                 AND NOT (COALESCE(p.main_categories && $3, false) OR COALESCE(p.secondary_categories && $4, false)) \
+                 AND p.google_rating >= $5"
+            ),
+            "{}",
+            sql
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_category_inclusion_and_exclusion() {
+        let pool = test_pool().await;
+        let city = format!("Categories {}", Uuid::new_v4());
+        let places = [
+            ("bakery", vec!["bakery"], Some(vec!["food"])),
+            ("delivery_bakery", vec!["bakery"], Some(vec!["meal_delivery"])),
+            ("cafe", vec!["restaurant"], Some(vec!["cafe"])),
+            ("delivery_only", vec!["meal_delivery"], None),
+            ("uncategorized", vec![], None),
+        ];
+        for (name, main, secondary) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": main,
+                "secondary_categories": secondary
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |categories: &str, exclude: &str| {
+            let list = |value: &str| (!value.is_empty()).then(|| value.split(',').map(str::to_string).collect());
+            let query = SearchQuery {
+                city: Some(city.clone()),
+                categories: list(categories),
+                exclude_categories: list(exclude),
+                ..Default::default()
+            };
+            let pool = pool.clone();
+            async move {
+                let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+                assert_eq!(total as usize, places.len());
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+
+        // Main or secondary categories match
+        assert_eq!(names("bakery,cafe", "").await, ["bakery", "cafe", "delivery_bakery"]);
+        // Exclusion checks both arrays and keeps places without categories
+        assert_eq!(names("", "meal_delivery").await, ["bakery", "cafe", "uncategorized"]);
+        // Exclusion wins over inclusion
+        assert_eq!(names("bakery", "meal_delivery").await, ["bakery"]);
+        assert!(names("bakery", "bakery").await.is_empty());
+    }
+
+    #[test]
+    fn test_search_tag_filters_are_bound() {
+        let query = SearchQuery {
+            type_: Some("bar".to_string()),
+            tags: Some(vec!["terrace".to_string(), "music:live".to_string()]),
+            ..Default::default()
+        };
+        let sql = PlaceRepository::search_count_query(&query).into_sql();
+        assert!(sql.ends_with(" AND p.type = $1 AND p.tags @> $2::jsonb AND p.tags @> $3::jsonb"), "{}", sql);
+    }
+
+    #[test]
+    fn test_search_full_text_binds_language_and_unaccents() {
+        let query = SearchQuery { q: Some("cafés".to_string()), ..Default::default() };
+
+        let sql = PlaceRepository::search_count_query(&query).into_sql();
+        assert!(
+            sql.ends_with(" AND p.search_vector @@ plainto_tsquery($1::regconfig, places_unaccent($2))"),
+            "{}",
+            sql
+        );
+        assert!(!sql.contains("spanish"), "{}", sql);
+
+        let english = NameMatch::FullText { language: "english" };
+        let count_query = PlaceRepository::search_count_query_matching(&query, english);
+        assert_eq!(count_query.sql(), sql);
+    }
+
+    #[test]
+    fn test_search_fuzzy_name_match_is_bound() {
+        let fuzzy = NameMatch::Fuzzy { threshold: 0.3 };
+        let query = SearchQuery { q: Some("cafe botanco".to_string()), city: Some("Zaragoza".to_string()), ..Default::default() };
+
+        let count_query = PlaceRepository::search_count_query_matching(&query, fuzzy);
+        assert!(
+            count_query.sql().ends_with(" WHERE p.is_active = true AND similarity(p.name, $1) > $2 AND p.city ILIKE '%' || $3 || '%'"),
+            "{}",
+            count_query.sql()
+        );
+        assert!(!count_query.sql().contains("plainto_tsquery"));
+
+        // Closest names first unless the client picked a sort
+        let page_query = PlaceRepository::search_page_query_matching(&query, fuzzy, 20, 0);
+        assert!(
+            page_query.sql().ends_with(" ORDER BY similarity(p.name, $4) DESC, p.google_rating DESC NULLS LAST, p.id ASC LIMIT $5 OFFSET $6"),
+            "{}",
+            page_query.sql()
+        );
+        let by_name = SearchQuery { sort: Some("name".to_string()), ..query };
+        let page_query = PlaceRepository::search_page_query_matching(&by_name, fuzzy, 20, 0);
+        assert!(page_query.sql().contains(" ORDER BY lower(p.name) ASC"), "{}", page_query.sql());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_fuzzy_matches_misspelled_names() {
+        let pool = test_pool().await;
+        let city = format!("Fuzzy {}", Uuid::new_v4());
+        for name in ["Cafe Botanico", "Bodega Tapas", "La Cafeteria del Parque"] {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "cafe",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let search = |q: &str, name_match: NameMatch<'static>| {
+            let query = SearchQuery { q: Some(q.to_string()), city: Some(city.clone()), ..Default::default() };
+            let pool = pool.clone();
+            async move {
+                let (places, total) = PlaceRepository::search_matching(&pool, &query, name_match).await.unwrap();
+                assert_eq!(total as usize, places.len());
+                places.into_iter().map(|place| place.name).collect::<Vec<_>>()
+            }
+        };
+        let fuzzy = NameMatch::Fuzzy { threshold: 0.3 };
+
+        assert!(search("cafe botanco", NameMatch::default()).await.is_empty());
+        assert_eq!(search("cafe botanco", fuzzy).await, ["Cafe Botanico"]);
+        assert_eq!(search("bodgea tapas", fuzzy).await, ["Bodega Tapas"]);
+        assert!(search("cafe botanco", NameMatch::Fuzzy { threshold: 0.95 }).await.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_ignores_accents_and_plurals() {
+        let pool = test_pool().await;
+        let city = format!("Acentos {}", Uuid::new_v4());
+        let places = [
+            ("Café Nómada", "Cafetería de especialidad"),
+            ("Cafe Central", "Desayunos y meriendas"),
+            ("Restaurante Casa Pepe", "Cocina aragonesa"),
+            ("Bodega Tapas", "Vinos y raciones"),
+        ];
+        for (name, description) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "description": description,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |q: &str| {
+            let query = SearchQuery { q: Some(q.to_string()), city: Some(city.clone()), ..Default::default() };
+            let pool = pool.clone();
+            async move {
+                let (places, _) = PlaceRepository::search(&pool, &query).await.unwrap();
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+
+        assert_eq!(names("cafe").await, ["Cafe Central", "Café Nómada"]);
+        assert_eq!(names("café").await, ["Cafe Central", "Café Nómada"]);
+        assert_eq!(names("nomada").await, ["Café Nómada"]);
+        assert_eq!(names("restaurantes").await, ["Restaurante Casa Pepe"]);
+        assert_eq!(names("tapa").await, ["Bodega Tapas"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_rebuild_search_vectors_keeps_updated_at() {
+        let pool = test_pool().await;
+        let id = insert_test_place(&pool, &format!("rebuild_{}", Uuid::new_v4())).await;
+        let before = PlaceRepository::get_by_id(&pool, id).await.unwrap().updated_at;
+
+        let rebuilt = PlaceRepository::rebuild_search_vectors(&pool, DEFAULT_SEARCH_LANGUAGE).await.unwrap();
+        assert!(rebuilt >= 1);
+
+        let after = PlaceRepository::get_by_id(&pool, id).await.unwrap().updated_at;
+        assert_eq!(before, after);
+        assert!(PlaceRepository::rebuild_search_vectors(&pool, "klingon").await.is_err());
+    }
+
+    #[test]
+    fn test_city_listing_matches_exact_city() {
+        let query = SearchQuery { type_: Some("bar".to_string()), sort: Some("name".to_string()), ..Default::default() };
+
+        let count_query = PlaceRepository::city_count_query("Málaga", &query);
+        assert_eq!(
+            count_query.sql(),
+            "SELECT COUNT(*) FROM places p WHERE p.is_active = true AND lower(p.city) = lower($1) AND p.type = $2"
+        );
+
+        let page_query = PlaceRepository::city_page_query("Málaga", &query, 20, 40);
+        let sql = page_query.sql();
+        assert!(!sql.contains("ILIKE") && !sql.contains("distance_km"), "{}", sql);
+        assert!(
+            sql.ends_with(" AND p.type = $2 ORDER BY lower(p.name) ASC NULLS LAST, p.id ASC LIMIT $3 OFFSET $4"),
+            "{}",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_nearby_query_excludes_anchor_and_orders_by_distance() {
+        let query = SearchQuery {
+            lat: Some(41.65),
+            lon: Some(-0.88),
+            radius_km: Some(1.0),
+            type_: Some("bar".to_string()),
+            sort: Some("distance".to_string()),
+            ..Default::default()
+        };
+
+        let nearby = PlaceRepository::nearby_query(Uuid::new_v4(), &query, 10);
+        let sql = nearby.sql();
+        assert!(sql.contains("/ 1000.0 AS distance_km"), "{}", sql);
+        assert!(sql.contains(" AND p.type = $3 AND ST_DWithin(p.location::geography"), "{}", sql);
+        assert!(
+            sql.ends_with(" AND p.id <> $7 ORDER BY distance_km ASC NULLS LAST, p.id ASC LIMIT $8"),
+            "{}",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_similar_query_scores_shared_attributes_and_distance() {
+        let anchor: Place = serde_json::from_value(serde_json::json!({
+            "id": Uuid::new_v4(),
+            "name": "anchor",
+            "type": "restaurant",
+            "latitude": 41.65,
+            "longitude": -0.88,
+            "city": "Zaragoza",
+            "cuisine_types": ["italian"],
+            "created_at": Utc::now(),
+            "updated_at": Utc::now()
+        }))
+        .unwrap();
+        let query = SearchQuery {
+            lat: Some(anchor.latitude),
+            lon: Some(anchor.longitude),
+            radius_km: Some(3.0),
+            type_: Some(anchor.type_field.clone()),
+            ..Default::default()
+        };
+        let weights = SimilarityWeights {
+            shared_cuisine: 2.0,
+            shared_category: 1.0,
+            rating: 1.0,
+            rating_count: 0.25,
+            distance_km: 1.0,
+        };
+
+        let similar = PlaceRepository::similar_query(&anchor, &query, &weights, 6);
+        let sql = similar.sql();
+        assert!(sql.contains(" WHERE p.is_active = true AND p.type = $3 AND ST_DWithin("), "{}", sql);
+        assert!(
+            sql.contains(" AND p.id <> $7 AND (p.cuisine_types && $8::text[] OR p.main_categories && $9::text[]) ORDER BY ($10 * cardinality("),
+            "{}",
+            sql
+        );
+        assert!(sql.contains("INTERSECT SELECT unnest($11::text[]))) + $12 * "), "{}", sql);
+        assert!(sql.contains(" + $14 * COALESCE(p.google_rating, 0) + $15 * ln("), "{}", sql);
+        assert!(sql.ends_with(" / 1000.0) DESC, p.id ASC LIMIT $19"), "{}", sql);
+    }
+
+    #[test]
+    fn test_featured_query_orders_by_seeded_hash() {
+        let query = SearchQuery { type_: Some("bar".to_string()), min_rating: Some(4.0), ..Default::default() };
+        let featured = PlaceRepository::featured_query(Some("Zaragoza"), &query, 42, 12, 24);
+        let sql = featured.sql();
+        assert!(sql.contains(" AND p.type = $1 AND p.google_rating >= $2"), "{}", sql);
+        assert!(
+            sql.ends_with(" AND lower(p.city) = lower($3) ORDER BY md5(p.id::text || ':' || $4::text), p.id ASC LIMIT $5 OFFSET $6"),
+            "{}",
+            sql
+        );
+        assert!(!sql.contains("42"));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_featured_pages_follow_the_seed() {
+        let pool = test_pool().await;
+        let city = format!("Featured {}", Uuid::new_v4());
+        for i in 0..6 {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": format!("Featured {}", i),
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "google_rating": if i == 0 { 3.5 } else { 4.5 },
+                "google_rating_count": 100,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+        let filters = SearchQuery { min_rating: Some(4.0), min_rating_count: Some(20), ..Default::default() };
+        let page = |seed: u32, limit: i64, offset: i64| {
+            let pool = pool.clone();
+            let city = city.to_uppercase();
+            let filters = filters.clone();
+            async move {
+                PlaceRepository::featured(&pool, Some(&city), &filters, seed, limit, offset)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|place| place.name)
+                    .collect::<Vec<String>>()
+            }
+        };
+
+        // The low-rated place never qualifies
+        let all = page(7, 10, 0).await;
+        assert_eq!(all.len(), 5);
+        assert!(!all.contains(&"Featured 0".to_string()));
+
+        // A seed is a stable order; its pages split it without overlap
+        assert_eq!(page(7, 10, 0).await, all);
+        let (first, second) = (page(7, 3, 0).await, page(7, 3, 3).await);
+        assert_eq!([first, second].concat(), all);
+
+        // Some other seed shuffles differently
+        let mut orders = Vec::new();
+        for seed in 0..20 {
+            orders.push(page(seed, 10, 0).await);
+        }
+        assert!(orders.iter().any(|order| order != &all));
+    }
+
+    #[test]
+    fn test_search_facet_query_shares_search_filters() {
+        let query = SearchQuery { city: Some("Zaragoza".to_string()), type_: Some("bar".to_string()), ..Default::default() };
+        let count_query = PlaceRepository::search_count_query(&query);
+        let facet_query = PlaceRepository::search_facet_query(&query, NameMatch::default(), FacetColumn::PriceLevel, 50);
+        let sql = facet_query.sql();
+
+        assert!(sql.starts_with("SELECT p.price_level::text AS value, COUNT(*) AS count FROM places p"), "{}", sql);
+        assert!(sql.contains(where_clause(count_query.sql())), "{}", sql);
+        assert!(sql.ends_with(" AND p.price_level IS NOT NULL GROUP BY 1 ORDER BY 2 DESC, 1 ASC LIMIT $3"), "{}", sql);
+    }
+
+    #[test]
+    fn test_cluster_query_groups_viewport_by_grid() {
+        let query = SearchQuery { type_: Some("bar".to_string()), ..viewport(41.70, -0.80, 41.60, -0.95) };
+        let clusters = PlaceRepository::cluster_query(&query, 0.01);
+        let sql = clusters.sql();
+        assert!(sql.contains(" AND p.type = $1 AND p.location && ST_MakeEnvelope($2, $3, $4, $5, 4326)"), "{}", sql);
+        assert!(
+            sql.ends_with(" GROUP BY ST_SnapToGrid(p.location, $6) ORDER BY count DESC, latitude ASC, longitude ASC"),
+            "{}",
+            sql
+        );
+    }
+
+    #[test]
+    fn test_include_inactive_drops_the_active_filter() {
+        let query = SearchQuery { type_: Some("bar".to_string()), include_inactive: true, ..Default::default() };
+        let sql = PlaceRepository::search_count_query(&query).sql().to_string();
+        assert!(sql.ends_with(" WHERE true AND p.type = $1"), "{}", sql);
+        assert!(!sql.contains("is_active"));
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("café"), "café");
+        assert_eq!(escape_like("100%_bar\\"), "100\\%\\_bar\\\\");
+    }
+
+    #[test]
+    fn test_suggest_query_binds_input() {
+        let query = PlaceRepository::suggest_query("ca%", Some("Zaragoza"), 8);
+        let sql = query.sql();
+        assert!(!sql.contains("ca%"), "{}", sql);
+        assert!(sql.contains("WHERE p.is_active = true AND (places_name_key(p.name) LIKE places_name_key($1) || '%'"), "{}", sql);
+        assert!(sql.contains(" AND lower(p.city) = lower($3) ORDER BY "), "{}", sql);
+        assert!(sql.ends_with("p.id ASC LIMIT $5"), "{}", sql);
+
+        let without_city = PlaceRepository::suggest_query("ca", None, 8);
+        assert!(!without_city.sql().contains("p.city"));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_suggest_ignores_accents_and_case() {
+        let pool = test_pool().await;
+        let city = format!("Suggest {}", Uuid::new_v4());
+        let mut ids = HashMap::new();
+        for (name, place_city) in [
+            ("Café Botánico", city.clone()),
+            ("Cafetería Central", city.clone()),
+            ("El Café de la Plaza", city.clone()),
+            ("100% Tapas", city.clone()),
+            ("Café Otra Ciudad", format!("{} Norte", city)),
+            ("Café Cerrado", city.clone()),
+        ] {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "cafe",
+                "location": [-0.8891, 41.6488],
+                "city": place_city,
+                "main_categories": []
+            }))
+            .unwrap();
+            ids.insert(name, PlaceRepository::create_place(&pool, &request).await.unwrap().id);
+        }
+        PlaceRepository::delete_place(&pool, ids["Café Cerrado"]).await.unwrap();
+
+        let names = |suggestions: Vec<PlaceSuggestion>| -> Vec<String> {
+            suggestions.into_iter().map(|suggestion| suggestion.name).collect()
+        };
+        let suggest = |q: &'static str| {
+            let pool = pool.clone();
+            let city = city.clone();
+            async move { names(PlaceRepository::suggest(&pool, q, Some(&city), 8).await.unwrap()) }
+        };
+
+        // Whole-name prefixes first, then word prefixes; inactive places never show
+        assert_eq!(suggest("cafe").await, ["Café Botánico", "Cafetería Central", "El Café de la Plaza"]);
+        assert_eq!(suggest("CAFÉ B").await, ["Café Botánico"]);
+        assert_eq!(suggest("bota").await, ["Café Botánico"]);
+        assert!(suggest("afe").await.is_empty());
+
+        // Wildcards in the input are literal
+        assert_eq!(suggest("100%").await, ["100% Tapas"]);
+        assert!(suggest("%").await.is_empty());
+
+        let everywhere = names(PlaceRepository::suggest(&pool, "café otra", None, 8).await.unwrap());
+        assert!(everywhere.contains(&"Café Otra Ciudad".to_string()));
+        let limited = PlaceRepository::suggest(&pool, "cafe", Some(&city.to_uppercase()), 1).await.unwrap();
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].id, ids["Café Botánico"]);
+        assert_eq!(limited[0].type_, "cafe");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_list_by_city_is_exact_and_case_insensitive() {
+        let pool = test_pool().await;
+        let city = format!("Málaga {}", Uuid::new_v4());
+        let places = [
+            ("Bar Pimpi", city.clone(), "bar"),
+            ("El Pimpi Restaurante", city.clone(), "restaurant"),
+            ("Chiringuito", format!("{} Este", city), "bar"),
+        ];
+        for (name, place_city, place_type) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": place_type,
+                "location": [-4.4214, 36.7213],
+                "city": place_city,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let by_name = SearchQuery { sort: Some("name".to_string()), ..Default::default() };
+        let (places, total) = PlaceRepository::list_by_city(&pool, &city.to_lowercase(), &by_name).await.unwrap();
+        let names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+        assert_eq!(names, ["Bar Pimpi", "El Pimpi Restaurante"]);
+        assert_eq!(total, 2);
+
+        let bars = SearchQuery { type_: Some("bar".to_string()), limit: Some(1), ..Default::default() };
+        let (places, total) = PlaceRepository::list_by_city(&pool, &city, &bars).await.unwrap();
+        assert_eq!((places.len(), total), (1, 1));
+    }
+
+    fn viewport(ne_lat: f64, ne_lng: f64, sw_lat: f64, sw_lng: f64) -> SearchQuery {
+        SearchQuery {
+            ne_lat: Some(ne_lat),
+            ne_lng: Some(ne_lng),
+            sw_lat: Some(sw_lat),
+            sw_lng: Some(sw_lng),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_search_bounding_box_is_bound() {
+        let query = SearchQuery { type_: Some("bar".to_string()), ..viewport(41.66, -0.87, 41.64, -0.90) };
+        let count_query = PlaceRepository::search_count_query(&query);
+        assert!(
+            count_query.sql().ends_with(" AND p.type = $1 AND p.location && ST_MakeEnvelope($2, $3, $4, $5, 4326)"),
+            "{}",
+            count_query.sql()
+        );
+
+        // Incomplete or inverted boxes add no filter (the service rejects them)
+        let partial = SearchQuery { sw_lng: None, ..viewport(41.66, -0.87, 41.64, -0.90) };
+        let crossing = viewport(10.0, -179.0, -10.0, 179.0);
+        for query in [partial, crossing] {
+            assert!(!PlaceRepository::search_count_query(&query).sql().contains("ST_MakeEnvelope"));
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_bounding_box_filter() {
+        let pool = test_pool().await;
+        let city = format!("Viewport {}", Uuid::new_v4());
+        let places = [
+            ("center", [-0.885, 41.650]),
+            ("on_edge", [-0.870, 41.660]),
+            ("just_north", [-0.885, 41.6601]),
+            ("just_east", [-0.8699, 41.650]),
+            ("just_south_west", [-0.9001, 41.6399]),
+        ];
+        for (name, location) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": location,
+                "city": city,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let query = SearchQuery { city: Some(city), ..viewport(41.66, -0.87, 41.64, -0.90) };
+        let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+        names.sort();
+        assert_eq!(names, ["center", "on_edge"]);
+        assert_eq!(total, 2);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_min_rating_count_excludes_uncounted_places() {
+        let pool = test_pool().await;
+        let city = format!("Counted {}", Uuid::new_v4());
+        for (name, rating, count) in [("popular", 4.6, Some(850)), ("friends_only", 5.0, Some(3)), ("uncounted", 4.8, None)] {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": [],
+                "google_rating": rating,
+                "google_rating_count": count
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let query = SearchQuery { city: Some(city.clone()), min_rating: Some(4.5), min_rating_count: Some(50), ..Default::default() };
+        let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(places[0].name, "popular");
+
+        let query = SearchQuery { city: Some(city), min_rating_count: Some(0), ..Default::default() };
+        let (_, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        assert_eq!(total, 2, "a zero minimum still needs a count");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_tag_filters() {
+        let pool = test_pool().await;
+        let city = format!("Tags {}", Uuid::new_v4());
+        let places = [
+            ("terrace_live", "bar", serde_json::json!({ "terrace": true, "music": "live" })),
+            ("terrace_dj", "bar", serde_json::json!({ "terrace": true, "music": "dj" })),
+            ("indoor", "bar", serde_json::json!({ "terrace": false })),
+            ("terrace_restaurant", "restaurant", serde_json::json!({ "terrace": true })),
+        ];
+        for (name, place_type, tags) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": place_type,
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": [],
+                "attributes": tags
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |tags: &str, type_: Option<&str>| {
+            let query = SearchQuery {
+                city: Some(city.clone()),
+                type_: type_.map(str::to_string),
+                tags: Some(tags.split(',').map(str::to_string).collect()),
+                ..Default::default()
+            };
+            let pool = pool.clone();
+            async move {
+                let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+                assert_eq!(total as usize, places.len());
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+
+        assert_eq!(names("terrace", None).await, ["terrace_dj", "terrace_live", "terrace_restaurant"]);
+        assert_eq!(names("terrace:false", None).await, ["indoor"]);
+        assert_eq!(names("terrace,music:live", None).await, ["terrace_live"]);
+        assert_eq!(names("terrace", Some("bar")).await, ["terrace_dj", "terrace_live"]);
+        assert!(names("music:jazz", None).await.is_empty());
+    }
+
+    #[test]
+    fn test_search_selects_distance_only_with_coordinates() {
+        let near = SearchQuery { lat: Some(41.65), lon: Some(-0.88), ..Default::default() };
+        let sql = PlaceRepository::search_page_query(&near, 20, 0).into_sql();
+        assert!(
+            sql.contains(", ST_Distance(p.location::geography, ST_SetSRID(ST_MakePoint($1, $2), 4326)::geography) / 1000.0 AS distance_km"),
+            "{}",
+            sql
+        );
+
+        let anywhere = PlaceRepository::search_page_query(&SearchQuery::default(), 20, 0).into_sql();
+        assert!(!anywhere.contains("distance_km"), "{}", anywhere);
+    }
+
+    #[test]
+    fn test_search_order_fragments() {
+        let order_by = |sort: Option<&str>, order: Option<&str>| {
+            let query = SearchQuery {
+                sort: sort.map(str::to_string),
+                order: order.map(str::to_string),
+                lat: Some(41.65),
+                lon: Some(-0.88),
+                ..Default::default()
+            };
+            let sql = PlaceRepository::search_page_query(&query, 20, 0).into_sql();
+            let start = sql.rfind(" ORDER BY ").unwrap();
+            let end = sql.rfind(" LIMIT ").unwrap();
+            sql[start..end].to_string()
+        };
+
+        assert_eq!(order_by(None, None), " ORDER BY p.google_rating DESC NULLS LAST, p.id ASC");
+        assert_eq!(order_by(Some("rating"), Some("asc")), " ORDER BY p.google_rating ASC NULLS LAST, p.id ASC");
+        assert_eq!(order_by(Some("rating_count"), None), " ORDER BY p.google_rating_count DESC NULLS LAST, p.id ASC");
+        assert_eq!(order_by(Some("created_at"), None), " ORDER BY p.created_at DESC NULLS LAST, p.id ASC");
+        assert_eq!(order_by(Some("name"), Some("DESC")), " ORDER BY lower(p.name) DESC NULLS LAST, p.id ASC");
+        assert_eq!(
+            order_by(Some("distance"), None),
+            " ORDER BY distance_km ASC NULLS LAST, p.id ASC"
+        );
+        // Anything else never reaches the SQL
+        assert_eq!(order_by(Some("name; DROP TABLE places"), Some("sideways")), order_by(None, None));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_sorts_are_stable_with_nulls_last() {
+        let pool = test_pool().await;
+        let city = format!("Sorted {}", Uuid::new_v4());
+        let places = [
+            ("Bravo", Some(4.5), Some(10), [-0.8891, 41.6488]),
+            ("alpha", None, None, [-0.8800, 41.6500]),
+            ("Charlie", Some(4.5), Some(300), [-0.9500, 41.7000]),
+            ("delta", Some(3.0), None, [-0.8890, 41.6490]),
+        ];
+        for (name, rating, count, location) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": location,
+                "city": city,
+                "main_categories": [],
+                "google_rating": rating,
+                "google_rating_count": count
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |sort: &str, order: Option<&str>| {
+            let query = SearchQuery {
+                city: Some(city.clone()),
+                sort: Some(sort.to_string()),
+                order: order.map(str::to_string),
+                lat: Some(41.6488),
+                lon: Some(-0.8891),
+                ..Default::default()
+            };
+            let pool = pool.clone();
+            async move {
+                let (places, _) = PlaceRepository::search(&pool, &query).await.unwrap();
+                places.into_iter().map(|place| place.name).collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(names("name", None).await, ["alpha", "Bravo", "Charlie", "delta"]);
+        assert_eq!(names("name", Some("desc")).await, ["delta", "Charlie", "Bravo", "alpha"]);
+        assert_eq!(names("distance", None).await, ["Bravo", "delta", "alpha", "Charlie"]);
+
+        let query = SearchQuery { city: Some(city.clone()), lat: Some(41.6488), lon: Some(-0.8891), ..Default::default() };
+        let (places, _) = PlaceRepository::search(&pool, &query).await.unwrap();
+        let bravo = places.iter().find(|place| place.name == "Bravo").unwrap();
+        assert!(bravo.distance_km.unwrap() < 0.001);
+        let charlie = places.iter().find(|place| place.name == "Charlie").unwrap().to_response();
+        assert!((charlie.distance_km.unwrap() - 7.62).abs() < 0.1, "{:?}", charlie.distance_km);
+        let (places, _) = PlaceRepository::search(&pool, &SearchQuery { lat: None, lon: None, ..query }).await.unwrap();
+        assert!(places.iter().all(|place| place.distance_km.is_none()));
+        let by_count = names("rating_count", None).await;
+        assert_eq!(&by_count[..2], ["Charlie", "Bravo"]);
+        assert_eq!(by_count, names("rating_count", None).await, "ties on NULL are broken by id");
+
+        // Unrated places stay last in both directions; equal ratings keep one order
+        let by_rating = names("rating", None).await;
+        assert_eq!(&by_rating[2..], ["delta", "alpha"]);
+        assert_eq!(by_rating, names("rating", None).await);
+        let ascending = names("rating", Some("asc")).await;
+        assert_eq!(ascending[0], "delta");
+        assert_eq!(ascending[3], "alpha");
+    }
+
+    #[test]
+    fn test_search_open_now_evaluates_opening_hours() {
+        let query = SearchQuery { open_now: Some(true), ..Default::default() };
+        let sql = PlaceRepository::search_count_query(&query).into_sql();
+
+        assert!(sql.contains(" AND COALESCE(place_is_open_at(p.opening_hours, p.timezone, NOW()), false)"), "{}", sql);
+        assert!(!sql.contains("p.is_open_now"), "{}", sql);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_sql_open_at_matches_opening_hours() {
+        use crate::models::OpeningHours;
+        use chrono::{Duration, TimeZone, Utc};
+
+        let pool = test_pool().await;
+        // Zones without daylight saving time, so their offset is the stored one
+        let schedules = [
+            // 24/7
+            ("Africa/Lagos", serde_json::json!({ "periods": [{ "open": { "day": 0, "time": "0000" } }], "utc_offset_minutes": 60 })),
+            // Friday 22:00 - Saturday 03:00 and Saturday 22:00 - Sunday 04:00
+            ("Africa/Johannesburg", serde_json::json!({
+                "periods": [
+                    { "open": { "day": 5, "time": "2200" }, "close": { "day": 6, "time": "0300" } },
+                    { "open": { "day": 6, "time": "2200" }, "close": { "day": 0, "time": "0400" } }
+                ],
+                "utc_offset_minutes": 120
+            })),
+            // Weekdays 09:00-14:00, negative offset
+            ("America/Bogota", serde_json::json!({
+                "periods": (1..=5)
+                    .map(|day| serde_json::json!({ "open": { "day": day, "time": "0900" }, "close": { "day": day, "time": "1400" } }))
+                    .collect::<Vec<_>>(),
+                "utc_offset_minutes": -300
+            })),
+        ];
+
+        for (tz, hours) in &schedules {
+            let expected = OpeningHours::from_value(hours).unwrap();
+            // Every half hour of a week
+            let start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+            for step in 0..(7 * 48) {
+                let at = start + Duration::minutes(30 * step);
+                let open: Option<bool> = sqlx::query_scalar("SELECT place_is_open_at($1, $2, $3)")
+                    .bind(hours)
+                    .bind(tz)
+                    .bind(at)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(open, expected.is_open_at_utc(at), "{} at {}", hours, at);
+            }
+        }
+
+        // Weekdays 09:00-14:00 in Madrid: 07:30 UTC is 09:30 in summer (CEST) but 08:30 in winter (CET)
+        let weekdays = &schedules[2].1;
+        let madrid = |at: chrono::DateTime<Utc>| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, Option<bool>>("SELECT place_is_open_at($1, 'Europe/Madrid', $2)")
+                    .bind(weekdays)
+                    .bind(at)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(madrid(Utc.with_ymd_and_hms(2026, 7, 1, 7, 30, 0).unwrap()).await, Some(true));
+        assert_eq!(madrid(Utc.with_ymd_and_hms(2026, 1, 14, 7, 30, 0).unwrap()).await, Some(false));
+
+        let no_periods: Option<bool> = sqlx::query_scalar("SELECT place_is_open_at($1, 'Europe/Madrid', NOW())")
+            .bind(serde_json::json!({ "open_now": true }))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(no_periods, Some(false));
+        let no_offset: Option<bool> = sqlx::query_scalar("SELECT place_is_open_at($1, NULL, NOW())")
+            .bind(&schedules[0].1)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(no_offset, None);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_places_take_their_city_timezone() {
+        let pool = test_pool().await;
+        let timezone = |id: Uuid| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, Option<String>>("SELECT timezone FROM places WHERE id = $1")
+                    .bind(id)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        // Built-in city
+        let zaragoza = insert_test_place(&pool, &format!("tz_{}", Uuid::new_v4())).await;
+        assert_eq!(timezone(zaragoza).await.as_deref(), Some("Europe/Madrid"));
+
+        // Stored city bounds with a zone of their own
+        let city = format!("Tz {}", Uuid::new_v4());
+        let bounds = crate::services::CityBounds {
+            name: city.clone(),
+            min_lat: 28.09,
+            max_lat: 28.16,
+            min_lng: -15.46,
+            max_lng: -15.40,
+            cell_size_km: 1.0,
+            radius_m: 800,
+            timezone: Some("Atlantic/Canary".to_string()),
+        };
+        crate::db::CityBoundsRepository::upsert(&pool, &bounds).await.unwrap();
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Guagua",
+            "type": "bar",
+            "location": [-15.43, 28.12],
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap();
+        let canary = PlaceRepository::create_place(&pool, &request).await.unwrap();
+        assert_eq!(timezone(canary.id).await.as_deref(), Some("Atlantic/Canary"));
+
+        // Unknown city
+        let request = CreatePlaceRequest { city: format!("Nowhere {}", Uuid::new_v4()), ..request };
+        let unknown = PlaceRepository::create_place(&pool, &request).await.unwrap();
+        assert_eq!(timezone(unknown.id).await, None);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_open_now_ignores_stale_flag_and_missing_hours_or_zone() {
+        let pool = test_pool().await;
+        let city = format!("Open {}", Uuid::new_v4());
+        let places = [
+            // Open around the clock, but synced while "closed"
+            ("always_open", Some(serde_json::json!({ "periods": [{ "open": { "day": 0, "time": "0000" } }], "utc_offset_minutes": 0 })), Some(false)),
+            // Flagged open, but no hours to evaluate
+            ("no_hours", None, Some(true)),
+            // Open around the clock and flagged open, but in an unknown zone
+            ("no_timezone", Some(serde_json::json!({ "periods": [{ "open": { "day": 0, "time": "0000" } }] })), Some(true)),
+        ];
+        for (name, opening_hours, is_open_now) in places {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": [],
+                "opening_hours": opening_hours,
+                "is_open_now": is_open_now
+            }))
+            .unwrap();
+            let created = PlaceRepository::create_place(&pool, &request).await.unwrap();
+            if name != "no_timezone" {
+                sqlx::query("UPDATE places SET timezone = 'Europe/Madrid' WHERE id = $1")
+                    .bind(created.id)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let query = SearchQuery { city: Some(city), open_now: Some(true), ..Default::default() };
+        let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(places[0].name, "always_open");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_cuisine_filter_matches_any_cuisine() {
+        let pool = test_pool().await;
+        let city = format!("Cuisine {}", Uuid::new_v4());
+        for (name, cuisines) in [("trattoria", vec!["italian"]), ("fusion", vec!["japanese", "thai"]), ("plain", vec![])] {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": [],
+                "cuisine_types": cuisines
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |cuisines: &str| {
+            let query = SearchQuery {
+                city: Some(city.clone()),
+                cuisines: Some(cuisines.split(',').map(str::to_string).collect()),
+                ..Default::default()
+            };
+            let pool = pool.clone();
+            async move {
+                let (places, _) = PlaceRepository::search(&pool, &query).await.unwrap();
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+
+        assert_eq!(names("italian,thai").await, ["fusion", "trattoria"]);
+        assert_eq!(names("japanese").await, ["fusion"]);
+        assert!(names("mexican").await.is_empty());
+        assert_eq!(names("klingon").await, ["fusion", "plain", "trattoria"]);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_price_filters_exclude_unpriced_places() {
+        let pool = test_pool().await;
+        let city = format!("Price {}", Uuid::new_v4());
+        for (name, price_level) in [("free", Some(0)), ("luxury", Some(4)), ("unpriced", None)] {
+            let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": [],
+                "price_level": price_level
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+
+        let names = |min_price: Option<i32>, max_price: Option<i32>| {
+            let query = SearchQuery { city: Some(city.clone()), min_price, max_price, ..Default::default() };
+            let pool = pool.clone();
+            async move {
+                let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+                assert_eq!(total as usize, places.len());
+                let mut names: Vec<String> = places.into_iter().map(|place| place.name).collect();
+                names.sort();
+                names
+            }
+        };
+
+        assert_eq!(names(None, None).await, ["free", "luxury", "unpriced"]);
+        assert_eq!(names(Some(0), None).await, ["free", "luxury"]);
+        assert_eq!(names(None, Some(4)).await, ["free", "luxury"]);
+        assert_eq!(names(None, Some(0)).await, ["free"]);
+        assert_eq!(names(Some(4), Some(4)).await, ["luxury"]);
+        assert!(names(Some(1), Some(3)).await.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_treats_sql_metacharacters_as_literals() {
+        let pool = test_pool().await;
+        let city = format!("O'Hare; --{}", Uuid::new_v4());
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Bar 'El Tubo'; --",
+            "type": "bar",
+            "location": [-0.8891, 41.6488],
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap();
+        PlaceRepository::create_place(&pool, &request).await.unwrap();
+
+        let query = SearchQuery { city: Some(city.clone()), ..Default::default() };
+        let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(places[0].name, "Bar 'El Tubo'; --");
+
+        let query = SearchQuery { city: Some(city), ..hostile_search_query() };
+        let (places, total) = PlaceRepository::search(&pool, &query).await.unwrap();
+        assert_eq!((places.len(), total), (0, 0));
+        assert!(PlaceRepository::search(&pool, &SearchQuery::default()).await.is_ok(), "places must survive");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_concurrent_upserts_create_exactly_once() {
+        let pool = test_pool().await;
+        let google_place_id = format!("concurrent_upsert_{}", Uuid::new_v4());
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Concurrent Place",
+            "type": "restaurant",
+            "location": [-0.8891, 41.6488],
+            "city": "Zaragoza",
+            "google_place_id": google_place_id,
+            "main_categories": []
+        }))
+        .unwrap();
+
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                let pool = pool.clone();
+                let request = request.clone();
+                tokio::spawn(async move { PlaceRepository::upsert_google_place(&pool, &request).await })
+            })
+            .collect();
+
+        let mut created = 0;
+        let mut ids = std::collections::HashSet::new();
+        for handle in handles {
+            let upserted = handle.await.unwrap().expect("upsert must not fail");
+            ids.insert(upserted.place.id);
+            if upserted.created {
+                created += 1;
+            }
+        }
+
+        assert_eq!(created, 1, "exactly one caller should observe the insert");
+        assert_eq!(ids.len(), 1, "all callers should see the same row");
+    }
+
+    /// Google place request with list fields of uneven lengths
+    fn bulk_request(google_place_id: &str, rating: f32, status: &str) -> CreatePlaceRequest {
+        serde_json::from_value(serde_json::json!({
+            "name": format!("Bulk {}", google_place_id),
+            "type": "restaurant",
+            "location": [-0.8891, 41.6488],
+            "city": "Zaragoza",
+            "google_place_id": google_place_id,
+            "main_categories": ["restaurant"],
+            "secondary_categories": ["food", "point_of_interest"],
+            "google_rating": rating,
+            "business_status": status,
+            "opening_hours": { "open_now": true },
+            "language": "es",
+            "attributes": { "dine_in": true }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bulk_upsert_columns_keep_request_order() {
+        let requests = [bulk_request("a", 4.5, "OPERATIONAL"), bulk_request("b", 3.0, "CLOSED_PERMANENTLY")];
+        let columns = BulkUpsertColumns::from_requests(&requests).unwrap();
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns.google_place_id, ["a", "b"]);
+        assert_eq!(columns.google_rating, [Some(4.5), Some(3.0)]);
+        assert_eq!(columns.longitude, [-0.8891, -0.8891]);
+        assert_eq!(columns.main_categories[0], serde_json::json!(["restaurant"]));
+        assert_eq!(columns.secondary_categories[1], serde_json::json!(["food", "point_of_interest"]));
+        assert_eq!(columns.cuisine_types[0], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_bulk_upsert_columns_reject_missing_and_repeated_ids() {
+        let mut without_id = bulk_request("a", 4.0, "OPERATIONAL");
+        without_id.google_place_id = None;
+        assert!(matches!(
+            BulkUpsertColumns::from_requests(&[bulk_request("b", 4.0, "OPERATIONAL"), without_id]),
+            Err(PlacesError::InvalidInput(message)) if message.contains("row 1")
+        ));
+
+        let repeated = [bulk_request("a", 4.0, "OPERATIONAL"), bulk_request("a", 4.5, "OPERATIONAL")];
+        assert!(matches!(
+            BulkUpsertColumns::from_requests(&repeated),
+            Err(PlacesError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_upsert_validates_before_querying() {
+        // The lazy pool has no database: both calls must return before any query
+        let pool = lazy_pool();
+        assert!(PlaceRepository::bulk_upsert(&pool, &[]).await.unwrap().is_empty());
+
+        let repeated = [bulk_request("a", 4.0, "OPERATIONAL"), bulk_request("a", 4.5, "OPERATIONAL")];
+        assert!(matches!(
+            PlaceRepository::bulk_upsert(&pool, &repeated).await,
+            Err(PlacesError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_bulk_upsert_matches_per_row_upserts() {
+        let pool = test_pool().await;
+        let run = Uuid::new_v4();
+        let batch = |prefix: &str, rating: f32, closed: bool| -> Vec<CreatePlaceRequest> {
+            (0..5)
+                .map(|i| {
+                    let status = if closed && i == 1 { "CLOSED_PERMANENTLY" } else { "OPERATIONAL" };
+                    bulk_request(&format!("{}_{}_{}", prefix, run, i), rating + i as f32 * 0.1, status)
+                })
+                .collect()
+        };
+        // Per-row and bulk write the same data under different Google ids
+        let per_row = |requests: Vec<CreatePlaceRequest>| {
+            let pool = pool.clone();
+            async move {
+                let mut upserted = Vec::new();
+                for request in &requests {
+                    upserted.push(PlaceRepository::upsert_google_place(&pool, request).await.unwrap());
+                }
+                upserted
+            }
+        };
+        let summary = |upserted: &[UpsertedPlace]| -> Vec<Value> {
+            upserted
+                .iter()
+                .map(|row| {
+                    serde_json::json!([
+                        row.created,
+                        row.deactivated,
+                        row.reactivated,
+                        row.changes.iter().map(|change| change.field.as_str()).collect::<Vec<_>>(),
+                        row.place.google_rating,
+                        row.place.is_active,
+                    ])
+                })
+                .collect()
+        };
+
+        // Seed two places of each batch so the first pass mixes inserts and updates
+        per_row(batch("per_row", 4.0, false).into_iter().take(2).collect()).await;
+        PlaceRepository::bulk_upsert(&pool, &batch("bulk", 4.0, false)[..2]).await.unwrap();
+
+        for (rating, closed) in [(4.0, false), (3.5, true), (3.5, false)] {
+            let expected = per_row(batch("per_row", rating, closed)).await;
+            let actual = PlaceRepository::bulk_upsert(&pool, &batch("bulk", rating, closed)).await.unwrap();
+            assert_eq!(summary(&actual), summary(&expected));
+            assert!(actual
+                .iter()
+                .zip(&expected)
+                .all(|(bulk, row)| bulk.place.name.replace("bulk", "per_row") == row.place.name
+                    && bulk.place.tags == row.place.tags
+                    && bulk.place.secondary_categories == row.place.secondary_categories));
+        }
+
+        let first = PlaceRepository::bulk_upsert(&pool, &batch("bulk", 3.5, true)).await.unwrap();
+        assert!(first[1].deactivated, "a CLOSED_PERMANENTLY place is stored inactive");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_written_place_matches_get_by_id() {
+        let pool = test_pool().await;
+        let stored = |place: &Place| {
+            let pool = pool.clone();
+            let id = place.id;
+            async move { serde_json::to_value(PlaceRepository::get_by_id(&pool, id).await.unwrap()).unwrap() }
+        };
+
+        let mut request = bulk_request(&format!("returning_{}", Uuid::new_v4()), 4.2, "OPERATIONAL");
+        let created = PlaceRepository::create_place(&pool, &request).await.unwrap();
+        assert_eq!(serde_json::to_value(&created).unwrap(), stored(&created).await);
+
+        request.google_rating = Some(4.6);
+        let updated = PlaceRepository::upsert_google_place(&pool, &request).await.unwrap();
+        assert!(!updated.created);
+        assert_eq!(updated.place.google_rating, Some(4.6));
+        assert_eq!(serde_json::to_value(&updated.place).unwrap(), stored(&updated.place).await);
+
+        let inserted = PlaceRepository::upsert_google_place(
+            &pool,
+            &bulk_request(&format!("returning_{}", Uuid::new_v4()), 3.9, "OPERATIONAL"),
+        )
+        .await
+        .unwrap();
+        assert!(inserted.created);
+        assert_eq!(serde_json::to_value(&inserted.place).unwrap(), stored(&inserted.place).await);
+
+        request.google_rating = Some(4.1);
+        let batch = PlaceRepository::bulk_upsert(&pool, &[request]).await.unwrap();
+        assert_eq!(serde_json::to_value(&batch[0].place).unwrap(), stored(&batch[0].place).await);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_count_stale_follows_verification() {
+        let pool = test_pool().await;
+        let id = insert_test_place(&pool, &format!("stale_count_{}", Uuid::new_v4())).await;
+        let cutoff = Utc::now() - chrono::Duration::days(30);
+
+        // Other tests may add places concurrently, so compare the listing for this row
+        let stale = PlaceRepository::list_for_refresh(&pool, None, Some(cutoff), i64::MAX).await.unwrap();
+        assert!(stale.iter().any(|candidate| candidate.id == id));
+        assert!(PlaceRepository::count_stale(&pool, cutoff).await.unwrap() >= 1);
+
+        PlaceRepository::mark_verified(&pool, id).await.unwrap();
+        let stale = PlaceRepository::list_for_refresh(&pool, None, Some(cutoff), i64::MAX).await.unwrap();
+        assert!(!stale.iter().any(|candidate| candidate.id == id));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_upsert_deactivates_closed_places_and_reactivates_them() {
+        let pool = test_pool().await;
+        let google_place_id = format!("closed_upsert_{}", Uuid::new_v4());
+        let request = |status: &str| -> CreatePlaceRequest {
+            serde_json::from_value(serde_json::json!({
+                "name": "Closing Place",
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": "Zaragoza",
+                "google_place_id": google_place_id,
+                "main_categories": [],
+                "business_status": status
+            }))
+            .unwrap()
+        };
+
+        let created = PlaceRepository::upsert_google_place(&pool, &request("OPERATIONAL")).await.unwrap();
+        assert!(created.created);
+        assert!(created.changes.is_empty());
+        assert_eq!(created.place.is_active, Some(true));
+
+        let closed = PlaceRepository::upsert_google_place(&pool, &request("CLOSED_PERMANENTLY")).await.unwrap();
+        assert!(closed.deactivated);
+        assert_eq!(closed.place.is_active, Some(false));
+        let changed: Vec<&str> = closed.changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(changed, ["business_status", "is_active"]);
+        assert!(PlaceRepository::get_by_id(&pool, closed.place.id).await.is_err());
+
+        let still_closed = PlaceRepository::upsert_google_place(&pool, &request("CLOSED_PERMANENTLY")).await.unwrap();
+        assert!(!still_closed.deactivated, "only the transition counts");
+        assert!(still_closed.changes.is_empty());
+
+        let reopened = PlaceRepository::upsert_google_place(&pool, &request("OPERATIONAL")).await.unwrap();
+        assert!(reopened.reactivated);
+        assert_eq!(reopened.place.is_active, Some(true));
+        assert!(PlaceRepository::get_by_id(&pool, reopened.place.id).await.is_ok());
+    }
+
+    /// Give a place one review and one photo
+    async fn insert_children(pool: &PgPool, place_id: Uuid) {
+        sqlx::query(
+            "INSERT INTO place_reviews (place_id, source, source_id, rating, posted_at)
+             VALUES ($1, 'google', $2, 4.0, NOW())",
+        )
+        .bind(place_id)
+        .bind(format!("review_{}", place_id))
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO place_photos (place_id, source, source_photo_reference, photo_url)
+             VALUES ($1, 'google', $2, 'https://example.com/photo.jpg')",
+        )
+        .bind(place_id)
+        .bind(format!("photo_{}", place_id))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn child_counts(pool: &PgPool, place_id: Uuid) -> (i64, i64) {
+        sqlx::query_as(
+            "SELECT (SELECT COUNT(*) FROM place_reviews WHERE place_id = $1),
+                    (SELECT COUNT(*) FROM place_photos WHERE place_id = $1)",
+        )
+        .bind(place_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_hard_delete_removes_place_and_children() {
+        let pool = test_pool().await;
+        let id = insert_test_place(&pool, &format!("hard_delete_{}", Uuid::new_v4())).await;
+        insert_children(&pool, id).await;
+
+        let report = PlaceRepository::hard_delete_place(&pool, id).await.unwrap();
+        assert_eq!(report.city, "Zaragoza");
+        assert_eq!((report.reviews_deleted, report.photos_deleted), (1, 1));
+        assert_eq!(child_counts(&pool, id).await, (0, 0));
+
+        let remaining: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM places WHERE id = $1")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining.0, 0);
+        assert!(matches!(
+            PlaceRepository::hard_delete_place(&pool, id).await,
+            Err(PlacesError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_hard_delete_refuses_subscribed_place() {
+        let pool = test_pool().await;
+        let id = insert_test_place(&pool, &format!("hard_delete_sub_{}", Uuid::new_v4())).await;
+        insert_children(&pool, id).await;
+        sqlx::query("UPDATE places SET is_subscribed = true WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            PlaceRepository::hard_delete_place(&pool, id).await,
+            Err(PlacesError::Conflict(_))
+        ));
+        assert!(PlaceRepository::get_by_id(&pool, id).await.is_ok());
+        assert_eq!(child_counts(&pool, id).await, (1, 1));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_soft_delete_keeps_row_and_children() {
+        let pool = test_pool().await;
+        let id = insert_test_place(&pool, &format!("soft_delete_{}", Uuid::new_v4())).await;
+        insert_children(&pool, id).await;
+
+        assert_eq!(PlaceRepository::delete_place(&pool, id).await.unwrap(), "Zaragoza");
+
+        let (active,): (Option<bool>,) = sqlx::query_as("SELECT is_active FROM places WHERE id = $1")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(active, Some(false));
+        assert_eq!(child_counts(&pool, id).await, (1, 1));
 
-        Ok(count)
+        // Inactive places can still be removed for good
+        let report = PlaceRepository::hard_delete_place(&pool, id).await.unwrap();
+        assert_eq!((report.reviews_deleted, report.photos_deleted), (1, 1));
     }
 }
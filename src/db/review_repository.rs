@@ -3,53 +3,317 @@
 // PURPOSE: Handle CRUD operations for place reviews
 
 use crate::errors::PlacesError;
-use crate::models::{CreateReviewRequest, Review};
-use sqlx::PgPool;
+use crate::models::{google_review_source_id, CreateReviewRequest, Review};
+use sqlx::{FromRow, PgPool};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 pub struct ReviewRepository;
 
+/// Pending source_id backfill for a review row
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceIdBackfill {
+    pub id: Uuid,
+    pub source: String,
+    pub source_id: String,
+}
+
+/// Work computed for `dedupe_null_source_ids`
+#[derive(Debug, Default)]
+pub struct ReviewDedupePlan {
+    /// Rows that keep living with a generated source_id
+    pub backfills: Vec<SourceIdBackfill>,
+    /// Newer duplicates to delete
+    pub deletes: Vec<Uuid>,
+}
+
+/// Decide which NULL-source_id reviews survive
+/// DOCUMENTATION: `rows` must be ordered oldest first; the first row per
+/// (source, fallback source_id) is kept and the rest are marked for deletion
+pub fn plan_null_source_id_dedupe(rows: &[Review]) -> ReviewDedupePlan {
+    let mut plan = ReviewDedupePlan::default();
+    let mut seen = HashSet::new();
+
+    for row in rows {
+        let source_id = row.fallback_source_id();
+        if seen.insert((row.source.clone(), source_id.clone())) {
+            plan.backfills.push(SourceIdBackfill {
+                id: row.id,
+                source: row.source.clone(),
+                source_id,
+            });
+        } else {
+            plan.deletes.push(row.id);
+        }
+    }
+
+    plan
+}
+
+/// Stored Google review with the Google id of its place
+#[derive(Debug, Clone, FromRow)]
+pub struct GoogleReviewRow {
+    #[sqlx(flatten)]
+    pub review: Review,
+    pub google_place_id: String,
+}
+
+/// Decide the hashed source_id of every stored Google review
+/// DOCUMENTATION: Reviews that hash to the same source_id are one review stored
+/// several times (edited, or re-synced under another timestamp); the most recently
+/// posted copy (then the most recently updated) is kept and the rest are deleted.
+/// Rows already carrying their hashed source_id are left alone
+pub fn plan_google_source_id_rehash(rows: &[GoogleReviewRow]) -> ReviewDedupePlan {
+    let mut groups: HashMap<String, Vec<&Review>> = HashMap::new();
+    for row in rows {
+        let review = &row.review;
+        let source_id = google_review_source_id(
+            &row.google_place_id,
+            review.author.as_deref(),
+            review.text.as_deref(),
+            Some(review.posted_at.timestamp()),
+        );
+        groups.entry(source_id).or_default().push(review);
+    }
+
+    let mut plan = ReviewDedupePlan::default();
+    for (source_id, mut reviews) in groups {
+        reviews.sort_by(|a, b| {
+            b.posted_at
+                .cmp(&a.posted_at)
+                .then(b.updated_at.cmp(&a.updated_at))
+                .then(a.id.cmp(&b.id))
+        });
+        let keep = reviews[0];
+        plan.deletes.extend(reviews[1..].iter().map(|review| review.id));
+        if keep.source_id.as_deref() != Some(source_id.as_str()) {
+            plan.backfills.push(SourceIdBackfill {
+                id: keep.id,
+                source: keep.source.clone(),
+                source_id,
+            });
+        }
+    }
+
+    plan.backfills.sort_by_key(|backfill| backfill.id);
+    plan.deletes.sort();
+    plan
+}
+
 impl ReviewRepository {
     /// Create a new review
     /// DOCUMENTATION: Insert review from any source (Google, Trustpilot, etc.)
+    /// source_id is never stored as NULL (see `CreateReviewRequest::effective_source_id`)
+    /// so re-posting the same review updates it instead of inserting a duplicate
+    /// A row still stored under `legacy_source_id` is first moved to the new
+    /// source_id (unless that one is already taken), so it is updated in place
     pub async fn create_review(
         pool: &PgPool,
         req: &CreateReviewRequest,
     ) -> Result<Review, PlacesError> {
+        let source_id = req.effective_source_id();
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin create review failed: {}", e))
+        })?;
+
+        if let Some(legacy) = req.legacy_source_id.as_deref().filter(|legacy| *legacy != source_id) {
+            sqlx::query(
+                r#"
+                UPDATE place_reviews
+                SET source_id = $3
+                WHERE source = $1 AND source_id = $2 AND place_id = $4
+                    AND NOT EXISTS (
+                        SELECT 1 FROM place_reviews WHERE source = $1 AND source_id = $3
+                    )
+                "#,
+            )
+            .bind(&req.source)
+            .bind(legacy)
+            .bind(&source_id)
+            .bind(&req.place_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to migrate review source_id {}: {}", legacy, e);
+                PlacesError::DatabaseError(format!("Migrate review source_id failed: {}", e))
+            })?;
+        }
+
         let review = sqlx::query_as::<_, Review>(
             r#"
             INSERT INTO place_reviews (
                 place_id, source, source_id, author, rating, text, posted_at,
-                is_verified, has_photo
+                is_verified, has_photo, language
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             ON CONFLICT (source, source_id) DO UPDATE
             SET
                 rating = EXCLUDED.rating,
                 text = EXCLUDED.text,
+                language = COALESCE(EXCLUDED.language, place_reviews.language),
                 updated_at = CURRENT_TIMESTAMP
             RETURNING *
             "#,
         )
         .bind(&req.place_id)
         .bind(&req.source)
-        .bind(&req.source_id)
+        .bind(&source_id)
         .bind(&req.author)
         .bind(req.rating)
         .bind(&req.text)
         .bind(&req.posted_at)
         .bind(req.is_verified.unwrap_or(false))
         .bind(req.has_photo.unwrap_or(false))
-        .fetch_one(pool)
+        .bind(&req.language)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
             log::error!("Failed to create review: {}", e);
             PlacesError::DatabaseError(format!("Create review failed: {}", e))
         })?;
 
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit create review failed: {}", e))
+        })?;
+
         Ok(review)
     }
 
+    /// Move stored Google reviews to hashed source_ids and merge duplicates
+    /// DOCUMENTATION: One-off backfill for reviews synced under
+    /// `{google_place_id}_{time}`; see `plan_google_source_id_rehash` for which copy
+    /// survives. Returns (source_ids updated, duplicates deleted).
+    pub async fn rehash_google_source_ids(pool: &PgPool) -> Result<(u64, u64), PlacesError> {
+        let rows = sqlx::query_as::<_, GoogleReviewRow>(
+            r#"
+            SELECT r.*, p.google_place_id
+            FROM place_reviews r
+            JOIN places p ON p.id = r.place_id
+            WHERE r.source = 'google' AND p.google_place_id IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to load Google reviews: {}", e);
+            PlacesError::DatabaseError(format!("Load reviews failed: {}", e))
+        })?;
+
+        let plan = plan_google_source_id_rehash(&rows);
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin review rehash failed: {}", e))
+        })?;
+
+        let mut deleted = 0u64;
+        if !plan.deletes.is_empty() {
+            deleted = sqlx::query("DELETE FROM place_reviews WHERE id = ANY($1)")
+                .bind(&plan.deletes)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PlacesError::DatabaseError(format!("Delete duplicates failed: {}", e)))?
+                .rows_affected();
+        }
+
+        // Rows may swap source_ids with each other, so every updated row first gets
+        // a placeholder that cannot collide
+        let ids: Vec<Uuid> = plan.backfills.iter().map(|backfill| backfill.id).collect();
+        if !ids.is_empty() {
+            sqlx::query("UPDATE place_reviews SET source_id = 'rehash_' || id::text WHERE id = ANY($1)")
+                .bind(&ids)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PlacesError::DatabaseError(format!("Rehash source_id failed: {}", e)))?;
+        }
+        for backfill in &plan.backfills {
+            sqlx::query("UPDATE place_reviews SET source_id = $1 WHERE id = $2")
+                .bind(&backfill.source_id)
+                .bind(backfill.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PlacesError::DatabaseError(format!("Rehash source_id failed: {}", e)))?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit review rehash failed: {}", e))
+        })?;
+
+        log::info!(
+            "Review rehash: {} source_ids updated, {} duplicates deleted",
+            plan.backfills.len(),
+            deleted
+        );
+
+        Ok((plan.backfills.len() as u64, deleted))
+    }
+
+    /// Collapse reviews stored with a NULL source_id
+    /// DOCUMENTATION: One-off cleanup for rows written before source_id was enforced.
+    /// Duplicates keep the oldest row; survivors get their fallback source_id so later
+    /// re-posts hit the ON CONFLICT clause. Returns (rows backfilled, rows deleted).
+    pub async fn dedupe_null_source_ids(pool: &PgPool) -> Result<(u64, u64), PlacesError> {
+        let rows = sqlx::query_as::<_, Review>(
+            r#"
+            SELECT * FROM place_reviews
+            WHERE source_id IS NULL
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to load reviews without source_id: {}", e);
+            PlacesError::DatabaseError(format!("Load reviews failed: {}", e))
+        })?;
+
+        let plan = plan_null_source_id_dedupe(&rows);
+        let mut tx = pool.begin().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Begin review dedupe failed: {}", e))
+        })?;
+
+        let mut deleted = 0u64;
+        if !plan.deletes.is_empty() {
+            deleted += sqlx::query("DELETE FROM place_reviews WHERE id = ANY($1)")
+                .bind(&plan.deletes)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PlacesError::DatabaseError(format!("Delete duplicates failed: {}", e)))?
+                .rows_affected();
+        }
+
+        for backfill in &plan.backfills {
+            // A newer copy may already own the fallback id; the oldest row wins
+            deleted += sqlx::query(
+                "DELETE FROM place_reviews WHERE source = $1 AND source_id = $2 AND id <> $3",
+            )
+            .bind(&backfill.source)
+            .bind(&backfill.source_id)
+            .bind(backfill.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Delete duplicates failed: {}", e)))?
+            .rows_affected();
+
+            sqlx::query("UPDATE place_reviews SET source_id = $1 WHERE id = $2")
+                .bind(&backfill.source_id)
+                .bind(backfill.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PlacesError::DatabaseError(format!("Backfill source_id failed: {}", e)))?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            PlacesError::DatabaseError(format!("Commit review dedupe failed: {}", e))
+        })?;
+
+        log::info!(
+            "Review dedupe: {} source_ids backfilled, {} duplicates deleted",
+            plan.backfills.len(),
+            deleted
+        );
+
+        Ok((plan.backfills.len() as u64, deleted))
+    }
+
     /// Get reviews for a place
     /// DOCUMENTATION: Fetch all reviews for a specific place, optionally filtered by source
     pub async fn get_reviews_by_place(
@@ -141,3 +405,153 @@ impl ReviewRepository {
         Ok(stats)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_test_place, test_pool};
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn stored_review(author: &str, text: &str, age_minutes: i64) -> Review {
+        let posted_at = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        Review {
+            id: Uuid::new_v4(),
+            place_id: Uuid::nil(),
+            source: "custom".to_string(),
+            source_id: None,
+            author: Some(author.to_string()),
+            rating: 4.0,
+            text: Some(text.to_string()),
+            posted_at,
+            sentiment: None,
+            sentiment_score: None,
+            extracted_tags: None,
+            helpful_count: None,
+            response_from_owner: None,
+            is_verified: None,
+            has_photo: None,
+            language: None,
+            created_at: Utc::now() - Duration::minutes(age_minutes),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_plan_keeps_oldest_duplicate() {
+        let oldest = stored_review("Ana", "Genial", 30);
+        let newer_copy = stored_review("Ana", "Genial", 10);
+        let other = stored_review("Luis", "Regular", 20);
+
+        let plan = plan_null_source_id_dedupe(&[oldest.clone(), other.clone(), newer_copy.clone()]);
+
+        assert_eq!(plan.deletes, vec![newer_copy.id]);
+        let kept: Vec<Uuid> = plan.backfills.iter().map(|b| b.id).collect();
+        assert_eq!(kept, vec![oldest.id, other.id]);
+        assert_eq!(plan.backfills[0].source_id, oldest.fallback_source_id());
+    }
+
+    fn google_row(author: &str, text: &str, posted_at: i64, source_id: &str) -> GoogleReviewRow {
+        let mut review = stored_review(author, text, 0);
+        review.source = "google".to_string();
+        review.posted_at = Utc.timestamp_opt(posted_at, 0).unwrap();
+        review.source_id = Some(source_id.to_string());
+        GoogleReviewRow { review, google_place_id: "ChIJ1".to_string() }
+    }
+
+    #[test]
+    fn test_rehash_plan_merges_edited_copies_and_keeps_the_latest() {
+        let original = google_row("Ana", "Muy bueno", 1_700_000_000, "ChIJ1_1700000000");
+        let re_synced = google_row("Ana", "muy  bueno", 1_700_000_500, "ChIJ1_1700000500");
+        let other = google_row("Luis", "Regular", 0, "ChIJ1_0");
+
+        let plan = plan_google_source_id_rehash(&[original.clone(), re_synced.clone(), other.clone()]);
+
+        assert_eq!(plan.deletes, vec![original.review.id]);
+        let mut kept: Vec<Uuid> = plan.backfills.iter().map(|b| b.id).collect();
+        kept.sort();
+        let mut expected = vec![re_synced.review.id, other.review.id];
+        expected.sort();
+        assert_eq!(kept, expected);
+        let backfill = plan.backfills.iter().find(|b| b.id == re_synced.review.id).unwrap();
+        assert_eq!(
+            backfill.source_id,
+            google_review_source_id("ChIJ1", Some("Ana"), Some("Muy bueno"), None)
+        );
+    }
+
+    #[test]
+    fn test_rehash_plan_leaves_hashed_rows_alone() {
+        let source_id = google_review_source_id("ChIJ1", Some("Ana"), Some("Genial"), None);
+        let plan = plan_google_source_id_rehash(&[google_row("Ana", "Genial", 1_700_000_000, &source_id)]);
+
+        assert!(plan.backfills.is_empty());
+        assert!(plan.deletes.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_legacy_google_review_is_moved_instead_of_duplicated() {
+        let pool = test_pool().await;
+        let google_place_id = format!("review_legacy_{}", Uuid::new_v4());
+        let place_id = insert_test_place(&pool, &google_place_id).await;
+        let legacy = CreateReviewRequest {
+            place_id,
+            source: "google".to_string(),
+            source_id: Some(format!("{}_0", google_place_id)),
+            author: Some("Ana".to_string()),
+            rating: 4.0,
+            text: Some("Bien".to_string()),
+            posted_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            is_verified: None,
+            has_photo: None,
+            language: None,
+            legacy_source_id: None,
+        };
+        ReviewRepository::create_review(&pool, &legacy).await.unwrap();
+
+        let hashed = CreateReviewRequest {
+            source_id: Some(google_review_source_id(&google_place_id, Some("Ana"), Some("Bien"), None)),
+            legacy_source_id: legacy.source_id.clone(),
+            rating: 5.0,
+            ..legacy.clone()
+        };
+        ReviewRepository::create_review(&pool, &hashed).await.unwrap();
+
+        let reviews = ReviewRepository::get_reviews_by_place(&pool, &place_id, None)
+            .await
+            .unwrap();
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].source_id, hashed.source_id);
+        assert_eq!(reviews[0].rating, 5.0);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_create_review_without_source_id_is_idempotent() {
+        let pool = test_pool().await;
+        let place_id = insert_test_place(&pool, &format!("review_dedupe_{}", Uuid::new_v4())).await;
+        let request = CreateReviewRequest {
+            place_id,
+            source: "custom".to_string(),
+            source_id: None,
+            author: Some("Ana".to_string()),
+            rating: 5.0,
+            text: Some("Muy bueno".to_string()),
+            posted_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            is_verified: None,
+            has_photo: None,
+            language: Some("es".to_string()),
+            legacy_source_id: None,
+        };
+
+        ReviewRepository::create_review(&pool, &request).await.unwrap();
+        ReviewRepository::create_review(&pool, &request).await.unwrap();
+
+        let reviews = ReviewRepository::get_reviews_by_place(&pool, &place_id, None)
+            .await
+            .unwrap();
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].source_id.as_deref(), Some(request.effective_source_id().as_str()));
+        assert_eq!(reviews[0].language.as_deref(), Some("es"));
+    }
+}
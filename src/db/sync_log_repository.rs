@@ -0,0 +1,192 @@
+// src/db/sync_log_repository.rs
+// DOCUMENTATION: Sync run history database operations
+// PURPOSE: Record background sync jobs in `data_sync_log`, one row per run (the row id
+// is the job id), so their outcome outlives the in-memory job registry
+
+use crate::errors::PlacesError;
+use crate::services::{SyncStats, WebhookDelivery};
+use sqlx::types::Json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// `data_sync_log.source` of Google Places syncs
+pub const SYNC_LOG_SOURCE: &str = "google_places";
+
+pub struct SyncLogRepository;
+
+impl SyncLogRepository {
+    /// Record a queued run as `pending`
+    /// DOCUMENTATION: `sync_type` is "city", "bbox" or "batch"; batch runs have no
+    /// city and list their cities in `notes`
+    pub async fn insert_run(
+        pool: &PgPool,
+        run_id: Uuid,
+        sync_type: &str,
+        city: Option<&str>,
+        notes: Option<&str>,
+    ) -> Result<(), PlacesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO data_sync_log (id, source, sync_type, city, status, notes)
+            VALUES ($1, $2, $3, $4, 'pending', $5)
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(run_id)
+        .bind(SYNC_LOG_SOURCE)
+        .bind(sync_type)
+        .bind(city)
+        .bind(notes)
+        .execute(pool)
+        .await
+        .map_err(|e| PlacesError::DatabaseError(format!("Record sync run failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Mark a run `in_progress` from now on
+    pub async fn mark_started(pool: &PgPool, run_id: Uuid) -> Result<(), PlacesError> {
+        sqlx::query("UPDATE data_sync_log SET status = 'in_progress', started_at = NOW() WHERE id = $1")
+            .bind(run_id)
+            .execute(pool)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Record sync run failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Store the final status and counts of a run
+    /// DOCUMENTATION: `status` is "completed", "partial" or "failed"; `error` is why the
+    /// run failed, per-cell errors only count towards `error_count`. The `by_type` and
+    /// `cells` breakdowns are stored as they are, `cells` already capped by the sync
+    pub async fn finish_run(
+        pool: &PgPool,
+        run_id: Uuid,
+        status: &str,
+        stats: &SyncStats,
+        error: Option<&str>,
+    ) -> Result<(), PlacesError> {
+        let count = |n: u32| i32::try_from(n).unwrap_or(i32::MAX);
+
+        sqlx::query(
+            r#"
+            UPDATE data_sync_log
+            SET status = $2,
+                records_requested = $3,
+                records_processed = $4,
+                records_created = $5,
+                records_updated = $6,
+                records_failed = $7,
+                error_message = $8,
+                error_count = $9,
+                api_quota_used = $10,
+                by_type = $11,
+                cells = $12,
+                completed_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(run_id)
+        .bind(status)
+        .bind(count(stats.places_retrieved))
+        .bind(count(stats.places_created.saturating_add(stats.places_skipped)))
+        .bind(count(stats.places_created))
+        .bind(count(stats.places_changed))
+        .bind(count(stats.places_failed))
+        .bind(error)
+        .bind(i32::try_from(stats.errors.len()).unwrap_or(i32::MAX) + i32::from(error.is_some()))
+        .bind(count(stats.api_requests))
+        .bind(Json(&stats.by_type))
+        .bind(Json(&stats.cells))
+        .execute(pool)
+        .await
+        .map_err(|e| PlacesError::DatabaseError(format!("Record sync run failed: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Store the outcome of the run's webhook notification
+    pub async fn record_webhook(
+        pool: &PgPool,
+        run_id: Uuid,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), PlacesError> {
+        sqlx::query("UPDATE data_sync_log SET webhook = $2 WHERE id = $1")
+            .bind(run_id)
+            .bind(Json(delivery))
+            .execute(pool)
+            .await
+            .map_err(|e| PlacesError::DatabaseError(format!("Record webhook delivery failed: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::CellStat;
+    use sqlx::Row;
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_run_row_follows_the_job() {
+        let pool = crate::test_support::test_pool().await;
+        let run_id = Uuid::new_v4();
+
+        SyncLogRepository::insert_run(&pool, run_id, "city", Some("Zaragoza"), None).await.unwrap();
+        SyncLogRepository::mark_started(&pool, run_id).await.unwrap();
+
+        let mut stats = SyncStats::new("Zaragoza".to_string());
+        stats.places_retrieved = 12;
+        stats.places_created = 5;
+        stats.places_skipped = 6;
+        stats.places_failed = 1;
+        stats.api_requests = 9;
+        stats.errors.push("Cell Zaragoza-3 failed".to_string());
+        stats.by_type.insert("restaurant".to_string(), 5);
+        stats.cells.push(CellStat {
+            cell_id: "Zaragoza-3".to_string(),
+            retrieved: 12,
+            created: 5,
+            skipped: 6,
+            failed: 1,
+        });
+        SyncLogRepository::finish_run(&pool, run_id, "partial", &stats, None).await.unwrap();
+
+        let delivery = WebhookDelivery {
+            url: "https://hooks.example.com/sync".to_string(),
+            delivered: false,
+            attempts: 3,
+            error: Some("HTTP 503".to_string()),
+        };
+        SyncLogRepository::record_webhook(&pool, run_id, &delivery).await.unwrap();
+
+        let row = sqlx::query(
+            "SELECT status, sync_type, city, records_processed, records_created, error_count, api_quota_used, webhook,
+                    by_type, cells
+             FROM data_sync_log WHERE id = $1",
+        )
+        .bind(run_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(row.get::<String, _>("status"), "partial");
+        assert_eq!(row.get::<String, _>("sync_type"), "city");
+        assert_eq!(row.get::<Option<String>, _>("city").as_deref(), Some("Zaragoza"));
+        assert_eq!(row.get::<Option<i32>, _>("records_processed"), Some(11));
+        assert_eq!(row.get::<Option<i32>, _>("records_created"), Some(5));
+        assert_eq!(row.get::<Option<i32>, _>("error_count"), Some(1));
+        assert_eq!(row.get::<Option<i32>, _>("api_quota_used"), Some(9));
+        let webhook: serde_json::Value = row.get("webhook");
+        assert_eq!(webhook["delivered"], false);
+        assert_eq!(webhook["error"], "HTTP 503");
+        let by_type: serde_json::Value = row.get("by_type");
+        assert_eq!(by_type, serde_json::json!({ "restaurant": 5 }));
+        let cells: serde_json::Value = row.get("cells");
+        assert_eq!(cells[0]["cell_id"], "Zaragoza-3");
+        assert_eq!(cells[0]["skipped"], 6);
+
+        sqlx::query("DELETE FROM data_sync_log WHERE id = $1").bind(run_id).execute(&pool).await.unwrap();
+    }
+}
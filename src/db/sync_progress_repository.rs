@@ -0,0 +1,145 @@
+// src/db/sync_progress_repository.rs
+// DOCUMENTATION: Sync progress database operations
+// PURPOSE: Record completed grid cells in `sync_cell_progress` so syncs can resume
+
+use crate::errors::PlacesError;
+use crate::services::GridCell;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+pub struct SyncProgressRepository;
+
+/// Last completed search of a grid cell
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct CompletedCell {
+    pub cell_id: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub radius_m: i32,
+    pub results_count: i32,
+    pub completed_at: DateTime<Utc>,
+}
+
+impl CompletedCell {
+    /// Whether this record is for `cell` (same center and radius, not just the same id)
+    pub fn matches(&self, cell: &GridCell) -> bool {
+        self.cell_id == cell.cell_id
+            && i64::from(self.radius_m) == i64::from(cell.radius)
+            && (self.latitude - cell.latitude).abs() < 1e-7
+            && (self.longitude - cell.longitude).abs() < 1e-7
+    }
+}
+
+impl SyncProgressRepository {
+    /// Record a searched cell, replacing any earlier record of the same cell id
+    pub async fn record_cell(
+        pool: &PgPool,
+        city: &str,
+        place_type: Option<&str>,
+        cell: &GridCell,
+        results_count: usize,
+    ) -> Result<(), PlacesError> {
+        sqlx::query(
+            r#"
+            INSERT INTO sync_cell_progress
+                (city, cell_id, place_type, latitude, longitude, radius_m, results_count, completed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, NOW())
+            ON CONFLICT (city, place_type, cell_id) DO UPDATE
+            SET latitude = EXCLUDED.latitude,
+                longitude = EXCLUDED.longitude,
+                radius_m = EXCLUDED.radius_m,
+                results_count = EXCLUDED.results_count,
+                completed_at = EXCLUDED.completed_at
+            "#,
+        )
+        .bind(city)
+        .bind(&cell.cell_id)
+        .bind(place_type.unwrap_or_default())
+        .bind(cell.latitude)
+        .bind(cell.longitude)
+        .bind(i32::try_from(cell.radius).unwrap_or(i32::MAX))
+        .bind(i32::try_from(results_count).unwrap_or(i32::MAX))
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to record sync progress for {}: {}", cell.cell_id, e);
+            PlacesError::DatabaseError(format!("Record sync progress failed: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    /// Cells of a sync completed at or after `since`, keyed by cell id
+    pub async fn completed_since(
+        pool: &PgPool,
+        city: &str,
+        place_type: Option<&str>,
+        since: DateTime<Utc>,
+    ) -> Result<HashMap<String, CompletedCell>, PlacesError> {
+        let cells = sqlx::query_as::<_, CompletedCell>(
+            r#"
+            SELECT cell_id, latitude, longitude, radius_m, results_count, completed_at
+            FROM sync_cell_progress
+            WHERE city = $1 AND place_type = $2 AND completed_at >= $3
+            "#,
+        )
+        .bind(city)
+        .bind(place_type.unwrap_or_default())
+        .bind(since)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            log::error!("Failed to load sync progress for {}: {}", city, e);
+            PlacesError::DatabaseError(format!("Load sync progress failed: {}", e))
+        })?;
+
+        Ok(cells.into_iter().map(|cell| (cell.cell_id.clone(), cell)).collect())
+    }
+
+    /// Forget the progress of a sync (all place types)
+    pub async fn clear(pool: &PgPool, city: &str) -> Result<u64, PlacesError> {
+        let result = sqlx::query("DELETE FROM sync_cell_progress WHERE city = $1")
+            .bind(city)
+            .execute(pool)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to clear sync progress for {}: {}", city, e);
+                PlacesError::DatabaseError(format!("Clear sync progress failed: {}", e))
+            })?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell() -> GridCell {
+        GridCell {
+            latitude: 41.65,
+            longitude: -0.88,
+            radius: 1000,
+            cell_id: "Zaragoza-3".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_completed_cell_matches_center_and_radius() {
+        let completed = CompletedCell {
+            cell_id: "Zaragoza-3".to_string(),
+            latitude: 41.65,
+            longitude: -0.88,
+            radius_m: 1000,
+            results_count: 12,
+            completed_at: Utc::now(),
+        };
+
+        assert!(completed.matches(&cell()));
+        // Same id from a grid with other parameters
+        assert!(!completed.matches(&GridCell { radius: 700, ..cell() }));
+        assert!(!completed.matches(&GridCell { latitude: 41.66, ..cell() }));
+        assert!(!completed.matches(&GridCell { cell_id: "Zaragoza-4".to_string(), ..cell() }));
+    }
+}
@@ -6,6 +6,91 @@ use actix_web::{error::ResponseError, http::StatusCode, HttpResponse};
 use serde_json::json;
 use thiserror::Error;
 
+/// Failure reported by, or while talking to, the Google Places API
+/// DOCUMENTATION: Categorises legacy `status` values and v1 HTTP errors so callers can
+/// tell an invalid key from a transient failure without matching on message text.
+/// The payload is Google's `error_message` when it sent one, otherwise our description
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GoogleApiError {
+    #[error("quota exceeded: {0}")]
+    OverQueryLimit(String),
+
+    #[error("request denied: {0}")]
+    RequestDenied(String),
+
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+
+    #[error("unknown error: {0}")]
+    Unknown(String),
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("network error: {0}")]
+    Network(String),
+
+    #[error("unparseable response: {0}")]
+    Parse(String),
+}
+
+impl GoogleApiError {
+    /// Map a legacy API `status` (anything but OK/ZERO_RESULTS) and its `error_message`
+    pub fn from_status(status: &str, error_message: Option<String>) -> Self {
+        let message = error_message.unwrap_or_else(|| status.to_string());
+        match status {
+            "OVER_QUERY_LIMIT" => GoogleApiError::OverQueryLimit(message),
+            "REQUEST_DENIED" => GoogleApiError::RequestDenied(message),
+            "INVALID_REQUEST" => GoogleApiError::InvalidRequest(message),
+            "NOT_FOUND" => GoogleApiError::NotFound(message),
+            "UNKNOWN_ERROR" => GoogleApiError::Unknown(message),
+            other => GoogleApiError::Unknown(format!("unexpected status {}: {}", other, message)),
+        }
+    }
+
+    /// Map a non-2xx HTTP status (v1 errors, legacy transport failures)
+    pub fn from_http(status: u16, message: String) -> Self {
+        match status {
+            429 => GoogleApiError::OverQueryLimit(message),
+            401 | 403 => GoogleApiError::RequestDenied(message),
+            400 => GoogleApiError::InvalidRequest(message),
+            404 => GoogleApiError::NotFound(message),
+            _ => GoogleApiError::Unknown(format!("HTTP {}: {}", status, message)),
+        }
+    }
+
+    /// Stable snake_case name used in logs and JSON error bodies
+    pub fn category(&self) -> &'static str {
+        match self {
+            GoogleApiError::OverQueryLimit(_) => "over_query_limit",
+            GoogleApiError::RequestDenied(_) => "request_denied",
+            GoogleApiError::InvalidRequest(_) => "invalid_request",
+            GoogleApiError::Unknown(_) => "unknown",
+            GoogleApiError::NotFound(_) => "not_found",
+            GoogleApiError::Network(_) => "network",
+            GoogleApiError::Parse(_) => "parse",
+        }
+    }
+
+    /// Worth retrying the same request after a short pause (Google suggests this for UNKNOWN_ERROR)
+    pub fn is_transient(&self) -> bool {
+        matches!(self, GoogleApiError::Unknown(_) | GoogleApiError::Network(_))
+    }
+
+    /// The quota is used up for now; it may be restored once Google's window resets
+    pub fn is_quota(&self) -> bool {
+        matches!(self, GoogleApiError::OverQueryLimit(_))
+    }
+
+    /// Every further request will fail the same way (quota or key problems)
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            GoogleApiError::OverQueryLimit(_) | GoogleApiError::RequestDenied(_)
+        )
+    }
+}
+
 /// Application-specific error types
 /// DOCUMENTATION: Comprehensive error enum for all possible failures
 /// Each variant maps to appropriate HTTP status code and error response
@@ -18,6 +103,9 @@ pub enum PlacesError {
     #[allow(dead_code)]
     AlreadyExists(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Database error: {0}")]
     DatabaseError(String),
 
@@ -40,11 +128,13 @@ pub enum PlacesError {
     #[error("External API error: {0}")]
     ExternalApiError(String),
 
+    #[error("Google Places API error: {0}")]
+    GoogleApi(#[from] GoogleApiError),
+
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
 
     #[error("Service temporarily unavailable")]
-    #[allow(dead_code)]
     ServiceUnavailable,
 }
 
@@ -55,6 +145,7 @@ impl ResponseError for PlacesError {
         let (status, error_code) = match self {
             PlacesError::NotFound(_) => (StatusCode::NOT_FOUND, "NOT_FOUND"),
             PlacesError::AlreadyExists(_) => (StatusCode::CONFLICT, "ALREADY_EXISTS"),
+            PlacesError::Conflict(_) => (StatusCode::CONFLICT, "CONFLICT"),
             PlacesError::DatabaseError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "DATABASE_ERROR"),
             PlacesError::InvalidInput(_) => (StatusCode::BAD_REQUEST, "INVALID_INPUT"),
             PlacesError::ValidationError(_) => (StatusCode::BAD_REQUEST, "VALIDATION_ERROR"),
@@ -62,6 +153,13 @@ impl ResponseError for PlacesError {
             PlacesError::Forbidden => (StatusCode::FORBIDDEN, "FORBIDDEN"),
             PlacesError::InternalError => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
             PlacesError::ExternalApiError(_) => (StatusCode::BAD_GATEWAY, "EXTERNAL_API_ERROR"),
+            PlacesError::GoogleApi(GoogleApiError::OverQueryLimit(_)) => {
+                (StatusCode::TOO_MANY_REQUESTS, "RATE_LIMIT_EXCEEDED")
+            }
+            PlacesError::GoogleApi(GoogleApiError::NotFound(_)) => {
+                (StatusCode::NOT_FOUND, "NOT_FOUND")
+            }
+            PlacesError::GoogleApi(_) => (StatusCode::BAD_GATEWAY, "EXTERNAL_API_ERROR"),
             PlacesError::RateLimitExceeded => {
                 (StatusCode::TOO_MANY_REQUESTS, "RATE_LIMIT_EXCEEDED")
             }
@@ -70,13 +168,16 @@ impl ResponseError for PlacesError {
             }
         };
 
-        let body = json!({
+        let mut body = json!({
             "error": {
                 "code": error_code,
                 "message": self.to_string(),
                 "timestamp": chrono::Utc::now().to_rfc3339()
             }
         });
+        if let PlacesError::GoogleApi(e) = self {
+            body["error"]["category"] = json!(e.category());
+        }
 
         HttpResponse::build(status).json(body)
     }
@@ -84,7 +185,7 @@ impl ResponseError for PlacesError {
     fn status_code(&self) -> StatusCode {
         match self {
             PlacesError::NotFound(_) => StatusCode::NOT_FOUND,
-            PlacesError::AlreadyExists(_) => StatusCode::CONFLICT,
+            PlacesError::AlreadyExists(_) | PlacesError::Conflict(_) => StatusCode::CONFLICT,
             PlacesError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
             PlacesError::InvalidInput(_) => StatusCode::BAD_REQUEST,
             PlacesError::ValidationError(_) => StatusCode::BAD_REQUEST,
@@ -92,8 +193,79 @@ impl ResponseError for PlacesError {
             PlacesError::Forbidden => StatusCode::FORBIDDEN,
             PlacesError::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
             PlacesError::ExternalApiError(_) => StatusCode::BAD_GATEWAY,
+            PlacesError::GoogleApi(GoogleApiError::OverQueryLimit(_)) => StatusCode::TOO_MANY_REQUESTS,
+            PlacesError::GoogleApi(GoogleApiError::NotFound(_)) => StatusCode::NOT_FOUND,
+            PlacesError::GoogleApi(_) => StatusCode::BAD_GATEWAY,
             PlacesError::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
             PlacesError::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 }
+
+impl PlacesError {
+    /// The Google failure behind this error, if it came from the Places API
+    pub fn google_error(&self) -> Option<&GoogleApiError> {
+        match self {
+            PlacesError::GoogleApi(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// NotFound from our database or from Google
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            PlacesError::NotFound(_) | PlacesError::GoogleApi(GoogleApiError::NotFound(_))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::MessageBody;
+
+    #[test]
+    fn test_google_status_mapping() {
+        assert_eq!(
+            GoogleApiError::from_status("REQUEST_DENIED", Some("The provided API key is invalid.".to_string())),
+            GoogleApiError::RequestDenied("The provided API key is invalid.".to_string())
+        );
+        assert_eq!(
+            GoogleApiError::from_status("OVER_QUERY_LIMIT", None).category(),
+            "over_query_limit"
+        );
+        assert!(GoogleApiError::from_status("UNKNOWN_ERROR", None).is_transient());
+        assert!(matches!(
+            GoogleApiError::from_status("MAX_ROUTE_LENGTH_EXCEEDED", None),
+            GoogleApiError::Unknown(_)
+        ));
+        assert!(GoogleApiError::from_http(403, String::new()).is_fatal());
+        assert!(!GoogleApiError::from_http(400, String::new()).is_transient());
+    }
+
+    #[test]
+    fn test_google_error_response_keeps_category_and_message() {
+        let error = PlacesError::from(GoogleApiError::RequestDenied("API key expired".to_string()));
+
+        let response = error.error_response();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        let body: serde_json::Value =
+            serde_json::from_slice(&response.into_body().try_into_bytes().unwrap()).unwrap();
+        assert_eq!(body["error"]["category"], "request_denied");
+        assert!(body["error"]["message"].as_str().unwrap().contains("API key expired"));
+
+        let quota = PlacesError::from(GoogleApiError::OverQueryLimit(String::new()));
+        assert_eq!(quota.status_code(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(PlacesError::from(GoogleApiError::NotFound("x".to_string())).is_not_found());
+    }
+
+    #[test]
+    fn test_conflict_maps_to_409() {
+        let response = PlacesError::Conflict("Place is subscribed".to_string()).error_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body: serde_json::Value =
+            serde_json::from_slice(&response.into_body().try_into_bytes().unwrap()).unwrap();
+        assert_eq!(body["error"]["code"], "CONFLICT");
+    }
+}
@@ -3,9 +3,15 @@
 // PURPOSE: Expose sync functionality via REST endpoints
 
 use crate::config::Config;
+use crate::db::{CityBoundsRepository, PlaceRepository, ReviewRepository};
 use crate::errors::PlacesError;
-use crate::services::{GooglePlacesClient, SyncService, PlacesCache};
+use crate::models::{detect_swapped_coordinates, PlaceDetailResponse};
+use crate::services::{
+    CityBounds, CityPolygon, FindPlaceInputType, GoogleApiOperation, GoogleApiUsage, GooglePlacesClient, GridGenerator, GridPattern, PlaceService, RefreshMode, RefreshStats, ResolvedPlace, SyncJobKind, SyncJobRegistry, SyncJobResult, SyncJobState, SyncScheduler, ScheduleStatus, is_http_url, SyncOptions, SyncService, PlacesCache,
+    DEFAULT_CELL_CONCURRENCY, DEFAULT_CELL_SIZE_KM, DEFAULT_CITY_CONCURRENCY, DEFAULT_DETAILS_REFRESH_DAYS, DEFAULT_RADIUS_M, DEFAULT_REFRESH_LIMIT, MAX_NEARBY_PAGES, MAX_NEARBY_RESULTS, MAX_REFRESH_LIMIT,
+};
 use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -19,6 +25,82 @@ pub struct SyncRequest {
     pub cell_size_km: Option<f64>,
     /// Optional search radius in meters
     pub radius_m: Option<u32>,
+    /// Optional Google response language (default GOOGLE_DEFAULT_LANGUAGE)
+    pub language: Option<String>,
+    /// Optional grid layout: "square" (default) or "hex"
+    pub grid_pattern: Option<GridPattern>,
+    /// Skip cells a recent run of the same sync already completed
+    #[serde(default)]
+    pub resume: bool,
+    /// Search and report without writing to the database
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Dry runs only: count new places without fetching Place Details
+    #[serde(default)]
+    pub skip_details: bool,
+    /// Re-fetch Place Details of stored places verified more than this many days ago
+    /// (default 30)
+    pub details_refresh_days: Option<u32>,
+    /// Stop after this many Google requests (default and maximum SYNC_MAX_API_REQUESTS)
+    pub max_api_requests: Option<u32>,
+    /// Grid cells searched at once (default 1, maximum SYNC_MAX_CELL_CONCURRENCY)
+    pub cell_concurrency: Option<usize>,
+    /// List the changed fields of each updated place in the stats
+    #[serde(default)]
+    pub verbose_changes: bool,
+    /// Notify this URL instead of WEBHOOK_URL when the sync job finishes
+    pub webhook_url: Option<String>,
+}
+
+impl SyncRequest {
+    /// Validate sync parameters before any API call is made
+    pub fn validate(&self) -> Result<(), PlacesError> {
+        if let Some(radius_m) = self.radius_m {
+            GooglePlacesClient::validate_radius(radius_m)?;
+        }
+        if let Some(language) = &self.language {
+            GooglePlacesClient::validate_language(language)?;
+        }
+        if self.grid_pattern == Some(GridPattern::Hex) && self.cell_size_km.is_some() {
+            return Err(PlacesError::InvalidInput(
+                "cell_size_km does not apply to the hex grid; its spacing follows radius_m".to_string(),
+            ));
+        }
+        if self.skip_details && !self.dry_run {
+            return Err(PlacesError::InvalidInput(
+                "skip_details is only allowed with dry_run".to_string(),
+            ));
+        }
+        validate_webhook_url(self.webhook_url.as_deref())
+    }
+
+    /// Request budget for the run: `max_api_requests`, or the server ceiling when absent
+    pub fn api_budget(&self, ceiling: u32) -> Result<u32, PlacesError> {
+        match self.max_api_requests {
+            None => Ok(ceiling),
+            Some(budget) if budget < u32::from(MAX_NEARBY_PAGES) || budget > ceiling => {
+                Err(PlacesError::InvalidInput(format!(
+                    "max_api_requests must be between {} and {}, got {}",
+                    MAX_NEARBY_PAGES, ceiling, budget
+                )))
+            }
+            Some(budget) => Ok(budget),
+        }
+    }
+
+    /// Cells searched at once: `cell_concurrency`, or serial when absent
+    pub fn cell_concurrency(&self, ceiling: usize) -> Result<usize, PlacesError> {
+        match self.cell_concurrency {
+            None => Ok(DEFAULT_CELL_CONCURRENCY),
+            Some(concurrency) if concurrency == 0 || concurrency > ceiling => {
+                Err(PlacesError::InvalidInput(format!(
+                    "cell_concurrency must be between 1 and {}, got {}",
+                    ceiling, concurrency
+                )))
+            }
+            Some(concurrency) => Ok(concurrency),
+        }
+    }
 }
 
 /// Response for sync status endpoint
@@ -32,17 +114,28 @@ pub struct SyncStatusResponse {
     pub recent_additions: i64,
     /// Number of active places
     pub active_places: i64,
+    /// Days without verification after which a place counts as stale
+    /// (SYNC_STALE_AFTER_DAYS)
+    pub stale_after_days: u32,
+    /// Active Google places not verified within `stale_after_days`
+    pub stale_places: i64,
+    /// Scheduled refresh: last and next run times
+    pub schedule: ScheduleStatus,
 }
 
 /// POST /admin/sync/{city}
 /// Trigger synchronization for a city
 ///
-/// DOCUMENTATION: Initiates Google Places sync for specified city
+/// DOCUMENTATION: Queues a background Google Places sync for the city and answers 202
+/// with its job id; progress is read from GET /admin/sync/jobs/{job_id}
 /// Requires admin authentication via X-Admin-Token header
+#[allow(clippy::too_many_arguments)]
 pub async fn sync_city(
     pool: web::Data<PgPool>,
     config: web::Data<Config>,
     cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    jobs: web::Data<Arc<SyncJobRegistry>>,
     req: HttpRequest,
     path: web::Path<String>,
     body: web::Json<SyncRequest>,
@@ -54,55 +147,427 @@ pub async fn sync_city(
 
     log::info!("Admin sync requested for city: {}", city);
 
-    // Create Google Places client with shared cache
+    body.validate()?;
+
     if config.google_places_api_key.is_empty() {
         return Err(PlacesError::InvalidInput(
             "Google Places API key not configured".to_string(),
         ));
     }
 
-    let google_client = GooglePlacesClient::new_with_cache(
-        config.google_places_api_key.clone(),
-        cache.get_ref().clone()
-    );
+    let max_api_requests = body.api_budget(config.sync_max_api_requests)?;
+    let cell_concurrency = body.cell_concurrency(config.sync_max_cell_concurrency)?;
+    let body = body.into_inner();
+    let options = SyncOptions {
+        cell_concurrency,
+        grid_pattern: body.grid_pattern.unwrap_or_default(),
+        resume: body.resume,
+        dry_run: body.dry_run,
+        skip_details: body.skip_details,
+        details_refresh_days: body.details_refresh_days.unwrap_or(DEFAULT_DETAILS_REFRESH_DAYS),
+        max_api_requests,
+        verbose_changes: body.verbose_changes,
+        ..SyncOptions::from_config(&config)
+    };
+    let pool = pool.get_ref().clone();
+    let cache = cache.get_ref().clone();
+    let google_client = google_client.get_ref().clone();
+    let job_city = city.clone();
+    let dry_run = body.dry_run;
+    let webhook_url = body.webhook_url.clone().or_else(|| config.webhook_url.clone());
 
-    // Execute sync
-    let stats = SyncService::sync_city(
-        pool.get_ref(),
-        &google_client,
-        &city,
-        body.place_type.as_deref(),
-        body.cell_size_km,
-        body.radius_m,
-    )
-    .await?;
+    let job_id = jobs.submit(SyncJobKind::City, &city, webhook_url, move |progress| async move {
+        let stats = SyncService::sync_city(
+            &pool,
+            google_client.as_ref(),
+            &job_city,
+            body.place_type.as_deref(),
+            body.cell_size_km,
+            body.radius_m,
+            body.language.as_deref(),
+            &SyncOptions { progress: Some(progress), ..options },
+        )
+        .await?;
 
-    log::info!(
-        "Sync completed for {}: {} created, {} skipped, {} failed",
-        city,
-        stats.places_created,
-        stats.places_skipped,
-        stats.places_failed
-    );
+        // Synced places change database search results for this city
+        if !stats.dry_run {
+            PlaceService::invalidate_search_cache(&cache, &job_city).await;
+        }
+
+        log::info!(
+            "Sync completed for {}: {} created, {} skipped, {} failed",
+            job_city,
+            stats.places_created,
+            stats.places_skipped,
+            stats.places_failed
+        );
+        Ok(stats)
+    })?;
+
+    log::info!("Queued sync job {} for {}", job_id, city);
+
+    Ok(HttpResponse::Accepted().json(serde_json::json!({
+        "job_id": job_id,
+        "city": city,
+        "state": SyncJobState::Queued,
+        "dry_run": dry_run,
+        "status_url": format!("/admin/sync/jobs/{}", job_id),
+    })))
+}
+
+/// GET /admin/sync/jobs/{job_id}
+/// State of a background sync
+///
+/// DOCUMENTATION: Returns the job state, its stats so far (final once completed) and
+/// the failure, if any. Finished jobs are kept for an hour
+pub async fn sync_job_status(
+    config: web::Data<Config>,
+    jobs: web::Data<Arc<SyncJobRegistry>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<impl Responder, PlacesError> {
+    verify_admin_token(&req, &config)?;
+
+    let job_id = path.into_inner();
+    let status = jobs
+        .status(&job_id)
+        .ok_or_else(|| PlacesError::NotFound(format!("No sync job '{}'", job_id)))?;
+
+    Ok(HttpResponse::Ok().json(status))
+}
+
+/// Request body for the bounding-box sync endpoint
+/// DOCUMENTATION: Box corners plus the usual sync parameters; `city` is stored on the
+/// synced places
+#[derive(Debug, Deserialize)]
+pub struct SyncBboxRequest {
+    /// City the area belongs to
+    pub city: String,
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+    #[serde(flatten)]
+    pub sync: SyncRequest,
+}
+
+impl SyncBboxRequest {
+    /// Validated bounds named after the box, e.g. "bbox:41.63,-0.91,41.66,-0.87"
+    pub fn bounds(&self, max_area_km2: f64) -> Result<CityBounds, PlacesError> {
+        if self.city.trim().is_empty() {
+            return Err(PlacesError::InvalidInput("city must not be empty".to_string()));
+        }
+        self.sync.validate()?;
+
+        // A box whose lat/lng are swapped lands far from the city; its center is
+        // checked against the city's known bounds
+        let center = [(self.min_lng + self.max_lng) / 2.0, (self.min_lat + self.max_lat) / 2.0];
+        if detect_swapped_coordinates(center, Some(&self.city)).is_some() {
+            return Err(PlacesError::InvalidInput(format!(
+                "Bounding box looks like latitude and longitude are swapped - did you mean min_lat={}, max_lat={}, min_lng={}, max_lng={}?",
+                self.min_lng, self.max_lng, self.min_lat, self.max_lat
+            )));
+        }
+
+        let bounds = CityBounds {
+            name: format!("bbox:{},{},{},{}", self.min_lat, self.min_lng, self.max_lat, self.max_lng),
+            min_lat: self.min_lat,
+            max_lat: self.max_lat,
+            min_lng: self.min_lng,
+            max_lng: self.max_lng,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: None,
+        };
+        bounds.validate().map_err(PlacesError::InvalidInput)?;
+
+        let area_km2 = GridGenerator::calculate_area_coverage(&bounds);
+        if area_km2 > max_area_km2 {
+            return Err(PlacesError::InvalidInput(format!(
+                "Bounding box covers {:.0} km², above the limit of {:.0} km²",
+                area_km2, max_area_km2
+            )));
+        }
+        Ok(bounds)
+    }
+}
+
+/// POST /admin/sync/bbox
+/// Trigger synchronization for a bounding box
+///
+/// DOCUMENTATION: Backfills one area (a neighborhood, a strip along a river) with the
+/// city sync pipeline; the box is limited to SYNC_MAX_BBOX_AREA_KM2. Runs as a
+/// background job like POST /admin/sync/{city} and answers 202 with its job id
+/// Requires admin authentication via X-Admin-Token header
+#[allow(clippy::too_many_arguments)]
+pub async fn sync_bbox(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    jobs: web::Data<Arc<SyncJobRegistry>>,
+    req: HttpRequest,
+    body: web::Json<SyncBboxRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let bounds = body.bounds(config.sync_max_bbox_area_km2)?;
+    let city = body.city.trim().to_string();
 
+    log::info!("Admin sync requested for {} (city {})", bounds.name, city);
+
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let sync = body.into_inner().sync;
+    let options = SyncOptions {
+        grid_pattern: sync.grid_pattern.unwrap_or_default(),
+        resume: sync.resume,
+        dry_run: sync.dry_run,
+        skip_details: sync.skip_details,
+        details_refresh_days: sync.details_refresh_days.unwrap_or(DEFAULT_DETAILS_REFRESH_DAYS),
+        max_api_requests: sync.api_budget(config.sync_max_api_requests)?,
+        cell_concurrency: sync.cell_concurrency(config.sync_max_cell_concurrency)?,
+        verbose_changes: sync.verbose_changes,
+        ..SyncOptions::from_config(&config)
+    };
+    let pool = pool.get_ref().clone();
+    let cache = cache.get_ref().clone();
+    let google_client = google_client.get_ref().clone();
+    let job_city = city.clone();
+    let job_bounds = bounds.clone();
+    let dry_run = sync.dry_run;
+    let webhook_url = sync.webhook_url.clone().or_else(|| config.webhook_url.clone());
+
+    let job_id = jobs.submit(SyncJobKind::Bbox, &city, webhook_url, move |progress| async move {
+        let stats = SyncService::sync_bounds(
+            &pool,
+            google_client.as_ref(),
+            &job_bounds,
+            &job_city,
+            sync.place_type.as_deref(),
+            sync.cell_size_km,
+            sync.radius_m,
+            sync.language.as_deref(),
+            &SyncOptions { progress: Some(progress), ..options },
+        )
+        .await?;
+
+        if !stats.dry_run {
+            PlaceService::invalidate_search_cache(&cache, &job_city).await;
+        }
+
+        log::info!(
+            "Sync completed for {}: {} created, {} skipped, {} failed",
+            job_bounds.name,
+            stats.places_created,
+            stats.places_skipped,
+            stats.places_failed
+        );
+        Ok(stats)
+    })?;
+
+    log::info!("Queued sync job {} for {}", job_id, bounds.name);
+
+    Ok(HttpResponse::Accepted().json(serde_json::json!({
+        "job_id": job_id,
+        "city": city,
+        "bbox": bounds.name,
+        "state": SyncJobState::Queued,
+        "dry_run": dry_run,
+        "status_url": format!("/admin/sync/jobs/{}", job_id),
+    })))
+}
+
+/// Request body for the refresh endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct RefreshRequest {
+    /// Only refresh places in this city
+    pub city: Option<String>,
+    /// Places to refresh (default DEFAULT_REFRESH_LIMIT, at most MAX_REFRESH_LIMIT)
+    pub limit: Option<u32>,
+    /// Only refresh places last verified more than this many days ago
+    pub older_than_days: Option<u32>,
+    /// "full" (default), "photos" or "reviews"
+    #[serde(default)]
+    pub mode: RefreshMode,
+}
+
+impl RefreshRequest {
+    /// Validate refresh parameters before any API call is made
+    pub fn validate(&self) -> Result<(), PlacesError> {
+        if let Some(limit) = self.limit {
+            if limit == 0 || limit > MAX_REFRESH_LIMIT {
+                return Err(PlacesError::InvalidInput(format!(
+                    "limit must be between 1 and {}, got {}",
+                    MAX_REFRESH_LIMIT, limit
+                )));
+            }
+        }
+        if self.city.as_deref().is_some_and(|city| city.trim().is_empty()) {
+            return Err(PlacesError::InvalidInput("city must not be empty".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// POST /admin/refresh
+/// Refresh stored places with current Google data
+///
+/// DOCUMENTATION: Re-fetches Place Details for the least recently verified places and
+/// updates their ratings, status, hours and contact data; places Google no longer
+/// knows are deactivated. `mode` "photos" or "reviews" re-fetches only that part
+/// with a smaller field mask. One billable request per place, capped by `limit`
+/// Requires admin authentication via X-Admin-Token header
+pub async fn refresh_places(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+    body: web::Json<RefreshRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    body.validate()?;
+
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let stats = run_refresh(&pool, &cache, &google_client, &body, body.older_than_days).await?;
     Ok(HttpResponse::Ok().json(stats))
 }
 
-/// POST /admin/sync/batch
-/// Trigger synchronization for multiple cities
+/// POST /admin/refresh/stale
+/// Refresh the places that have not been verified for a while
 ///
-/// DOCUMENTATION: Batch sync operation for multiple cities
+/// DOCUMENTATION: Same as POST /admin/refresh, but `older_than_days` defaults to
+/// SYNC_STALE_AFTER_DAYS, so only places verified before that (or never verified)
+/// are re-fetched, stalest first. GET /admin/sync/status reports how many remain
+/// Requires admin authentication via X-Admin-Token header
+pub async fn refresh_stale_places(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+    body: web::Json<RefreshRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    body.validate()?;
+    let older_than_days = body.older_than_days.unwrap_or(config.sync_stale_after_days);
+    if older_than_days == 0 {
+        return Err(PlacesError::InvalidInput(
+            "older_than_days must be greater than 0".to_string(),
+        ));
+    }
+
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let stats = run_refresh(&pool, &cache, &google_client, &body, Some(older_than_days)).await?;
+    Ok(HttpResponse::Ok().json(stats))
+}
+
+/// Refresh stored places as `body` asks and drop the search caches of the cities touched
+async fn run_refresh(
+    pool: &PgPool,
+    cache: &Arc<PlacesCache>,
+    google_client: &Arc<GooglePlacesClient>,
+    body: &RefreshRequest,
+    older_than_days: Option<u32>,
+) -> Result<RefreshStats, PlacesError> {
+    let city = body.city.as_deref().map(str::trim);
+    let stats = SyncService::refresh_existing_places(
+        pool,
+        google_client.as_ref(),
+        city,
+        body.limit.unwrap_or(DEFAULT_REFRESH_LIMIT),
+        older_than_days.map(|days| chrono::Duration::days(i64::from(days))),
+        body.mode,
+    )
+    .await?;
+
+    // Refreshed ratings and deactivations change search results in those cities
+    for city in &stats.cities {
+        PlaceService::invalidate_search_cache(cache, city).await;
+    }
+
+    Ok(stats)
+}
+
+/// Reject a `webhook_url` the job could not POST to
+fn validate_webhook_url(url: Option<&str>) -> Result<(), PlacesError> {
+    match url {
+        Some(url) if !is_http_url(url) => Err(PlacesError::InvalidInput(format!(
+            "webhook_url must be an http(s) URL, got '{}'",
+            url
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Request body for the batch sync endpoint
 #[derive(Debug, Deserialize)]
 pub struct BatchSyncRequest {
     /// List of city names to sync
     pub cities: Vec<String>,
     /// Optional place type filter
     pub place_type: Option<String>,
+    /// Cities synced at once (default 1, maximum SYNC_MAX_CITY_CONCURRENCY)
+    pub concurrency: Option<usize>,
+    /// Notify this URL instead of WEBHOOK_URL when the batch job finishes
+    pub webhook_url: Option<String>,
+}
+
+impl BatchSyncRequest {
+    /// Validate batch parameters before any API call is made
+    pub fn validate(&self) -> Result<(), PlacesError> {
+        validate_webhook_url(self.webhook_url.as_deref())
+    }
+
+    /// Cities synced at once: `concurrency`, or one after another when absent
+    pub fn concurrency(&self, ceiling: usize) -> Result<usize, PlacesError> {
+        match self.concurrency {
+            None => Ok(DEFAULT_CITY_CONCURRENCY),
+            Some(concurrency) if concurrency == 0 || concurrency > ceiling => {
+                Err(PlacesError::InvalidInput(format!(
+                    "concurrency must be between 1 and {}, got {}",
+                    ceiling, concurrency
+                )))
+            }
+            Some(concurrency) => Ok(concurrency),
+        }
+    }
 }
 
+/// POST /admin/sync/batch
+/// Trigger synchronization for multiple cities
+///
+/// DOCUMENTATION: Queues one background job for the whole batch and answers 202 with
+/// its job id. The job's stats stay empty while it runs (cities sync concurrently) and
+/// become the batch summary at the end, with each city's stats in `details`
+/// Requires admin authentication via X-Admin-Token header
+#[allow(clippy::too_many_arguments)]
 pub async fn sync_cities_batch(
     pool: web::Data<PgPool>,
     config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    jobs: web::Data<Arc<SyncJobRegistry>>,
     req: HttpRequest,
     body: web::Json<BatchSyncRequest>,
 ) -> Result<impl Responder, PlacesError> {
@@ -120,40 +585,66 @@ pub async fn sync_cities_batch(
         ));
     }
 
-    let google_client = GooglePlacesClient::new(config.google_places_api_key.clone());
+    body.validate()?;
+    let concurrency = body.concurrency(config.sync_max_city_concurrency)?;
+    let body = body.into_inner();
+    let label = body.cities.join(", ");
+    let webhook_url = body.webhook_url.clone().or_else(|| config.webhook_url.clone());
+    let options = SyncOptions::from_config(&config);
+    let max_cell_stats = config.sync_max_cell_stats;
+    let pool = pool.get_ref().clone();
+    let cache = cache.get_ref().clone();
+    let google_client = google_client.get_ref().clone();
+    let cities = body.cities.clone();
 
-    // Execute batch sync
-    let stats_list = SyncService::sync_cities(
-        pool.get_ref(),
-        &google_client,
-        &body.cities,
-        body.place_type.as_deref(),
-    )
-    .await;
+    let job_id = jobs.submit(SyncJobKind::Batch, &label, webhook_url, move |_| async move {
+        let started = std::time::Instant::now();
+        let stats_list = SyncService::sync_cities(
+            &pool,
+            google_client.as_ref(),
+            &body.cities,
+            body.place_type.as_deref(),
+            &options,
+            concurrency,
+        )
+        .await;
+
+        for city in &body.cities {
+            PlaceService::invalidate_search_cache(&cache, city).await;
+        }
 
-    // Aggregate statistics
-    let aggregated = SyncService::aggregate_stats(&stats_list);
+        // Aggregate statistics; with overlapping cities the summed durations overstate the
+        // run, so the summary reports the batch's own wall-clock time
+        let mut aggregated = SyncService::aggregate_stats(&stats_list, max_cell_stats);
+        aggregated.duration_seconds = started.elapsed().as_secs();
 
-    log::info!(
-        "Batch sync completed: {} total created, {} total skipped, {} total failed",
-        aggregated.places_created,
-        aggregated.places_skipped,
-        aggregated.places_failed
-    );
+        log::info!(
+            "Batch sync completed: {} total created, {} total skipped, {} total failed",
+            aggregated.places_created,
+            aggregated.places_skipped,
+            aggregated.places_failed
+        );
+        Ok(SyncJobResult { stats: aggregated, details: stats_list })
+    })?;
 
-    Ok(HttpResponse::Ok().json(serde_json::json!({
-        "summary": aggregated,
-        "details": stats_list,
+    log::info!("Queued batch sync job {} for {} cities", job_id, cities.len());
+
+    Ok(HttpResponse::Accepted().json(serde_json::json!({
+        "job_id": job_id,
+        "cities": cities,
+        "state": SyncJobState::Queued,
+        "status_url": format!("/admin/sync/jobs/{}", job_id),
     })))
 }
 
 /// GET /admin/sync/status
 /// Get sync status and database statistics
 ///
-/// DOCUMENTATION: Returns current system status and place counts
+/// DOCUMENTATION: Returns current system status, place counts and the refresh schedule
 pub async fn sync_status(
     pool: web::Data<PgPool>,
     config: web::Data<Config>,
+    scheduler: web::Data<Arc<SyncScheduler>>,
     req: HttpRequest,
 ) -> Result<impl Responder, PlacesError> {
     // Authenticate admin request
@@ -178,11 +669,17 @@ pub async fn sync_status(
     .await
     .map_err(|e| PlacesError::DatabaseError(e.to_string()))?;
 
+    let stale_before = Utc::now() - chrono::Duration::days(i64::from(config.sync_stale_after_days));
+    let stale_places = PlaceRepository::count_stale(pool.get_ref(), stale_before).await?;
+
     let response = SyncStatusResponse {
         message: "Sync service operational".to_string(),
         total_places: total_places.0,
         active_places: active_places.0,
         recent_additions: recent_additions.0,
+        stale_after_days: config.sync_stale_after_days,
+        stale_places,
+        schedule: scheduler.status(),
     };
 
     Ok(HttpResponse::Ok().json(response))
@@ -241,12 +738,13 @@ pub async fn database_stats(
         "places_by_type": type_counts,
         "places_by_city": city_counts,
         "average_rating": avg_rating.0,
+        "google_resolver": PlaceService::resolver_stats(),
     })))
 }
 
 /// Helper function to verify admin authentication
 /// DOCUMENTATION: Checks X-Admin-Token header against configured admin token
-fn verify_admin_token(req: &HttpRequest, config: &Config) -> Result<(), PlacesError> {
+pub(crate) fn verify_admin_token(req: &HttpRequest, config: &Config) -> Result<(), PlacesError> {
     let token = req
         .headers()
         .get("X-Admin-Token")
@@ -272,6 +770,7 @@ fn verify_admin_token(req: &HttpRequest, config: &Config) -> Result<(), PlacesEr
 pub async fn get_place_raw(
     pool: web::Data<PgPool>,
     config: web::Data<Config>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
     req: HttpRequest,
     path: web::Path<uuid::Uuid>,
 ) -> Result<impl Responder, PlacesError> {
@@ -345,15 +844,36 @@ pub async fn get_place_raw(
     .map_err(|e| PlacesError::DatabaseError(e.to_string()))?
     .ok_or_else(|| PlacesError::NotFound(place_id.to_string()))?;
 
-    // If we have a google_place_id, try to fetch fresh data from Google
+    // If we have a google_place_id, resolve it (Google is only called when our data is stale).
+    // Inactive places are skipped so debugging never re-activates them through the upsert
     let mut google_data = None;
+    let mut resolved = None;
     if let Some(ref gp_id) = record.google_place_id {
-        if !config.google_places_api_key.is_empty() {
-            let google_client = GooglePlacesClient::new(config.google_places_api_key.clone());
-            
-            match google_client.get_place_details(gp_id).await {
-                Ok(place) => {
-                    google_data = Some(place);
+        if !config.google_places_api_key.is_empty() && record.is_active != Some(false) {
+            let freshness = chrono::Duration::hours(config.details_freshness_hours as i64);
+
+            match PlaceService::resolve_google_place(
+                pool.get_ref(),
+                google_client.get_ref().as_ref(),
+                gp_id,
+                Some(&record.city),
+                freshness,
+            )
+            .await
+            {
+                Ok(ResolvedPlace::Stored(place)) => {
+                    resolved = Some(serde_json::json!({
+                        "source": "database",
+                        "last_verified_at": place.last_verified_at,
+                    }));
+                }
+                Ok(ResolvedPlace::Refreshed { place, google_place, created }) => {
+                    google_data = Some(*google_place);
+                    resolved = Some(serde_json::json!({
+                        "source": "google",
+                        "last_verified_at": place.last_verified_at,
+                        "created": created,
+                    }));
                 }
                 Err(e) => {
                     log::warn!("Could not fetch Google data for {}: {}", gp_id, e);
@@ -366,10 +886,158 @@ pub async fn get_place_raw(
         "place_id": place_id,
         "database_record": record,
         "google_data": google_data,
+        "resolved": resolved,
         "note": "This endpoint exposes internal fields for debugging. Do not use in production API."
     })))
 }
 
+/// Request body for import-by-name endpoint
+#[derive(Debug, Deserialize)]
+pub struct ImportByNameRequest {
+    /// Venue name (ideally with city) or phone number
+    pub input: String,
+    /// How to interpret `input` ("textquery" or "phonenumber", default textquery)
+    #[serde(default)]
+    pub input_type: FindPlaceInputType,
+    /// Optional city override (otherwise parsed from the Google address)
+    pub city: Option<String>,
+}
+
+/// POST /admin/places/import-by-name
+/// Import a single venue without a grid sync
+///
+/// DOCUMENTATION: Resolves the input with Find Place From Text and stores the top
+/// candidate like the sync path. 404 when Google has no candidate; 201 for a new
+/// place, 409 (with the refreshed place) when it already existed
+/// Requires admin authentication via X-Admin-Token header
+pub async fn import_place_by_name(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+    body: web::Json<ImportByNameRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    if body.input.trim().is_empty() {
+        return Err(PlacesError::ValidationError("input must not be empty".to_string()));
+    }
+
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let persisted = PlaceService::import_place_by_name(
+        pool.get_ref(),
+        google_client.get_ref().as_ref(),
+        &body.input,
+        body.input_type,
+        body.city.as_deref(),
+    )
+    .await?;
+
+    PlaceService::invalidate_search_cache(cache.get_ref(), &persisted.place.city).await;
+
+    let response = serde_json::json!({
+        "created": persisted.created,
+        "reviews_created": persisted.reviews_created,
+        "photos_created": persisted.photos_created,
+        "place": persisted.place.to_response(),
+    });
+
+    if persisted.created {
+        Ok(HttpResponse::Created().json(response))
+    } else {
+        Ok(HttpResponse::Conflict().json(response))
+    }
+}
+
+/// Request body for the sync-one endpoint
+#[derive(Debug, Deserialize)]
+pub struct SyncOneRequest {
+    /// Google place id to pull in or refresh
+    pub google_place_id: String,
+    /// Optional city override (otherwise parsed from the Google address)
+    pub city: Option<String>,
+}
+
+/// POST /admin/places/sync-one
+/// Sync a single place by Google place id
+///
+/// DOCUMENTATION: Fetches Place Details and stores the place with its photos and
+/// reviews exactly like the city sync, even outside every configured city. Answers
+/// 201 for a new place and 200 for an updated one, with the stored place detail;
+/// 502 when Google reports the id as NOT_FOUND
+/// Requires admin authentication via X-Admin-Token header
+pub async fn sync_one_place(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+    body: web::Json<SyncOneRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let google_place_id = body.google_place_id.trim();
+    if google_place_id.is_empty() {
+        return Err(PlacesError::ValidationError("google_place_id must not be empty".to_string()));
+    }
+    if body.city.as_deref().is_some_and(|city| city.trim().is_empty()) {
+        return Err(PlacesError::ValidationError("city must not be empty".to_string()));
+    }
+
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let persisted = PlaceService::sync_google_place(
+        pool.get_ref(),
+        google_client.get_ref().as_ref(),
+        google_place_id,
+        body.city.as_deref().map(str::trim),
+    )
+    .await?;
+
+    PlaceService::invalidate_search_cache(cache.get_ref(), &persisted.place.city).await;
+
+    log::info!(
+        "Synced single place {} ({})",
+        google_place_id,
+        if persisted.created { "created" } else { "updated" }
+    );
+
+    // Permanently closed places are stored inactive and hidden from get_place
+    let detail = if persisted.place.is_active.unwrap_or(true) {
+        PlaceService::get_place(pool.get_ref(), persisted.place.id).await?
+    } else {
+        PlaceDetailResponse {
+            place: persisted.place.to_response(),
+            photos: Vec::new(),
+            reviews: Vec::new(),
+        }
+    };
+    let response = serde_json::json!({
+        "created": persisted.created,
+        "reviews_created": persisted.reviews_created,
+        "photos_created": persisted.photos_created,
+        "place": detail,
+    });
+
+    if persisted.created {
+        Ok(HttpResponse::Created().json(response))
+    } else {
+        Ok(HttpResponse::Ok().json(response))
+    }
+}
+
 /// GET /admin/cache/stats
 /// Get cache statistics
 ///
@@ -387,36 +1055,1244 @@ pub async fn cache_stats(
     Ok(HttpResponse::Ok().json(stats))
 }
 
+/// Optional request body for cache clear endpoint
+#[derive(Debug, Deserialize)]
+pub struct CacheClearRequest {
+    /// Only clear keys with this prefix (e.g. "search:*", "details:*")
+    pub prefix: Option<String>,
+}
+
 /// POST /admin/cache/clear
-/// Clear all cache entries
+/// Clear cache entries
 ///
-/// DOCUMENTATION: Clears the entire cache
+/// DOCUMENTATION: Clears the entire cache, or only keys matching `prefix` when given
 /// Requires admin authentication via X-Admin-Token header
 pub async fn cache_clear(
     config: web::Data<Config>,
     cache: web::Data<Arc<PlacesCache>>,
     req: HttpRequest,
+    body: Option<web::Json<CacheClearRequest>>,
 ) -> Result<impl Responder, PlacesError> {
     // Authenticate admin request
     verify_admin_token(&req, &config)?;
 
-    cache.clear().await;
-    
+    let prefix = body
+        .and_then(|b| b.into_inner().prefix)
+        .filter(|p| !p.trim_end_matches('*').is_empty());
+
+    let removed = match &prefix {
+        Some(prefix) => cache.clear_prefix(prefix).await,
+        None => {
+            let count = cache.stats().await.total_entries;
+            cache.clear().await;
+            count
+        }
+    };
+
     Ok(HttpResponse::Ok().json(serde_json::json!({
-        "message": "Cache cleared successfully"
+        "message": "Cache cleared successfully",
+        "prefix": prefix,
+        "entries_removed": removed
     })))
 }
 
-/// Configuration for admin routes
-pub fn config(cfg: &mut web::ServiceConfig) {
-    cfg.service(
-        web::scope("/admin")
-            .route("/sync/{city}", web::post().to(sync_city))
-            .route("/sync/batch", web::post().to(sync_cities_batch))
-            .route("/sync/status", web::get().to(sync_status))
-            .route("/stats", web::get().to(database_stats))
-            .route("/places/{id}/raw", web::get().to(get_place_raw))
-            .route("/cache/stats", web::get().to(cache_stats))
-            .route("/cache/clear", web::post().to(cache_clear)),
-    );
+/// POST /admin/reviews/dedupe
+/// Collapse reviews stored without a source_id
+///
+/// DOCUMENTATION: One-off maintenance task; keeps the oldest copy of each duplicate
+/// and backfills the deterministic fallback source_id on the survivors
+/// Requires admin authentication via X-Admin-Token header
+pub async fn dedupe_reviews(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let (backfilled, deleted) = ReviewRepository::dedupe_null_source_ids(pool.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Review dedupe completed",
+        "source_ids_backfilled": backfilled,
+        "duplicates_deleted": deleted
+    })))
+}
+
+/// POST /admin/reviews/rehash
+/// Move Google reviews to hashed source_ids
+///
+/// DOCUMENTATION: One-off backfill after the switch from `{google_place_id}_{time}`
+/// to content-hashed source_ids; copies of the same review are merged, keeping the
+/// most recently posted one
+/// Requires admin authentication via X-Admin-Token header
+pub async fn rehash_reviews(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let (updated, deleted) = ReviewRepository::rehash_google_source_ids(pool.get_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Review rehash completed",
+        "source_ids_updated": updated,
+        "duplicates_deleted": deleted
+    })))
+}
+
+/// POST /admin/search/rebuild
+/// Rebuild every search_vector with SEARCH_LANGUAGE
+///
+/// DOCUMENTATION: Run after changing SEARCH_LANGUAGE so stored vectors and queries
+/// use the same text search configuration. Cached database searches are dropped
+/// Requires admin authentication via X-Admin-Token header
+pub async fn rebuild_search(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let rebuilt = PlaceRepository::rebuild_search_vectors(pool.get_ref(), &config.search_language).await?;
+    let cleared = cache.clear_prefix("db_search:").await;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Search vectors rebuilt",
+        "language": config.search_language,
+        "places_rebuilt": rebuilt,
+        "cache_entries_removed": cleared
+    })))
+}
+
+/// Query parameters for DELETE /admin/places/{id}
+#[derive(Debug, Default, Deserialize)]
+pub struct DeletePlaceQuery {
+    /// Remove the place, its reviews and photos instead of deactivating it
+    #[serde(default)]
+    pub hard: bool,
+}
+
+/// DELETE /admin/places/{id}
+/// Deactivate a place, or remove it permanently with `?hard=true`
+///
+/// DOCUMENTATION: The default soft delete sets is_active=false and returns 204. A
+/// hard delete removes the place with its reviews and photos in one transaction and
+/// returns the removed counts; subscribed places are refused with 409
+/// Requires admin authentication via X-Admin-Token header
+pub async fn delete_place(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+    path: web::Path<uuid::Uuid>,
+    query: web::Query<DeletePlaceQuery>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let place_id = path.into_inner();
+    if !query.hard {
+        let city = PlaceService::delete_place(pool.get_ref(), place_id).await?;
+        PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
+        return Ok(HttpResponse::NoContent().finish());
+    }
+
+    let report = PlaceService::hard_delete_place(pool.get_ref(), place_id).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &report.city).await;
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Place permanently deleted",
+        "place_id": report.place_id,
+        "reviews_deleted": report.reviews_deleted,
+        "photos_deleted": report.photos_deleted
+    })))
+}
+
+/// Request body for PUT /admin/cities/{name}/bounds
+#[derive(Debug, Deserialize)]
+pub struct CityBoundsRequest {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+    /// Default grid cell size in kilometers (default 1.5)
+    pub cell_size_km: Option<f64>,
+    /// Default search radius per cell in meters (default 1000)
+    pub radius_m: Option<u32>,
+    /// IANA time zone of the city, e.g. "Europe/Madrid"
+    pub timezone: Option<String>,
+}
+
+impl CityBoundsRequest {
+    /// Validated bounds for `name`
+    pub fn into_bounds(self, name: &str) -> Result<CityBounds, PlacesError> {
+        let bounds = CityBounds {
+            name: name.trim().to_string(),
+            min_lat: self.min_lat,
+            max_lat: self.max_lat,
+            min_lng: self.min_lng,
+            max_lng: self.max_lng,
+            cell_size_km: self.cell_size_km.unwrap_or(DEFAULT_CELL_SIZE_KM),
+            radius_m: self.radius_m.unwrap_or(DEFAULT_RADIUS_M),
+            timezone: self.timezone.map(|timezone| timezone.trim().to_string()),
+        };
+        bounds.validate().map_err(PlacesError::InvalidInput)?;
+        GooglePlacesClient::validate_radius(bounds.radius_m)?;
+        Ok(bounds)
+    }
+}
+
+/// Bounds plus where they came from ("database" or "builtin")
+fn city_bounds_body(bounds: &CityBounds, source: &str) -> serde_json::Value {
+    let mut body = serde_json::to_value(bounds).unwrap_or_default();
+    body["source"] = serde_json::json!(source);
+    body
+}
+
+/// GET /admin/cities/{name}/bounds
+/// Get the sync bounds of a city
+///
+/// DOCUMENTATION: Stored bounds win over the registered (built-in or configured) ones
+/// Requires admin authentication via X-Admin-Token header
+pub async fn get_city_bounds(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let name = path.into_inner();
+
+    if let Some(bounds) = CityBoundsRepository::get_by_name(pool.get_ref(), &name).await? {
+        return Ok(HttpResponse::Ok().json(city_bounds_body(&bounds, "database")));
+    }
+
+    match GridGenerator::get_registered_city(&name) {
+        Some(city) => Ok(HttpResponse::Ok().json(city_bounds_body(&city.bounds, city.source))),
+        None => Err(PlacesError::NotFound(format!("No bounds for city '{}'", name))),
+    }
+}
+
+/// GET /admin/cities
+/// List every city that can currently be synced
+///
+/// DOCUMENTATION: Stored bounds first; registered cities (built-in or
+/// CITIES_CONFIG_PATH) are added unless a stored row overrides them
+/// Requires admin authentication via X-Admin-Token header
+pub async fn list_cities(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let stored = CityBoundsRepository::list(pool.get_ref()).await?;
+    let stored_names: Vec<String> = stored.iter().map(|bounds| bounds.name.to_lowercase()).collect();
+
+    let mut cities: Vec<serde_json::Value> =
+        stored.iter().map(|bounds| city_bounds_body(bounds, "database")).collect();
+    for city in GridGenerator::registered_cities() {
+        if !stored_names.contains(&city.bounds.name.to_lowercase()) {
+            cities.push(city_bounds_body(&city.bounds, city.source));
+        }
+    }
+    cities.sort_by_key(|city| city["name"].as_str().unwrap_or_default().to_lowercase());
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "total": cities.len(),
+        "cities": cities,
+    })))
+}
+
+/// PUT /admin/cities/{name}/bounds
+/// Create or replace the sync bounds of a city
+///
+/// DOCUMENTATION: Makes the city available to POST /admin/sync/{city}
+/// Requires admin authentication via X-Admin-Token header
+pub async fn put_city_bounds(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<CityBoundsRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let bounds = body.into_inner().into_bounds(&path.into_inner())?;
+    let saved = CityBoundsRepository::upsert(pool.get_ref(), &bounds).await?;
+
+    log::info!("Saved bounds for city {}", saved.name);
+
+    Ok(HttpResponse::Ok().json(city_bounds_body(&saved, "database")))
+}
+
+/// PUT /admin/cities/{name}/polygon
+/// Store the outline of a city as GeoJSON
+///
+/// DOCUMENTATION: Body is a GeoJSON Polygon, MultiPolygon or Feature ([lng, lat]
+/// positions). Syncs of the city then skip grid cells centered outside it. The city
+/// needs stored bounds first (404 otherwise).
+/// Requires admin authentication via X-Admin-Token header
+pub async fn put_city_polygon(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<serde_json::Value>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let name = path.into_inner();
+    CityPolygon::from_geojson(&body).map_err(PlacesError::InvalidInput)?;
+
+    if !CityBoundsRepository::set_polygon(pool.get_ref(), &name, Some(&body)).await? {
+        return Err(PlacesError::NotFound(format!(
+            "No stored bounds for city '{}'; add them with PUT /admin/cities/{{name}}/bounds",
+            name
+        )));
+    }
+
+    log::info!("Saved polygon for city {}", name);
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "name": name, "polygon": body.into_inner() })))
+}
+
+/// DELETE /admin/cities/{name}/polygon
+/// Remove the outline of a city
+///
+/// DOCUMENTATION: Later syncs cover the whole grid again
+/// Requires admin authentication via X-Admin-Token header
+pub async fn delete_city_polygon(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let name = path.into_inner();
+    if !CityBoundsRepository::set_polygon(pool.get_ref(), &name, None).await? {
+        return Err(PlacesError::NotFound(format!("No stored bounds for city '{}'", name)));
+    }
+
+    log::info!("Removed polygon for city {}", name);
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Configured unit price (USD per 1000 requests) for a Google operation
+fn unit_price_per_1000(config: &Config, operation: GoogleApiOperation) -> f64 {
+    match operation {
+        GoogleApiOperation::NearbySearch => config.google_price_nearby_search,
+        GoogleApiOperation::TextSearch => config.google_price_text_search,
+        GoogleApiOperation::PlaceDetails => config.google_price_place_details,
+        GoogleApiOperation::Photo => config.google_price_photo,
+        GoogleApiOperation::Autocomplete => config.google_price_autocomplete,
+        GoogleApiOperation::FindPlace => config.google_price_find_place,
+        GoogleApiOperation::Geocoding => config.google_price_geocoding,
+    }
+}
+
+/// Usage report with per-operation and total estimated cost
+fn usage_report(usage: &GoogleApiUsage, config: &Config) -> serde_json::Value {
+    let mut operations = serde_json::Map::new();
+    let mut total_cost = 0.0;
+    for (operation, requests) in &usage.requests {
+        let unit_price = unit_price_per_1000(config, *operation);
+        let cost = *requests as f64 * unit_price / 1000.0;
+        total_cost += cost;
+        operations.insert(
+            operation.as_str().to_string(),
+            serde_json::json!({
+                "requests": requests,
+                "unit_price_per_1000": unit_price,
+                "estimated_cost_usd": cost
+            }),
+        );
+    }
+
+    serde_json::json!({
+        "operations": operations,
+        "total_requests": usage.total(),
+        "estimated_cost_usd": total_cost,
+        "since": usage.since
+    })
+}
+
+/// GET /admin/google/usage
+/// Google Places request counts and estimated cost
+///
+/// DOCUMENTATION: Counts every request sent to Google since startup or the last
+/// reset (cache hits are free and not counted); cost uses the GOOGLE_PRICE_* settings
+/// Requires admin authentication via X-Admin-Token header
+pub async fn google_usage(
+    config: web::Data<Config>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let usage = google_client.usage();
+    Ok(HttpResponse::Ok().json(usage_report(&usage, &config)))
+}
+
+/// POST /admin/google/usage/reset
+/// Zero the Google usage counters
+///
+/// DOCUMENTATION: Returns the report for the period that was just closed
+/// Requires admin authentication via X-Admin-Token header
+pub async fn reset_google_usage(
+    config: web::Data<Config>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    req: HttpRequest,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let previous = google_client.reset_usage();
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "message": "Google usage counters reset",
+        "previous": usage_report(&previous, &config)
+    })))
+}
+
+/// Request counts and cost bounds for syncing `cells` grid cells
+/// DOCUMENTATION: Nearby Search costs one page per cell when cells are sparse and
+/// three when they are dense; the details worst case assumes every result is new.
+/// Subdivided cells come on top, up to SYNC_MAX_CELLS.
+fn sync_estimate(cells: usize, config: &Config) -> serde_json::Value {
+    let cells = cells as u64;
+    let nearby_min = cells;
+    let nearby_max = cells * u64::from(MAX_NEARBY_PAGES);
+    let details_max = cells * MAX_NEARBY_RESULTS as u64;
+
+    let nearby_price = unit_price_per_1000(config, GoogleApiOperation::NearbySearch);
+    let details_price = unit_price_per_1000(config, GoogleApiOperation::PlaceDetails);
+    let cost = |requests: u64, unit_price: f64| requests as f64 * unit_price / 1000.0;
+
+    serde_json::json!({
+        "nearby_search_requests": { "min": nearby_min, "max": nearby_max },
+        "place_details_requests_max": details_max,
+        "estimated_cost_usd": {
+            "min": cost(nearby_min, nearby_price),
+            "max": cost(nearby_max, nearby_price) + cost(details_max, details_price)
+        }
+    })
+}
+
+/// GET /admin/sync/{city}/preview
+/// Preview the grid and cost of a city sync
+///
+/// DOCUMENTATION: Takes the sync parameters as query string (`place_type`,
+/// `cell_size_km`, `radius_m`, `grid_pattern`) and makes no Google calls, so cities
+/// that would need the geocoding fallback are reported as unknown
+/// Requires admin authentication via X-Admin-Token header
+pub async fn sync_preview(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<SyncRequest>,
+) -> Result<impl Responder, PlacesError> {
+    // Authenticate admin request
+    verify_admin_token(&req, &config)?;
+
+    let city = path.into_inner();
+    query.validate()?;
+
+    let cells = GridGenerator::generate_for_city(
+        pool.get_ref(),
+        None,
+        &city,
+        query.grid_pattern.unwrap_or_default(),
+        query.cell_size_km,
+        query.radius_m,
+    )
+    .await?;
+
+    // Same polygon filtering as the sync itself
+    let total = cells.len();
+    let cells = match GridGenerator::resolve_city_polygon(pool.get_ref(), &city).await {
+        Some(polygon) => GridGenerator::optimize_grid(cells, &polygon),
+        None => cells,
+    };
+
+    Ok(HttpResponse::Ok().json(serde_json::json!({
+        "city": city,
+        "place_type": query.place_type,
+        "cell_count": cells.len(),
+        "cells_skipped_outside_polygon": total - cells.len(),
+        "max_cells": config.sync_max_cells,
+        "estimate": sync_estimate(cells.len(), &config),
+        "cells": GridGenerator::cells_to_geojson(&cells),
+    })))
+}
+
+/// Configuration for admin routes
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin")
+            .route("/sync/bbox", web::post().to(sync_bbox))
+            .route("/sync/batch", web::post().to(sync_cities_batch))
+            .route("/sync/jobs/{job_id}", web::get().to(sync_job_status))
+            .route("/sync/{city}", web::post().to(sync_city))
+            .route("/sync/{city}/preview", web::get().to(sync_preview))
+            .route("/sync/status", web::get().to(sync_status))
+            .route("/refresh", web::post().to(refresh_places))
+            .route("/refresh/stale", web::post().to(refresh_stale_places))
+            .route("/stats", web::get().to(database_stats))
+            .route("/places/{id}", web::delete().to(delete_place))
+            .route("/places/{id}/raw", web::get().to(get_place_raw))
+            .route("/places/import-by-name", web::post().to(import_place_by_name))
+            .route("/places/sync-one", web::post().to(sync_one_place))
+            .route("/cache/stats", web::get().to(cache_stats))
+            .route("/cache/clear", web::post().to(cache_clear))
+            .route("/reviews/dedupe", web::post().to(dedupe_reviews))
+            .route("/reviews/rehash", web::post().to(rehash_reviews))
+            .route("/search/rebuild", web::post().to(rebuild_search))
+            .route("/cities", web::get().to(list_cities))
+            .route("/cities/{name}/bounds", web::get().to(get_city_bounds))
+            .route("/cities/{name}/bounds", web::put().to(put_city_bounds))
+            .route("/cities/{name}/polygon", web::put().to(put_city_polygon))
+            .route("/cities/{name}/polygon", web::delete().to(delete_city_polygon))
+            .route("/google/usage", web::get().to(google_usage))
+            .route("/google/usage/reset", web::post().to(reset_google_usage)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::DEFAULT_MAX_ENTRIES;
+    use crate::test_support::{lazy_pool, test_config, MockHttpServer};
+    use actix_web::{test as actix_test, App};
+
+    fn cache_app(cache: Arc<PlacesCache>) -> App<
+        impl actix_web::dev::ServiceFactory<
+            actix_web::dev::ServiceRequest,
+            Config = (),
+            Response = actix_web::dev::ServiceResponse,
+            Error = actix_web::Error,
+            InitError = (),
+        >,
+    > {
+        App::new()
+            .app_data(web::Data::new(test_config()))
+            .app_data(web::Data::new(cache))
+            .configure(config)
+    }
+
+    #[actix_web::test]
+    async fn test_cache_endpoints_reject_bad_tokens() {
+        let app = actix_test::init_service(cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))).await;
+
+        let missing = actix_test::TestRequest::get().uri("/admin/cache/stats").to_request();
+        assert_eq!(actix_test::call_service(&app, missing).await.status(), 401);
+
+        let wrong = actix_test::TestRequest::post()
+            .uri("/admin/cache/clear")
+            .insert_header(("X-Admin-Token", "nope"))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, wrong).await.status(), 403);
+    }
+
+    #[actix_web::test]
+    async fn test_cache_clear_by_prefix_and_stats() {
+        let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+        cache.set("search:1".to_string(), "{}".to_string()).await;
+        cache.set("details:1".to_string(), "{}".to_string()).await;
+        let app = actix_test::init_service(cache_app(cache.clone())).await;
+
+        let clear = actix_test::TestRequest::post()
+            .uri("/admin/cache/clear")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(serde_json::json!({ "prefix": "search:*" }))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, clear).await;
+        assert_eq!(body["entries_removed"], 1);
+        assert!(cache.get("details:1").await.is_some());
+
+        let stats = actix_test::TestRequest::get()
+            .uri("/admin/cache/stats")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, stats).await;
+        assert_eq!(body["active_by_namespace"]["details"], 1);
+        assert_eq!(body["hits"], 1);
+
+        let clear_all = actix_test::TestRequest::post()
+            .uri("/admin/cache/clear")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, clear_all).await;
+        assert_eq!(body["entries_removed"], 1);
+        assert_eq!(cache.stats().await.total_entries, 0);
+    }
+
+    #[actix_web::test]
+    async fn test_import_by_name_rejects_blank_input() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(GooglePlacesClient::new("key".to_string())))),
+        )
+        .await;
+
+        let blank = actix_test::TestRequest::post()
+            .uri("/admin/places/import-by-name")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(serde_json::json!({ "input": "   " }))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, blank).await.status(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_sync_one_validates_and_reports_google_not_found() {
+        let server = MockHttpServer::start_json(vec![r#"{"status":"NOT_FOUND"}"#.to_string()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(Config { google_places_api_key: "test_key".to_string(), ..test_config() }))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(client))),
+        )
+        .await;
+
+        let blank = actix_test::TestRequest::post()
+            .uri("/admin/places/sync-one")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(serde_json::json!({ "google_place_id": " ", "city": "Zaragoza" }))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, blank).await.status(), 400);
+        assert_eq!(server.hits(), 0);
+
+        let missing = actix_test::TestRequest::post()
+            .uri("/admin/places/sync-one")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(serde_json::json!({ "google_place_id": "ChIJmissingEverywhere01", "city": "Zaragoza" }))
+            .to_request();
+        let response = actix_test::call_service(&app, missing).await;
+        assert_eq!(response.status(), 502);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(body["error"]["code"], "EXTERNAL_API_ERROR");
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_google_usage_report_and_reset() {
+        let client = Arc::new(GooglePlacesClient::new("key".to_string()));
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(client.clone())),
+        )
+        .await;
+
+        let unauthorized = actix_test::TestRequest::get().uri("/admin/google/usage").to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        let usage = actix_test::TestRequest::get()
+            .uri("/admin/google/usage")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, usage).await;
+        assert_eq!(body["total_requests"], 0);
+        assert_eq!(body["operations"]["place_details"]["unit_price_per_1000"], 17.0);
+        assert_eq!(body["estimated_cost_usd"], 0.0);
+
+        let reset = actix_test::TestRequest::post()
+            .uri("/admin/google/usage/reset")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, reset).await;
+        assert_eq!(body["previous"]["total_requests"], 0);
+    }
+
+    #[actix_web::test]
+    async fn test_put_city_bounds_validates_before_saving() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+        let inverted = serde_json::json!({
+            "min_lat": 42.16, "max_lat": 42.12, "min_lng": -0.43, "max_lng": -0.38
+        });
+
+        let unauthorized = actix_test::TestRequest::put()
+            .uri("/admin/cities/Huesca/bounds")
+            .set_json(&inverted)
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        let invalid = actix_test::TestRequest::put()
+            .uri("/admin/cities/Huesca/bounds")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(&inverted)
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, invalid).await.status(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_place_requires_admin_token() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+
+        let request = actix_test::TestRequest::delete()
+            .uri(&format!("/admin/places/{}?hard=true", uuid::Uuid::new_v4()))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 401);
+    }
+
+    fn bbox_request(max_lat: f64, max_lng: f64) -> SyncBboxRequest {
+        serde_json::from_value(serde_json::json!({
+            "city": "Zaragoza",
+            "min_lat": 41.63, "max_lat": max_lat, "min_lng": -0.91, "max_lng": max_lng,
+            "place_type": "bar",
+            "radius_m": 500
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sync_bbox_request_bounds() {
+        let request = bbox_request(41.66, -0.87);
+        assert_eq!(request.sync.place_type.as_deref(), Some("bar"));
+
+        let bounds = request.bounds(2000.0).unwrap();
+        assert_eq!(bounds.name, "bbox:41.63,-0.91,41.66,-0.87");
+
+        // Degenerate and oversized boxes are rejected
+        assert!(matches!(bbox_request(41.63, -0.87).bounds(2000.0), Err(PlacesError::InvalidInput(_))));
+        let err = bbox_request(42.2, 0.0).bounds(2000.0).unwrap_err();
+        assert!(err.to_string().contains("above the limit of 2000 km²"));
+
+        let request = SyncBboxRequest { city: " ".to_string(), ..bbox_request(41.66, -0.87) };
+        assert!(matches!(request.bounds(2000.0), Err(PlacesError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_sync_bbox_request_rejects_swapped_box() {
+        let request = SyncBboxRequest {
+            min_lat: -0.91,
+            max_lat: -0.87,
+            min_lng: 41.63,
+            max_lng: 41.66,
+            ..bbox_request(41.66, -0.87)
+        };
+        let err = request.bounds(2000.0).unwrap_err();
+        assert!(matches!(err, PlacesError::InvalidInput(_)));
+        assert!(err.to_string().contains("min_lat=41.63, max_lat=41.66, min_lng=-0.91, max_lng=-0.87"), "{}", err);
+
+        // Cities without known bounds cannot be checked
+        let request = SyncBboxRequest { city: "Teruel".to_string(), ..request };
+        assert!(request.bounds(2000.0).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_sync_bbox_route_validates_before_syncing() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(GooglePlacesClient::new("test_key".to_string()))))
+                .app_data(web::Data::new(Arc::new(SyncJobRegistry::from_config(&test_config())))),
+        )
+        .await;
+        let oversized = serde_json::json!({
+            "city": "Zaragoza", "min_lat": 41.0, "max_lat": 42.0, "min_lng": -1.5, "max_lng": 0.0
+        });
+
+        // Routed to the bbox handler, not to /sync/{city} with city "bbox"
+        let response = actix_test::call_service(
+            &app,
+            actix_test::TestRequest::post()
+                .uri("/admin/sync/bbox")
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(&oversized)
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 400);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert!(body.to_string().contains("km²"));
+    }
+
+    #[actix_web::test]
+    async fn test_list_cities_requires_admin_token() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+
+        let missing = actix_test::TestRequest::get().uri("/admin/cities").to_request();
+        assert_eq!(actix_test::call_service(&app, missing).await.status(), 401);
+    }
+
+    #[actix_web::test]
+    async fn test_search_rebuild_requires_admin_token() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+
+        let missing = actix_test::TestRequest::post().uri("/admin/search/rebuild").to_request();
+        assert_eq!(actix_test::call_service(&app, missing).await.status(), 401);
+    }
+
+    #[actix_web::test]
+    async fn test_put_city_polygon_validates_before_saving() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+        let point = serde_json::json!({ "type": "Point", "coordinates": [-0.88, 41.65] });
+
+        let unauthorized = actix_test::TestRequest::put()
+            .uri("/admin/cities/Zaragoza/polygon")
+            .set_json(&point)
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        let invalid = actix_test::TestRequest::put()
+            .uri("/admin/cities/Zaragoza/polygon")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(&point)
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, invalid).await.status(), 400);
+    }
+
+    #[test]
+    fn test_sync_estimate_uses_configured_prices() {
+        let config = Config {
+            google_price_nearby_search: 32.0,
+            google_price_place_details: 17.0,
+            ..test_config()
+        };
+
+        let estimate = sync_estimate(10, &config);
+
+        assert_eq!(estimate["nearby_search_requests"]["min"], 10);
+        assert_eq!(estimate["nearby_search_requests"]["max"], 30);
+        assert_eq!(estimate["place_details_requests_max"], 600);
+        assert_eq!(estimate["estimated_cost_usd"]["min"], 0.32);
+        // 30 * 0.032 + 600 * 0.017
+        assert!((estimate["estimated_cost_usd"]["max"].as_f64().unwrap() - 11.16).abs() < 1e-9);
+    }
+
+    #[actix_web::test]
+    async fn test_sync_batch_runs_as_background_job() {
+        let denied = serde_json::json!({
+            "status": "REQUEST_DENIED",
+            "error_message": "The provided API key is invalid."
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![denied]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(Config { google_places_api_key: "test_key".to_string(), ..test_config() }))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(client)))
+                .app_data(web::Data::new(Arc::new(SyncJobRegistry::from_config(&test_config())))),
+        )
+        .await;
+
+        let response = actix_test::call_service(
+            &app,
+            actix_test::TestRequest::post()
+                .uri("/admin/sync/batch")
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(serde_json::json!({ "cities": ["Zaragoza"] }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 202);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(body["state"], "queued");
+        assert_eq!(body["cities"], serde_json::json!(["Zaragoza"]));
+        let status_url = body["status_url"].as_str().unwrap().to_string();
+
+        let mut status = serde_json::Value::Null;
+        for _ in 0..500 {
+            let request = actix_test::TestRequest::get()
+                .uri(&status_url)
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .to_request();
+            status = actix_test::call_and_read_body_json(&app, request).await;
+            if status["state"] == "completed" {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(status["state"], "completed");
+        assert_eq!(status["city"], "Zaragoza");
+        assert_eq!(status["stats"]["city"], "Multiple Cities");
+        assert_eq!(status["details"][0]["city"], "Zaragoza");
+        assert!(status["details"][0]["errors"][0].as_str().unwrap().contains("invalid"));
+    }
+
+    #[actix_web::test]
+    async fn test_sync_city_runs_as_background_job() {
+        let denied = serde_json::json!({
+            "status": "REQUEST_DENIED",
+            "error_message": "The provided API key is invalid."
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![denied]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(Config { google_places_api_key: "test_key".to_string(), ..test_config() }))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(client)))
+                .app_data(web::Data::new(Arc::new(SyncJobRegistry::from_config(&test_config())))),
+        )
+        .await;
+
+        let response = actix_test::call_service(
+            &app,
+            actix_test::TestRequest::post()
+                .uri("/admin/sync/Zaragoza")
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(serde_json::json!({ "cell_size_km": 10.0, "radius_m": 5000 }))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 202);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(body["state"], "queued");
+        let status_url = body["status_url"].as_str().unwrap().to_string();
+        assert_eq!(status_url, format!("/admin/sync/jobs/{}", body["job_id"].as_str().unwrap()));
+
+        let mut status = serde_json::Value::Null;
+        for _ in 0..500 {
+            let request = actix_test::TestRequest::get()
+                .uri(&status_url)
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .to_request();
+            status = actix_test::call_and_read_body_json(&app, request).await;
+            if status["state"] == "completed" {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(status["state"], "completed");
+        assert_eq!(status["stats"]["city"], "Zaragoza");
+        assert!(status["stats"]["errors"][0].as_str().unwrap().contains("invalid"));
+        assert_eq!(server.hits(), 1);
+
+        let unknown = actix_test::TestRequest::get()
+            .uri("/admin/sync/jobs/unknown")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unknown).await.status(), 404);
+        let unauthorized = actix_test::TestRequest::get().uri(&status_url).to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+    }
+
+    #[test]
+    fn test_batch_sync_webhook_url_validated() {
+        let request = |webhook_url: &str| BatchSyncRequest {
+            cities: vec!["Zaragoza".to_string()],
+            place_type: None,
+            concurrency: None,
+            webhook_url: Some(webhook_url.to_string()),
+        };
+
+        assert!(request("https://hooks.example.com/sync").validate().is_ok());
+        assert!(matches!(request("hooks.example.com").validate(), Err(PlacesError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_batch_sync_concurrency() {
+        let request = |concurrency: Option<usize>| BatchSyncRequest {
+            cities: vec!["Zaragoza".to_string(), "Huesca".to_string()],
+            place_type: None,
+            concurrency,
+            webhook_url: None,
+        };
+
+        assert_eq!(request(None).concurrency(3).unwrap(), DEFAULT_CITY_CONCURRENCY);
+        assert_eq!(request(Some(3)).concurrency(3).unwrap(), 3);
+        for concurrency in [0, 4] {
+            assert!(matches!(request(Some(concurrency)).concurrency(3), Err(PlacesError::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn test_refresh_request_validation() {
+        assert!(RefreshRequest::default().validate().is_ok());
+        let request = RefreshRequest { limit: Some(MAX_REFRESH_LIMIT), ..RefreshRequest::default() };
+        assert!(request.validate().is_ok());
+
+        for limit in [0, MAX_REFRESH_LIMIT + 1] {
+            let request = RefreshRequest { limit: Some(limit), ..RefreshRequest::default() };
+            assert!(matches!(request.validate(), Err(PlacesError::InvalidInput(_))));
+        }
+        let request = RefreshRequest { city: Some(" ".to_string()), ..RefreshRequest::default() };
+        assert!(request.validate().is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_refresh_route_validates_before_refreshing() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(GooglePlacesClient::new("test_key".to_string())))),
+        )
+        .await;
+
+        let unauthorized = actix_test::TestRequest::post()
+            .uri("/admin/refresh")
+            .set_json(serde_json::json!({}))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        let invalid = actix_test::TestRequest::post()
+            .uri("/admin/refresh")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .set_json(serde_json::json!({ "limit": 0 }))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, invalid).await.status(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_stale_refresh_route_validates_before_refreshing() {
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool()))
+                .app_data(web::Data::new(Arc::new(GooglePlacesClient::new("test_key".to_string())))),
+        )
+        .await;
+
+        let unauthorized = actix_test::TestRequest::post()
+            .uri("/admin/refresh/stale")
+            .set_json(serde_json::json!({}))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        for body in [serde_json::json!({ "older_than_days": 0 }), serde_json::json!({ "limit": 0 })] {
+            let invalid = actix_test::TestRequest::post()
+                .uri("/admin/refresh/stale")
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(body)
+                .to_request();
+            assert_eq!(actix_test::call_service(&app, invalid).await.status(), 400);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_sync_preview_without_google_client() {
+        // No GooglePlacesClient is registered: the preview must not need one
+        let app = actix_test::init_service(
+            cache_app(Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES)))
+                .app_data(web::Data::new(lazy_pool())),
+        )
+        .await;
+
+        let unauthorized = actix_test::TestRequest::get().uri("/admin/sync/Zaragoza/preview").to_request();
+        assert_eq!(actix_test::call_service(&app, unauthorized).await.status(), 401);
+
+        let response = actix_test::call_service(
+            &app,
+            actix_test::TestRequest::get()
+                .uri("/admin/sync/Zaragoza/preview?place_type=bar&cell_size_km=5&radius_m=3000")
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+
+        let cells = body["cell_count"].as_u64().unwrap();
+        assert!(cells > 0);
+        assert_eq!(body["place_type"], "bar");
+        assert_eq!(body["cells"]["features"].as_array().unwrap().len() as u64, cells);
+        assert_eq!(body["estimate"]["nearby_search_requests"]["max"], cells * 3);
+
+        let unknown = actix_test::TestRequest::get()
+            .uri("/admin/sync/Atlantis/preview")
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, unknown).await.status(), 400);
+    }
+
+    #[test]
+    fn test_city_bounds_request_defaults() {
+        let request: CityBoundsRequest = serde_json::from_value(serde_json::json!({
+            "min_lat": 42.12, "max_lat": 42.16, "min_lng": -0.43, "max_lng": -0.38
+        }))
+        .unwrap();
+
+        let bounds = request.into_bounds(" Huesca ").unwrap();
+        assert_eq!(bounds.name, "Huesca");
+        assert_eq!(bounds.cell_size_km, DEFAULT_CELL_SIZE_KM);
+        assert_eq!(bounds.radius_m, DEFAULT_RADIUS_M);
+        assert_eq!(bounds.timezone, None);
+
+        let body = city_bounds_body(&bounds, "database");
+        assert_eq!(body["source"], "database");
+        assert_eq!(body["radius_m"], 1000);
+        assert!(body.get("timezone").is_none());
+
+        let too_wide: CityBoundsRequest = serde_json::from_value(serde_json::json!({
+            "min_lat": 42.12, "max_lat": 42.16, "min_lng": -0.43, "max_lng": -0.38,
+            "radius_m": 60000
+        }))
+        .unwrap();
+        assert!(too_wide.into_bounds("Huesca").is_err());
+
+        let offset: CityBoundsRequest = serde_json::from_value(serde_json::json!({
+            "min_lat": 42.12, "max_lat": 42.16, "min_lng": -0.43, "max_lng": -0.38,
+            "timezone": "+01:00"
+        }))
+        .unwrap();
+        assert!(matches!(offset.into_bounds("Huesca"), Err(PlacesError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_usage_report_multiplies_unit_prices() {
+        let usage = GoogleApiUsage {
+            requests: vec![
+                (GoogleApiOperation::NearbySearch, 10),
+                (GoogleApiOperation::PlaceDetails, 1000),
+                (GoogleApiOperation::Photo, 0),
+            ],
+            since: "2026-01-01T00:00:00Z".to_string(),
+        };
+
+        let report = usage_report(&usage, &test_config());
+
+        assert_eq!(report["total_requests"], 1010);
+        assert_eq!(report["operations"]["nearby_search"]["estimated_cost_usd"], 0.32);
+        assert_eq!(report["operations"]["place_details"]["estimated_cost_usd"], 17.0);
+        assert_eq!(report["operations"]["photo"]["requests"], 0);
+        assert!((report["estimated_cost_usd"].as_f64().unwrap() - 17.32).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_import_by_name_request_defaults_to_text_query() {
+        let body: ImportByNameRequest =
+            serde_json::from_value(serde_json::json!({ "input": "Café Botánico Zaragoza" })).unwrap();
+        assert_eq!(body.input_type, FindPlaceInputType::TextQuery);
+
+        let body: ImportByNameRequest = serde_json::from_value(
+            serde_json::json!({ "input": "+34976000000", "input_type": "phonenumber" }),
+        )
+        .unwrap();
+        assert_eq!(body.input_type, FindPlaceInputType::PhoneNumber);
+    }
+
+    fn sync_request(radius_m: Option<u32>) -> SyncRequest {
+        SyncRequest {
+            place_type: None,
+            cell_size_km: None,
+            radius_m,
+            language: None,
+            grid_pattern: None,
+            resume: false,
+            dry_run: false,
+            skip_details: false,
+            details_refresh_days: None,
+            max_api_requests: None,
+            cell_concurrency: None,
+            verbose_changes: false,
+            webhook_url: None,
+        }
+    }
+
+    #[test]
+    fn test_sync_request_radius_limit() {
+        assert!(sync_request(None).validate().is_ok());
+        assert!(sync_request(Some(50_000)).validate().is_ok());
+        assert!(matches!(
+            sync_request(Some(50_001)).validate(),
+            Err(PlacesError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            sync_request(Some(0)).validate(),
+            Err(PlacesError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_sync_request_language_validated() {
+        let request = SyncRequest { language: Some("en".to_string()), ..sync_request(None) };
+        assert!(request.validate().is_ok());
+
+        let request = SyncRequest { language: Some("english".to_string()), ..sync_request(None) };
+        assert!(matches!(request.validate(), Err(PlacesError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_api_budget_is_capped_by_config() {
+        assert_eq!(sync_request(None).api_budget(500).unwrap(), 500);
+
+        let request = SyncRequest { max_api_requests: Some(120), ..sync_request(None) };
+        assert_eq!(request.api_budget(500).unwrap(), 120);
+
+        for budget in [0, 2, 501] {
+            let request = SyncRequest { max_api_requests: Some(budget), ..sync_request(None) };
+            assert!(matches!(request.api_budget(500), Err(PlacesError::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn test_cell_concurrency_is_capped_by_config() {
+        assert_eq!(sync_request(None).cell_concurrency(4).unwrap(), DEFAULT_CELL_CONCURRENCY);
+
+        let request = SyncRequest { cell_concurrency: Some(4), ..sync_request(None) };
+        assert_eq!(request.cell_concurrency(4).unwrap(), 4);
+
+        for concurrency in [0, 5] {
+            let request = SyncRequest { cell_concurrency: Some(concurrency), ..sync_request(None) };
+            assert!(matches!(request.cell_concurrency(4), Err(PlacesError::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn test_skip_details_requires_dry_run() {
+        let request = SyncRequest { skip_details: true, ..sync_request(None) };
+        assert!(matches!(request.validate(), Err(PlacesError::InvalidInput(_))));
+
+        let request = SyncRequest { dry_run: true, skip_details: true, ..sync_request(None) };
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_sync_request_webhook_url_validated() {
+        let request = SyncRequest { webhook_url: Some("https://hooks.example.com/sync".to_string()), ..sync_request(None) };
+        assert!(request.validate().is_ok());
+
+        let request = SyncRequest { webhook_url: Some("hooks.example.com".to_string()), ..sync_request(None) };
+        assert!(matches!(request.validate(), Err(PlacesError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_sync_request_grid_pattern() {
+        let request: SyncRequest =
+            serde_json::from_value(serde_json::json!({ "grid_pattern": "hex", "radius_m": 800 })).unwrap();
+        assert_eq!(request.grid_pattern, Some(GridPattern::Hex));
+        assert!(request.validate().is_ok());
+
+        let request = SyncRequest { cell_size_km: Some(1.0), ..request };
+        assert!(matches!(request.validate(), Err(PlacesError::InvalidInput(_))));
+    }
 }
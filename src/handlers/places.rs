@@ -4,11 +4,21 @@
 
 use crate::config::Config;
 use crate::errors::PlacesError;
-use crate::models::{CreatePlaceRequest, SearchQuery, UpdatePlaceRequest};
-use crate::services::{PlaceService, GooglePlacesClient, PlacesCache};
-use actix_web::{web, HttpResponse, Responder};
+use crate::handlers::admin::verify_admin_token;
+use crate::models::{
+    AddPlacePhotoRequest, AutocompleteQuery, BatchPlacesRequest, CityPlacesQuery, ClusterQuery, CreatePlaceRequest, FeaturedPlacesQuery, NearbyPlacesQuery, PlacePhotosQuery, ReorderPhotosRequest, SearchQuery,
+    SimilarPlacesQuery, SuggestQuery, UpdatePlaceRequest,
+};
+use crate::services::{
+    GooglePlacesClient, OnDemandImport, OnDemandImportLimiter, PlaceService, PlacesCache,
+    SearchSettings, MAX_PHOTO_WIDTH,
+};
+use actix_web::dev::Payload;
+use actix_web::{http::header, web, FromRequest, HttpRequest, HttpResponse, Responder};
 use sqlx::PgPool;
+use std::future::{ready, Ready};
 use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 use validator::Validate;
 
@@ -16,6 +26,7 @@ use validator::Validate;
 /// Create a new place
 pub async fn create_place(
     pool: web::Data<PgPool>,
+    cache: web::Data<Arc<PlacesCache>>,
     req: web::Json<CreatePlaceRequest>,
 ) -> Result<impl Responder, PlacesError> {
     // Validate request
@@ -24,6 +35,7 @@ pub async fn create_place(
     }
 
     let place = PlaceService::create_place(pool.get_ref(), req.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &place.city).await;
     Ok(HttpResponse::Created().json(place))
 }
 
@@ -31,6 +43,7 @@ pub async fn create_place(
 /// Create or update a place based on google_place_id
 pub async fn upsert_place(
     pool: web::Data<PgPool>,
+    cache: web::Data<Arc<PlacesCache>>,
     req: web::Json<CreatePlaceRequest>,
 ) -> Result<impl Responder, PlacesError> {
     // Validate request
@@ -39,6 +52,7 @@ pub async fn upsert_place(
     }
 
     let (place, created) = PlaceService::upsert_place(pool.get_ref(), req.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &place.city).await;
     
     if created {
         Ok(HttpResponse::Created().json(place.to_response()))
@@ -47,50 +61,355 @@ pub async fn upsert_place(
     }
 }
 
+/// Query string accepted by GET /places/{id}
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct GetPlaceQuery {
+    /// Autocomplete session token to close with the Place Details call
+    pub session: Option<String>,
+
+    /// Also find deactivated places (admin token required, see `AdminAuth`)
+    pub include_inactive: Option<bool>,
+}
+
+/// Query string flag of GET /places/search for admin reads
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct IncludeInactiveQuery {
+    pub include_inactive: Option<bool>,
+}
+
+/// App config of a request and whether it carries a valid X-Admin-Token
+/// DOCUMENTATION: Extracted for public endpoints that admins may widen. A missing or
+/// wrong token is not an error, so the public behavior does not depend on it
+pub struct AdminAuth {
+    pub config: web::Data<Config>,
+    pub is_admin: bool,
+}
+
+impl AdminAuth {
+    /// Whether an include_inactive request is honored: only for admins, otherwise
+    /// the flag is ignored rather than rejected
+    pub fn include_inactive(&self, requested: Option<bool>) -> bool {
+        requested == Some(true) && self.is_admin
+    }
+}
+
+impl FromRequest for AdminAuth {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let auth = req
+            .app_data::<web::Data<Config>>()
+            .cloned()
+            .map(|config| AdminAuth {
+                is_admin: verify_admin_token(req, &config).is_ok(),
+                config,
+            })
+            .ok_or_else(|| actix_web::error::ErrorInternalServerError("Config is not registered"));
+        ready(auth)
+    }
+}
+
 /// GET /places/{id}
-/// Retrieve a place by ID (UUID or Google Place ID)
+/// Retrieve a place by ID (UUID or Google Place ID), importing unknown Google ids
 pub async fn get_place(
+    auth: AdminAuth,
     pool: web::Data<PgPool>,
+    cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    import_limiter: web::Data<Arc<OnDemandImportLimiter>>,
     path: web::Path<String>,
+    query: web::Query<GetPlaceQuery>,
 ) -> Result<impl Responder, PlacesError> {
     let identifier = path.into_inner();
-    let place = PlaceService::get_place_by_id_or_google_id(pool.get_ref(), &identifier).await?;
+    // Unknown Google place ids are fetched from Google and stored (deep links from search)
+    let import = OnDemandImport::from_config(&auth.config, google_client.get_ref().as_ref(), &import_limiter)
+        .map(|import| import.with_session_token(query.session.as_deref()));
+
+    let include_inactive = auth.include_inactive(query.include_inactive);
+
+    let (place, imported) =
+        PlaceService::get_or_import_place(pool.get_ref(), &identifier, import, include_inactive).await?;
+    if imported {
+        PlaceService::invalidate_search_cache(cache.get_ref(), &place.place.city).await;
+    }
     Ok(HttpResponse::Ok().json(place))
 }
 
+/// POST /places/batch
+/// Retrieve up to 100 places by UUID and/or Google Place ID in one call
+pub async fn get_places_batch(
+    pool: web::Data<PgPool>,
+    req: web::Json<BatchPlacesRequest>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::get_places_batch(pool.get_ref(), &req.ids).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// GET /places/{id}/nearby
+/// Active places around a place (UUID or Google Place ID), closest first
+pub async fn nearby_places(
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+    query: web::Query<NearbyPlacesQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::nearby_places(pool.get_ref(), &path.into_inner(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// GET /places/{id}/photos
+/// Paginated photos of a place (UUID or Google Place ID), optionally from one source
+pub async fn place_photos(
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+    query: web::Query<PlacePhotosQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::place_photos(pool.get_ref(), &path.into_inner(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// POST /places/{id}/photos
+/// Attach a photo by URL to a place (UUID or Google Place ID); requires X-Admin-Token
+pub async fn add_place_photo(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<AddPlacePhotoRequest>,
+) -> Result<impl Responder, PlacesError> {
+    verify_admin_token(&req, config.get_ref())?;
+    if let Err(e) = body.validate() {
+        return Err(PlacesError::ValidationError(e.to_string()));
+    }
+
+    let (photo, city) = PlaceService::add_place_photo(pool.get_ref(), &path.into_inner(), body.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
+    Ok(HttpResponse::Created().json(photo))
+}
+
+/// DELETE /places/{place_id}/photos/{photo_id}
+/// Remove one photo of a place (UUID or Google Place ID); requires X-Admin-Token
+pub async fn delete_place_photo(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<impl Responder, PlacesError> {
+    verify_admin_token(&req, config.get_ref())?;
+
+    let (place_id, photo_id) = path.into_inner();
+    let city = PlaceService::delete_place_photo(pool.get_ref(), &place_id, photo_id).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// PUT /places/{place_id}/photos/{photo_id}/primary
+/// Make a photo the one fronting its place; requires X-Admin-Token. Returns the
+/// place's photos
+pub async fn set_primary_place_photo(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<impl Responder, PlacesError> {
+    verify_admin_token(&req, config.get_ref())?;
+
+    let (place_id, photo_id) = path.into_inner();
+    let (photos, city) = PlaceService::set_primary_place_photo(pool.get_ref(), &place_id, photo_id).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
+    Ok(HttpResponse::Ok().json(photos))
+}
+
+/// PUT /places/{place_id}/photos/order
+/// Set the gallery order of a place's photos; requires X-Admin-Token. Returns the
+/// reordered photos
+pub async fn reorder_place_photos(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    cache: web::Data<Arc<PlacesCache>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<ReorderPhotosRequest>,
+) -> Result<impl Responder, PlacesError> {
+    verify_admin_token(&req, config.get_ref())?;
+
+    let (photos, city) =
+        PlaceService::reorder_place_photos(pool.get_ref(), &path.into_inner(), body.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
+    Ok(HttpResponse::Ok().json(photos))
+}
+
+/// GET /places/{id}/similar
+/// Active places like a place (UUID or Google Place ID): same type, nearby, shared
+/// cuisines and categories, well rated
+pub async fn similar_places(
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+    query: web::Query<SimilarPlacesQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::similar_places(pool.get_ref(), &path.into_inner(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// GET /places/clusters
+/// Active places in a map viewport grouped into a zoom-dependent grid
+pub async fn place_clusters(
+    pool: web::Data<PgPool>,
+    query: web::Query<ClusterQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::place_clusters(pool.get_ref(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// GET /places/featured
+/// Seeded random sample of well-rated places for discovery carousels
+pub async fn featured_places(
+    pool: web::Data<PgPool>,
+    config: web::Data<Config>,
+    query: web::Query<FeaturedPlacesQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::featured_places(
+        pool.get_ref(),
+        query.into_inner(),
+        config.featured_min_rating,
+        config.featured_min_rating_count,
+    )
+    .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// GET /cities/{city}/places
+/// List the active places of a city (exact, case-insensitive name), paginated
+pub async fn list_city_places(
+    pool: web::Data<PgPool>,
+    path: web::Path<String>,
+    query: web::Query<CityPlacesQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let response = PlaceService::list_city_places(pool.get_ref(), &path.into_inner(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
 /// GET /places/search
 /// Search places with filters (from Google Places API with caching)
 pub async fn search_places(
+    auth: AdminAuth,
     pool: web::Data<PgPool>,
-    config: web::Data<Config>,
     cache: web::Data<Arc<PlacesCache>>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
     query: web::Query<SearchQuery>,
+    inactive: web::Query<IncludeInactiveQuery>,
 ) -> Result<impl Responder, PlacesError> {
-    // Check if Google Places API key is configured
-    if config.google_places_api_key.is_empty() {
-        // Fallback to database search if API key not configured
-        let result = PlaceService::search_places(pool.get_ref(), query.into_inner()).await?;
+    let config = auth.config.get_ref();
+    // Use the database when no Google Places API key is configured or source=db is forced
+    if config.google_places_api_key.is_empty() || query.source.as_deref() == Some("db") {
+        let query = SearchQuery {
+            include_inactive: auth.include_inactive(inactive.include_inactive),
+            ..query.into_inner()
+        };
+        let result = if config.search_cache_enabled {
+            PlaceService::search_places_cached(
+                pool.get_ref(),
+                cache.get_ref(),
+                query,
+                Duration::from_secs(config.search_cache_ttl_seconds),
+                &SearchSettings::from_config(config),
+            )
+            .await?
+        } else {
+            PlaceService::search_places(pool.get_ref(), query, &SearchSettings::from_config(config)).await?
+        };
         return Ok(HttpResponse::Ok().json(result));
     }
 
-    // Use Google Places API directly with shared cache
-    let google_client = GooglePlacesClient::new_with_cache(
-        config.google_places_api_key.clone(),
-        cache.get_ref().clone()
-    );
-    let result = PlaceService::search_places_from_google(&google_client, query.into_inner()).await?;
+    // Use Google Places API directly through the shared client
+    let result =
+        PlaceService::search_places_from_google(google_client.get_ref().as_ref(), query.into_inner()).await?;
     Ok(HttpResponse::Ok().json(result))
 }
 
+/// GET /places/autocomplete
+/// Typeahead predictions from Google Place Autocomplete
+pub async fn autocomplete(
+    config: web::Data<Config>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    query: web::Query<AutocompleteQuery>,
+) -> Result<impl Responder, PlacesError> {
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let result = PlaceService::autocomplete(google_client.get_ref().as_ref(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(result))
+}
+
+/// Cache-Control of suggestions: short enough to pick up new places, long enough
+/// for a client retyping or deleting characters to be served from its cache
+const SUGGEST_CACHE_CONTROL: &str = "public, max-age=60, stale-while-revalidate=300";
+
+/// GET /places/suggest
+/// Typeahead suggestions from stored places (no Google request)
+pub async fn suggest_places(
+    pool: web::Data<PgPool>,
+    query: web::Query<SuggestQuery>,
+) -> Result<impl Responder, PlacesError> {
+    let result = PlaceService::suggest_places(pool.get_ref(), query.into_inner()).await?;
+    Ok(HttpResponse::Ok()
+        .insert_header((header::CACHE_CONTROL, SUGGEST_CACHE_CONTROL))
+        .json(result))
+}
+
+/// Query string accepted by GET /places/photos/{photo_reference}
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct PhotoQuery {
+    /// Maximum width in pixels (1-1600, default 800)
+    pub maxwidth: Option<i32>,
+}
+
+/// GET /places/photos/{photo_reference}
+/// Relay a Google photo so the API key never reaches clients
+pub async fn get_photo(
+    config: web::Data<Config>,
+    google_client: web::Data<Arc<GooglePlacesClient>>,
+    path: web::Path<String>,
+    query: web::Query<PhotoQuery>,
+) -> Result<impl Responder, PlacesError> {
+    if config.google_places_api_key.is_empty() {
+        return Err(PlacesError::InvalidInput(
+            "Google Places API key not configured".to_string(),
+        ));
+    }
+
+    let max_width = query.maxwidth.unwrap_or(800);
+    if !(1..=MAX_PHOTO_WIDTH).contains(&max_width) {
+        return Err(PlacesError::ValidationError(format!(
+            "maxwidth must be between 1 and {}, got {}",
+            MAX_PHOTO_WIDTH, max_width
+        )));
+    }
+
+    let photo = google_client.fetch_photo(&path.into_inner(), max_width).await?;
+    Ok(HttpResponse::Ok()
+        .content_type(photo.content_type)
+        .insert_header((header::CACHE_CONTROL, "public, max-age=86400"))
+        .body(photo.bytes))
+}
+
 /// PUT /places/{id}
 /// Update a place
 pub async fn update_place(
     pool: web::Data<PgPool>,
+    cache: web::Data<Arc<PlacesCache>>,
     path: web::Path<Uuid>,
     req: web::Json<UpdatePlaceRequest>,
 ) -> Result<impl Responder, PlacesError> {
     let place =
         PlaceService::update_place(pool.get_ref(), path.into_inner(), req.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &place.city).await;
     Ok(HttpResponse::Ok().json(place))
 }
 
@@ -98,9 +417,11 @@ pub async fn update_place(
 /// Soft delete a place
 pub async fn delete_place(
     pool: web::Data<PgPool>,
+    cache: web::Data<Arc<PlacesCache>>,
     path: web::Path<Uuid>,
 ) -> Result<impl Responder, PlacesError> {
-    PlaceService::delete_place(pool.get_ref(), path.into_inner()).await?;
+    let city = PlaceService::delete_place(pool.get_ref(), path.into_inner()).await?;
+    PlaceService::invalidate_search_cache(cache.get_ref(), &city).await;
     Ok(HttpResponse::NoContent().finish())
 }
 
@@ -110,9 +431,651 @@ pub fn config(cfg: &mut web::ServiceConfig) {
         web::scope("/places")
             .route("", web::post().to(create_place))
             .route("/upsert", web::post().to(upsert_place))
+            .route("/batch", web::post().to(get_places_batch))
             .route("/search", web::get().to(search_places))
+            .route("/autocomplete", web::get().to(autocomplete))
+            .route("/suggest", web::get().to(suggest_places))
+            .route("/featured", web::get().to(featured_places))
+            .route("/clusters", web::get().to(place_clusters))
+            // v1 photo names contain slashes ("places/{id}/photos/{ref}")
+            .route("/photos/{photo_reference:.+}", web::get().to(get_photo))
             .route("/{id}", web::get().to(get_place))
+            .route("/{id}/nearby", web::get().to(nearby_places))
+            .route("/{id}/similar", web::get().to(similar_places))
+            .route("/{id}/photos", web::get().to(place_photos))
+            .route("/{id}/photos", web::post().to(add_place_photo))
+            .route("/{id}/photos/order", web::put().to(reorder_place_photos))
+            .route("/{id}/photos/{photo_id}", web::delete().to(delete_place_photo))
+            .route("/{id}/photos/{photo_id}/primary", web::put().to(set_primary_place_photo))
             .route("/{id}", web::put().to(update_place))
             .route("/{id}", web::delete().to(delete_place)),
     );
+    cfg.route("/cities/{city}/places", web::get().to(list_city_places));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_config, MockHttpServer};
+    use actix_web::{test as actix_test, App};
+
+    async fn get_photo_response(client: GooglePlacesClient, uri: &str) -> (u16, Vec<u8>) {
+        let app_config = Config {
+            google_places_api_key: "test_key".to_string(),
+            ..test_config()
+        };
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(app_config))
+                .app_data(web::Data::new(Arc::new(client)))
+                .configure(config),
+        )
+        .await;
+        let response = actix_test::call_service(&app, actix_test::TestRequest::get().uri(uri).to_request()).await;
+        let status = response.status().as_u16();
+        (status, actix_test::read_body(response).await.to_vec())
+    }
+
+    #[actix_web::test]
+    async fn test_photo_proxy_relays_bytes_without_key() {
+        let server = MockHttpServer::start_with_headers(vec![(
+            200,
+            vec![("Content-Type", "image/jpeg".to_string())],
+            "JPEG".to_string(),
+        )])
+        .await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let (status, body) = get_photo_response(client, "/places/photos/ref1?maxwidth=400").await;
+
+        assert_eq!(status, 200);
+        assert_eq!(body, b"JPEG");
+        assert!(server.requests()[0].contains("photoreference=ref1"));
+    }
+
+    #[actix_web::test]
+    async fn test_photo_proxy_accepts_v1_names_and_validates_width() {
+        let server = MockHttpServer::start_json(vec!["{}".to_string()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_v1_base_url(&server.base_url);
+
+        let (status, _) = get_photo_response(client, "/places/photos/places/abc/photos/ref1").await;
+        assert_eq!(status, 200);
+        assert!(server.requests()[0].starts_with("GET /places/abc/photos/ref1/media?maxWidthPx=800"));
+
+        for width in ["0", "1601"] {
+            let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+            let (status, _) = get_photo_response(client, &format!("/places/photos/ref1?maxwidth={}", width)).await;
+            assert_eq!(status, 400, "maxwidth={}", width);
+        }
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_batch_route_rejects_more_than_100_ids() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        let ids: Vec<String> = (0..101).map(|i| format!("ChIJ_{}", i)).collect();
+        let request = actix_test::TestRequest::post()
+            .uri("/places/batch")
+            .set_json(serde_json::json!({ "ids": ids }))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_city_places_validates_before_querying() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        for uri in ["/cities/M%C3%A1laga/places?sort=distance", "/cities/Zaragoza/places?order=up", "/cities/%20/places"] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_nearby_route_validates_radius_before_querying() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        for uri in ["/places/ChIJ_anchor/nearby?radius_km=0", "/places/ChIJ_anchor/nearby?limit=many"] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_similar_route_rejects_malformed_limit() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        let request = actix_test::TestRequest::get().uri("/places/ChIJ_anchor/similar?limit=six").to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 400);
+    }
+
+    #[actix_web::test]
+    async fn test_place_photos_route_validates_source_before_querying() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        for uri in ["/places/ChIJ_anchor/photos?source=flickr", "/places/ChIJ_anchor/photos?page=first"] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_add_photo_requires_admin_token_and_http_url() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+        let post = |token: Option<&str>, body: serde_json::Value| {
+            let mut request = actix_test::TestRequest::post().uri("/places/ChIJ_anchor/photos").set_json(body);
+            if let Some(token) = token {
+                request = request.insert_header(("X-Admin-Token", token));
+            }
+            request.to_request()
+        };
+        let valid = serde_json::json!({ "photo_url": "https://cdn.example.com/terraza.jpg" });
+
+        assert_eq!(actix_test::call_service(&app, post(None, valid.clone())).await.status(), 401);
+        assert_eq!(actix_test::call_service(&app, post(Some("wrong"), valid)).await.status(), 403);
+
+        // Rejected before the (absent) database is asked for the place
+        let long_url = format!("https://cdn.example.com/{}.jpg", "a".repeat(2048));
+        for body in [
+            serde_json::json!({ "photo_url": "javascript:alert(1)" }),
+            serde_json::json!({ "photo_url": "ftp://cdn.example.com/terraza.jpg" }),
+            serde_json::json!({ "photo_url": "/terraza.jpg" }),
+            serde_json::json!({ "photo_url": long_url }),
+            serde_json::json!({ "photo_url": "https://cdn.example.com/a.jpg", "thumbnail_url": "data:image/png;base64,AA" }),
+            serde_json::json!({ "photo_url": "https://cdn.example.com/a.jpg", "display_order": -1 }),
+        ] {
+            let response = actix_test::call_service(&app, post(Some("test-admin-token"), body.clone())).await;
+            assert_eq!(response.status(), 400, "{}", body);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_delete_photo_requires_admin_token() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+        let uri = format!("/places/ChIJ_anchor/photos/{}", Uuid::new_v4());
+
+        let request = actix_test::TestRequest::delete().uri(&uri).to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 401);
+        let request = actix_test::TestRequest::delete()
+            .uri(&uri)
+            .insert_header(("X-Admin-Token", "wrong"))
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 403);
+    }
+
+    #[actix_web::test]
+    async fn test_reorder_photos_requires_admin_token() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+        let body = serde_json::json!({ "photo_ids": [Uuid::new_v4()] });
+
+        let request = actix_test::TestRequest::put().uri("/places/ChIJ_anchor/photos/order").set_json(&body).to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 401);
+        let request = actix_test::TestRequest::put()
+            .uri("/places/ChIJ_anchor/photos/order")
+            .insert_header(("X-Admin-Token", "wrong"))
+            .set_json(&body)
+            .to_request();
+        assert_eq!(actix_test::call_service(&app, request).await.status(), 403);
+    }
+
+    #[actix_web::test]
+    async fn test_clusters_route_requires_viewport_and_zoom() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        for uri in [
+            "/places/clusters?zoom=12",
+            "/places/clusters?sw_lat=41.6&sw_lng=-0.95&ne_lat=41.7&ne_lng=-0.8",
+            "/places/clusters?sw_lat=41.6&sw_lng=-0.95&ne_lat=41.7&ne_lng=-0.8&zoom=-1",
+        ] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_featured_route_rejects_malformed_seed() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .app_data(web::Data::new(test_config()))
+                .configure(config),
+        )
+        .await;
+
+        for uri in ["/places/featured?seed=-1", "/places/featured?seed=abc", "/places/featured?page=first"] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_suggest_route_requires_two_characters() {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(crate::test_support::lazy_pool()))
+                .configure(config),
+        )
+        .await;
+
+        // Not captured by /places/{id}
+        for uri in ["/places/suggest", "/places/suggest?q=a", "/places/suggest?q=ca&limit=many"] {
+            let request = actix_test::TestRequest::get().uri(uri).to_request();
+            assert_eq!(actix_test::call_service(&app, request).await.status(), 400, "{}", uri);
+        }
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_suggest_is_cacheable() {
+        let pool = crate::test_support::test_pool().await;
+        let city = format!("Suggest {}", Uuid::new_v4().simple());
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Bodega Almau",
+            "type": "bar",
+            "location": [-0.8789, 41.6529],
+            "city": city,
+            "district": "Casco Antiguo",
+            "main_categories": []
+        }))
+        .unwrap();
+        PlaceService::create_place(&pool, request).await.unwrap();
+
+        let app = actix_test::init_service(App::new().app_data(web::Data::new(pool)).configure(config)).await;
+        let request = actix_test::TestRequest::get()
+            .uri(&format!("/places/suggest?q=bo&city={}", city.replace(' ', "%20")))
+            .to_request();
+        let response = actix_test::call_service(&app, request).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers().get(header::CACHE_CONTROL).unwrap(), SUGGEST_CACHE_CONTROL);
+
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(body["data"][0]["name"], "Bodega Almau");
+        assert_eq!(body["data"][0]["type"], "bar");
+        assert_eq!(body["data"][0]["district"], "Casco Antiguo");
+    }
+
+    #[actix_web::test]
+    async fn test_include_inactive_needs_a_valid_admin_token() {
+        let auth = |token: Option<&str>| {
+            let mut request = actix_test::TestRequest::get().app_data(web::Data::new(test_config()));
+            if let Some(token) = token {
+                request = request.insert_header(("X-Admin-Token", token));
+            }
+            let (request, mut payload) = request.to_http_parts();
+            AdminAuth::from_request(&request, &mut payload).into_inner().unwrap()
+        };
+
+        assert!(auth(Some("test-admin-token")).include_inactive(Some(true)));
+        assert!(!auth(Some("test-admin-token")).include_inactive(None));
+        assert!(!auth(Some("wrong")).include_inactive(Some(true)));
+        assert!(!auth(None).include_inactive(Some(true)));
+
+        // The search query never takes the flag from the query string itself
+        let query = web::Query::<SearchQuery>::from_query("include_inactive=true").unwrap();
+        assert!(!query.include_inactive);
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_admin_reads_include_inactive_places() {
+        let pool = crate::test_support::test_pool().await;
+        let city = format!("Inactive {}", Uuid::new_v4().simple());
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Bar Cerrado",
+            "type": "bar",
+            "location": [-0.8891, 41.6488],
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap();
+        let place = PlaceService::create_place(&pool, request).await.unwrap();
+        crate::db::PlaceRepository::delete_place(&pool, place.id).await.unwrap();
+
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .app_data(web::Data::new(Arc::new(GooglePlacesClient::new(String::new()))))
+                .app_data(web::Data::new(Arc::new(PlaceService::on_demand_limiter(1))))
+                .configure(config),
+        )
+        .await;
+        let detail_uri = format!("/places/{}?include_inactive=true", place.id);
+        let search_uri = format!("/places/search?city={}&include_inactive=true", city);
+
+        // Without a valid token the flag is ignored: the place stays hidden
+        for token in [None, Some("wrong")] {
+            let mut detail = actix_test::TestRequest::get().uri(&detail_uri);
+            let mut search = actix_test::TestRequest::get().uri(&search_uri);
+            if let Some(token) = token {
+                detail = detail.insert_header(("X-Admin-Token", token));
+                search = search.insert_header(("X-Admin-Token", token));
+            }
+            assert_eq!(actix_test::call_service(&app, detail.to_request()).await.status(), 404);
+            let body: serde_json::Value = actix_test::call_and_read_body_json(&app, search.to_request()).await;
+            assert_eq!(body["total_count"], 0);
+        }
+
+        let detail = actix_test::TestRequest::get()
+            .uri(&detail_uri)
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, detail).await;
+        assert_eq!(body["place"]["is_active"], false);
+
+        let search = actix_test::TestRequest::get()
+            .uri(&search_uri)
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, search).await;
+        assert_eq!(body["total_count"], 1);
+        assert_eq!(body["data"][0]["is_active"], false);
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_manual_create_is_not_verified() {
+        let pool = crate::test_support::test_pool().await;
+        let cache = Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool.clone()))
+                .app_data(web::Data::new(cache))
+                .configure(config),
+        )
+        .await;
+
+        // Even with a Google place id, data posted by a client was not checked against Google
+        let request = actix_test::TestRequest::post()
+            .uri("/places")
+            .set_json(serde_json::json!({
+                "name": "Manual Bar",
+                "type": "bar",
+                "location": [-0.8891, 41.6488],
+                "city": "Zaragoza",
+                "google_place_id": format!("manual_{}", Uuid::new_v4()),
+                "main_categories": []
+            }))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, request).await;
+
+        let id: Uuid = serde_json::from_value(body["id"].clone()).unwrap();
+        let stored = crate::db::PlaceRepository::get_by_id(&pool, id).await.unwrap();
+        assert!(stored.last_verified_at.is_none());
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_city_places_decodes_accented_city() {
+        let pool = crate::test_support::test_pool().await;
+        let city = format!("Málaga {}", Uuid::new_v4().simple());
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Bar Pimpi",
+            "type": "bar",
+            "location": [-4.4214, 36.7213],
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap();
+        PlaceService::create_place(&pool, request).await.unwrap();
+
+        let app = actix_test::init_service(App::new().app_data(web::Data::new(pool)).configure(config)).await;
+        let encoded = city.replace('á', "%C3%A1").replace(' ', "%20");
+        let request = actix_test::TestRequest::get()
+            .uri(&format!("/cities/{}/places?limit=5", encoded))
+            .to_request();
+        let body: serde_json::Value = actix_test::call_and_read_body_json(&app, request).await;
+
+        assert_eq!(body["total_count"], 1);
+        assert_eq!(body["data"][0]["name"], "Bar Pimpi");
+        assert_eq!(body["filters"]["city"], city);
+        assert_eq!(body["limit"], 5);
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_added_primary_photo_demotes_the_others() {
+        let pool = crate::test_support::test_pool().await;
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Casa Montal",
+            "type": "restaurant",
+            "location": [-0.8784, 41.6534],
+            "city": "Zaragoza",
+            "google_place_id": format!("manual_photo_{}", Uuid::new_v4()),
+            "main_categories": []
+        }))
+        .unwrap();
+        let place = PlaceService::create_place(&pool, request).await.unwrap();
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool.clone()))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+        let post = |identifier: String, body: serde_json::Value| {
+            actix_test::TestRequest::post()
+                .uri(&format!("/places/{}/photos", identifier))
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(body)
+                .to_request()
+        };
+
+        let first = post(
+            place.id.to_string(),
+            serde_json::json!({ "photo_url": "https://cdn.example.com/sala.jpg", "is_primary": true }),
+        );
+        let response = actix_test::call_service(&app, first).await;
+        assert_eq!(response.status(), 201);
+        let first: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(first["source"], "manual");
+
+        // Google place ids work too; the new primary photo replaces the first one
+        let second = post(
+            place.google_place_id.clone().unwrap(),
+            serde_json::json!({
+                "photo_url": "https://cdn.example.com/terraza.jpg",
+                "thumbnail_url": "https://cdn.example.com/terraza_small.jpg",
+                "attribution": "Casa Montal",
+                "is_primary": true,
+                "display_order": 1
+            }),
+        );
+        let second: serde_json::Value = actix_test::call_and_read_body_json(&app, second).await;
+        assert_eq!(second["is_primary"], true);
+        assert_eq!(second["display_order"], 1);
+        assert_eq!(second["attribution"], "Casa Montal");
+
+        let photos = crate::db::PhotoRepository::get_photos_by_place(&pool, &place.id, None).await.unwrap();
+        let primaries: Vec<String> = photos
+            .iter()
+            .filter(|photo| photo.is_primary == Some(true))
+            .map(|photo| photo.photo_url.clone())
+            .collect();
+        assert_eq!(photos.len(), 2);
+        assert_eq!(primaries, ["https://cdn.example.com/terraza.jpg"]);
+
+        let missing = post(Uuid::new_v4().to_string(), serde_json::json!({ "photo_url": "https://cdn.example.com/a.jpg" }));
+        assert_eq!(actix_test::call_service(&app, missing).await.status(), 404);
+    }
+
+    async fn place_with_photos(pool: &PgPool, count: i32) -> (Uuid, Vec<Uuid>) {
+        let place_id = crate::test_support::insert_test_place(pool, &format!("primary_{}", Uuid::new_v4())).await;
+        let mut photos = Vec::new();
+        for order in 0..count {
+            let photo = crate::models::CreatePhotoRequest {
+                place_id,
+                source: "manual".to_string(),
+                source_photo_reference: None,
+                photo_url: format!("https://cdn.example.com/{}/{}.jpg", place_id, order),
+                thumbnail_url: None,
+                width: None,
+                height: None,
+                attribution: None,
+                is_primary: Some(order == 0),
+                display_order: Some(order),
+            };
+            photos.push(crate::db::PhotoRepository::create_photo(pool, &photo).await.unwrap().id);
+        }
+        (place_id, photos)
+    }
+
+    fn set_primary_request(place_id: Uuid, photo_id: Uuid) -> actix_test::TestRequest {
+        actix_test::TestRequest::put()
+            .uri(&format!("/places/{}/photos/{}/primary", place_id, photo_id))
+            .insert_header(("X-Admin-Token", "test-admin-token"))
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_set_primary_photo_returns_the_updated_list() {
+        let pool = crate::test_support::test_pool().await;
+        let (place_id, photos) = place_with_photos(&pool, 3).await;
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+
+        let response = actix_test::call_service(&app, set_primary_request(place_id, photos[2]).to_request()).await;
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        assert_eq!(body["place_id"], place_id.to_string());
+        let listed: Vec<(String, bool)> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|photo| (photo["id"].as_str().unwrap().to_string(), photo["is_primary"].as_bool().unwrap()))
+            .collect();
+        let expected: Vec<(String, bool)> = [(photos[2], true), (photos[0], false), (photos[1], false)]
+            .iter()
+            .map(|(id, primary)| (id.to_string(), *primary))
+            .collect();
+        assert_eq!(listed, expected);
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_set_primary_photo_of_unknown_or_foreign_photo_is_404() {
+        let pool = crate::test_support::test_pool().await;
+        let (place_id, photos) = place_with_photos(&pool, 2).await;
+        let (_, foreign) = place_with_photos(&pool, 1).await;
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool.clone()))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+
+        for photo_id in [Uuid::new_v4(), foreign[0]] {
+            let response = actix_test::call_service(&app, set_primary_request(place_id, photo_id).to_request()).await;
+            assert_eq!(response.status(), 404, "{}", photo_id);
+        }
+
+        // The failed attempts rolled back: the original primary is still the only one
+        let stored = crate::db::PhotoRepository::get_photos_by_place(&pool, &place_id, None).await.unwrap();
+        let primaries: Vec<Uuid> = stored.iter().filter(|photo| photo.is_primary == Some(true)).map(|photo| photo.id).collect();
+        assert_eq!(primaries, [photos[0]]);
+    }
+
+    #[actix_web::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_reorder_photos_returns_the_new_order() {
+        let pool = crate::test_support::test_pool().await;
+        let (place_id, photos) = place_with_photos(&pool, 3).await;
+        let (_, foreign) = place_with_photos(&pool, 1).await;
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(pool))
+                .app_data(web::Data::new(test_config()))
+                .app_data(web::Data::new(Arc::new(PlacesCache::new(60, crate::services::DEFAULT_MAX_ENTRIES))))
+                .configure(config),
+        )
+        .await;
+        let reorder = |photo_ids: Vec<Uuid>| {
+            actix_test::TestRequest::put()
+                .uri(&format!("/places/{}/photos/order", place_id))
+                .insert_header(("X-Admin-Token", "test-admin-token"))
+                .set_json(serde_json::json!({ "photo_ids": photo_ids }))
+                .to_request()
+        };
+
+        let response = actix_test::call_service(&app, reorder(vec![photos[2], photos[0], photos[1]])).await;
+        assert_eq!(response.status(), 200);
+        let body: serde_json::Value = actix_test::read_body_json(response).await;
+        let listed: Vec<(String, i64)> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|photo| (photo["id"].as_str().unwrap().to_string(), photo["display_order"].as_i64().unwrap()))
+            .collect();
+        let expected: Vec<(String, i64)> = [photos[2], photos[0], photos[1]]
+            .iter()
+            .enumerate()
+            .map(|(order, id)| (id.to_string(), order as i64))
+            .collect();
+        assert_eq!(listed, expected);
+
+        let response = actix_test::call_service(&app, reorder(vec![photos[0], foreign[0]])).await;
+        assert_eq!(response.status(), 400);
+    }
 }
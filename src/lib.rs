@@ -0,0 +1,13 @@
+// src/lib.rs
+// DOCUMENTATION: Library target for the places microservice
+// PURPOSE: Expose service modules to the server binary, benchmarks, and tooling
+
+pub mod config;
+pub mod db;
+pub mod errors;
+pub mod handlers;
+pub mod models;
+pub mod services;
+
+#[cfg(test)]
+pub(crate) mod test_support;
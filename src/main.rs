@@ -2,19 +2,16 @@
 // DOCUMENTATION: Application entry point
 // PURPOSE: Initialize config, database, and start HTTP server
 
-mod config;
-mod db;
-mod errors;
-mod handlers;
-mod models;
-mod services;
-
 use actix_web::{middleware::Logger, web, App, HttpServer};
-use config::Config;
+use auphere_places::config::{self, Config};
+use auphere_places::handlers;
+use auphere_places::services::{
+    GooglePlacesClient, GridGenerator, PlaceService, PlacesCache, SyncJobRegistry, SyncScheduler,
+    start_cleanup_task,
+};
 use dotenv::dotenv;
 use std::io;
 use std::sync::Arc;
-use services::{PlacesCache, start_cleanup_task};
 
 #[actix_web::main]
 async fn main() -> io::Result<()> {
@@ -25,7 +22,7 @@ async fn main() -> io::Result<()> {
     let config = Config::from_env();
     if let Err(e) = config.validate() {
         eprintln!("Configuration error: {}", e);
-        // We continue but log error, or we could panic
+        std::process::exit(1);
     }
 
     // 3. Initialize logging
@@ -48,6 +45,20 @@ async fn main() -> io::Result<()> {
         config.server_port
     );
 
+    // Merge configured cities over the built-in bounds before anything looks them up
+    if let Some(path) = &config.cities_config_path {
+        match GridGenerator::load_cities_file(path) {
+            Ok(cities) => {
+                log::info!("Loaded {} cities from {}", cities.len(), path);
+                GridGenerator::init_city_registry(cities);
+            }
+            Err(e) => {
+                log::error!("Failed to load cities config: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // 4. Initialize database connection pool
     let pool = match config::init_db_pool(&config).await {
         Ok(pool) => pool,
@@ -58,23 +69,63 @@ async fn main() -> io::Result<()> {
     };
 
     // 5. Initialize cache for Google Places API responses
-    let cache = Arc::new(PlacesCache::new(3600)); // 1 hour TTL
-    log::info!("Initialized Places API cache (TTL: 1 hour)");
-    
-    // Start background cleanup task (runs every 5 minutes)
-    start_cleanup_task(cache.clone(), 300);
-    log::info!("Started cache cleanup task (interval: 5 minutes)");
+    let cache = match PlacesCache::from_config(&config).await {
+        Ok(cache) => Arc::new(cache),
+        Err(e) => {
+            log::error!("Failed to initialize cache: {}", e);
+            std::process::exit(1);
+        }
+    };
+    log::info!(
+        "Initialized Places API cache (backend: {}, TTL: {}s, max entries: {})",
+        cache.backend_name(),
+        config.cache_search_ttl_seconds,
+        config.cache_max_entries
+    );
+
+    // Start background cleanup task
+    start_cleanup_task(cache.clone(), config.cache_cleanup_interval_seconds);
+    log::info!(
+        "Started cache cleanup task (interval: {}s)",
+        config.cache_cleanup_interval_seconds
+    );
+
+    // 6. Initialize shared Google Places client (one connection pool for all requests)
+    let google_client = Arc::new(GooglePlacesClient::from_config(&config, cache.clone()));
+
+    // Limits Place Details calls triggered by GET /places/{id} for unknown Google ids
+    let import_limiter = Arc::new(PlaceService::on_demand_limiter(
+        config.on_demand_imports_per_minute,
+    ));
+
+    // Background syncs started by POST /admin/sync/{city}, /bbox and /batch, recorded in data_sync_log
+    let sync_jobs = Arc::new(SyncJobRegistry::from_config(&config).with_pool(pool.clone()));
+
+    // Periodic refresh of stored places (SYNC_SCHEDULE_ENABLED)
+    let scheduler = Arc::new(SyncScheduler::from_config(&config));
+    scheduler.start(pool.clone(), google_client.clone(), cache.clone());
+    if config.sync_schedule_enabled {
+        log::info!(
+            "Started scheduled refresh (every {}h, {} places per city)",
+            config.sync_schedule_interval_hours,
+            config.sync_schedule_limit
+        );
+    }
 
-    // 6. Start HTTP server
+    // 7. Start HTTP server
     let server_addr = format!("{}:{}", config.server_address, config.server_port);
     let config_clone = config.clone();
 
     HttpServer::new(move || {
         App::new()
-            // Application state (database pool, config, and cache)
+            // Application state (database pool, config, cache, Google client, import limiter, sync jobs, scheduler)
             .app_data(web::Data::new(pool.clone()))
             .app_data(web::Data::new(config_clone.clone()))
             .app_data(web::Data::new(cache.clone()))
+            .app_data(web::Data::new(google_client.clone()))
+            .app_data(web::Data::new(import_limiter.clone()))
+            .app_data(web::Data::new(sync_jobs.clone()))
+            .app_data(web::Data::new(scheduler.clone()))
             // Middleware
             .wrap(Logger::default())
             .wrap(actix_web::middleware::Compress::default())
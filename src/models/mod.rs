@@ -2,10 +2,12 @@
 // DOCUMENTATION: Models module organization
 // PURPOSE: Re-export model components
 
+pub mod opening_hours;
 pub mod photo;
 pub mod place;
 pub mod review;
 
+pub use opening_hours::*;
 pub use photo::*;
 pub use place::*;
 pub use review::*;
@@ -0,0 +1,263 @@
+// src/models/opening_hours.rs
+// DOCUMENTATION: Typed weekly opening hours parsed from Google's `periods`
+// PURPOSE: Compute "open now" when a response is built instead of trusting a sync-time flag
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Timelike, Utc};
+use serde_json::Value;
+
+use crate::services::{GoogleOpeningHours, GoogleOpeningTime};
+
+/// Minutes in a week; period boundaries are minutes since Sunday 00:00
+const MINUTES_PER_WEEK: u32 = 7 * 24 * 60;
+
+/// Point in the week (Google days: 0 = Sunday ... 6 = Saturday)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekTime {
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl WeekTime {
+    /// Parse Google's `{ "day": 5, "time": "2230" }`; "2400" is accepted as end of day
+    fn from_google(time: &GoogleOpeningTime) -> Option<Self> {
+        let day = u32::try_from(time.day?).ok().filter(|day| *day < 7)?;
+        let raw = time.time.as_deref()?;
+        if raw.len() != 4 || !raw.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let hour: u32 = raw[..2].parse().ok()?;
+        let minute: u32 = raw[2..].parse().ok()?;
+        if minute >= 60 || hour > 24 || (hour == 24 && minute > 0) {
+            return None;
+        }
+        Some(WeekTime { day, hour, minute })
+    }
+
+    fn minute_of_week(&self) -> u32 {
+        (self.day * 24 + self.hour) * 60 + self.minute
+    }
+}
+
+/// One opening interval; `close = None` only for places open around the clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpeningPeriod {
+    pub open: WeekTime,
+    pub close: Option<WeekTime>,
+}
+
+impl OpeningPeriod {
+    /// Whether `minute` (minutes since Sunday 00:00) falls in this period
+    /// DOCUMENTATION: A close at or before the open wraps into the next week
+    /// (Saturday 22:00 - Sunday 02:00); overnight closes within the week
+    /// (Friday 22:00 - Saturday 02:00) already carry the next day.
+    fn contains(&self, minute: u32) -> bool {
+        let Some(close) = self.close else {
+            return true;
+        };
+        let start = self.open.minute_of_week();
+        let mut end = close.minute_of_week();
+        if end <= start {
+            end += MINUTES_PER_WEEK;
+        }
+        (start..end).contains(&minute) || (start..end).contains(&(minute + MINUTES_PER_WEEK))
+    }
+}
+
+/// Weekly schedule of a place
+/// DOCUMENTATION: Built from the stored/Google `opening_hours` object. `utc_offset_minutes`
+/// is the place's offset captured from Place Details and is needed to know the local time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningHours {
+    pub periods: Vec<OpeningPeriod>,
+    pub utc_offset_minutes: Option<i32>,
+}
+
+impl OpeningHours {
+    /// Parse Google opening hours; None when there are no usable periods
+    pub fn from_google(hours: &GoogleOpeningHours) -> Option<Self> {
+        let periods: Vec<OpeningPeriod> = hours
+            .periods
+            .as_deref()?
+            .iter()
+            .filter_map(|period| {
+                let open = WeekTime::from_google(period.open.as_ref()?)?;
+                let close = match &period.close {
+                    Some(close) => Some(WeekTime::from_google(close)?),
+                    None => None,
+                };
+                Some(OpeningPeriod { open, close })
+            })
+            .collect();
+
+        if periods.is_empty() {
+            return None;
+        }
+
+        Some(OpeningHours {
+            periods,
+            utc_offset_minutes: hours.utc_offset_minutes,
+        })
+    }
+
+    /// Parse the `opening_hours` JSON stored on a place
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let hours: GoogleOpeningHours = serde_json::from_value(value.clone()).ok()?;
+        Self::from_google(&hours)
+    }
+
+    /// Google's encoding of "open 24 hours": a single Sunday 00:00 period without a close
+    pub fn is_always_open(&self) -> bool {
+        matches!(
+            self.periods.as_slice(),
+            [OpeningPeriod { open: WeekTime { day: 0, hour: 0, minute: 0 }, close: None }]
+        )
+    }
+
+    /// Whether the place is open at `at`, read in `at`'s own time zone
+    pub fn is_open_at<Tz: TimeZone>(&self, at: &DateTime<Tz>) -> bool {
+        if self.is_always_open() {
+            return true;
+        }
+        let minute = (at.weekday().num_days_from_sunday() * 24 + at.hour()) * 60 + at.minute();
+        self.periods.iter().any(|period| period.contains(minute))
+    }
+
+    /// Whether the place is open right now; None without a known UTC offset
+    pub fn is_open_now(&self) -> Option<bool> {
+        self.is_open_at_utc(Utc::now())
+    }
+
+    /// `is_open_at` for a UTC instant, converted with the place's UTC offset
+    pub fn is_open_at_utc(&self, at: DateTime<Utc>) -> Option<bool> {
+        let offset = FixedOffset::east_opt(self.utc_offset_minutes? * 60)?;
+        Some(self.is_open_at(&at.with_timezone(&offset)))
+    }
+}
+
+/// Open-now flag for a response
+/// DOCUMENTATION: Computed from the schedule when it has periods and a UTC offset.
+/// Hours without periods give None; hours stored before offsets were captured fall
+/// back to `fallback` (Google's flag) until the place is synced again
+pub fn compute_open_now(hours: Option<&Value>, fallback: Option<bool>) -> Option<bool> {
+    let hours = OpeningHours::from_value(hours?)?;
+    hours.is_open_now().or(fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(periods: Value, utc_offset_minutes: Option<i32>) -> OpeningHours {
+        OpeningHours::from_value(&serde_json::json!({
+            "periods": periods,
+            "utc_offset_minutes": utc_offset_minutes
+        }))
+        .unwrap()
+    }
+
+    fn local(day_of_month: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+        // 2026-03-01 is a Sunday
+        FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 3, day_of_month, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_regular_periods() {
+        // Monday-Friday 09:00-14:00
+        let periods: Vec<Value> = (1..=5)
+            .map(|day| serde_json::json!({
+                "open": { "day": day, "time": "0900" },
+                "close": { "day": day, "time": "1400" }
+            }))
+            .collect();
+        let hours = hours(Value::Array(periods), Some(60));
+
+        assert!(hours.is_open_at(&local(2, 9, 0)));
+        assert!(hours.is_open_at(&local(6, 13, 59)));
+        assert!(!hours.is_open_at(&local(2, 14, 0)));
+        assert!(!hours.is_open_at(&local(1, 10, 0)));
+    }
+
+    #[test]
+    fn test_always_open() {
+        let hours = hours(serde_json::json!([{ "open": { "day": 0, "time": "0000" } }]), None);
+
+        assert!(hours.is_always_open());
+        assert!(hours.is_open_at(&local(4, 3, 30)));
+    }
+
+    #[test]
+    fn test_overnight_periods() {
+        let hours = hours(
+            serde_json::json!([
+                // Friday 22:00 - Saturday 03:00
+                { "open": { "day": 5, "time": "2200" }, "close": { "day": 6, "time": "0300" } },
+                // Saturday 22:00 - Sunday 04:00 (wraps the week)
+                { "open": { "day": 6, "time": "2200" }, "close": { "day": 0, "time": "0400" } }
+            ]),
+            Some(60),
+        );
+
+        assert!(hours.is_open_at(&local(6, 23, 0)));
+        assert!(hours.is_open_at(&local(7, 2, 59)));
+        assert!(!hours.is_open_at(&local(7, 3, 0)));
+        assert!(hours.is_open_at(&local(7, 23, 30)));
+        assert!(hours.is_open_at(&local(8, 3, 0)));
+        assert!(!hours.is_open_at(&local(8, 4, 0)));
+    }
+
+    #[test]
+    fn test_utc_instant_uses_place_offset() {
+        let hours = hours(
+            serde_json::json!([{ "open": { "day": 1, "time": "0900" }, "close": { "day": 1, "time": "1000" } }]),
+            Some(120),
+        );
+        // Monday 07:30 UTC is 09:30 at UTC+2
+        let at = Utc.with_ymd_and_hms(2026, 3, 2, 7, 30, 0).unwrap();
+
+        assert_eq!(hours.is_open_at_utc(at), Some(true));
+        assert_eq!(OpeningHours { utc_offset_minutes: None, ..hours }.is_open_at_utc(at), None);
+    }
+
+    #[test]
+    fn test_utc_instants_for_overnight_and_always_open() {
+        // Saturday 22:00 - Sunday 04:00 local at UTC+2 is Saturday 20:00 - Sunday 02:00 UTC
+        let overnight = hours(
+            serde_json::json!([{ "open": { "day": 6, "time": "2200" }, "close": { "day": 0, "time": "0400" } }]),
+            Some(120),
+        );
+        let utc = |day_of_month: u32, hour: u32| Utc.with_ymd_and_hms(2026, 3, day_of_month, hour, 0, 0).unwrap();
+
+        assert_eq!(overnight.is_open_at_utc(utc(7, 19)), Some(false));
+        assert_eq!(overnight.is_open_at_utc(utc(7, 20)), Some(true));
+        assert_eq!(overnight.is_open_at_utc(utc(8, 1)), Some(true));
+        assert_eq!(overnight.is_open_at_utc(utc(8, 2)), Some(false));
+
+        let always = hours(serde_json::json!([{ "open": { "day": 0, "time": "0000" } }]), Some(-300));
+        assert!((0..24).all(|hour| always.is_open_at_utc(utc(4, hour)) == Some(true)));
+    }
+
+    #[test]
+    fn test_missing_or_invalid_periods() {
+        assert!(OpeningHours::from_value(&serde_json::json!({ "open_now": true })).is_none());
+        assert!(OpeningHours::from_value(&serde_json::json!({ "periods": [] })).is_none());
+        assert!(OpeningHours::from_value(&serde_json::json!({
+            "periods": [{ "open": { "day": 9, "time": "0900" } }]
+        }))
+        .is_none());
+
+        assert_eq!(compute_open_now(None, Some(true)), None);
+        assert_eq!(compute_open_now(Some(&serde_json::json!({ "open_now": true })), Some(true)), None);
+        // Periods but no offset: keep the stored flag
+        let no_offset = serde_json::json!({ "periods": [{ "open": { "day": 0, "time": "0000" } }] });
+        assert_eq!(compute_open_now(Some(&no_offset), Some(false)), Some(false));
+        let with_offset = serde_json::json!({
+            "periods": [{ "open": { "day": 0, "time": "0000" } }],
+            "utc_offset_minutes": 60
+        });
+        assert_eq!(compute_open_now(Some(&with_offset), Some(false)), Some(true));
+    }
+}
@@ -3,7 +3,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use std::borrow::Cow;
 use uuid::Uuid;
+use validator::{Validate, ValidationError};
 
 /// Place photo from multiple sources
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -38,8 +40,60 @@ pub struct CreatePhotoRequest {
     pub display_order: Option<i32>,
 }
 
+/// Body of PUT /places/{place_id}/photos/order
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReorderPhotosRequest {
+    /// Photo ids in their new display order; photos left out keep their relative
+    /// order after these
+    pub photo_ids: Vec<Uuid>,
+}
+
+/// Response of PUT /places/{place_id}/photos/{photo_id}/primary and
+/// PUT /places/{place_id}/photos/order
+/// DOCUMENTATION: Every photo of the place; primary first, then by display_order
+/// after choosing the primary photo, by display_order alone after a reorder
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlacePhotoListResponse {
+    pub place_id: Uuid,
+    pub data: Vec<PhotoResponse>,
+}
+
+/// Body of POST /places/{id}/photos
+/// DOCUMENTATION: Photo URLs must be absolute http(s) URLs of at most 2048 characters.
+/// `is_primary` makes the photo the place's only primary one
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+pub struct AddPlacePhotoRequest {
+    #[validate(length(min = 1, max = 2048), custom = "validate_photo_url")]
+    pub photo_url: String,
+
+    #[validate(length(min = 1, max = 2048), custom = "validate_photo_url")]
+    pub thumbnail_url: Option<String>,
+
+    /// Credit shown next to the photo
+    #[validate(length(max = 500))]
+    pub attribution: Option<String>,
+
+    pub is_primary: Option<bool>,
+
+    /// Position among the place's photos (0 first)
+    #[validate(range(min = 0))]
+    pub display_order: Option<i32>,
+}
+
+/// Field validator for photo URLs: absolute, with a host, over http or https
+fn validate_photo_url(url: &str) -> Result<(), ValidationError> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(()),
+        _ => {
+            let mut error = ValidationError::new("photo_url");
+            error.message = Some(Cow::from("must be an absolute http or https URL"));
+            Err(error)
+        }
+    }
+}
+
 /// Photo DTO for API responses
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhotoResponse {
     pub id: Uuid,
     pub source: String,
@@ -52,6 +106,47 @@ pub struct PhotoResponse {
     pub display_order: Option<i32>,
 }
 
+/// Sources allowed by the valid_photo_source constraint of place_photos
+pub const PHOTO_SOURCES: &[&str] = &[
+    "google",
+    "trustpilot",
+    "yelp",
+    "tripadvisor",
+    "instagram",
+    "user_upload",
+    "owner_upload",
+    "manual",
+];
+
+/// Source of photos attached by URL through POST /places/{id}/photos
+pub const MANUAL_PHOTO_SOURCE: &str = "manual";
+
+/// Query parameters of GET /places/{id}/photos
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PlacePhotosQuery {
+    /// Only photos from this source (one of PHOTO_SOURCES)
+    pub source: Option<String>,
+
+    /// Page number (1-based)
+    pub page: Option<i64>,
+
+    /// Photos per page (default 20, capped at 100)
+    pub limit: Option<i64>,
+}
+
+/// Response of GET /places/{id}/photos
+/// DOCUMENTATION: `total_count` counts the place's photos matching `source`; a place
+/// without photos has an empty `data`, a missing place is a 404
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlacePhotosResponse {
+    pub place_id: Uuid,
+    pub data: Vec<PhotoResponse>,
+    pub total_count: i64,
+    pub page: i64,
+    pub limit: i64,
+    pub has_more: bool,
+}
+
 impl Photo {
     /// Convert database photo into API response DTO
     pub fn to_response(&self) -> PhotoResponse {
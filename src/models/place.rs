@@ -6,10 +6,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::FromRow;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
-use super::{PhotoResponse, ReviewResponse};
+use super::{compute_open_now, PhotoResponse, ReviewResponse};
+use crate::services::{AutocompletePrediction, GooglePlacesClient, GridGenerator};
 
 /// Represents a complete place record from the database
 /// DOCUMENTATION: This struct maps directly to the places table in PostgreSQL
@@ -133,12 +136,18 @@ pub struct Place {
     /// Primary thumbnail URL for faster listing rendering
     #[sqlx(skip)]
     pub primary_photo_thumbnail_url: Option<String>,
+
+    /// Distance in kilometers from the search point (database searches with lat/lon)
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance_km: Option<f64>,
 }
 
 /// Request DTO for creating a new place
 /// DOCUMENTATION: Data transfer object for POST /places endpoint
 /// Used for API input validation and database inserts
 #[derive(Debug, Serialize, Deserialize, Validate, Clone)]
+#[validate(schema(function = "validate_coordinate_order", skip_on_field_errors = false))]
 pub struct CreatePlaceRequest {
     /// Place name (required)
     #[validate(length(min = 1, max = 255))]
@@ -218,6 +227,60 @@ pub struct CreatePlaceRequest {
     /// Suitable for tags
     #[serde(default)]
     pub suitable_for: Vec<String>,
+
+    /// Language the Google data was requested in (stored as `tags.language`)
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Google service attributes such as `dine_in` (merged into `tags`)
+    /// Only set from Google data; never read from or written to request bodies
+    #[serde(skip)]
+    pub attributes: Option<Value>,
+}
+
+/// How far (in degrees) a point may sit outside known city bounds before it is suspicious
+const CITY_BOUNDS_MARGIN_DEG: f64 = 0.5;
+
+/// Detect a location sent as [latitude, longitude] instead of [longitude, latitude]
+/// DOCUMENTATION: Returns the hint message when the pair is almost certainly swapped:
+/// - the latitude slot holds a value beyond ±90 while the longitude slot fits as a latitude
+/// - the city has known bounds, the point is far outside them, and the swapped point is inside
+///
+/// Ambiguous pairs (both values within ±90, unknown city) are accepted.
+pub fn detect_swapped_coordinates(location: [f64; 2], city: Option<&str>) -> Option<String> {
+    let [lng, lat] = location;
+    let hint = || {
+        format!(
+            "location looks like [latitude, longitude]; expected [longitude, latitude] - did you mean [{}, {}]?",
+            lat, lng
+        )
+    };
+
+    if lng.abs() <= 90.0 && lat.abs() > 90.0 {
+        return Some(hint());
+    }
+
+    if let Some(bounds) = city.and_then(GridGenerator::get_city_bounds) {
+        if !bounds.contains(lat, lng, CITY_BOUNDS_MARGIN_DEG)
+            && bounds.contains(lng, lat, CITY_BOUNDS_MARGIN_DEG)
+        {
+            return Some(hint());
+        }
+    }
+
+    None
+}
+
+/// Schema-level validator for CreatePlaceRequest coordinates
+fn validate_coordinate_order(req: &CreatePlaceRequest) -> Result<(), ValidationError> {
+    match detect_swapped_coordinates(req.location, Some(&req.city)) {
+        Some(hint) => {
+            let mut error = ValidationError::new("swapped_coordinates");
+            error.message = Some(Cow::from(hint));
+            Err(error)
+        }
+        None => Ok(()),
+    }
 }
 
 /// Request DTO for updating an existing place
@@ -308,6 +371,20 @@ pub struct PlaceResponse {
     /// Media
     pub primary_photo_url: Option<String>,
     pub primary_photo_thumbnail_url: Option<String>,
+
+    /// Distance in kilometers from the search point, rounded to 2 decimals; only
+    /// present in searches that sent lat/lon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance_km: Option<f64>,
+
+    /// First photos of the place, primary first; only present in database searches
+    /// that sent include_photos=true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photos: Option<Vec<PhotoResponse>>,
+
+    /// Whether the place is active; only present in admin reads with include_inactive=true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
 }
 
 /// Detailed response DTO
@@ -321,10 +398,175 @@ pub struct PlaceDetailResponse {
     pub reviews: Vec<ReviewResponse>,
 }
 
+/// Request body for POST /places/batch
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchPlacesRequest {
+    /// Place UUIDs and/or Google place ids, mixed freely (at most 100)
+    pub ids: Vec<String>,
+}
+
+/// Response of POST /places/batch
+/// DOCUMENTATION: `data` holds each found place once, in the order its first
+/// identifier was requested; `missing` lists identifiers with no active place
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchPlacesResponse {
+    pub data: Vec<PlaceResponse>,
+    pub missing: Vec<String>,
+}
+
+/// Query parameters of GET /places/{id}/nearby
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct NearbyPlacesQuery {
+    /// Search radius around the place in kilometers (default 1, capped at 10)
+    pub radius_km: Option<f64>,
+
+    /// Filter by place type
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+
+    /// Maximum number of places (default 10, capped at 50)
+    pub limit: Option<i64>,
+}
+
+/// Response of GET /places/{id}/nearby
+/// DOCUMENTATION: `data` is ordered by distance from the place, closest first, and
+/// never contains the place itself. `radius_km` and `limit` are the values applied
+/// after capping
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NearbyPlacesResponse {
+    pub place_id: Uuid,
+    pub radius_km: f64,
+    pub limit: i64,
+    pub data: Vec<PlaceResponse>,
+}
+
+/// Query parameters of GET /places/{id}/similar
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SimilarPlacesQuery {
+    /// Maximum number of places (default 6, capped at 20)
+    pub limit: Option<i64>,
+}
+
+/// Response of GET /places/{id}/similar
+/// DOCUMENTATION: `data` is ordered by similarity score, best first, and never
+/// contains the place itself. `limit` is the value applied after capping
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimilarPlacesResponse {
+    pub place_id: Uuid,
+    pub limit: i64,
+    pub data: Vec<PlaceResponse>,
+}
+
+/// Query parameters of GET /places/clusters
+/// DOCUMENTATION: The four viewport corners and the zoom level are required
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ClusterQuery {
+    pub sw_lat: Option<f64>,
+    pub sw_lng: Option<f64>,
+    pub ne_lat: Option<f64>,
+    pub ne_lng: Option<f64>,
+
+    /// Map zoom level (0-22); higher zooms use smaller cells
+    pub zoom: Option<u8>,
+
+    /// Filter by place type
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+}
+
+/// Places of one grid cell of GET /places/clusters
+/// DOCUMENTATION: `latitude`/`longitude` is the centroid of the cell's places;
+/// `place` is only set for single-place clusters
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlaceCluster {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place: Option<PlaceResponse>,
+}
+
+/// Response of GET /places/clusters
+/// DOCUMENTATION: `total_count` is the number of active places in the viewport,
+/// the sum of the cluster counts; `cell_size_deg` is the grid cell applied
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClustersResponse {
+    pub zoom: u8,
+    pub cell_size_deg: f64,
+    pub total_count: i64,
+    pub clusters: Vec<PlaceCluster>,
+}
+
+/// Query parameters of GET /places/featured
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FeaturedPlacesQuery {
+    /// Restrict to one city (exact name, case-insensitive)
+    pub city: Option<String>,
+
+    /// Filter by place type
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+
+    /// Places per page (default 12, capped at 50)
+    pub limit: Option<i64>,
+
+    /// Shuffle seed; the same seed gives the same order (random when absent)
+    pub seed: Option<u32>,
+
+    /// Page of the shuffled order (default 1)
+    pub page: Option<i64>,
+}
+
+/// Response of GET /places/featured
+/// DOCUMENTATION: `seed` is the shuffle used for this page; passing it back with
+/// the next `page` continues the same order without repeating places
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FeaturedPlacesResponse {
+    pub seed: u32,
+    pub page: i64,
+    pub limit: i64,
+    pub data: Vec<PlaceResponse>,
+}
+
+/// Query parameters of GET /cities/{city}/places
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CityPlacesQuery {
+    /// Filter by place type
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+
+    /// Result ordering: "rating" (default), "rating_count", "created_at" or "name"
+    pub sort: Option<String>,
+
+    /// Sort direction: "asc" or "desc" (default depends on `sort`)
+    pub order: Option<String>,
+
+    /// Page number (1-based)
+    pub page: Option<i64>,
+
+    /// Results per page (max 100)
+    pub limit: Option<i64>,
+}
+
+impl CityPlacesQuery {
+    /// The equivalent search query, for sorting and the response envelope
+    pub fn to_search_query(&self, city: &str) -> SearchQuery {
+        SearchQuery {
+            city: Some(city.to_string()),
+            type_: self.type_.clone(),
+            sort: self.sort.clone(),
+            order: self.order.clone(),
+            page: self.page,
+            limit: self.limit,
+            ..Default::default()
+        }
+    }
+}
+
 /// Search query parameters
 /// DOCUMENTATION: DTO for parsing query string in /places/search endpoint
 /// All parameters are optional for flexible searching
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct SearchQuery {
     /// Full-text search query
     pub q: Option<String>,
@@ -348,11 +590,75 @@ pub struct SearchQuery {
     /// Search radius in kilometers
     pub radius_km: Option<f64>,
 
+    /// Map viewport: north-east corner latitude. The four corner parameters go
+    /// together and cannot be combined with `radius_km`. Database search only
+    pub ne_lat: Option<f64>,
+
+    /// Map viewport: north-east corner longitude
+    pub ne_lng: Option<f64>,
+
+    /// Map viewport: south-west corner latitude
+    pub sw_lat: Option<f64>,
+
+    /// Map viewport: south-west corner longitude
+    pub sw_lng: Option<f64>,
+
+    /// Name matching for `q` in database search: "true" matches names by trigram
+    /// similarity (tolerates typos), "false" disables the fuzzy fallback that runs
+    /// when full-text search finds nothing
+    pub fuzzy: Option<bool>,
+
     /// Minimum rating filter
     pub min_rating: Option<f32>,
 
-    /// Filter by specific tags
-    #[allow(dead_code)]
+    /// Minimum number of ratings
+    pub min_rating_count: Option<i32>,
+
+    /// Only places reported as currently open
+    pub open_now: Option<bool>,
+
+    /// Minimum price level (0-4)
+    pub min_price: Option<i32>,
+
+    /// Maximum price level (0-4)
+    pub max_price: Option<i32>,
+
+    /// Result ordering: "distance", "rating", "rating_count", "created_at" or "name"
+    /// (default: relevance). With a type or keyword, "distance" makes Google rank by distance
+    pub sort: Option<String>,
+
+    /// Sort direction: "asc" or "desc" (default depends on `sort`)
+    pub order: Option<String>,
+
+    /// Google response language (e.g. "es", "en"); defaults to GOOGLE_DEFAULT_LANGUAGE
+    pub language: Option<String>,
+
+    /// Google region bias (ccTLD code, e.g. "es"); defaults to GOOGLE_REGION_BIAS
+    pub region: Option<String>,
+
+    /// Cuisine filter as a comma-separated list (e.g. "italian,japanese"); matches
+    /// places serving any of them. Unknown cuisines are ignored
+    #[serde(default, deserialize_with = "deserialize_comma_list")]
+    pub cuisines: Option<Vec<String>>,
+
+    /// Audience filter as a comma-separated list (e.g. "families,groups"); matches
+    /// places suitable for any of them. Allowed: couples, families, groups, solo, budget
+    #[serde(default, deserialize_with = "deserialize_comma_list")]
+    pub suitable_for: Option<Vec<String>>,
+
+    /// Google categories as a comma-separated list (e.g. "bakery,cafe"); matches places
+    /// with any of them in main_categories or secondary_categories
+    #[serde(default, deserialize_with = "deserialize_comma_list")]
+    pub categories: Option<Vec<String>>,
+
+    /// Google categories to leave out (e.g. "meal_delivery"); wins over `categories`
+    #[serde(default, deserialize_with = "deserialize_comma_list")]
+    pub exclude_categories: Option<Vec<String>>,
+
+    /// Tag filter as a comma-separated list of `key` (tags.key is true) or `key:value`
+    /// (tags.key equals value; "true"/"false" compare as booleans) expressions;
+    /// every expression must match. Database search only
+    #[serde(default, deserialize_with = "deserialize_comma_list")]
     pub tags: Option<Vec<String>>,
 
     /// Page number (1-based)
@@ -360,11 +666,267 @@ pub struct SearchQuery {
 
     /// Results per page (max 100)
     pub limit: Option<i64>,
+
+    /// Force a data source: "db" skips Google even when a key is configured
+    pub source: Option<String>,
+
+    /// Continuation cursor from a previous Google-backed response (`next_cursor`)
+    pub cursor: Option<String>,
+
+    /// Add result counts per type, district and price level (`facets`). Database
+    /// search only; off by default as it costs three extra queries
+    pub include_facets: Option<bool>,
+
+    /// Attach the first photos of each result (`photos`). Database search only;
+    /// all photos of a page are read in one extra query
+    pub include_photos: Option<bool>,
+
+    /// Also match deactivated places. Not read from the query string: the handler
+    /// sets it for include_inactive=true requests with a valid admin token
+    #[serde(skip)]
+    pub include_inactive: bool,
+}
+
+/// Split a comma-separated query value into trimmed, non-empty items
+fn deserialize_comma_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    }))
+}
+
+/// Parse one `tags` filter expression into the JSONB object it must contain
+/// DOCUMENTATION: `terrace` gives {"terrace": true} and `music:live` gives
+/// {"music": "live"}; "true" and "false" values become booleans. Keys are letters,
+/// digits, `_` and `-`, compared case-sensitively like the stored keys
+pub fn parse_tag_filter(expression: &str) -> Result<Value, String> {
+    let expression = expression.trim();
+    let (key, value) = match expression.split_once(':') {
+        Some((key, value)) => (key.trim(), Some(value.trim())),
+        None => (expression, None),
+    };
+
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(format!(
+            "invalid tag '{}': keys may only contain letters, digits, '_' and '-'",
+            expression
+        ));
+    }
+    let value = match value {
+        None | Some("true") => Value::Bool(true),
+        Some("false") => Value::Bool(false),
+        Some("") => return Err(format!("invalid tag '{}': missing value after ':'", expression)),
+        Some(value) => Value::String(value.to_string()),
+    };
+
+    Ok(serde_json::json!({ key: value }))
+}
+
+/// Map viewport of a search, in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub ne_lat: f64,
+    pub ne_lng: f64,
+    pub sw_lat: f64,
+    pub sw_lng: f64,
+}
+
+/// Trimmed, lowercased and deduplicated items of a list filter, in request order
+fn normalized_list(values: &Option<Vec<String>>) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+    for value in values.iter().flatten() {
+        let value = value.trim().to_lowercase();
+        if !value.is_empty() && !items.contains(&value) {
+            items.push(value);
+        }
+    }
+    items
+}
+
+impl SearchQuery {
+    /// Known cuisines of the `cuisines` filter
+    /// DOCUMENTATION: Lowercased, deduplicated and in request order; cuisines without
+    /// a known category are dropped (see `unknown_cuisines`)
+    pub fn known_cuisines(&self) -> Vec<String> {
+        let mut known: Vec<String> = Vec::new();
+        for cuisine in self.cuisines.iter().flatten() {
+            let cuisine = cuisine.trim().to_lowercase();
+            if GooglePlacesClient::cuisine_google_type(&cuisine).is_some() && !known.contains(&cuisine) {
+                known.push(cuisine);
+            }
+        }
+        known
+    }
+
+    /// Cuisines of the `cuisines` filter that match no known category
+    pub fn unknown_cuisines(&self) -> Vec<String> {
+        self.cuisines
+            .iter()
+            .flatten()
+            .map(|cuisine| cuisine.trim().to_string())
+            .filter(|cuisine| !cuisine.is_empty() && GooglePlacesClient::cuisine_google_type(cuisine).is_none())
+            .collect()
+    }
+
+    /// The `suitable_for` filter lowercased and deduplicated, in request order
+    pub fn suitable_for_tags(&self) -> Vec<String> {
+        normalized_list(&self.suitable_for)
+    }
+
+    /// The `categories` filter lowercased and deduplicated, in request order
+    pub fn included_categories(&self) -> Vec<String> {
+        normalized_list(&self.categories)
+    }
+
+    /// The `exclude_categories` filter lowercased and deduplicated, in request order
+    pub fn excluded_categories(&self) -> Vec<String> {
+        normalized_list(&self.exclude_categories)
+    }
+
+    /// JSONB objects the `tags` filter requires, one per expression
+    pub fn tag_filters(&self) -> Result<Vec<Value>, String> {
+        self.tags.iter().flatten().map(|tag| parse_tag_filter(tag)).collect()
+    }
+
+    /// Map viewport given by the `ne_*`/`sw_*` parameters
+    /// DOCUMENTATION: None when no corner is given. Errors when only some corners are
+    /// given, a coordinate is out of range or the south-west corner is not below and
+    /// left of the north-east one. A viewport crossing the antimeridian (sw_lng >
+    /// ne_lng) is rejected for now rather than split into two envelopes
+    pub fn bounding_box(&self) -> Result<Option<BoundingBox>, String> {
+        let (ne_lat, ne_lng, sw_lat, sw_lng) = match (self.ne_lat, self.ne_lng, self.sw_lat, self.sw_lng) {
+            (None, None, None, None) => return Ok(None),
+            (Some(ne_lat), Some(ne_lng), Some(sw_lat), Some(sw_lng)) => (ne_lat, ne_lng, sw_lat, sw_lng),
+            _ => return Err("ne_lat, ne_lng, sw_lat and sw_lng must be given together".to_string()),
+        };
+
+        let lats_valid = [ne_lat, sw_lat].iter().all(|lat| (-90.0..=90.0).contains(lat));
+        let lngs_valid = [ne_lng, sw_lng].iter().all(|lng| (-180.0..=180.0).contains(lng));
+        if !lats_valid || !lngs_valid {
+            return Err("viewport latitudes must be within ±90 and longitudes within ±180".to_string());
+        }
+        if sw_lat > ne_lat {
+            return Err(format!("sw_lat ({}) must not be greater than ne_lat ({})", sw_lat, ne_lat));
+        }
+        if sw_lng > ne_lng {
+            return Err(format!(
+                "viewports crossing the antimeridian are not supported (sw_lng {} is greater than ne_lng {})",
+                sw_lng, ne_lng
+            ));
+        }
+
+        Ok(Some(BoundingBox { ne_lat, ne_lng, sw_lat, sw_lng }))
+    }
+
+    /// Filters this query applies, echoed back in search responses
+    /// DOCUMENTATION: Logs a warning for each ignored cuisine
+    pub fn applied_filters(&self) -> AppliedFilters {
+        let ignored_cuisines = self.unknown_cuisines();
+        if !ignored_cuisines.is_empty() {
+            log::warn!("Ignoring unknown cuisines in search: {}", ignored_cuisines.join(", "));
+        }
+
+        AppliedFilters {
+            q: self.q.clone(),
+            city: self.city.clone(),
+            district: self.district.clone(),
+            type_: self.type_.clone(),
+            cuisines: self.known_cuisines(),
+            ignored_cuisines,
+            suitable_for: self.suitable_for_tags(),
+            categories: self.included_categories(),
+            exclude_categories: self.excluded_categories(),
+            tags: self.tags.clone().unwrap_or_default(),
+            min_rating: self.min_rating,
+            min_rating_count: self.min_rating_count,
+            open_now: self.open_now,
+            min_price: self.min_price,
+            max_price: self.max_price,
+        }
+    }
+
+    /// Cache scope for this query
+    /// DOCUMENTATION: Normalized city filter, or "*" when the query spans all cities.
+    /// Used to look up the per-city generation number embedded in cache keys
+    pub fn cache_scope(&self) -> String {
+        self.city
+            .as_deref()
+            .map(|c| c.trim().to_lowercase())
+            .filter(|c| !c.is_empty())
+            .unwrap_or_else(|| "*".to_string())
+    }
+
+    /// Canonical representation of the effective query
+    /// DOCUMENTATION: Fields in alphabetical order with pagination defaults applied,
+    /// so semantically identical queries produce the same cache key
+    pub fn normalized_key(&self) -> String {
+        fn text(value: &Option<String>) -> String {
+            value
+                .as_deref()
+                .map(|v| v.trim().to_lowercase())
+                .unwrap_or_default()
+        }
+        fn number<T: std::fmt::Display>(value: Option<T>) -> String {
+            value.map(|v| v.to_string()).unwrap_or_default()
+        }
+
+        let mut tags = self.tags.clone().unwrap_or_default();
+        tags.sort();
+        let mut cuisines = self.known_cuisines();
+        cuisines.sort();
+        let mut suitable_for = self.suitable_for_tags();
+        suitable_for.sort();
+        let mut categories = self.included_categories();
+        categories.sort();
+        let mut exclude_categories = self.excluded_categories();
+        exclude_categories.sort();
+
+        [
+            format!("categories={}", categories.join(",")),
+            format!("city={}", text(&self.city)),
+            format!("cuisines={}", cuisines.join(",")),
+            format!("district={}", text(&self.district)),
+            format!("exclude_categories={}", exclude_categories.join(",")),
+            format!("fuzzy={}", number(self.fuzzy)),
+            format!("include_facets={}", self.include_facets.unwrap_or(false)),
+            format!("include_inactive={}", self.include_inactive),
+            format!("include_photos={}", self.include_photos.unwrap_or(false)),
+            format!("lat={}", number(self.lat)),
+            format!("limit={}", self.limit.unwrap_or(20).min(100)),
+            format!("lon={}", number(self.lon)),
+            format!("max_price={}", number(self.max_price)),
+            format!("min_price={}", number(self.min_price)),
+            format!("min_rating={}", number(self.min_rating)),
+            format!("min_rating_count={}", number(self.min_rating_count)),
+            format!("ne_lat={}", number(self.ne_lat)),
+            format!("ne_lng={}", number(self.ne_lng)),
+            format!("open_now={}", number(self.open_now)),
+            format!("order={}", text(&self.order)),
+            format!("page={}", self.page.unwrap_or(1).max(1)),
+            format!("q={}", text(&self.q)),
+            format!("radius_km={}", number(self.radius_km)),
+            format!("region={}", text(&self.region)),
+            format!("sort={}", text(&self.sort)),
+            format!("suitable_for={}", suitable_for.join(",")),
+            format!("sw_lat={}", number(self.sw_lat)),
+            format!("sw_lng={}", number(self.sw_lng)),
+            format!("tags={}", tags.join(",")),
+            format!("type={}", self.type_.as_deref().map(str::trim).unwrap_or_default()),
+        ]
+        .join("&")
+    }
 }
 
 /// Paginated search response
 /// DOCUMENTATION: DTO for returning search results with pagination metadata
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SearchResponse {
     /// Array of place results
     pub data: Vec<PlaceResponse>,
@@ -380,6 +942,135 @@ pub struct SearchResponse {
 
     /// Whether more results exist on next page
     pub has_more: bool,
+
+    /// Filters applied to this search
+    #[serde(default)]
+    pub filters: AppliedFilters,
+
+    /// Whether `q` was matched by name similarity instead of full-text search
+    #[serde(default)]
+    pub fuzzy: bool,
+
+    /// Result counts per value, when requested with include_facets=true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<SearchFacets>,
+}
+
+/// Search result counts per value of a few fields
+/// DOCUMENTATION: Counts cover every match of the search, not just the page. Each map
+/// keeps only the most frequent values (see MAX_FACET_VALUES); places without a
+/// value (no district, no price level) are not counted
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchFacets {
+    pub types: BTreeMap<String, i64>,
+    pub districts: BTreeMap<String, i64>,
+    /// Keyed by price level ("0" to "4")
+    pub price_levels: BTreeMap<String, i64>,
+}
+
+/// Filters a search applied
+/// DOCUMENTATION: Echo of the query filters as interpreted by the service, so clients
+/// can tell when part of a filter (an unknown cuisine) was ignored
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppliedFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub district: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    /// Known cuisines, normalized
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cuisines: Vec<String>,
+    /// Requested cuisines that were not recognized and did not filter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignored_cuisines: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suitable_for: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_categories: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rating: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_rating_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_now: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_price: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_price: Option<i32>,
+}
+
+/// Suggest query parameters
+/// DOCUMENTATION: Query string for GET /places/suggest, typeahead over stored places
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SuggestQuery {
+    /// Text typed so far (at least 2 characters)
+    pub q: String,
+
+    /// Restrict to one city (exact name, case-insensitive)
+    pub city: Option<String>,
+
+    /// Maximum number of suggestions (default 8, max 20)
+    pub limit: Option<i64>,
+}
+
+/// One typeahead suggestion
+/// DOCUMENTATION: Deliberately small; clients open the place with GET /places/{id}
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlaceSuggestion {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(rename = "type")]
+    #[sqlx(rename = "type")]
+    pub type_: String,
+    pub district: Option<String>,
+    /// Thumbnail of the primary photo (the photo itself when it has no thumbnail)
+    pub thumbnail_url: Option<String>,
+}
+
+/// Response of GET /places/suggest
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuggestResponse {
+    pub data: Vec<PlaceSuggestion>,
+}
+
+/// Autocomplete query parameters
+/// DOCUMENTATION: Query string for GET /places/autocomplete. `session` is a
+/// client-generated token reused for the GET /places/{id} call that ends the session
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AutocompleteQuery {
+    /// Text typed so far (at least 2 characters)
+    pub input: String,
+
+    /// Autocomplete session token
+    pub session: Option<String>,
+
+    /// Bias latitude (requires lon)
+    pub lat: Option<f64>,
+
+    /// Bias longitude (requires lat)
+    pub lon: Option<f64>,
+
+    /// Bias radius in meters (default 5000)
+    pub radius_m: Option<u32>,
+
+    /// Restrict predictions to a country (ccTLD code); defaults to GOOGLE_REGION_BIAS
+    pub region: Option<String>,
+}
+
+/// Autocomplete response
+/// DOCUMENTATION: Predictions in Google's order
+#[derive(Debug, Serialize)]
+pub struct AutocompleteResponse {
+    /// Predicted places (id, description, types)
+    pub predictions: Vec<AutocompletePrediction>,
 }
 
 /// Frontend-compatible place response
@@ -475,12 +1166,93 @@ pub struct FrontendReviewResponse {
     pub rating: Option<i32>,
     pub text: Option<String>,
     pub relative_time_description: Option<String>,
+    /// Language the review was written in
+    pub language: Option<String>,
+}
+
+/// Result ordering requested through `sort`
+/// DOCUMENTATION: Without a sort the source's relevance order is kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchSort {
+    /// Nearest first (needs search coordinates)
+    Distance,
+    /// Highest rating first
+    Rating,
+    /// Most ratings first
+    RatingCount,
+    /// Most recently added first (stored places only)
+    CreatedAt,
+    /// Alphabetical by name
+    Name,
+}
+
+impl SearchSort {
+    /// Parse the `sort` query value; None for unknown values
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "distance" => Some(Self::Distance),
+            "rating" => Some(Self::Rating),
+            "rating_count" => Some(Self::RatingCount),
+            "created_at" => Some(Self::CreatedAt),
+            "name" => Some(Self::Name),
+            _ => None,
+        }
+    }
+
+    /// Direction used when the query has no `order`
+    pub fn default_order(self) -> SortOrder {
+        match self {
+            Self::Distance | Self::Name => SortOrder::Asc,
+            Self::Rating | Self::RatingCount | Self::CreatedAt => SortOrder::Desc,
+        }
+    }
+}
+
+/// Sort direction requested through `order`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    /// Parse the `order` query value ("asc" or "desc"); None for unknown values
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "asc" => Some(Self::Asc),
+            "desc" => Some(Self::Desc),
+            _ => None,
+        }
+    }
+
+    /// SQL keyword for this direction
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// Outcome of a Google-backed search
+/// DOCUMENTATION: Lets the frontend show "no places here" for zero_results,
+/// while failures surface as HTTP errors instead of an empty list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchStatus {
+    /// Google returned places for the area
+    Ok,
+    /// Google reported that the area has no matching places
+    ZeroResults,
 }
 
 /// Frontend search response
 /// DOCUMENTATION: Response format for /places/search endpoint
 #[derive(Debug, Serialize)]
 pub struct FrontendSearchResponse {
+    /// Search outcome ("ok" or "zero_results")
+    pub status: SearchStatus,
+
     /// Array of places
     pub places: Vec<FrontendPlaceResponse>,
     
@@ -495,12 +1267,114 @@ pub struct FrontendSearchResponse {
     
     /// Total pages
     pub total_pages: i64,
+
+    /// `total` is a lower bound: Google has more results or the 60-result ceiling was hit
+    pub capped: bool,
+
+    /// Whether more results exist after this page
+    pub has_more: bool,
+
+    /// Opaque cursor for the next Google page (pass back as `cursor`)
+    pub next_cursor: Option<String>,
+
+    /// Filters applied to this search
+    pub filters: AppliedFilters,
+}
+
+/// One field of a stored place that an update changed
+/// DOCUMENTATION: `old` and `new` are normalized values: empty strings, empty lists and
+/// missing values are all `null`, ratings are rounded to two decimals and locations
+/// to six
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Trimmed text, or null when missing or blank
+fn text_value(value: Option<&str>) -> Value {
+    value
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map_or(Value::Null, |text| Value::String(text.to_string()))
+}
+
+/// List of strings, or null when missing or empty
+fn list_value(values: Option<&Vec<String>>) -> Value {
+    match values {
+        Some(values) if !values.is_empty() => Value::from(values.clone()),
+        _ => Value::Null,
+    }
+}
+
+/// JSON document, or null when missing, null or an empty object
+fn json_value(value: Option<&Value>) -> Value {
+    match value {
+        Some(Value::Object(map)) if map.is_empty() => Value::Null,
+        Some(value) => value.clone(),
+        None => Value::Null,
+    }
+}
+
+/// Number rounded to `decimals`, so f32 storage noise does not count as a change
+fn rounded_value(value: Option<f64>, decimals: i32) -> Value {
+    let scale = 10f64.powi(decimals);
+    value.map_or(Value::Null, |value| Value::from((value * scale).round() / scale))
 }
 
 impl Place {
+    /// Fields that differ between this stored place and its `updated` version
+    /// DOCUMENTATION: Compares the fields a Google sync writes. Timestamps
+    /// (`updated_at`, `last_verified_at`) and `is_open_now`, which only reflects the
+    /// moment of the fetch, are ignored. Values are normalized (see FieldChange) so a
+    /// None stored as "" or an empty list is not reported
+    pub fn changes_to(&self, updated: &Place) -> Vec<FieldChange> {
+        let fields = |place: &Place| -> Vec<(&'static str, Value)> {
+            vec![
+                ("name", text_value(Some(&place.name))),
+                ("description", text_value(place.description.as_deref())),
+                ("type", text_value(Some(&place.type_field))),
+                (
+                    "location",
+                    Value::from(vec![
+                        rounded_value(Some(place.longitude), 6),
+                        rounded_value(Some(place.latitude), 6),
+                    ]),
+                ),
+                ("address", text_value(place.address.as_deref())),
+                ("city", text_value(Some(&place.city))),
+                ("district", text_value(place.district.as_deref())),
+                ("postal_code", text_value(place.postal_code.as_deref())),
+                ("phone", text_value(place.phone.as_deref())),
+                ("website", text_value(place.website.as_deref())),
+                ("google_place_url", text_value(place.google_place_url.as_deref())),
+                ("google_rating", rounded_value(place.google_rating.map(f64::from), 2)),
+                ("google_rating_count", Value::from(place.google_rating_count)),
+                ("price_level", Value::from(place.price_level)),
+                ("main_categories", list_value(place.main_categories.as_ref())),
+                ("secondary_categories", list_value(place.secondary_categories.as_ref())),
+                ("cuisine_types", list_value(place.cuisine_types.as_ref())),
+                ("opening_hours", json_value(place.opening_hours.as_ref())),
+                ("business_status", text_value(place.business_status.as_deref())),
+                ("suitable_for", list_value(place.suitable_for.as_ref())),
+                ("tags", json_value(place.tags.as_ref())),
+                ("is_active", Value::from(place.is_active.unwrap_or(true))),
+            ]
+        };
+
+        fields(self)
+            .into_iter()
+            .zip(fields(updated))
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((field, old), (_, new))| FieldChange { field: field.to_string(), old, new })
+            .collect()
+    }
+
     /// Convert Place to PlaceResponse for API
     /// DOCUMENTATION: Maps database model to API response DTO
-    /// Excludes internal fields like search_vector
+    /// Excludes internal fields like search_vector. `is_open_now` is computed from
+    /// the stored schedule at response time rather than copied from the sync snapshot
     pub fn to_response(&self) -> PlaceResponse {
         PlaceResponse {
             id: self.id,
@@ -527,13 +1401,256 @@ impl Place {
             vibe_descriptor: self.vibe_descriptor.clone(),
             suitable_for: self.suitable_for.clone(),
             opening_hours: self.opening_hours.clone(),
-            is_open_now: self.is_open_now,
+            is_open_now: compute_open_now(self.opening_hours.as_ref(), self.is_open_now),
             business_status: self.business_status.clone(),
             is_subscribed: self.is_subscribed,
             created_at: self.created_at,
             updated_at: self.updated_at,
             primary_photo_url: self.primary_photo_url.clone(),
             primary_photo_thumbnail_url: self.primary_photo_thumbnail_url.clone(),
+            distance_km: self.distance_km.map(|km| (km * 100.0).round() / 100.0),
+            photos: None,
+            is_active: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_request(location: [f64; 2], city: &str) -> CreatePlaceRequest {
+        serde_json::from_value(serde_json::json!({
+            "name": "Test Place",
+            "type": "restaurant",
+            "location": location,
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap()
+    }
+
+    fn stored_place(fields: serde_json::Value) -> Place {
+        let mut place = serde_json::json!({
+            "id": Uuid::nil(),
+            "name": "Bodega",
+            "type": "bar",
+            "longitude": -0.8891,
+            "latitude": 41.6488,
+            "city": "Zaragoza",
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z"
+        });
+        place.as_object_mut().unwrap().extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(place).unwrap()
+    }
+
+    #[test]
+    fn test_response_distance_is_rounded_and_omitted_without_coordinates() {
+        let near = stored_place(serde_json::json!({ "distance_km": 1.23756 })).to_response();
+        assert_eq!(near.distance_km, Some(1.24));
+        assert_eq!(serde_json::to_value(&near).unwrap()["distance_km"], 1.24);
+
+        let without = serde_json::to_value(stored_place(serde_json::json!({})).to_response()).unwrap();
+        assert!(without.get("distance_km").is_none());
+    }
+
+    #[test]
+    fn test_tag_filter_syntax() {
+        assert_eq!(parse_tag_filter("terrace").unwrap(), serde_json::json!({ "terrace": true }));
+        assert_eq!(parse_tag_filter(" music : live ").unwrap(), serde_json::json!({ "music": "live" }));
+        assert_eq!(parse_tag_filter("dine_in:false").unwrap(), serde_json::json!({ "dine_in": false }));
+        assert_eq!(parse_tag_filter("wifi:true").unwrap(), serde_json::json!({ "wifi": true }));
+        assert_eq!(parse_tag_filter("dress-code:smart casual").unwrap(), serde_json::json!({ "dress-code": "smart casual" }));
+
+        for invalid in ["", ":live", "music:", "live music", "tags->>'x'", "a'b:c"] {
+            assert!(parse_tag_filter(invalid).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_tags_parse_from_comma_separated_query() {
+        let query = actix_web::web::Query::<SearchQuery>::from_query("tags=terrace,music:live")
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            query.tag_filters().unwrap(),
+            [serde_json::json!({ "terrace": true }), serde_json::json!({ "music": "live" })]
+        );
+        assert_eq!(query.applied_filters().tags, ["terrace", "music:live"]);
+
+        let invalid = SearchQuery { tags: Some(vec!["terrace".to_string(), "music:".to_string()]), ..Default::default() };
+        assert!(invalid.tag_filters().is_err());
+    }
+
+    #[test]
+    fn test_changes_report_moved_values() {
+        let before = stored_place(serde_json::json!({ "google_rating": 4.3, "phone": "976 000 000" }));
+        let after = stored_place(serde_json::json!({ "google_rating": 4.1, "phone": "976 111 111" }));
+
+        let changes = before.changes_to(&after);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "phone");
+        assert_eq!(changes[1].field, "google_rating");
+        assert_eq!(changes[1].old, serde_json::json!(4.3));
+        assert_eq!(changes[1].new, serde_json::json!(4.1));
+    }
+
+    #[test]
+    fn test_changes_ignore_empty_values_and_timestamps() {
+        let before = stored_place(serde_json::json!({
+            "website": "",
+            "cuisine_types": [],
+            "tags": {},
+            "is_open_now": true,
+            "longitude": -0.88910001
+        }));
+        let after = stored_place(serde_json::json!({
+            "updated_at": "2025-06-01T00:00:00Z",
+            "last_verified_at": "2025-06-01T00:00:00Z",
+            "is_open_now": false,
+            "is_active": true
+        }));
+
+        assert!(before.changes_to(&after).is_empty());
+        assert!(after.changes_to(&before).is_empty());
+    }
+
+    #[test]
+    fn test_normalized_key_distinguishes_price_filters() {
+        let keys: std::collections::HashSet<String> = [
+            SearchQuery::default(),
+            SearchQuery { min_price: Some(0), ..Default::default() },
+            SearchQuery { max_price: Some(0), ..Default::default() },
+            SearchQuery { min_price: Some(0), max_price: Some(4), ..Default::default() },
+        ]
+        .iter()
+        .map(SearchQuery::normalized_key)
+        .collect();
+
+        assert_eq!(keys.len(), 4);
+    }
+
+    #[test]
+    fn test_cuisines_parse_from_comma_separated_query() {
+        let query = actix_web::web::Query::<SearchQuery>::from_query("cuisines=Italian,%20japanese,,klingon,italian")
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(query.cuisines.as_deref().unwrap(), ["Italian", "japanese", "klingon", "italian"]);
+        assert_eq!(query.known_cuisines(), ["italian", "japanese"]);
+        assert_eq!(query.unknown_cuisines(), ["klingon"]);
+
+        let filters = query.applied_filters();
+        assert_eq!(filters.cuisines, ["italian", "japanese"]);
+        assert_eq!(filters.ignored_cuisines, ["klingon"]);
+
+        let none = actix_web::web::Query::<SearchQuery>::from_query("q=tapas").unwrap().into_inner();
+        assert!(none.cuisines.is_none());
+        assert_eq!(serde_json::to_value(none.applied_filters()).unwrap(), serde_json::json!({ "q": "tapas" }));
+    }
+
+    #[test]
+    fn test_normalized_key_separates_facet_requests() {
+        let plain = SearchQuery::default();
+        let faceted = SearchQuery { include_facets: Some(true), ..Default::default() };
+        let explicit_off = SearchQuery { include_facets: Some(false), ..Default::default() };
+
+        assert_ne!(plain.normalized_key(), faceted.normalized_key());
+        assert_eq!(plain.normalized_key(), explicit_off.normalized_key());
+    }
+
+    #[test]
+    fn test_normalized_key_uses_known_cuisines() {
+        let key = |cuisines: &[&str]| {
+            SearchQuery { cuisines: Some(cuisines.iter().map(|c| c.to_string()).collect()), ..Default::default() }
+                .normalized_key()
+        };
+
+        assert_eq!(key(&["japanese", "Italian"]), key(&["italian", "klingon", "japanese"]));
+        assert_eq!(key(&["klingon"]), SearchQuery::default().normalized_key());
+        assert_ne!(key(&["italian"]), key(&["japanese"]));
+    }
+
+    #[test]
+    fn test_suitable_for_parses_and_normalizes() {
+        let query = actix_web::web::Query::<SearchQuery>::from_query("suitable_for=Families,%20groups,families")
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(query.suitable_for_tags(), ["families", "groups"]);
+        assert_eq!(query.applied_filters().suitable_for, ["families", "groups"]);
+
+        let reordered = SearchQuery { suitable_for: Some(vec!["groups".to_string(), "families".to_string()]), ..Default::default() };
+        assert_eq!(query.normalized_key(), reordered.normalized_key());
+    }
+
+    #[test]
+    fn test_bounding_box_parses_viewport() {
+        let parse = |query: &str| {
+            actix_web::web::Query::<SearchQuery>::from_query(query)
+                .unwrap()
+                .into_inner()
+                .bounding_box()
+        };
+
+        assert_eq!(
+            parse("ne_lat=41.66&ne_lng=-0.87&sw_lat=41.64&sw_lng=-0.90").unwrap(),
+            Some(BoundingBox { ne_lat: 41.66, ne_lng: -0.87, sw_lat: 41.64, sw_lng: -0.90 })
+        );
+        assert_eq!(parse("lat=41.65&lon=-0.88").unwrap(), None);
+        assert!(parse("ne_lat=41.66&ne_lng=-0.87&sw_lat=41.64").is_err());
+        assert!(parse("ne_lat=41.66&ne_lng=-0.87&sw_lat=41.64&sw_lng=-180.5").is_err());
+        assert!(parse("ne_lat=41.60&ne_lng=-0.87&sw_lat=41.64&sw_lng=-0.90").is_err());
+        let crossing = parse("ne_lat=10&ne_lng=-179&sw_lat=-10&sw_lng=179").unwrap_err();
+        assert!(crossing.contains("antimeridian"), "{}", crossing);
+    }
+
+    #[test]
+    fn test_genuine_coordinates_accepted() {
+        assert!(create_request([-0.8891, 41.6488], "Zaragoza").validate().is_ok());
+        assert!(create_request([151.2093, -33.8688], "Sydney").validate().is_ok());
+        assert!(detect_swapped_coordinates([-0.8891, 41.6488], None).is_none());
+    }
+
+    #[test]
+    fn test_swapped_coordinates_rejected_by_range() {
+        let err = create_request([-33.8688, 151.2093], "Sydney")
+            .validate()
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("expected [longitude, latitude]"));
+        assert!(err.contains("[151.2093, -33.8688]"));
+    }
+
+    #[test]
+    fn test_swapped_coordinates_rejected_by_city_bounds() {
+        assert!(create_request([41.6488, -0.8891], "Zaragoza").validate().is_err());
+        assert!(detect_swapped_coordinates([41.6488, -0.8891], Some("zaragoza")).is_some());
+    }
+
+    #[test]
+    fn test_ambiguous_coordinates_accepted() {
+        // Both values fit either axis and the city has no known bounds
+        assert!(create_request([41.6488, -0.8891], "Unknown Town").validate().is_ok());
+        // Outside Zaragoza but the swapped point is not inside either
+        assert!(detect_swapped_coordinates([2.1734, 41.3851], Some("Zaragoza")).is_none());
+    }
+
+    #[test]
+    fn test_create_request_ignores_client_attributes() {
+        let request: CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Test Place",
+            "type": "restaurant",
+            "location": [-0.8891, 41.6488],
+            "city": "Zaragoza",
+            "main_categories": [],
+            "attributes": { "is_subscribed": true, "language": { "nested": 1 } }
+        }))
+        .unwrap();
+        assert!(request.attributes.is_none());
+    }
+}
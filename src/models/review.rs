@@ -1,5 +1,6 @@
 // src/models/review.rs
 
+use crate::services::stable_hash;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -23,6 +24,8 @@ pub struct Review {
     pub response_from_owner: Option<String>,
     pub is_verified: Option<bool>,
     pub has_photo: Option<bool>,
+    /// Language the review was written in (NULL for rows synced before it was recorded)
+    pub language: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -39,6 +42,103 @@ pub struct CreateReviewRequest {
     pub posted_at: DateTime<Utc>,
     pub is_verified: Option<bool>,
     pub has_photo: Option<bool>,
+    /// Language the review was written in (e.g. "es")
+    pub language: Option<String>,
+    /// source_id older versions stored this review under; a row still using it is
+    /// moved to `source_id` on upsert instead of being duplicated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_source_id: Option<String>,
+}
+
+/// Number of text characters that contribute to a fallback source_id
+const FALLBACK_TEXT_PREFIX_CHARS: usize = 100;
+
+/// Deterministic source_id for reviews that arrive without one
+/// DOCUMENTATION: NULL source_ids never collide in the UNIQUE(source, source_id)
+/// constraint, so re-posting such a review would duplicate it. The fallback is a
+/// stable FNV-1a hash of place, author, posting time (seconds), and text prefix.
+pub fn fallback_review_source_id(
+    place_id: &Uuid,
+    author: Option<&str>,
+    posted_at: &DateTime<Utc>,
+    text: Option<&str>,
+) -> String {
+    let text_prefix: String = text
+        .unwrap_or_default()
+        .chars()
+        .take(FALLBACK_TEXT_PREFIX_CHARS)
+        .collect();
+    let key = format!(
+        "{}|{}|{}|{}",
+        place_id,
+        author.unwrap_or_default(),
+        posted_at.timestamp(),
+        text_prefix
+    );
+
+    format!("fp_{:016x}", stable_hash(&key))
+}
+
+/// Lowercase `text` and collapse its whitespace, so reformatting does not change a hash
+pub fn normalize_review_text(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// source_id of a Google review
+/// DOCUMENTATION: A hash of the Google place id, author and normalized text. Google
+/// omits `time` on some reviews and changes it when a review is edited, so the
+/// timestamp only breaks ties between reviews with neither author nor text
+pub fn google_review_source_id(
+    google_place_id: &str,
+    author: Option<&str>,
+    text: Option<&str>,
+    time: Option<i64>,
+) -> String {
+    let author = normalize_review_text(author.unwrap_or_default());
+    let text = normalize_review_text(text.unwrap_or_default());
+    let tiebreaker = if author.is_empty() && text.is_empty() {
+        time.unwrap_or_default().to_string()
+    } else {
+        String::new()
+    };
+    let key = format!("{}|{}|{}|{}", google_place_id, author, text, tiebreaker);
+
+    format!("gr_{:016x}", stable_hash(&key))
+}
+
+/// source_id Google reviews were stored under before `google_review_source_id`
+pub fn legacy_google_review_source_id(google_place_id: &str, time: Option<i64>) -> String {
+    format!("{}_{}", google_place_id, time.unwrap_or(0))
+}
+
+impl CreateReviewRequest {
+    /// source_id to store: the provided one, or the deterministic fallback
+    pub fn effective_source_id(&self) -> String {
+        match self.source_id.as_deref().map(str::trim) {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => fallback_review_source_id(
+                &self.place_id,
+                self.author.as_deref(),
+                &self.posted_at,
+                self.text.as_deref(),
+            ),
+        }
+    }
+}
+
+impl Review {
+    /// Fallback source_id this stored review would have received
+    pub fn fallback_source_id(&self) -> String {
+        fallback_review_source_id(
+            &self.place_id,
+            self.author.as_deref(),
+            &self.posted_at,
+            self.text.as_deref(),
+        )
+    }
 }
 
 /// Review response DTO exposed via API
@@ -52,6 +152,7 @@ pub struct ReviewResponse {
     pub posted_at: DateTime<Utc>,
     pub helpful_count: Option<i32>,
     pub is_verified: Option<bool>,
+    pub language: Option<String>,
 }
 
 impl Review {
@@ -66,6 +167,85 @@ impl Review {
             posted_at: self.posted_at,
             helpful_count: self.helpful_count,
             is_verified: self.is_verified,
+            language: self.language.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn request(source_id: Option<&str>, text: &str) -> CreateReviewRequest {
+        CreateReviewRequest {
+            place_id: Uuid::nil(),
+            source: "custom".to_string(),
+            source_id: source_id.map(str::to_string),
+            author: Some("Ana".to_string()),
+            rating: 4.0,
+            text: Some(text.to_string()),
+            posted_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+            is_verified: None,
+            has_photo: None,
+            language: None,
+            legacy_source_id: None,
+        }
+    }
+
+    #[test]
+    fn test_explicit_source_id_is_kept() {
+        assert_eq!(request(Some("g_123"), "Bien").effective_source_id(), "g_123");
+    }
+
+    #[test]
+    fn test_fallback_source_id_is_deterministic() {
+        let first = request(None, "Muy buen sitio").effective_source_id();
+        let second = request(Some("  "), "Muy buen sitio").effective_source_id();
+
+        assert!(first.starts_with("fp_"));
+        assert_eq!(first, second);
+        assert_ne!(first, request(None, "Otro texto").effective_source_id());
+    }
+
+    #[test]
+    fn test_fallback_ignores_text_beyond_prefix() {
+        let base = "x".repeat(FALLBACK_TEXT_PREFIX_CHARS);
+        assert_eq!(
+            request(None, &format!("{}tail one", base)).effective_source_id(),
+            request(None, &format!("{}tail two", base)).effective_source_id()
+        );
+    }
+
+    #[test]
+    fn test_google_source_id_ignores_time_and_formatting() {
+        let id = google_review_source_id("ChIJ1", Some("Ana"), Some("Muy  buen\nsitio"), Some(1_700_000_000));
+
+        assert!(id.starts_with("gr_"));
+        assert_eq!(id, google_review_source_id("ChIJ1", Some("Ana"), Some("muy buen sitio "), None));
+        assert_eq!(id, google_review_source_id("ChIJ1", Some("Ana"), Some("Muy buen sitio"), Some(1_800_000_000)));
+        assert_ne!(id, google_review_source_id("ChIJ1", Some("Luis"), Some("Muy buen sitio"), None));
+        assert_ne!(id, google_review_source_id("ChIJ2", Some("Ana"), Some("Muy buen sitio"), None));
+    }
+
+    #[test]
+    fn test_google_source_id_without_time_does_not_collide() {
+        // Both used to be stored as "ChIJ1_0"
+        let first = google_review_source_id("ChIJ1", Some("Ana"), Some("Genial"), None);
+        let second = google_review_source_id("ChIJ1", Some("Luis"), Some("Regular"), None);
+        assert_ne!(first, second);
+        assert_eq!(legacy_google_review_source_id("ChIJ1", None), "ChIJ1_0");
+    }
+
+    #[test]
+    fn test_google_source_id_uses_time_only_without_content() {
+        assert_ne!(
+            google_review_source_id("ChIJ1", None, Some(" "), Some(1)),
+            google_review_source_id("ChIJ1", None, None, Some(2))
+        );
+        assert_eq!(
+            google_review_source_id("ChIJ1", None, None, Some(1)),
+            google_review_source_id("ChIJ1", Some(""), Some(""), Some(1))
+        );
+    }
+}
@@ -1,25 +1,102 @@
 // src/services/cache.rs
-// DOCUMENTATION: Simple in-memory cache for Google Places API responses
-// PURPOSE: Reduce API calls by caching search results
+// DOCUMENTATION: Cache for Google Places API responses with pluggable storage
+// PURPOSE: Reduce API calls by caching search results. PlacesCache is the type the
+// rest of the service uses; the storage behind it is a CacheBackend (in-memory by
+// default, Redis with the `redis` feature so replicas share one cache)
 
-use std::collections::HashMap;
+use crate::config::Config;
+use crate::errors::PlacesError;
+use crate::services::google_places_client::{LocationBias, NearbySearchParams};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use serde::de::DeserializeOwned;
 use serde::{Serialize, Deserialize};
 
+/// Default cap on cached entries
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+/// Result of a backend lookup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheLookup {
+    /// Live value
+    Hit(String),
+    /// No entry for the key
+    Miss,
+    /// An entry existed but its TTL had passed
+    Expired,
+}
+
+impl CacheLookup {
+    /// The value, if the lookup was a hit
+    pub fn into_value(self) -> Option<String> {
+        match self {
+            CacheLookup::Hit(value) => Some(value),
+            CacheLookup::Miss | CacheLookup::Expired => None,
+        }
+    }
+}
+
+/// Storage behind PlacesCache
+/// DOCUMENTATION: Implementations must be safe to share across requests and should
+/// degrade to cache misses (never errors) when their store is unavailable
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Short backend name reported in stats ("memory", "redis")
+    fn name(&self) -> &'static str;
+
+    /// Look up a key
+    async fn get(&self, key: &str) -> CacheLookup;
+
+    /// Store a value for `ttl`
+    async fn set(&self, key: String, value: String, ttl: Duration);
+
+    /// Remove a key; returns whether it existed
+    async fn delete(&self, key: &str) -> bool;
+
+    /// Drop expired entries (no-op for stores that expire keys themselves)
+    async fn cleanup(&self);
+
+    /// Entry counts; request counters are filled in by PlacesCache
+    async fn stats(&self) -> CacheStats;
+
+    /// Reset backend-side counters (e.g. evictions)
+    fn reset_counters(&self) {}
+
+    /// Remove every entry; returns the number removed
+    async fn clear(&self) -> usize;
+
+    /// Remove entries whose key starts with `prefix`; returns the number removed
+    async fn clear_prefix(&self, prefix: &str) -> usize;
+
+    /// Current generation number for an invalidation scope
+    async fn generation(&self, scope: &str) -> u64;
+
+    /// Increment the generation number for a scope and return the new value
+    async fn bump_generation(&self, scope: &str) -> u64;
+}
+
 /// Cache entry with expiration
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 struct CacheEntry<T> {
     data: T,
     expires_at: Instant,
+    /// Tick of the write, unique per entry (breaks expiry ties)
+    written: u64,
+    /// Access tick of the last read or write (higher = more recent)
+    last_access: u64,
 }
 
 impl<T> CacheEntry<T> {
-    fn new(data: T, ttl: Duration) -> Self {
+    fn new(data: T, ttl: Duration, tick: u64) -> Self {
         Self {
             data,
             expires_at: Instant::now() + ttl,
+            written: tick,
+            last_access: tick,
         }
     }
 
@@ -28,56 +105,389 @@ impl<T> CacheEntry<T> {
     }
 }
 
-/// Simple in-memory cache with TTL
-/// DOCUMENTATION: Thread-safe cache for API responses
+/// Stable 64-bit FNV-1a hash (identical across processes and replicas)
+/// DOCUMENTATION: Also backs review fingerprints, which are stored and must not change
+pub fn stable_hash(value: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in value.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Namespace of a cache key: the prefix before the first ':' (e.g. "search", "details")
+pub fn key_namespace(key: &str) -> &str {
+    key.split_once(':').map_or(key, |(namespace, _)| namespace)
+}
+
+/// Count keys per namespace
+pub(crate) fn count_by_namespace<'a>(
+    keys: impl Iterator<Item = &'a String>,
+) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for key in keys {
+        *counts.entry(key_namespace(key).to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Entries of the memory backend, indexed by recency and by expiry
+/// DOCUMENTATION: Every entry is in all three maps, so finding the least recently
+/// used or the expired entries costs O(log n) per entry instead of a full scan
+#[derive(Default)]
+struct MemoryStore {
+    entries: HashMap<String, CacheEntry<String>>,
+    /// Keys by last access tick, least recently used first
+    by_access: BTreeMap<u64, String>,
+    /// Keys by expiry time and write tick, soonest first
+    by_expiry: BTreeMap<(Instant, u64), String>,
+}
+
+impl MemoryStore {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn insert(&mut self, key: String, entry: CacheEntry<String>) {
+        self.remove(&key);
+        self.by_access.insert(entry.last_access, key.clone());
+        self.by_expiry.insert((entry.expires_at, entry.written), key.clone());
+        self.entries.insert(key, entry);
+    }
+
+    fn remove(&mut self, key: &str) -> Option<CacheEntry<String>> {
+        let entry = self.entries.remove(key)?;
+        self.by_access.remove(&entry.last_access);
+        self.by_expiry.remove(&(entry.expires_at, entry.written));
+        Some(entry)
+    }
+
+    /// Look up a key, moving a live entry to the most recently used position
+    fn touch(&mut self, key: &str, tick: u64) -> Option<&CacheEntry<String>> {
+        let entry = self.entries.get_mut(key)?;
+        if !entry.is_expired() {
+            if let Some(key) = self.by_access.remove(&entry.last_access) {
+                self.by_access.insert(tick, key);
+            }
+            entry.last_access = tick;
+        }
+        Some(entry)
+    }
+
+    /// Remove every expired entry and return their keys
+    fn remove_expired(&mut self) -> Vec<String> {
+        let now = Instant::now();
+        let mut removed = Vec::new();
+        while let Some((&(expires_at, _), key)) = self.by_expiry.first_key_value() {
+            if expires_at >= now {
+                break;
+            }
+            let key = key.clone();
+            self.remove(&key);
+            removed.push(key);
+        }
+        removed
+    }
+
+    /// Remove the least recently used entry and return its key
+    fn remove_least_recent(&mut self) -> Option<String> {
+        let key = self.by_access.first_key_value().map(|(_, key)| key.clone())?;
+        self.remove(&key);
+        Some(key)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.by_access.clear();
+        self.by_expiry.clear();
+    }
+}
+
+/// In-memory cache backend with TTL
+/// DOCUMENTATION: Thread-safe map local to this process. Holds at most `max_entries`;
+/// inserting beyond the cap drops expired entries first, then the least recently used
+pub struct MemoryBackend {
+    store: RwLock<MemoryStore>,
+    /// Generation numbers per invalidation scope (e.g. city), embedded in keys
+    generations: RwLock<HashMap<String, u64>>,
+    max_entries: usize,
+    /// Monotonic access counter used for LRU ordering
+    clock: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl MemoryBackend {
+    /// Create an empty store capped at `max_entries`
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            store: RwLock::new(MemoryStore::default()),
+            generations: RwLock::new(HashMap::new()),
+            max_entries: max_entries.max(1),
+            clock: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Free one slot: drop expired entries, or else the least recently used one
+    fn make_room(&self, store: &mut MemoryStore) {
+        if !store.remove_expired().is_empty() {
+            return;
+        }
+
+        if let Some(key) = store.remove_least_recent() {
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+            log::debug!("Cache EVICT (LRU) for key: {}", key);
+        }
+    }
+}
+
+#[async_trait]
+impl CacheBackend for MemoryBackend {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn get(&self, key: &str) -> CacheLookup {
+        let mut store = self.store.write().await;
+
+        match store.touch(key, self.tick()) {
+            Some(entry) if !entry.is_expired() => {
+                log::debug!("Cache HIT for key: {}", key);
+                CacheLookup::Hit(entry.data.clone())
+            }
+            Some(_) => {
+                log::debug!("Cache EXPIRED for key: {}", key);
+                CacheLookup::Expired
+            }
+            None => {
+                log::debug!("Cache MISS for key: {}", key);
+                CacheLookup::Miss
+            }
+        }
+    }
+
+    async fn set(&self, key: String, value: String, ttl: Duration) {
+        let mut store = self.store.write().await;
+        if !store.entries.contains_key(&key) && store.len() >= self.max_entries {
+            self.make_room(&mut store);
+        }
+        store.insert(key.clone(), CacheEntry::new(value, ttl, self.tick()));
+        log::debug!("Cache SET for key: {} (TTL: {}s)", key, ttl.as_secs());
+    }
+
+    async fn delete(&self, key: &str) -> bool {
+        self.store.write().await.remove(key).is_some()
+    }
+
+    async fn cleanup(&self) {
+        let mut store = self.store.write().await;
+        let removed = count_by_namespace(store.remove_expired().iter());
+
+        if !removed.is_empty() {
+            log::info!(
+                "Cache cleanup: removed {} expired entries {:?} ({} remaining)",
+                removed.values().sum::<usize>(),
+                removed,
+                store.len()
+            );
+        }
+    }
+
+    async fn stats(&self) -> CacheStats {
+        let store = self.store.read().await;
+        let total = store.len();
+        let expired = store.entries.values().filter(|e| e.is_expired()).count();
+        let active_by_namespace = count_by_namespace(
+            store.entries.iter().filter(|(_, entry)| !entry.is_expired()).map(|(key, _)| key),
+        );
+
+        CacheStats {
+            backend: self.name().to_string(),
+            total_entries: total,
+            expired_entries: expired,
+            active_entries: total - expired,
+            max_entries: Some(self.max_entries),
+            evictions: self.evictions.load(Ordering::Relaxed),
+            active_by_namespace,
+            ..CacheStats::default()
+        }
+    }
+
+    fn reset_counters(&self) {
+        self.evictions.store(0, Ordering::Relaxed);
+    }
+
+    async fn clear(&self) -> usize {
+        let mut store = self.store.write().await;
+        let count = store.len();
+        store.clear();
+        count
+    }
+
+    async fn clear_prefix(&self, prefix: &str) -> usize {
+        let mut store = self.store.write().await;
+        let keys: Vec<String> = store.entries.keys().filter(|key| key.starts_with(prefix)).cloned().collect();
+        for key in &keys {
+            store.remove(key);
+        }
+        keys.len()
+    }
+
+    async fn generation(&self, scope: &str) -> u64 {
+        let generations = self.generations.read().await;
+        generations.get(scope).copied().unwrap_or(0)
+    }
+
+    async fn bump_generation(&self, scope: &str) -> u64 {
+        let mut generations = self.generations.write().await;
+        let generation = generations.entry(scope.to_string()).or_insert(0);
+        *generation += 1;
+        *generation
+    }
+}
+
+/// Cache used throughout the service
+/// DOCUMENTATION: Wraps a CacheBackend with the default TTL, key helpers, typed
+/// JSON helpers and hit/miss counters, so callers never depend on the storage
 pub struct PlacesCache {
-    store: Arc<RwLock<HashMap<String, CacheEntry<String>>>>,
+    backend: Arc<dyn CacheBackend>,
     default_ttl: Duration,
+    counters: CacheCounters,
+}
+
+/// Request counters kept by PlacesCache (independent of cleanup runs)
+#[derive(Default)]
+struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    expired_on_read: AtomicU64,
 }
 
 impl PlacesCache {
-    /// Create new cache with default TTL
-    pub fn new(ttl_seconds: u64) -> Self {
+    /// Create new in-memory cache with default TTL and a cap on stored entries
+    pub fn new(ttl_seconds: u64, max_entries: usize) -> Self {
+        Self::with_backend(Arc::new(MemoryBackend::new(max_entries)), ttl_seconds)
+    }
+
+    /// Create a cache over an arbitrary backend
+    pub fn with_backend(backend: Arc<dyn CacheBackend>, ttl_seconds: u64) -> Self {
         Self {
-            store: Arc::new(RwLock::new(HashMap::new())),
+            backend,
             default_ttl: Duration::from_secs(ttl_seconds),
+            counters: CacheCounters::default(),
+        }
+    }
+
+    /// Create the cache selected by CACHE_BACKEND ("memory" or "redis")
+    /// DOCUMENTATION: Default TTL is CACHE_SEARCH_TTL_SECONDS. Redis needs REDIS_URL
+    /// and a build with `--features redis`
+    pub async fn from_config(config: &Config) -> Result<Self, PlacesError> {
+        let ttl_seconds = config.cache_search_ttl_seconds;
+        match config.cache_backend.as_str() {
+            "memory" => Ok(Self::new(ttl_seconds, config.cache_max_entries)),
+            #[cfg(feature = "redis")]
+            "redis" => {
+                let url = config.redis_url.as_deref().ok_or_else(|| {
+                    PlacesError::InvalidInput("CACHE_BACKEND=redis requires REDIS_URL".to_string())
+                })?;
+                let backend = crate::services::RedisBackend::connect(url).await?;
+                Ok(Self::with_backend(Arc::new(backend), ttl_seconds))
+            }
+            #[cfg(not(feature = "redis"))]
+            "redis" => Err(PlacesError::InvalidInput(
+                "CACHE_BACKEND=redis requires building with --features redis".to_string(),
+            )),
+            other => Err(PlacesError::InvalidInput(format!(
+                "Unknown CACHE_BACKEND '{}' (expected memory or redis)",
+                other
+            ))),
         }
     }
 
-    /// Generate cache key from search parameters
-    pub fn generate_key(
-        lat: f64,
-        lon: f64,
-        radius: u32,
-        place_type: Option<&str>,
-        keyword: Option<&str>,
-    ) -> String {
+    /// Name of the backend in use
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.name()
+    }
+
+    /// Get current generation number for a scope
+    /// DOCUMENTATION: Keys embedding an old generation are never read again
+    /// and simply expire, which gives cheap invalidation without scanning the store
+    pub async fn generation(&self, scope: &str) -> u64 {
+        self.backend.generation(scope).await
+    }
+
+    /// Bump the generation number for a scope
+    /// Returns the new generation
+    pub async fn bump_generation(&self, scope: &str) -> u64 {
+        let generation = self.backend.bump_generation(scope).await;
+        log::debug!("Cache generation for scope {} bumped to {}", scope, generation);
+        generation
+    }
+
+    /// Generate cache key for a Nearby Search request
+    /// DOCUMENTATION: Hashes the canonical form of every parameter sent to Google, so
+    /// searches differing in any parameter never share an entry while formatting
+    /// differences (keyword case/whitespace, coordinate noise below ~10m) do
+    pub fn generate_key(params: &NearbySearchParams) -> String {
+        format!("search:{:016x}", stable_hash(&params.canonical()))
+    }
+
+    /// Generate cache key for autocomplete predictions
+    /// DOCUMENTATION: Input is lowercased with whitespace collapsed, so "Cafe  Bot"
+    /// and "cafe bot" share predictions for the same bias and country restriction
+    pub fn autocomplete_key(input: &str, bias: Option<&LocationBias>, region: Option<&str>) -> String {
+        let input = input.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        let bias = bias.map(LocationBias::canonical).unwrap_or_default();
+        let region = region.unwrap_or_default().to_lowercase();
+        format!(
+            "autocomplete:{:016x}",
+            stable_hash(&format!("{}|{}|{}", input, bias, region))
+        )
+    }
+
+    /// Generate cache key for geocoded city bounds (case-insensitive city name)
+    pub fn geocode_key(city: &str) -> String {
+        format!("geocode:{}", city.trim().to_lowercase())
+    }
+
+    /// Generate cache key for a Place Details lookup
+    /// DOCUMENTATION: Localized details are cached separately per language
+    pub fn details_key(place_id: &str, language: Option<&str>) -> String {
+        match language {
+            Some(language) => format!("details:{}:{}", language.to_lowercase(), place_id),
+            None => format!("details:{}", place_id),
+        }
+    }
+
+    /// Generate cache key for a resolved photo CDN URL
+    /// DOCUMENTATION: References are long opaque strings, so they are hashed
+    pub fn photo_url_key(photo_reference: &str, max_width: i32) -> String {
         format!(
-            "search:{}:{}:{}:{}:{}",
-            (lat * 10000.0).round() as i64, // Round to ~10m precision
-            (lon * 10000.0).round() as i64,
-            radius,
-            place_type.unwrap_or("all"),
-            keyword.unwrap_or("")
+            "photo_url:{:016x}",
+            stable_hash(&format!("{}|{}", photo_reference, max_width))
         )
     }
 
     /// Get cached value
     pub async fn get(&self, key: &str) -> Option<String> {
-        let store = self.store.read().await;
-        
-        if let Some(entry) = store.get(key) {
-            if !entry.is_expired() {
-                log::debug!("Cache HIT for key: {}", key);
-                return Some(entry.data.clone());
-            } else {
-                log::debug!("Cache EXPIRED for key: {}", key);
+        let lookup = self.backend.get(key).await;
+        let counters = &self.counters;
+        match lookup {
+            CacheLookup::Hit(_) => counters.hits.fetch_add(1, Ordering::Relaxed),
+            CacheLookup::Miss => counters.misses.fetch_add(1, Ordering::Relaxed),
+            CacheLookup::Expired => {
+                // An expired entry is a miss too
+                counters.expired_on_read.fetch_add(1, Ordering::Relaxed);
+                counters.misses.fetch_add(1, Ordering::Relaxed)
             }
-        } else {
-            log::debug!("Cache MISS for key: {}", key);
-        }
-        
-        None
+        };
+        lookup.into_value()
     }
 
     /// Set cached value with default TTL
@@ -87,55 +497,117 @@ impl PlacesCache {
 
     /// Set cached value with custom TTL
     pub async fn set_with_ttl(&self, key: String, value: String, ttl: Duration) {
-        let mut store = self.store.write().await;
-        store.insert(key.clone(), CacheEntry::new(value, ttl));
-        log::debug!("Cache SET for key: {} (TTL: {}s)", key, ttl.as_secs());
+        self.counters.sets.fetch_add(1, Ordering::Relaxed);
+        self.backend.set(key, value, ttl).await;
+    }
+
+    /// Remove a single key
+    pub async fn delete(&self, key: &str) -> bool {
+        self.backend.delete(key).await
+    }
+
+    /// Get a cached value deserialized from JSON
+    /// DOCUMENTATION: Entries that fail to deserialize (e.g. written by an older
+    /// version of the type) are treated as misses
+    pub async fn get_typed<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let json = self.get(key).await?;
+        match serde_json::from_str(&json) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                log::warn!("Failed to deserialize cached value for key {}: {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Cache a value serialized as JSON with the default TTL
+    pub async fn set_typed<T: Serialize>(&self, key: String, value: &T) {
+        self.set_typed_with_ttl(key, value, self.default_ttl).await;
+    }
+
+    /// Cache a value serialized as JSON with a custom TTL
+    pub async fn set_typed_with_ttl<T: Serialize>(&self, key: String, value: &T, ttl: Duration) {
+        match serde_json::to_string(value) {
+            Ok(json) => self.set_with_ttl(key, json, ttl).await,
+            Err(e) => log::warn!("Failed to serialize value for key {}: {}", key, e),
+        }
     }
 
     /// Clear expired entries
     pub async fn cleanup(&self) {
-        let mut store = self.store.write().await;
-        let before_count = store.len();
-        store.retain(|_, entry| !entry.is_expired());
-        let after_count = store.len();
-        
-        if before_count > after_count {
-            log::info!(
-                "Cache cleanup: removed {} expired entries ({} remaining)",
-                before_count - after_count,
-                after_count
-            );
-        }
+        self.backend.cleanup().await;
     }
 
     /// Get cache statistics
     pub async fn stats(&self) -> CacheStats {
-        let store = self.store.read().await;
-        let total = store.len();
-        let expired = store.values().filter(|e| e.is_expired()).count();
-        
+        let hits = self.counters.hits.load(Ordering::Relaxed);
+        let misses = self.counters.misses.load(Ordering::Relaxed);
+        let lookups = hits + misses;
         CacheStats {
-            total_entries: total,
-            expired_entries: expired,
-            active_entries: total - expired,
+            hits,
+            misses,
+            sets: self.counters.sets.load(Ordering::Relaxed),
+            expired_on_read: self.counters.expired_on_read.load(Ordering::Relaxed),
+            hit_ratio: if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 },
+            ..self.backend.stats().await
         }
     }
 
+    /// Reset hit/miss/set/expiry and eviction counters (entries are kept)
+    pub fn reset_stats(&self) {
+        let counters = &self.counters;
+        for counter in [
+            &counters.hits,
+            &counters.misses,
+            &counters.sets,
+            &counters.expired_on_read,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.backend.reset_counters();
+    }
+
     /// Clear all cache entries
     pub async fn clear(&self) {
-        let mut store = self.store.write().await;
-        let count = store.len();
-        store.clear();
+        let count = self.backend.clear().await;
         log::info!("Cache cleared: {} entries removed", count);
     }
+
+    /// Clear entries whose key starts with `prefix`
+    /// DOCUMENTATION: A trailing '*' is ignored, so "search:*" and "search:" are
+    /// equivalent. Returns the number of entries removed
+    pub async fn clear_prefix(&self, prefix: &str) -> usize {
+        let prefix = prefix.trim_end_matches('*');
+        let removed = self.backend.clear_prefix(prefix).await;
+        log::info!("Cache cleared for prefix {}*: {} entries removed", prefix, removed);
+        removed
+    }
 }
 
 /// Cache statistics
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct CacheStats {
+    /// Backend in use ("memory", "redis")
+    pub backend: String,
     pub total_entries: usize,
     pub expired_entries: usize,
     pub active_entries: usize,
+    /// Entry cap (None when the store manages its own memory, e.g. Redis)
+    pub max_entries: Option<usize>,
+    /// Entries dropped by the LRU cap since startup
+    pub evictions: u64,
+    /// Live entries per key namespace (e.g. {"details": 12, "search": 40})
+    pub active_by_namespace: BTreeMap<String, usize>,
+    /// Lookups answered from the cache since startup
+    pub hits: u64,
+    /// Lookups that found no live entry since startup (includes expired_on_read)
+    pub misses: u64,
+    /// Values written since startup
+    pub sets: u64,
+    /// Lookups that found an entry past its TTL
+    pub expired_on_read: u64,
+    /// hits / (hits + misses), 0 when nothing was looked up
+    pub hit_ratio: f64,
 }
 
 /// Start background cleanup task
@@ -157,7 +629,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cache_set_get() {
-        let cache = PlacesCache::new(60);
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
         let key = "test_key".to_string();
         let value = "test_value".to_string();
 
@@ -169,7 +641,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cache_expiration() {
-        let cache = PlacesCache::new(1); // 1 second TTL
+        let cache = PlacesCache::new(1, DEFAULT_MAX_ENTRIES); // 1 second TTL
         let key = "test_key".to_string();
         let value = "test_value".to_string();
 
@@ -185,19 +657,98 @@ mod tests {
         assert!(cache.get(&key).await.is_none());
     }
 
-    #[tokio::test]
-    async fn test_generate_key() {
-        let key1 = PlacesCache::generate_key(40.4168, -3.7038, 1000, Some("restaurant"), None);
-        let key2 = PlacesCache::generate_key(40.4168, -3.7038, 1000, Some("restaurant"), None);
-        let key3 = PlacesCache::generate_key(40.4169, -3.7038, 1000, Some("restaurant"), None);
+    fn zaragoza_search() -> NearbySearchParams {
+        NearbySearchParams {
+            place_type: Some("restaurant".to_string()),
+            keyword: Some("tapas".to_string()),
+            ..NearbySearchParams::new(41.6488, -0.8891, 1000)
+        }
+    }
+
+    #[test]
+    fn test_generate_key() {
+        let key1 = PlacesCache::generate_key(&NearbySearchParams::new(40.4168, -3.7038, 1000));
+        let key2 = PlacesCache::generate_key(&NearbySearchParams::new(40.4168, -3.7038, 1000));
+        let key3 = PlacesCache::generate_key(&NearbySearchParams::new(40.4169, -3.7038, 1000));
 
         assert_eq!(key1, key2); // Same coordinates should generate same key
         assert_ne!(key1, key3); // Different coordinates should generate different key
+        assert_eq!(key_namespace(&key1), "search");
+    }
+
+    #[test]
+    fn test_generate_key_distinguishes_every_parameter() {
+        let base = PlacesCache::generate_key(&zaragoza_search());
+        let variants = [
+            NearbySearchParams { latitude: 41.6500, ..zaragoza_search() },
+            NearbySearchParams { longitude: -0.8900, ..zaragoza_search() },
+            NearbySearchParams { radius: 1500, ..zaragoza_search() },
+            NearbySearchParams { place_type: Some("bar".to_string()), ..zaragoza_search() },
+            NearbySearchParams { place_type: None, ..zaragoza_search() },
+            NearbySearchParams { keyword: Some("vermut".to_string()), ..zaragoza_search() },
+            NearbySearchParams { keyword: None, ..zaragoza_search() },
+            NearbySearchParams { language: Some("en".to_string()), ..zaragoza_search() },
+            NearbySearchParams { region: Some("es".to_string()), ..zaragoza_search() },
+            NearbySearchParams { open_now: true, ..zaragoza_search() },
+            NearbySearchParams { min_price: Some(1), ..zaragoza_search() },
+            NearbySearchParams { max_price: Some(3), ..zaragoza_search() },
+        ];
+
+        for variant in variants {
+            assert_ne!(PlacesCache::generate_key(&variant), base, "{:?}", variant);
+        }
+    }
+
+    #[test]
+    fn test_autocomplete_key_includes_region() {
+        let spain = PlacesCache::autocomplete_key("Cafe  Bot", None, Some("ES"));
+        assert_eq!(spain, PlacesCache::autocomplete_key("cafe bot", None, Some("es")));
+        assert_ne!(spain, PlacesCache::autocomplete_key("cafe bot", None, Some("pt")));
+        assert_ne!(spain, PlacesCache::autocomplete_key("cafe bot", None, None));
+    }
+
+    #[test]
+    fn test_generate_key_ignores_formatting() {
+        let base = PlacesCache::generate_key(&zaragoza_search());
+        let reformatted = NearbySearchParams {
+            latitude: 41.648_801,
+            place_type: Some(" Restaurant ".to_string()),
+            keyword: Some("  TAPAS ".to_string()),
+            ..zaragoza_search()
+        };
+        assert_eq!(PlacesCache::generate_key(&reformatted), base);
+
+        let multi_word = |kw: &str| NearbySearchParams {
+            keyword: Some(kw.to_string()),
+            ..zaragoza_search()
+        };
+        assert_eq!(
+            PlacesCache::generate_key(&multi_word("tapas  y\tvermut")),
+            PlacesCache::generate_key(&multi_word("Tapas y Vermut"))
+        );
+
+        // A blank keyword is the same search as no keyword
+        assert_eq!(
+            PlacesCache::generate_key(&multi_word("   ")),
+            PlacesCache::generate_key(&NearbySearchParams { keyword: None, ..zaragoza_search() })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bump_generation() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+
+        assert_eq!(cache.generation("zaragoza").await, 0);
+        assert_eq!(cache.bump_generation("zaragoza").await, 1);
+        assert_eq!(cache.generation("zaragoza").await, 1);
+
+        // Other scopes are unaffected
+        assert_eq!(cache.generation("madrid").await, 0);
     }
 
     #[tokio::test]
     async fn test_cache_cleanup() {
-        let cache = PlacesCache::new(1);
+        let cache = PlacesCache::new(1, DEFAULT_MAX_ENTRIES);
         
         cache.set("key1".to_string(), "value1".to_string()).await;
         cache.set("key2".to_string(), "value2".to_string()).await;
@@ -209,4 +760,190 @@ mod tests {
         let stats = cache.stats().await;
         assert_eq!(stats.active_entries, 0);
     }
+
+    #[tokio::test]
+    async fn test_lru_eviction_drops_least_recently_used() {
+        let cache = PlacesCache::new(60, 3);
+        for key in ["a", "b", "c"] {
+            cache.set(key.to_string(), key.to_string()).await;
+        }
+
+        // "a" is the oldest insert but was just read, so "b" is least recently used
+        assert!(cache.get("a").await.is_some());
+        cache.set("d".to_string(), "d".to_string()).await;
+
+        assert!(cache.get("b").await.is_none());
+        for key in ["a", "c", "d"] {
+            assert!(cache.get(key).await.is_some(), "{} should survive", key);
+        }
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.total_entries, 3);
+        assert_eq!(stats.evictions, 1);
+    }
+
+    #[tokio::test]
+    async fn test_lru_order_follows_deletes_and_overwrites() {
+        let cache = PlacesCache::new(60, 3);
+        for key in ["a", "b", "c"] {
+            cache.set(key.to_string(), key.to_string()).await;
+        }
+
+        // Rewriting "a" makes it the most recent; deleting "b" leaves "c" the oldest
+        cache.set("a".to_string(), "a2".to_string()).await;
+        assert!(cache.delete("b").await);
+        cache.set("d".to_string(), "d".to_string()).await;
+        cache.set("e".to_string(), "e".to_string()).await;
+
+        assert!(cache.get("c").await.is_none());
+        assert_eq!(cache.get("a").await, Some("a2".to_string()));
+        for key in ["d", "e"] {
+            assert!(cache.get(key).await.is_some(), "{} should survive", key);
+        }
+        assert_eq!(cache.stats().await.evictions, 1);
+
+        assert_eq!(cache.clear_prefix("d").await, 1);
+        cache.set("f".to_string(), "f".to_string()).await;
+        assert_eq!(cache.stats().await.evictions, 1, "clear_prefix freed a slot");
+    }
+
+    #[tokio::test]
+    async fn test_overwrite_at_capacity_does_not_evict() {
+        let cache = PlacesCache::new(60, 2);
+        cache.set("a".to_string(), "1".to_string()).await;
+        cache.set("b".to_string(), "1".to_string()).await;
+        cache.set("a".to_string(), "2".to_string()).await;
+
+        assert_eq!(cache.get("a").await, Some("2".to_string()));
+        assert!(cache.get("b").await.is_some());
+        assert_eq!(cache.stats().await.evictions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_dropped_before_lru() {
+        let cache = PlacesCache::new(60, 2);
+        cache
+            .set_with_ttl("stale".to_string(), "x".to_string(), Duration::from_millis(1))
+            .await;
+        cache.set("fresh".to_string(), "x".to_string()).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        cache.set("new".to_string(), "x".to_string()).await;
+
+        assert!(cache.get("fresh").await.is_some());
+        assert_eq!(cache.stats().await.evictions, 0);
+    }
+
+    #[tokio::test]
+    async fn test_typed_round_trip_shares_string_store() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Entry {
+            name: String,
+            rating: Option<f32>,
+        }
+
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        let entry = Entry { name: "Bar".to_string(), rating: Some(4.5) };
+        cache.set_typed("details:x".to_string(), &entry).await;
+
+        assert_eq!(cache.get_typed::<Entry>("details:x").await, Some(entry));
+        assert!(cache.get("details:x").await.unwrap().contains("\"Bar\""));
+        assert!(cache.get_typed::<Vec<u32>>("details:x").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stats_count_live_entries_per_namespace() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        cache.set(PlacesCache::details_key("a", None), "{}".to_string()).await;
+        cache.set(PlacesCache::details_key("b", Some("es")), "{}".to_string()).await;
+        cache
+            .set(
+                PlacesCache::generate_key(&NearbySearchParams::new(41.65, -0.88, 1000)),
+                "{}".to_string(),
+            )
+            .await;
+        cache
+            .set_with_ttl(PlacesCache::details_key("old", None), "{}".to_string(), Duration::ZERO)
+            .await;
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.active_by_namespace.get("details"), Some(&2));
+        assert_eq!(stats.active_by_namespace.get("search"), Some(&1));
+        assert_eq!(stats.expired_entries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_clear_prefix_and_hit_miss_counters() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        cache.set("search:1".to_string(), "a".to_string()).await;
+        cache.set("search:2".to_string(), "b".to_string()).await;
+        cache.set("details:1".to_string(), "c".to_string()).await;
+
+        assert_eq!(cache.clear_prefix("search:*").await, 2);
+        assert!(cache.get("search:1").await.is_none());
+        assert!(cache.get("details:1").await.is_some());
+
+        let stats = cache.stats().await;
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.total_entries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_from_config_selects_backend() {
+        let mut config = crate::test_support::test_config();
+        let cache = PlacesCache::from_config(&config).await.unwrap();
+        assert_eq!(cache.backend_name(), "memory");
+        assert_eq!(cache.stats().await.max_entries, Some(config.cache_max_entries));
+
+        config.cache_backend = "memcached".to_string();
+        assert!(PlacesCache::from_config(&config).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_memory_backend_ttl_and_delete_through_trait() {
+        let backend: Arc<dyn CacheBackend> = Arc::new(MemoryBackend::new(10));
+        backend
+            .set("search:a".to_string(), "1".to_string(), Duration::from_millis(20))
+            .await;
+        backend
+            .set("search:b".to_string(), "2".to_string(), Duration::from_secs(60))
+            .await;
+
+        assert!(backend.delete("search:b").await);
+        assert!(!backend.delete("search:b").await);
+        assert_eq!(backend.get("search:a").await, CacheLookup::Hit("1".to_string()));
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(backend.get("search:a").await, CacheLookup::Expired);
+        assert_eq!(backend.get("search:zzz").await, CacheLookup::Miss);
+    }
+
+    #[tokio::test]
+    async fn test_request_counters_survive_cleanup_and_reset() {
+        let cache = PlacesCache::new(60, 1);
+        cache.set("a".to_string(), "1".to_string()).await;
+        cache
+            .set_with_ttl("b".to_string(), "2".to_string(), Duration::from_millis(1))
+            .await; // evicts "a"
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        assert!(cache.get("a").await.is_none()); // miss
+        assert!(cache.get("b").await.is_none()); // expired on read
+        cache.set("c".to_string(), "3".to_string()).await;
+        assert!(cache.get("c").await.is_some()); // hit
+        cache.cleanup().await;
+
+        let stats = cache.stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.sets), (1, 2, 3));
+        assert_eq!(stats.expired_on_read, 1);
+        assert_eq!(stats.evictions, 1);
+        assert!((stats.hit_ratio - 1.0 / 3.0).abs() < 1e-9);
+
+        cache.reset_stats();
+        let stats = cache.stats().await;
+        assert_eq!((stats.hits, stats.misses, stats.sets, stats.evictions), (0, 0, 0, 0));
+        assert_eq!(stats.hit_ratio, 0.0);
+        assert_eq!(stats.active_entries, 1, "reset keeps entries");
+    }
 }
@@ -0,0 +1,261 @@
+// src/services/google_places_api.rs
+// DOCUMENTATION: Abstraction over the Google Places endpoints the services use
+// PURPOSE: Let PlaceService/SyncService run against the real client or a canned mock
+
+use crate::errors::PlacesError;
+use crate::services::{
+    AutocompletePrediction, CityBounds, DetailsOptions, FindPlaceCandidate, FindPlaceInputType,
+    GooglePlace, GooglePlacesClient, LocationBias, NearbyOutcome, NearbyPage, NearbyPages,
+    NearbySearchParams, MAX_NEARBY_PAGES, MAX_NEARBY_RESULTS,
+};
+use async_trait::async_trait;
+
+/// Google Places operations needed by the services
+/// DOCUMENTATION: Implemented by `GooglePlacesClient` (HTTP, caching, rate limiting)
+/// and by the fixture-backed mock used in tests. Pagination, default details options
+/// and thumbnail sizing are provided on top of the required methods so every
+/// implementation behaves the same way.
+#[async_trait]
+pub trait GooglePlacesApi: Send + Sync {
+    /// Fetch one page of Nearby Search results (`page_token = None` for the first page)
+    async fn nearby_search_page(
+        &self,
+        search: &NearbySearchParams,
+        page_token: Option<&str>,
+    ) -> Result<NearbyPage, PlacesError>;
+
+    /// Get place details with a session token and/or language
+    async fn get_place_details_with(
+        &self,
+        place_id: &str,
+        options: DetailsOptions<'_>,
+    ) -> Result<GooglePlace, PlacesError>;
+
+    /// Typeahead predictions for partially typed input, optionally restricted to a country
+    async fn autocomplete(
+        &self,
+        input: &str,
+        session_token: Option<&str>,
+        location_bias: Option<&LocationBias>,
+        region: Option<&str>,
+    ) -> Result<Vec<AutocompletePrediction>, PlacesError>;
+
+    /// Resolve a single place from a name or phone number
+    async fn find_place(
+        &self,
+        input: &str,
+        input_type: FindPlaceInputType,
+    ) -> Result<Vec<FindPlaceCandidate>, PlacesError>;
+
+    /// Photo URL for a photo reference (legacy reference or v1 photo name)
+    fn get_photo_url(&self, photo_reference: &str, max_width: Option<i32>) -> String;
+
+    /// Free-text search ("tapas near Plaza del Pilar")
+    /// DOCUMENTATION: Not wired to an endpoint yet; implementations without text
+    /// search report it as an upstream error so callers can fall back to Nearby Search
+    async fn text_search(
+        &self,
+        _query: &str,
+        _location_bias: Option<&LocationBias>,
+    ) -> Result<Vec<GooglePlace>, PlacesError> {
+        Err(PlacesError::ExternalApiError(
+            "Text Search is not supported by this client".to_string(),
+        ))
+    }
+
+    /// Derive sync bounds for a city by geocoding its name
+    /// DOCUMENTATION: Implementations without geocoding know no extra cities
+    async fn geocode_city(&self, _name: &str) -> Result<Option<CityBounds>, PlacesError> {
+        Ok(None)
+    }
+
+    /// Fetch up to `max_pages` pages of Nearby Search results and concatenate them
+    /// DOCUMENTATION: Follows next_page_token until Google runs out, `max_pages` is
+    /// reached or 60 results are collected. A failing follow-up page ends pagination
+    /// with the results gathered so far; only a failing first page is an error.
+    /// `max_pages` is clamped to 1..=3.
+    async fn nearby_search_pages(
+        &self,
+        search: &NearbySearchParams,
+        max_pages: u8,
+    ) -> Result<NearbyPages, PlacesError> {
+        let max_pages = max_pages.clamp(1, MAX_NEARBY_PAGES);
+
+        let first = self.nearby_search_page(search, None).await?;
+        let mut api_requests = u32::from(!first.cached);
+        let mut places = match first.outcome {
+            NearbyOutcome::Empty => {
+                return Ok(NearbyPages {
+                    outcome: NearbyOutcome::Empty,
+                    pages_fetched: 1,
+                    api_requests,
+                })
+            }
+            NearbyOutcome::Results(places) => places,
+        };
+
+        let mut pages_fetched = 1;
+        let mut page_token = first.next_page_token;
+        while let Some(token) = page_token.take() {
+            if pages_fetched >= max_pages as u32 || places.len() >= MAX_NEARBY_RESULTS {
+                break;
+            }
+
+            pages_fetched += 1;
+            match self.nearby_search_page(search, Some(&token)).await {
+                Ok(page) => {
+                    api_requests += u32::from(!page.cached);
+                    places.extend(page.outcome.into_places());
+                    page_token = page.next_page_token;
+                }
+                Err(e) => {
+                    api_requests += 1;
+                    log::warn!(
+                        "Nearby search page {} failed, keeping {} places: {}",
+                        pages_fetched,
+                        places.len(),
+                        e
+                    );
+                    break;
+                }
+            }
+        }
+
+        Ok(NearbyPages {
+            outcome: NearbyOutcome::Results(places),
+            pages_fetched,
+            api_requests,
+        })
+    }
+
+    /// Get place details with default options (no session, default language)
+    async fn get_place_details(&self, place_id: &str) -> Result<GooglePlace, PlacesError> {
+        self.get_place_details_with(place_id, DetailsOptions::default())
+            .await
+    }
+
+    /// Thumbnail photo URL (400px width)
+    fn get_photo_thumbnail_url(&self, photo_reference: &str) -> String {
+        self.get_photo_url(photo_reference, Some(400))
+    }
+}
+
+#[async_trait]
+impl GooglePlacesApi for GooglePlacesClient {
+    async fn nearby_search_page(
+        &self,
+        search: &NearbySearchParams,
+        page_token: Option<&str>,
+    ) -> Result<NearbyPage, PlacesError> {
+        GooglePlacesClient::nearby_search_page(self, search, page_token).await
+    }
+
+    async fn get_place_details_with(
+        &self,
+        place_id: &str,
+        options: DetailsOptions<'_>,
+    ) -> Result<GooglePlace, PlacesError> {
+        GooglePlacesClient::get_place_details_with(self, place_id, options).await
+    }
+
+    async fn autocomplete(
+        &self,
+        input: &str,
+        session_token: Option<&str>,
+        location_bias: Option<&LocationBias>,
+        region: Option<&str>,
+    ) -> Result<Vec<AutocompletePrediction>, PlacesError> {
+        GooglePlacesClient::autocomplete(self, input, session_token, location_bias, region).await
+    }
+
+    async fn find_place(
+        &self,
+        input: &str,
+        input_type: FindPlaceInputType,
+    ) -> Result<Vec<FindPlaceCandidate>, PlacesError> {
+        GooglePlacesClient::find_place(self, input, input_type).await
+    }
+
+    async fn geocode_city(&self, name: &str) -> Result<Option<CityBounds>, PlacesError> {
+        GooglePlacesClient::geocode_city(self, name).await
+    }
+
+    fn get_photo_url(&self, photo_reference: &str, max_width: Option<i32>) -> String {
+        GooglePlacesClient::get_photo_url(self, photo_reference, max_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::GoogleApiError;
+    use crate::test_support::MockGooglePlacesClient;
+
+    fn place(place_id: &str) -> GooglePlace {
+        serde_json::from_value(serde_json::json!({
+            "place_id": place_id,
+            "name": format!("Place {}", place_id),
+            "types": ["restaurant"],
+            "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pagination_is_shared_by_implementations() {
+        let client = MockGooglePlacesClient::new()
+            .with_nearby_page(vec![place("a"), place("b")])
+            .with_nearby_page(vec![place("c")])
+            .with_nearby_page(vec![place("d")]);
+        let api: &dyn GooglePlacesApi = &client;
+
+        let pages = api
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 2)
+            .await
+            .unwrap();
+
+        assert_eq!(pages.pages_fetched, 2);
+        assert_eq!(pages.outcome.into_places().len(), 3);
+        assert_eq!(client.calls(), vec!["nearby:first", "nearby:page-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_empty_first_page_stops_pagination() {
+        let client = MockGooglePlacesClient::new();
+
+        let pages = client
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 3)
+            .await
+            .unwrap();
+
+        assert!(pages.outcome.is_empty());
+        assert_eq!(pages.pages_fetched, 1);
+        assert_eq!(client.call_count("nearby"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fixture_mock_serves_details_and_thumbnails() {
+        let client = MockGooglePlacesClient::from_fixtures();
+        let api: &dyn GooglePlacesApi = &client;
+
+        let details = api.get_place_details("ChIJ2QdR5bEUWQ0RkN_n1cAQFh4").await.unwrap();
+        assert_eq!(details.name, "El Tubo Tapas");
+        assert!(matches!(
+            api.get_place_details("ChIJunknown").await,
+            Err(PlacesError::GoogleApi(GoogleApiError::NotFound(_)))
+        ));
+        assert_eq!(
+            api.get_photo_thumbnail_url("ref"),
+            "https://photos.test/ref?maxwidth=400"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_real_client_has_no_text_search_yet() {
+        let client = GooglePlacesClient::new("test_key".to_string());
+
+        let result = GooglePlacesApi::text_search(&client, "tapas", None).await;
+
+        assert!(matches!(result, Err(PlacesError::ExternalApiError(_))));
+    }
+}
@@ -2,13 +2,25 @@
 // DOCUMENTATION: Google Places API client
 // PURPOSE: Handle communication with Google Places API for place data retrieval
 
-use crate::errors::PlacesError;
+use crate::config::Config;
+use crate::errors::{GoogleApiError, PlacesError};
 use crate::models::CreatePlaceRequest;
-use crate::services::PlacesCache;
+use crate::services::google_places_v1::{
+    self, GoogleApiVersion, V1ErrorResponse, V1Place, V1SearchResponse, V1_BASE_URL,
+    V1_DETAILS_FIELD_MASK, V1_PHOTOS_FIELD_MASK, V1_REVIEWS_FIELD_MASK, V1_SEARCH_FIELD_MASK,
+};
+use crate::services::{
+    CityBounds, PlacesCache, DEFAULT_CELL_SIZE_KM, DEFAULT_MAX_ENTRIES,
+    DEFAULT_RADIUS_M,
+};
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
 
 /// Google Places API client
 /// DOCUMENTATION: Handles authentication and API calls to Google Places
@@ -21,13 +33,48 @@ pub struct GooglePlacesClient {
     base_url: String,
     /// Cache for API responses
     cache: Arc<PlacesCache>,
+    /// Wait before requesting a page by next_page_token
+    page_token_delay: std::time::Duration,
+    /// TTL for cached Place Details (`details:` namespace)
+    details_ttl: std::time::Duration,
+    /// TTL for the ZERO_RESULTS marker (negative cache)
+    empty_results_ttl: std::time::Duration,
+    /// TTL for cached autocomplete predictions (`autocomplete:` namespace)
+    autocomplete_ttl: std::time::Duration,
+    /// Total per-request timeout (reported in timeout errors)
+    request_timeout: std::time::Duration,
+    /// Language requested when a call does not specify one (None lets Google guess)
+    default_language: Option<String>,
+    /// Region bias applied when a request does not choose one
+    default_region: Option<String>,
+    /// API generation used for Nearby Search and Place Details
+    api_version: GoogleApiVersion,
+    /// Base URL for the Places API (New)
+    v1_base_url: String,
+    /// Base URL for the Geocoding API
+    geocoding_base_url: String,
+    /// Token bucket every Google request waits on (shared by sync workers and live traffic)
+    limiter: Arc<DefaultDirectRateLimiter>,
+    /// HTTP client that does not follow redirects (resolves photo CDN URLs)
+    redirect_client: Client,
+    /// Public base URL of the photo proxy ("" for relative URLs)
+    photo_proxy_base_url: String,
+    /// Resolved photo CDN URLs, kept in memory only (they are short-lived)
+    photo_urls: PlacesCache,
+    /// TTL for resolved photo CDN URLs
+    photo_url_ttl: std::time::Duration,
+    /// Largest photo relayed by `fetch_photo`
+    max_photo_bytes: usize,
+    /// Requests issued per operation (the client lives in app state, so these span requests)
+    usage: UsageCounters,
 }
 
 /// Response from Google Places Nearby Search
 /// DOCUMENTATION: Parsed response from Google Places API
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GooglePlacesResponse {
-    /// Results array from API
+    /// Results array from API (may be absent on error statuses)
+    #[serde(default)]
     pub results: Vec<GooglePlace>,
     /// Status of the API call
     pub status: String,
@@ -37,6 +84,537 @@ pub struct GooglePlacesResponse {
     pub error_message: Option<String>,
 }
 
+/// Outcome of a Nearby Search call
+/// DOCUMENTATION: Separates Google's explicit ZERO_RESULTS from a result list so callers
+/// (sync stats, negative cache, frontend) can tell "nothing here" apart from other cases
+#[derive(Debug, Clone)]
+pub enum NearbyOutcome {
+    /// Google returned places (status OK)
+    Results(Vec<GooglePlace>),
+    /// Google reported ZERO_RESULTS for the area
+    Empty,
+}
+
+impl NearbyOutcome {
+    /// Whether Google reported no places for the area
+    pub fn is_empty(&self) -> bool {
+        matches!(self, NearbyOutcome::Empty)
+    }
+
+    /// Consume the outcome, yielding the places (none for Empty)
+    pub fn into_places(self) -> Vec<GooglePlace> {
+        match self {
+            NearbyOutcome::Results(places) => places,
+            NearbyOutcome::Empty => Vec::new(),
+        }
+    }
+}
+
+/// Result ordering of a Nearby Search
+/// DOCUMENTATION: Prominence searches a radius; Distance orders by distance from the
+/// center without a radius and, per Google's contract, needs a type or keyword
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankBy {
+    #[default]
+    Prominence,
+    Distance,
+}
+
+impl RankBy {
+    /// Value of Google's `rankby` parameter
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RankBy::Prominence => "prominence",
+            RankBy::Distance => "distance",
+        }
+    }
+}
+
+/// Effective parameters of a Nearby Search request
+/// DOCUMENTATION: Everything sent to Google for a first page. The cache key is derived
+/// from `canonical()`, so a parameter added here is automatically part of the key
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearbySearchParams {
+    /// Center point latitude
+    pub latitude: f64,
+    /// Center point longitude
+    pub longitude: f64,
+    /// Search radius in meters (max 50000)
+    pub radius: u32,
+    /// Optional type filter (e.g., "restaurant", "bar")
+    pub place_type: Option<String>,
+    /// Optional keyword search
+    pub keyword: Option<String>,
+    /// Response language (e.g. "es"); the client default applies when None
+    pub language: Option<String>,
+    /// Only places open at request time
+    pub open_now: bool,
+    /// Minimum price level (0-4)
+    pub min_price: Option<i32>,
+    /// Maximum price level (0-4)
+    pub max_price: Option<i32>,
+    /// Prominence within `radius` or nearest first
+    pub rank_by: RankBy,
+    /// Region bias as a ccTLD country code (e.g. "es"); the client default applies when None
+    pub region: Option<String>,
+}
+
+impl NearbySearchParams {
+    /// Search around a point without type or keyword filters
+    pub fn new(latitude: f64, longitude: f64, radius: u32) -> Self {
+        Self {
+            latitude,
+            longitude,
+            radius,
+            place_type: None,
+            keyword: None,
+            language: None,
+            open_now: false,
+            min_price: None,
+            max_price: None,
+            rank_by: RankBy::Prominence,
+            region: None,
+        }
+    }
+
+    /// Keyword trimmed with inner whitespace collapsed; None when blank
+    pub fn normalized_keyword(&self) -> Option<String> {
+        self.keyword
+            .as_deref()
+            .map(|kw| kw.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|kw| !kw.is_empty())
+    }
+
+    /// Type filter trimmed; None when blank
+    pub fn normalized_type(&self) -> Option<String> {
+        self.place_type
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Canonical representation used for cache keys
+    /// DOCUMENTATION: Fields in a fixed order, coordinates rounded to ~10m and
+    /// text lowercased, so formatting differences map to the same string
+    pub fn canonical(&self) -> String {
+        [
+            format!("lat={}", (self.latitude * 10000.0).round() as i64),
+            format!("lng={}", (self.longitude * 10000.0).round() as i64),
+            format!("radius={}", self.radius),
+            format!("type={}", self.normalized_type().unwrap_or_default().to_lowercase()),
+            format!("keyword={}", self.normalized_keyword().unwrap_or_default().to_lowercase()),
+            format!("lang={}", self.language.as_deref().unwrap_or_default().to_lowercase()),
+            format!("opennow={}", self.open_now),
+            format!("minprice={}", self.min_price.map(|p| p.to_string()).unwrap_or_default()),
+            format!("maxprice={}", self.max_price.map(|p| p.to_string()).unwrap_or_default()),
+            format!("rankby={}", self.rank_by.as_str()),
+            format!("region={}", self.region.as_deref().unwrap_or_default().to_lowercase()),
+        ]
+        .join("&")
+    }
+}
+
+/// Maximum radius accepted by Google Nearby Search, in meters
+pub const MAX_NEARBY_RADIUS_M: u32 = 50_000;
+
+/// One page of Nearby Search results
+/// DOCUMENTATION: `next_page_token` is present when Google has more results (up to 60)
+#[derive(Debug, Clone)]
+pub struct NearbyPage {
+    /// Places on this page or Empty
+    pub outcome: NearbyOutcome,
+    /// Token for the following page, if any
+    pub next_page_token: Option<String>,
+    /// Served from the client's cache, without a request to Google
+    pub cached: bool,
+}
+
+/// Nearby Search results gathered across pages
+#[derive(Debug, Clone)]
+pub struct NearbyPages {
+    /// Places from every fetched page, or Empty for ZERO_RESULTS
+    pub outcome: NearbyOutcome,
+    /// Pages read, including a first page served from cache
+    pub pages_fetched: u32,
+    /// Pages requested from Google: `pages_fetched` without a cached first page
+    pub api_requests: u32,
+}
+
+/// Cached form of a first Nearby Search page
+#[derive(Serialize, Deserialize)]
+enum CachedNearby {
+    /// Negative cache entry for ZERO_RESULTS
+    Empty,
+    Page {
+        results: Vec<GooglePlace>,
+        next_page_token: Option<String>,
+    },
+}
+
+impl From<CachedNearby> for NearbyPage {
+    fn from(cached: CachedNearby) -> Self {
+        match cached {
+            CachedNearby::Empty => NearbyPage {
+                outcome: NearbyOutcome::Empty,
+                next_page_token: None,
+                cached: false,
+            },
+            CachedNearby::Page { results, next_page_token } => NearbyPage {
+                outcome: NearbyOutcome::Results(results),
+                next_page_token,
+                cached: false,
+            },
+        }
+    }
+}
+
+/// Maximum results Google returns across all Nearby Search pages
+pub const MAX_NEARBY_RESULTS: usize = 60;
+
+/// Maximum Nearby Search pages Google serves for one search (20 results each)
+pub const MAX_NEARBY_PAGES: u8 = 3;
+
+/// Cuisine categories stored in `cuisine_types` and their Google restaurant types
+/// DOCUMENTATION: The typed categories exist only in the Places API (New); the legacy
+/// API neither returns nor accepts them
+pub const CUISINE_GOOGLE_TYPES: [(&str, &str); 12] = [
+    ("italian", "italian_restaurant"),
+    ("chinese", "chinese_restaurant"),
+    ("japanese", "japanese_restaurant"),
+    ("mexican", "mexican_restaurant"),
+    ("indian", "indian_restaurant"),
+    ("spanish", "spanish_restaurant"),
+    ("french", "french_restaurant"),
+    ("thai", "thai_restaurant"),
+    ("american", "american_restaurant"),
+    ("mediterranean", "mediterranean_restaurant"),
+    ("vietnamese", "vietnamese_restaurant"),
+    ("korean", "korean_restaurant"),
+];
+
+/// Tags `suitable_for` can hold (derived at sync time from types and price level)
+pub const SUITABLE_FOR_TAGS: [&str; 5] = ["couples", "families", "groups", "solo", "budget"];
+
+/// Wait before using a freshly issued next_page_token
+const PAGE_TOKEN_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Default TTL for cached Place Details
+/// DOCUMENTATION: Phone, website, photos and reviews change far less often than
+/// nearby results, so details outlive the regular 1-hour search TTL
+pub const DEFAULT_DETAILS_TTL: std::time::Duration = std::time::Duration::from_secs(24 * 3600);
+
+/// How long a ZERO_RESULTS answer is remembered
+/// DOCUMENTATION: Shorter than the regular TTL so newly opened places show up sooner
+pub const DEFAULT_EMPTY_RESULTS_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// Default TCP connect timeout for Google requests
+pub const DEFAULT_CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Default total timeout for Google requests
+/// DOCUMENTATION: Bounds how long a hung Google connection can stall a sync worker
+/// or an inbound search request
+pub const DEFAULT_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Default Google requests per second allowed by the client-side limiter
+pub const DEFAULT_REQUESTS_PER_SECOND: u32 = 10;
+
+/// Default TTL for cached autocomplete predictions
+/// DOCUMENTATION: Short, since predictions are cheap to refresh and users type fast
+pub const DEFAULT_AUTOCOMPLETE_TTL: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Default TTL for a resolved photo CDN URL
+pub const DEFAULT_PHOTO_URL_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// Default cap on the size of a proxied photo
+pub const DEFAULT_MAX_PHOTO_BYTES: usize = 5 * 1024 * 1024;
+
+/// Largest photo width Google serves
+pub const MAX_PHOTO_WIDTH: i32 = 1600;
+
+/// Longest photo reference relayed by the photo proxy
+const MAX_PHOTO_REFERENCE_LEN: usize = 1024;
+
+/// Resolved photo CDN URLs kept in memory
+const PHOTO_URL_CACHE_ENTRIES: usize = 2_000;
+
+/// Base URL of the Geocoding API
+pub const GEOCODING_BASE_URL: &str = "https://maps.googleapis.com/maps/api/geocode";
+
+/// TTL for geocoded city bounds (a city's viewport practically never changes)
+pub const GEOCODE_TTL: std::time::Duration = std::time::Duration::from_secs(30 * 24 * 3600);
+
+/// Billable Google request types tracked by the usage counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoogleApiOperation {
+    NearbySearch,
+    TextSearch,
+    PlaceDetails,
+    Photo,
+    Autocomplete,
+    FindPlace,
+    Geocoding,
+}
+
+impl GoogleApiOperation {
+    /// Every operation, in reporting order
+    pub const ALL: [GoogleApiOperation; 7] = [
+        GoogleApiOperation::NearbySearch,
+        GoogleApiOperation::TextSearch,
+        GoogleApiOperation::PlaceDetails,
+        GoogleApiOperation::Photo,
+        GoogleApiOperation::Autocomplete,
+        GoogleApiOperation::FindPlace,
+        GoogleApiOperation::Geocoding,
+    ];
+
+    /// Stable name used in logs and usage reports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GoogleApiOperation::NearbySearch => "nearby_search",
+            GoogleApiOperation::TextSearch => "text_search",
+            GoogleApiOperation::PlaceDetails => "place_details",
+            GoogleApiOperation::Photo => "photo",
+            GoogleApiOperation::Autocomplete => "autocomplete",
+            GoogleApiOperation::FindPlace => "find_place",
+            GoogleApiOperation::Geocoding => "geocoding",
+        }
+    }
+}
+
+/// Requests issued to Google per operation since `since`
+#[derive(Debug, Clone)]
+pub struct GoogleApiUsage {
+    /// Requests per operation, in `GoogleApiOperation::ALL` order
+    pub requests: Vec<(GoogleApiOperation, u64)>,
+    /// When counting started (client creation or last reset), RFC 3339
+    pub since: String,
+}
+
+impl GoogleApiUsage {
+    /// Requests issued for one operation
+    pub fn count(&self, operation: GoogleApiOperation) -> u64 {
+        self.requests
+            .iter()
+            .find(|(op, _)| *op == operation)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Requests issued across all operations
+    pub fn total(&self) -> u64 {
+        self.requests.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// Per-operation request counters
+/// DOCUMENTATION: Incremented once per request sent to Google; cache hits cost
+/// nothing and are not counted
+struct UsageCounters {
+    counts: [AtomicU64; GoogleApiOperation::ALL.len()],
+    since: RwLock<DateTime<Utc>>,
+}
+
+impl UsageCounters {
+    fn new() -> Self {
+        Self {
+            counts: Default::default(),
+            since: RwLock::new(Utc::now()),
+        }
+    }
+
+    fn record(&self, operation: GoogleApiOperation) {
+        self.counts[operation as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> GoogleApiUsage {
+        GoogleApiUsage {
+            requests: GoogleApiOperation::ALL
+                .iter()
+                .map(|op| (*op, self.counts[*op as usize].load(Ordering::Relaxed)))
+                .collect(),
+            since: self.since.read().unwrap().to_rfc3339(),
+        }
+    }
+
+    /// Zero every counter, returning the usage counted until now
+    fn reset(&self) -> GoogleApiUsage {
+        let mut since = self.since.write().unwrap();
+        let usage = GoogleApiUsage {
+            requests: GoogleApiOperation::ALL
+                .iter()
+                .map(|op| (*op, self.counts[*op as usize].swap(0, Ordering::Relaxed)))
+                .collect(),
+            since: since.to_rfc3339(),
+        };
+        *since = Utc::now();
+        usage
+    }
+}
+
+/// Photo bytes fetched from Google for the photo proxy
+#[derive(Debug, Clone)]
+pub struct PhotoBytes {
+    /// Content type reported by Google (e.g. "image/jpeg")
+    pub content_type: String,
+    /// Image data
+    pub bytes: Vec<u8>,
+}
+
+/// Circle that biases autocomplete predictions towards an area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocationBias {
+    /// Center latitude
+    pub latitude: f64,
+    /// Center longitude
+    pub longitude: f64,
+    /// Bias radius in meters
+    pub radius_m: u32,
+}
+
+impl LocationBias {
+    /// Canonical representation used for cache keys (coordinates rounded to ~10m)
+    pub fn canonical(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            (self.latitude * 10000.0).round() as i64,
+            (self.longitude * 10000.0).round() as i64,
+            self.radius_m
+        )
+    }
+}
+
+/// Autocomplete prediction from Google Places
+/// DOCUMENTATION: Only the fields the frontend needs; `place_id` can be passed to
+/// GET /places/{id} together with the same session token
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AutocompletePrediction {
+    /// Google Place ID of the predicted place
+    pub place_id: String,
+    /// Human-readable prediction (e.g. "Café Botánico, Zaragoza, Spain")
+    pub description: String,
+    /// Place types of the prediction
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// How Find Place interprets its input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindPlaceInputType {
+    /// Free text such as a name plus city ("Café Botánico Zaragoza")
+    #[default]
+    TextQuery,
+    /// Phone number in international format ("+34976000000")
+    PhoneNumber,
+}
+
+impl FindPlaceInputType {
+    /// Value of the `inputtype` query parameter
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TextQuery => "textquery",
+            Self::PhoneNumber => "phonenumber",
+        }
+    }
+}
+
+/// Candidate returned by Find Place From Text
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FindPlaceCandidate {
+    /// Google Place ID of the candidate
+    pub place_id: String,
+    /// Place name
+    pub name: Option<String>,
+    /// Full address
+    pub formatted_address: Option<String>,
+}
+
+/// Order of the (at most five) reviews returned by Place Details
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReviewsSort {
+    /// Google's relevance order
+    #[default]
+    MostRelevant,
+    /// Most recent first
+    Newest,
+}
+
+impl ReviewsSort {
+    /// Value of the `reviews_sort` request parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MostRelevant => "most_relevant",
+            Self::Newest => "newest",
+        }
+    }
+}
+
+/// Legacy Place Details `fields` for a full place
+const DETAILS_FIELDS: &str = "name,place_id,geometry,formatted_address,address_components,vicinity,rating,user_ratings_total,price_level,types,business_status,opening_hours,utc_offset,formatted_phone_number,international_phone_number,website,url,reviews,photos,editorial_summary,dine_in,takeout,delivery,reservable,serves_breakfast,serves_brunch,serves_lunch,serves_dinner,serves_beer,serves_wine,serves_vegetarian_food,wheelchair_accessible_entrance";
+
+/// Legacy Place Details `fields` for a photo-only refresh
+const PHOTOS_DETAILS_FIELDS: &str = "name,place_id,geometry,types,photos";
+
+/// Legacy Place Details `fields` for a review-only refresh
+const REVIEWS_DETAILS_FIELDS: &str = "name,place_id,geometry,types,reviews";
+
+/// Which fields a Place Details request asks for
+/// DOCUMENTATION: Partial requests keep only the identity fields GooglePlace needs to
+/// parse (id, name, types, location) plus photos or reviews, so photo and review
+/// refreshes skip the contact and atmosphere fields Google bills separately
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DetailsFields {
+    /// Everything stored for a place
+    #[default]
+    Full,
+    /// Identity fields and photos
+    Photos,
+    /// Identity fields and reviews
+    Reviews,
+}
+
+impl DetailsFields {
+    /// Value of the legacy `fields` request parameter
+    pub fn legacy_fields(&self) -> &'static str {
+        match self {
+            Self::Full => DETAILS_FIELDS,
+            Self::Photos => PHOTOS_DETAILS_FIELDS,
+            Self::Reviews => REVIEWS_DETAILS_FIELDS,
+        }
+    }
+
+    /// Value of the v1 `X-Goog-FieldMask` header
+    pub fn v1_field_mask(&self) -> &'static str {
+        match self {
+            Self::Full => V1_DETAILS_FIELD_MASK,
+            Self::Photos => V1_PHOTOS_FIELD_MASK,
+            Self::Reviews => V1_REVIEWS_FIELD_MASK,
+        }
+    }
+
+    /// Cache key suffix; partial responses must not be served as full places
+    fn cache_suffix(&self) -> Option<&'static str> {
+        match self {
+            Self::Full => None,
+            Self::Photos => Some("photos"),
+            Self::Reviews => Some("reviews"),
+        }
+    }
+}
+
+/// Optional Place Details request parameters
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DetailsOptions<'a> {
+    /// Autocomplete session closed by this details call
+    pub session_token: Option<&'a str>,
+    /// Response language; the client default applies when None
+    pub language: Option<&'a str>,
+    /// Which reviews Google returns
+    pub reviews_sort: ReviewsSort,
+    /// Which fields Google returns
+    pub fields: DetailsFields,
+}
+
 /// Individual place from Google Places API
 /// DOCUMENTATION: Place data structure returned by Google Places
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -77,6 +655,155 @@ pub struct GooglePlace {
     pub reviews: Option<Vec<GoogleReview>>,
     /// Photos (from Place Details)
     pub photos: Option<Vec<GooglePhoto>>,
+    /// Minutes from UTC at the place's location (from Place Details)
+    #[serde(default, alias = "utc_offset")]
+    pub utc_offset_minutes: Option<i32>,
+    /// Short description written by Google (from Place Details)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editorial_summary: Option<GoogleEditorialSummary>,
+    /// Service attributes such as dine_in or serves_vegetarian_food (from Place Details)
+    #[serde(flatten)]
+    pub attributes: GoogleServiceAttributes,
+    /// Language the data was requested in (set by the client, not returned by Google)
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// Google's editorial summary of a place
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GoogleEditorialSummary {
+    /// Summary text
+    pub overview: Option<String>,
+    /// Language of the overview
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Boolean service attributes from Place Details
+/// DOCUMENTATION: Flattened into GooglePlace so the legacy top-level keys deserialize
+/// directly; None means Google did not report the attribute
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GoogleServiceAttributes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dine_in: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub takeout: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reservable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_breakfast: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_brunch: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_lunch: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_dinner: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_beer: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_wine: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serves_vegetarian_food: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wheelchair_accessible_entrance: Option<bool>,
+}
+
+impl GoogleServiceAttributes {
+    /// Attribute-wise merge preferring `self` (details) over `other` (nearby)
+    pub fn or(self, other: GoogleServiceAttributes) -> GoogleServiceAttributes {
+        GoogleServiceAttributes {
+            dine_in: self.dine_in.or(other.dine_in),
+            takeout: self.takeout.or(other.takeout),
+            delivery: self.delivery.or(other.delivery),
+            reservable: self.reservable.or(other.reservable),
+            serves_breakfast: self.serves_breakfast.or(other.serves_breakfast),
+            serves_brunch: self.serves_brunch.or(other.serves_brunch),
+            serves_lunch: self.serves_lunch.or(other.serves_lunch),
+            serves_dinner: self.serves_dinner.or(other.serves_dinner),
+            serves_beer: self.serves_beer.or(other.serves_beer),
+            serves_wine: self.serves_wine.or(other.serves_wine),
+            serves_vegetarian_food: self.serves_vegetarian_food.or(other.serves_vegetarian_food),
+            wheelchair_accessible_entrance: self
+                .wheelchair_accessible_entrance
+                .or(other.wheelchair_accessible_entrance),
+        }
+    }
+
+    /// Reported attributes as a JSON object for `tags`; None when nothing was reported
+    pub fn to_tags(&self) -> Option<serde_json::Value> {
+        match serde_json::to_value(self).ok()? {
+            serde_json::Value::Object(map) if !map.is_empty() => Some(serde_json::Value::Object(map)),
+            _ => None,
+        }
+    }
+}
+
+impl GooglePlace {
+    /// Opening hours carrying the place's UTC offset, ready to be stored or returned
+    pub fn opening_hours_with_offset(&self) -> Option<GoogleOpeningHours> {
+        self.opening_hours.clone().map(|mut hours| {
+            hours.utc_offset_minutes = hours.utc_offset_minutes.or(self.utc_offset_minutes);
+            hours
+        })
+    }
+
+    /// Merge a Nearby Search result with its Place Details result
+    /// DOCUMENTATION: Field-wise merge that prefers the details value and falls back to
+    /// the nearby value when details left it empty. Details responses only carry the
+    /// requested fields, so nearby-only data (vicinity, user_ratings_total, ...) survives.
+    pub fn merge(nearby: GooglePlace, details: GooglePlace) -> GooglePlace {
+        fn prefer<T>(details: Option<T>, nearby: Option<T>) -> Option<T> {
+            details.or(nearby)
+        }
+
+        fn prefer_list<T>(details: Option<Vec<T>>, nearby: Option<Vec<T>>) -> Option<Vec<T>> {
+            match details {
+                Some(list) if !list.is_empty() => Some(list),
+                _ => nearby.or(details),
+            }
+        }
+
+        // A (0, 0) location means the details response omitted geometry
+        let details_location = &details.geometry.location;
+        let geometry = if details_location.lat == 0.0 && details_location.lng == 0.0 {
+            nearby.geometry
+        } else {
+            details.geometry
+        };
+
+        GooglePlace {
+            place_id: if details.place_id.is_empty() { nearby.place_id } else { details.place_id },
+            name: if details.name.is_empty() { nearby.name } else { details.name },
+            types: if details.types.is_empty() { nearby.types } else { details.types },
+            geometry,
+            formatted_address: prefer(details.formatted_address, nearby.formatted_address),
+            vicinity: prefer(details.vicinity, nearby.vicinity),
+            address_components: prefer_list(details.address_components, nearby.address_components),
+            rating: prefer(details.rating, nearby.rating),
+            user_ratings_total: prefer(details.user_ratings_total, nearby.user_ratings_total),
+            price_level: prefer(details.price_level, nearby.price_level),
+            business_status: prefer(details.business_status, nearby.business_status),
+            opening_hours: prefer(details.opening_hours, nearby.opening_hours),
+            utc_offset_minutes: prefer(details.utc_offset_minutes, nearby.utc_offset_minutes),
+            editorial_summary: prefer(details.editorial_summary, nearby.editorial_summary),
+            attributes: details.attributes.or(nearby.attributes),
+            formatted_phone_number: prefer(
+                details.formatted_phone_number,
+                nearby.formatted_phone_number,
+            ),
+            international_phone_number: prefer(
+                details.international_phone_number,
+                nearby.international_phone_number,
+            ),
+            website: prefer(details.website, nearby.website),
+            url: prefer(details.url, nearby.url),
+            reviews: prefer_list(details.reviews, nearby.reviews),
+            photos: prefer_list(details.photos, nearby.photos),
+            language: prefer(details.language, nearby.language),
+        }
+    }
 }
 
 /// Geographic location from Google
@@ -115,6 +842,9 @@ pub struct GoogleOpeningHours {
     pub weekday_text: Option<Vec<String>>,
     /// Opening periods
     pub periods: Option<Vec<GoogleOpeningPeriod>>,
+    /// Place's UTC offset, copied from the place so stored hours can be evaluated later
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utc_offset_minutes: Option<i32>,
 }
 
 /// Google opening period metadata
@@ -147,6 +877,21 @@ pub struct GoogleReview {
     pub relative_time_description: Option<String>,
     /// Profile photo URL
     pub profile_photo_url: Option<String>,
+    /// Language of `text` (e.g. "es")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Language the review was written in; differs from `language` when translated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_language: Option<String>,
+}
+
+impl GoogleReview {
+    /// Language the review text is stored in
+    /// DOCUMENTATION: Details are requested with `reviews_no_translations=true`, so
+    /// the original language is the language of `text`
+    pub fn text_language(&self) -> Option<&str> {
+        self.original_language.as_deref().or(self.language.as_deref())
+    }
 }
 
 /// Photo from Google Places
@@ -167,154 +912,543 @@ impl GooglePlacesClient {
     /// Create new Google Places API client
     /// DOCUMENTATION: Initializes client with API key and cache
     pub fn new(api_key: String) -> Self {
-        Self {
-            client: Client::new(),
-            api_key,
-            base_url: "https://maps.googleapis.com/maps/api/place".to_string(),
-            cache: Arc::new(PlacesCache::new(3600)), // 1 hour cache
-        }
+        Self::new_with_cache(api_key, Arc::new(PlacesCache::new(3600, DEFAULT_MAX_ENTRIES))) // 1 hour cache
     }
 
     /// Create new Google Places API client with custom cache
     /// DOCUMENTATION: Initializes client with shared cache instance
     pub fn new_with_cache(api_key: String, cache: Arc<PlacesCache>) -> Self {
         Self {
-            client: Client::new(),
+            client: Self::http_client(DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT),
             api_key,
             base_url: "https://maps.googleapis.com/maps/api/place".to_string(),
             cache,
+            page_token_delay: PAGE_TOKEN_DELAY,
+            details_ttl: DEFAULT_DETAILS_TTL,
+            empty_results_ttl: DEFAULT_EMPTY_RESULTS_TTL,
+            autocomplete_ttl: DEFAULT_AUTOCOMPLETE_TTL,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            default_language: None,
+            default_region: None,
+            api_version: GoogleApiVersion::Legacy,
+            v1_base_url: V1_BASE_URL.to_string(),
+            geocoding_base_url: GEOCODING_BASE_URL.to_string(),
+            limiter: Self::rate_limiter(DEFAULT_REQUESTS_PER_SECOND),
+            redirect_client: Self::redirect_client(DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT),
+            photo_proxy_base_url: String::new(),
+            photo_urls: PlacesCache::new(
+                DEFAULT_PHOTO_URL_TTL.as_secs(),
+                PHOTO_URL_CACHE_ENTRIES,
+            ),
+            photo_url_ttl: DEFAULT_PHOTO_URL_TTL,
+            max_photo_bytes: DEFAULT_MAX_PHOTO_BYTES,
+            usage: UsageCounters::new(),
         }
     }
 
+    /// Token bucket refilling `per_second` permits per second (burst of one second)
+    fn rate_limiter(per_second: u32) -> Arc<DefaultDirectRateLimiter> {
+        let per_second = NonZeroU32::new(per_second).unwrap_or(NonZeroU32::MIN);
+        Arc::new(RateLimiter::direct(Quota::per_second(per_second)))
+    }
+
+    /// Build the reqwest client with connect and total request timeouts
+    fn http_client(connect_timeout: std::time::Duration, request_timeout: std::time::Duration) -> Client {
+        Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
+            .build()
+            .expect("HTTP client configuration is static and valid")
+    }
+
+    /// Same timeouts as `http_client`, but redirects are returned instead of followed
+    fn redirect_client(connect_timeout: std::time::Duration, request_timeout: std::time::Duration) -> Client {
+        Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("HTTP client configuration is static and valid")
+    }
+
+    /// Create Google Places API client from application configuration
+    /// DOCUMENTATION: Built once at startup and shared through app data so every
+    /// request reuses the same reqwest connection pool (keep-alive, TLS sessions)
+    pub fn from_config(config: &Config, cache: Arc<PlacesCache>) -> Self {
+        Self::new_with_cache(config.google_places_api_key.clone(), cache)
+            .with_base_url(&config.google_places_base_url)
+            .with_details_ttl(std::time::Duration::from_secs(config.cache_details_ttl_seconds))
+            .with_empty_results_ttl(std::time::Duration::from_secs(
+                config.cache_empty_results_ttl_seconds,
+            ))
+            .with_autocomplete_ttl(std::time::Duration::from_secs(
+                config.cache_autocomplete_ttl_seconds,
+            ))
+            .with_timeouts(
+                std::time::Duration::from_millis(config.google_connect_timeout_ms),
+                std::time::Duration::from_millis(config.google_request_timeout_ms),
+            )
+            .with_default_language(&config.google_default_language)
+            .with_region_bias(&config.google_region_bias)
+            .with_api_version(
+                GoogleApiVersion::parse(&config.google_api_version).unwrap_or_default(),
+            )
+            .with_v1_base_url(&config.google_places_v1_base_url)
+            .with_rate_limit(config.google_requests_per_second)
+            .with_photo_proxy_base_url(&config.photo_proxy_base_url)
+            .with_photo_url_ttl(std::time::Duration::from_secs(config.photo_url_cache_ttl_seconds))
+            .with_max_photo_bytes(usize::try_from(config.photo_max_bytes).unwrap_or(usize::MAX))
+    }
+
+    /// Point the client at a different Places API base URL
+    /// DOCUMENTATION: Used for proxies and for tests against a local mock server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Point the client at a different Geocoding API base URL (tests use a mock server)
+    pub fn with_geocoding_base_url(mut self, base_url: &str) -> Self {
+        self.geocoding_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Override the wait before next_page_token requests (tests use zero)
+    pub fn with_page_token_delay(mut self, delay: std::time::Duration) -> Self {
+        self.page_token_delay = delay;
+        self
+    }
+
+    /// Override the TTL for cached Place Details
+    pub fn with_details_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.details_ttl = ttl;
+        self
+    }
+
+    /// Override the TTL for cached ZERO_RESULTS answers
+    pub fn with_empty_results_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.empty_results_ttl = ttl;
+        self
+    }
+
+    /// Override the TTL for cached autocomplete predictions
+    pub fn with_autocomplete_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.autocomplete_ttl = ttl;
+        self
+    }
+
+    /// Override the connect and total request timeouts
+    pub fn with_timeouts(
+        mut self,
+        connect_timeout: std::time::Duration,
+        request_timeout: std::time::Duration,
+    ) -> Self {
+        self.client = Self::http_client(connect_timeout, request_timeout);
+        self.redirect_client = Self::redirect_client(connect_timeout, request_timeout);
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Prefix photo proxy URLs with a public base URL (e.g. https://places.auphere.com)
+    pub fn with_photo_proxy_base_url(mut self, base_url: &str) -> Self {
+        self.photo_proxy_base_url = base_url.trim().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Override how long resolved photo CDN URLs are reused
+    pub fn with_photo_url_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.photo_url_ttl = ttl;
+        self
+    }
+
+    /// Override the size cap for proxied photos
+    pub fn with_max_photo_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_photo_bytes = max_bytes;
+        self
+    }
+
+    /// Select the legacy API or the Places API (New)
+    pub fn with_api_version(mut self, api_version: GoogleApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Point the v1 calls at a different base URL (tests, proxies)
+    pub fn with_v1_base_url(mut self, v1_base_url: &str) -> Self {
+        self.v1_base_url = v1_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Request results in this language unless a call overrides it (empty disables)
+    pub fn with_default_language(mut self, language: &str) -> Self {
+        let language = language.trim();
+        self.default_language = (!language.is_empty()).then(|| language.to_string());
+        self
+    }
+
+    /// Bias searches and autocomplete towards this country unless a call overrides it
+    /// (ccTLD code such as "es"; empty disables)
+    pub fn with_region_bias(mut self, region: &str) -> Self {
+        let region = region.trim().to_lowercase();
+        self.default_region = (!region.is_empty()).then_some(region);
+        self
+    }
+
+    /// Region sent to Google: the explicit one, else the client default
+    pub fn effective_region<'a>(&'a self, region: Option<&'a str>) -> Option<&'a str> {
+        region.or(self.default_region.as_deref())
+    }
+
+    /// Validate a region code: two ASCII letters (ccTLD, e.g. "es", "pt")
+    pub fn validate_region(region: &str) -> Result<(), PlacesError> {
+        if region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()) {
+            Ok(())
+        } else {
+            Err(PlacesError::InvalidInput(format!(
+                "region must be a two-letter country code, got '{}'",
+                region
+            )))
+        }
+    }
+
+    /// Language sent to Google: the explicit one, else the client default
+    pub fn effective_language<'a>(&'a self, language: Option<&'a str>) -> Option<&'a str> {
+        language.or(self.default_language.as_deref())
+    }
+
+    /// Validate a Google language code ("es", "en", "pt-BR", "zh-Hant")
+    pub fn validate_language(language: &str) -> Result<(), PlacesError> {
+        let mut parts = language.splitn(2, '-');
+        let primary = parts.next().unwrap_or_default();
+        let valid_primary =
+            (2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic());
+        let valid_subtag = parts.next().is_none_or(|subtag| {
+            (2..=4).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+
+        if valid_primary && valid_subtag {
+            Ok(())
+        } else {
+            Err(PlacesError::InvalidInput(format!(
+                "language must be a language code like 'es' or 'pt-BR', got '{}'",
+                language
+            )))
+        }
+    }
+
+    /// Limit outgoing Google requests to `per_second` (0 is treated as 1)
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        self.limiter = Self::rate_limiter(per_second);
+        self
+    }
+
+    /// Send a Google request once the rate limiter grants a permit
+    /// DOCUMENTATION: Every API call goes through here, so all callers sharing this
+    /// client (sync cells, details, live searches) draw from one request budget and
+    /// each call is counted exactly once in the usage counters
+    async fn send(
+        &self,
+        operation: GoogleApiOperation,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, PlacesError> {
+        self.limiter.until_ready().await;
+        self.usage.record(operation);
+        request
+            .send()
+            .await
+            .map_err(|e| self.request_error(operation.as_str(), e))
+    }
+
+    /// Requests issued to Google per operation since creation or the last reset
+    pub fn usage(&self) -> GoogleApiUsage {
+        self.usage.snapshot()
+    }
+
+    /// Zero the usage counters, returning what had been counted
+    pub fn reset_usage(&self) -> GoogleApiUsage {
+        self.usage.reset()
+    }
+
+    /// Map a failed send to a network error, calling out timeouts explicitly
+    /// DOCUMENTATION: The URL is stripped first: legacy endpoints carry the API key in
+    /// the query string and these messages reach logs and 502 bodies
+    fn request_error(&self, operation: &str, e: reqwest::Error) -> PlacesError {
+        let e = e.without_url();
+        if e.is_timeout() {
+            log::error!(
+                "Google Places {} request timed out after {:?}: {}",
+                operation,
+                self.request_timeout,
+                e
+            );
+            return GoogleApiError::Network(format!(
+                "Request timed out after {}ms",
+                self.request_timeout.as_millis()
+            ))
+            .into();
+        }
+
+        log::error!("Google Places {} request failed: {}", operation, e);
+        GoogleApiError::Network(format!("Request failed: {}", e)).into()
+    }
+
     /// Get API key
     /// DOCUMENTATION: Returns the Google Places API key
     pub fn get_api_key(&self) -> &str {
         &self.api_key
     }
 
-    /// Perform nearby search for places
-    /// DOCUMENTATION: Searches for places near a geographic point with caching
-    ///
-    /// # Arguments
-    /// * `latitude` - Center point latitude
-    /// * `longitude` - Center point longitude
-    /// * `radius` - Search radius in meters (max 50000)
-    /// * `place_type` - Optional type filter (e.g., "restaurant", "bar")
-    /// * `keyword` - Optional keyword search
-    ///
-    /// # Returns
-    /// Vector of GooglePlace results
-    pub async fn nearby_search(
+    /// Validate a Nearby Search radius
+    /// DOCUMENTATION: Google rejects radii above 50,000 m with INVALID_REQUEST; checking
+    /// up front avoids spending (and counting) API calls that are bound to fail
+    pub fn validate_radius(radius_m: u32) -> Result<(), PlacesError> {
+        if radius_m == 0 || radius_m > MAX_NEARBY_RADIUS_M {
+            return Err(PlacesError::InvalidInput(format!(
+                "Search radius must be between 1 and {} meters, got {}",
+                MAX_NEARBY_RADIUS_M, radius_m
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a suitable_for filter
+    /// DOCUMENTATION: Tags are compared case-insensitively against SUITABLE_FOR_TAGS;
+    /// an unknown tag could never match, so it is rejected instead of ignored
+    pub fn validate_suitable_for(tags: &[String]) -> Result<(), PlacesError> {
+        let unknown: Vec<&str> = tags
+            .iter()
+            .map(|tag| tag.trim())
+            .filter(|tag| !SUITABLE_FOR_TAGS.iter().any(|known| known.eq_ignore_ascii_case(tag)))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(PlacesError::ValidationError(format!(
+                "suitable_for must be among {}; got {}",
+                SUITABLE_FOR_TAGS.join(", "),
+                unknown.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a price level range
+    /// DOCUMENTATION: Google price levels run from 0 (free) to 4 (very expensive);
+    /// Nearby Search answers INVALID_REQUEST for anything else or for min > max
+    pub fn validate_price_range(min_price: Option<i32>, max_price: Option<i32>) -> Result<(), PlacesError> {
+        for (name, value) in [("min_price", min_price), ("max_price", max_price)] {
+            if let Some(value) = value {
+                if !(0..=4).contains(&value) {
+                    return Err(PlacesError::ValidationError(format!(
+                        "{} must be between 0 and 4, got {}",
+                        name, value
+                    )));
+                }
+            }
+        }
+        if let (Some(min), Some(max)) = (min_price, max_price) {
+            if min > max {
+                return Err(PlacesError::ValidationError(format!(
+                    "min_price ({}) must not exceed max_price ({})",
+                    min, max
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the ranking mode of a Nearby Search
+    /// DOCUMENTATION: Google rejects rankby=distance without a type or keyword
+    /// (INVALID_REQUEST), so such searches are refused before any API call
+    pub fn validate_rank_by(search: &NearbySearchParams) -> Result<(), PlacesError> {
+        if search.rank_by == RankBy::Distance
+            && search.normalized_type().is_none()
+            && search.normalized_keyword().is_none()
+        {
+            return Err(PlacesError::InvalidInput(
+                "rankby=distance requires a type or keyword".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fetch one page of Nearby Search results
+    /// DOCUMENTATION: With `page_token = None` the first page is requested (and cached,
+    /// including its next_page_token). With a token, the search parameters are ignored
+    /// by Google and the follow-up page is fetched uncached after `page_token_delay`,
+    /// since tokens only become valid a short while after they are issued.
+    pub async fn nearby_search_page(
         &self,
-        latitude: f64,
-        longitude: f64,
-        radius: u32,
-        place_type: Option<&str>,
-        keyword: Option<&str>,
-    ) -> Result<Vec<GooglePlace>, PlacesError> {
-        // Generate cache key
-        let cache_key = PlacesCache::generate_key(latitude, longitude, radius, place_type, keyword);
-        
+        search: &NearbySearchParams,
+        page_token: Option<&str>,
+    ) -> Result<NearbyPage, PlacesError> {
+        Self::validate_radius(search.radius)?;
+        Self::validate_price_range(search.min_price, search.max_price)?;
+        Self::validate_rank_by(search)?;
+        if let Some(region) = &search.region {
+            Self::validate_region(region)?;
+        }
+
+        // Resolve the default language and region first so they are part of the cache key
+        let localized;
+        let search = if (search.language.is_none() && self.default_language.is_some())
+            || (search.region.is_none() && self.default_region.is_some())
+        {
+            localized = NearbySearchParams {
+                language: search.language.clone().or_else(|| self.default_language.clone()),
+                region: search.region.clone().or_else(|| self.default_region.clone()),
+                ..search.clone()
+            };
+            &localized
+        } else {
+            search
+        };
+
+        // Only first pages are cached; page tokens are short-lived
+        let cache_key = page_token.is_none().then(|| PlacesCache::generate_key(search));
+
         // Check cache first
-        if let Some(cached_json) = self.cache.get(&cache_key).await {
-            match serde_json::from_str::<Vec<GooglePlace>>(&cached_json) {
-                Ok(places) => {
-                    log::info!("Returning {} cached places", places.len());
-                    return Ok(places);
-                }
-                Err(e) => {
-                    log::warn!("Failed to deserialize cached data: {}", e);
-                    // Continue to API call if cache is corrupted
+        if let Some(cache_key) = &cache_key {
+            if let Some(cached) = self.cache.get_typed::<CachedNearby>(cache_key).await {
+                match &cached {
+                    CachedNearby::Empty => log::info!("Returning cached ZERO_RESULTS"),
+                    CachedNearby::Page { results, .. } => {
+                        log::info!("Returning {} cached places", results.len())
+                    }
                 }
+                return Ok(NearbyPage {
+                    cached: true,
+                    ..cached.into()
+                });
             }
         }
 
+        if self.api_version == GoogleApiVersion::V1 {
+            return self.nearby_search_page_v1(search, page_token, cache_key).await;
+        }
+
         let url = format!("{}/nearbysearch/json", self.base_url);
 
         let mut params = HashMap::new();
-        params.insert("location", format!("{},{}", latitude, longitude));
-        params.insert("radius", radius.to_string());
         params.insert("key", self.api_key.clone());
-
-        if let Some(pt) = place_type {
-            params.insert("type", pt.to_string());
+        if let Some(language) = &search.language {
+            params.insert("language", language.clone());
         }
+        if let Some(region) = &search.region {
+            params.insert("region", region.to_lowercase());
+        }
+
+        match page_token {
+            Some(token) => {
+                tokio::time::sleep(self.page_token_delay).await;
+                params.insert("pagetoken", token.to_string());
+            }
+            None => {
+                params.insert("location", format!("{},{}", search.latitude, search.longitude));
+                // rankby=distance and radius are mutually exclusive
+                match search.rank_by {
+                    RankBy::Prominence => {
+                        params.insert("radius", search.radius.to_string());
+                    }
+                    RankBy::Distance => {
+                        params.insert("rankby", RankBy::Distance.as_str().to_string());
+                    }
+                }
+
+                let (place_type, keyword) = Self::legacy_type_and_keyword(search);
+                if let Some(pt) = place_type {
+                    params.insert("type", pt);
+                }
+
+                if let Some(kw) = keyword {
+                    params.insert("keyword", kw);
+                }
 
-        if let Some(kw) = keyword {
-            params.insert("keyword", kw.to_string());
+                if search.open_now {
+                    params.insert("opennow", "true".to_string());
+                }
+                if let Some(min_price) = search.min_price {
+                    params.insert("minprice", min_price.to_string());
+                }
+                if let Some(max_price) = search.max_price {
+                    params.insert("maxprice", max_price.to_string());
+                }
+            }
         }
 
         log::debug!(
-            "Google Places nearby search (API call): lat={}, lng={}, radius={}",
-            latitude,
-            longitude,
-            radius
+            "Google Places nearby search (API call): lat={}, lng={}, radius={}, page_token={}",
+            search.latitude,
+            search.longitude,
+            search.radius,
+            page_token.is_some()
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                log::error!("Google Places API request failed: {}", e);
-                PlacesError::ExternalApiError(format!("Request failed: {}", e))
-            })?;
+        let request = self.client.get(&url).query(&params);
+        let response = self.send(GoogleApiOperation::NearbySearch, request).await?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
             log::error!("Google Places API error {}: {}", status, body);
-            return Err(PlacesError::ExternalApiError(format!(
-                "API error {}: {}",
-                status, body
-            )));
+            return Err(GoogleApiError::from_http(status.as_u16(), body).into());
         }
 
         let api_response: GooglePlacesResponse = response.json().await.map_err(|e| {
             log::error!("Failed to parse Google Places response: {}", e);
-            PlacesError::ExternalApiError(format!("Parse error: {}", e))
+            GoogleApiError::Parse(e.to_string())
         })?;
 
         // Check API response status
         match api_response.status.as_str() {
-            "OK" | "ZERO_RESULTS" => {
+            "OK" => {
                 log::info!(
                     "Google Places search returned {} results",
                     api_response.results.len()
                 );
                 
+                let mut results = api_response.results;
+                for place in &mut results {
+                    place.language = search.language.clone();
+                }
+
+                let page = CachedNearby::Page {
+                    results,
+                    next_page_token: api_response.next_page_token,
+                };
+
                 // Cache the results
-                if let Ok(json) = serde_json::to_string(&api_response.results) {
-                    self.cache.set(cache_key, json).await;
+                if let Some(cache_key) = cache_key {
+                    self.cache.set_typed(cache_key, &page).await;
                 }
-                
-                Ok(api_response.results)
-            }
-            "OVER_QUERY_LIMIT" => {
-                log::error!("Google Places API quota exceeded");
-                Err(PlacesError::RateLimitExceeded)
+
+                Ok(page.into())
             }
-            "REQUEST_DENIED" | "INVALID_REQUEST" => {
-                let msg = api_response
-                    .error_message
-                    .unwrap_or_else(|| "Unknown error".to_string());
-                log::error!("Google Places API request denied: {}", msg);
-                Err(PlacesError::ExternalApiError(msg))
+            "ZERO_RESULTS" => {
+                log::info!("Google Places search returned ZERO_RESULTS");
+
+                // Negative cache: remember the empty area for a shorter period
+                if let Some(cache_key) = cache_key {
+                    self.cache
+                        .set_typed_with_ttl(cache_key, &CachedNearby::Empty, self.empty_results_ttl)
+                        .await;
+                }
+
+                Ok(NearbyPage {
+                    outcome: NearbyOutcome::Empty,
+                    next_page_token: None,
+                    cached: false,
+                })
             }
             other => {
-                let msg = api_response
-                    .error_message
-                    .unwrap_or_else(|| format!("Unknown status: {}", other));
-                log::error!("Google Places API unexpected status: {}", msg);
-                Err(PlacesError::ExternalApiError(msg))
+                let error = GoogleApiError::from_status(other, api_response.error_message);
+                log::error!("Google Places nearby search failed ({}): {}", error.category(), error);
+                Err(error.into())
             }
         }
     }
 
     /// Get photo URL from photo reference
-    /// DOCUMENTATION: Converts Google photo_reference to actual photo URL
+    /// DOCUMENTATION: Points at our photo proxy (GET /places/photos/{reference}), so
+    /// the API key never appears in stored or returned URLs
     ///
     /// # Arguments
     /// * `photo_reference` - Photo reference from Google Places API
@@ -323,13 +1457,172 @@ impl GooglePlacesClient {
     /// # Returns
     /// Photo URL that can be used directly in img tags
     pub fn get_photo_url(&self, photo_reference: &str, max_width: Option<i32>) -> String {
-        let width = max_width.unwrap_or(800);
         format!(
-            "{}/photo?maxwidth={}&photoreference={}&key={}",
-            self.base_url, width, photo_reference, self.api_key
+            "{}/places/photos/{}?maxwidth={}",
+            self.photo_proxy_base_url,
+            photo_reference,
+            max_width.unwrap_or(800)
         )
     }
 
+    /// Validate a photo reference before it is relayed to Google
+    /// DOCUMENTATION: Legacy references are opaque `[A-Za-z0-9_-]` tokens and v1 names
+    /// have the shape `places/{place_id}/photos/{ref}`; anything else could never
+    /// resolve, so it is rejected without spending a billed photo call
+    pub fn validate_photo_reference(photo_reference: &str) -> Result<(), PlacesError> {
+        let is_token = |segment: &str| {
+            !segment.is_empty()
+                && segment
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        };
+        let valid = photo_reference.len() <= MAX_PHOTO_REFERENCE_LEN
+            && match photo_reference.split('/').collect::<Vec<_>>().as_slice() {
+                [reference] => is_token(reference),
+                ["places", place_id, "photos", reference] => is_token(place_id) && is_token(reference),
+                _ => false,
+            };
+        if !valid {
+            return Err(PlacesError::ValidationError(
+                "Invalid photo reference".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Google photo request; only sent server-side
+    /// DOCUMENTATION: v1 takes the key in the X-Goog-Api-Key header. The legacy endpoint
+    /// only accepts it as a query parameter, so transport errors are always formatted
+    /// without their URL (see `request_error`).
+    fn upstream_photo_request(&self, photo_reference: &str, max_width: i32) -> reqwest::RequestBuilder {
+        // v1 photo names ("places/{id}/photos/{ref}") are served by the media endpoint
+        if photo_reference.starts_with("places/") {
+            return self
+                .redirect_client
+                .get(format!("{}/{}/media", self.v1_base_url, photo_reference))
+                .query(&[("maxWidthPx", max_width.to_string())])
+                .header("X-Goog-Api-Key", &self.api_key);
+        }
+        self.redirect_client
+            .get(format!("{}/photo", self.base_url))
+            .query(&[
+                ("maxwidth", max_width.to_string().as_str()),
+                ("photoreference", photo_reference),
+                ("key", self.api_key.as_str()),
+            ])
+    }
+
+    /// Fetch a photo for the photo proxy
+    /// DOCUMENTATION: Google answers the photo endpoint with a 302 to its CDN. The
+    /// redirect target is cached in memory per reference and width for `photo_url_ttl`
+    /// so repeated views skip the billed photo call; a stale target is resolved again.
+    /// Photos larger than `max_photo_bytes` are rejected instead of buffered.
+    pub async fn fetch_photo(
+        &self,
+        photo_reference: &str,
+        max_width: i32,
+    ) -> Result<PhotoBytes, PlacesError> {
+        Self::validate_photo_reference(photo_reference)?;
+
+        let cache_key = PlacesCache::photo_url_key(photo_reference, max_width);
+        if let Some(cdn_url) = self.photo_urls.get_typed::<String>(&cache_key).await {
+            match self.download_photo(&cdn_url).await {
+                Ok(photo) => return Ok(photo),
+                Err(e) => {
+                    log::debug!("Cached photo URL failed ({}), resolving again", e);
+                    self.photo_urls.delete(&cache_key).await;
+                }
+            }
+        }
+
+        let request = self.upstream_photo_request(photo_reference, max_width);
+        let response = self.send(GoogleApiOperation::Photo, request).await?;
+
+        if !response.status().is_redirection() {
+            // Served without a redirect (or an error status)
+            return self.read_photo(response).await;
+        }
+
+        let cdn_url = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| response.url().join(location).ok())
+            .ok_or_else(|| {
+                GoogleApiError::Parse("Photo redirect without a Location header".to_string())
+            })?
+            .to_string();
+        self.photo_urls
+            .set_typed_with_ttl(cache_key, &cdn_url, self.photo_url_ttl)
+            .await;
+
+        self.download_photo(&cdn_url).await
+    }
+
+    /// Download a photo from Google's CDN (no API key, not rate limited)
+    async fn download_photo(&self, cdn_url: &str) -> Result<PhotoBytes, PlacesError> {
+        let response = self
+            .client
+            .get(cdn_url)
+            .send()
+            .await
+            .map_err(|e| self.request_error(GoogleApiOperation::Photo.as_str(), e))?;
+        self.read_photo(response).await
+    }
+
+    /// Read a photo response body, enforcing `max_photo_bytes`
+    async fn read_photo(&self, mut response: reqwest::Response) -> Result<PhotoBytes, PlacesError> {
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(GoogleApiError::NotFound("Photo not found".to_string()).into());
+        }
+        if !status.is_success() {
+            log::error!("Google photo request failed with status {}", status);
+            return Err(GoogleApiError::from_http(
+                status.as_u16(),
+                "Photo request failed".to_string(),
+            )
+            .into());
+        }
+
+        let too_large = || {
+            PlacesError::ExternalApiError(format!(
+                "Photo exceeds the {} byte limit",
+                self.max_photo_bytes
+            ))
+        };
+        if response
+            .content_length()
+            .is_some_and(|length| length > self.max_photo_bytes as u64)
+        {
+            return Err(too_large());
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("image/jpeg")
+            .to_string();
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| self.request_error(GoogleApiOperation::Photo.as_str(), e))?
+        {
+            if bytes.len() + chunk.len() > self.max_photo_bytes {
+                return Err(too_large());
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+
+        Ok(PhotoBytes {
+            content_type,
+            bytes,
+        })
+    }
+
     /// Get thumbnail photo URL from photo reference
     /// DOCUMENTATION: Converts Google photo_reference to thumbnail URL (smaller size)
     ///
@@ -351,53 +1644,486 @@ impl GooglePlacesClient {
     /// # Returns
     /// Detailed place information including photos and reviews
     pub async fn get_place_details(&self, place_id: &str) -> Result<GooglePlace, PlacesError> {
+        self.get_place_details_with(place_id, DetailsOptions::default()).await
+    }
+
+    /// Get place details with a session token and/or language
+    /// DOCUMENTATION: Passing the session token used for `autocomplete` lets Google
+    /// bill the predictions and this details call as one session. Details are cached
+    /// per place and language.
+    pub async fn get_place_details_with(
+        &self,
+        place_id: &str,
+        options: DetailsOptions<'_>,
+    ) -> Result<GooglePlace, PlacesError> {
+        let language = self.effective_language(options.language);
+        let mut cache_key = PlacesCache::details_key(place_id, language);
+        if options.reviews_sort != ReviewsSort::default() {
+            cache_key = format!("{}:{}", cache_key, options.reviews_sort.as_str());
+        }
+        if let Some(suffix) = options.fields.cache_suffix() {
+            cache_key = format!("{}:{}", cache_key, suffix);
+        }
+        if let Some(place) = self.cache.get_typed::<GooglePlace>(&cache_key).await {
+            log::debug!("Returning cached details for place_id={}", place_id);
+            return Ok(place);
+        }
+
+        if self.api_version == GoogleApiVersion::V1 {
+            let mut place = self
+                .place_details_v1(place_id, options.session_token, language, options.fields)
+                .await?;
+            place.language = language.map(str::to_string);
+            self.cache
+                .set_typed_with_ttl(cache_key, &place, self.details_ttl)
+                .await;
+            return Ok(place);
+        }
+
         let url = format!("{}/details/json", self.base_url);
 
-        let params = [
+        let mut params = vec![
             ("place_id", place_id),
             ("key", &self.api_key),
-            // Request comprehensive place information including reviews, photos, and address components
-            ("fields", "name,place_id,geometry,formatted_address,address_components,vicinity,rating,user_ratings_total,price_level,types,business_status,opening_hours,formatted_phone_number,international_phone_number,website,url,reviews,photos"),
+            // Comprehensive place information unless a partial refresh asked for less
+            ("fields", options.fields.legacy_fields()),
+            // Keep reviews in the language they were written in
+            ("reviews_no_translations", "true"),
+            ("reviews_sort", options.reviews_sort.as_str()),
         ];
+        if let Some(token) = options.session_token {
+            params.push(("sessiontoken", token));
+        }
+        if let Some(language) = language {
+            params.push(("language", language));
+        }
 
         log::debug!("Google Places details lookup: place_id={}", place_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                log::error!("Google Places details request failed: {}", e);
-                PlacesError::ExternalApiError(format!("Request failed: {}", e))
-            })?;
+        let request = self.client.get(&url).query(&params);
+        let response = self.send(GoogleApiOperation::PlaceDetails, request).await?;
 
         if !response.status().is_success() {
-            return Err(PlacesError::ExternalApiError(
+            return Err(GoogleApiError::from_http(
+                response.status().as_u16(),
                 "Details request failed".to_string(),
-            ));
+            )
+            .into());
         }
 
         #[derive(Deserialize)]
         struct DetailsResponse {
-            result: GooglePlace,
+            // Absent when status is not OK
+            result: Option<GooglePlace>,
             status: String,
+            error_message: Option<String>,
         }
 
         let api_response: DetailsResponse = response
             .json()
             .await
-            .map_err(|e| PlacesError::ExternalApiError(format!("Parse error: {}", e)))?;
+            .map_err(|e| GoogleApiError::Parse(e.to_string()))?;
+
+        match (api_response.status.as_str(), api_response.result) {
+            ("OK", Some(mut result)) => {
+                result.language = language.map(str::to_string);
+                self.cache
+                    .set_typed_with_ttl(cache_key, &result, self.details_ttl)
+                    .await;
+                Ok(result)
+            }
+            ("NOT_FOUND", _) => Err(GoogleApiError::NotFound(place_id.to_string()).into()),
+            ("OK", None) => Err(GoogleApiError::Parse("Details response without a result".to_string()).into()),
+            (status, _) => Err(GoogleApiError::from_status(status, api_response.error_message).into()),
+        }
+    }
 
-        if api_response.status == "OK" {
-            Ok(api_response.result)
-        } else {
-            Err(PlacesError::ExternalApiError(format!(
-                "Details status: {}",
-                api_response.status
-            )))
+    /// One page of nearby results from the Places API (New)
+    /// DOCUMENTATION: searchNearby for plain area searches; searchText (biased to the
+    /// same circle) when a keyword is given, since searchNearby has no keyword filter.
+    /// Only searchText paginates. Results are cached like legacy first pages.
+    async fn nearby_search_page_v1(
+        &self,
+        search: &NearbySearchParams,
+        page_token: Option<&str>,
+        cache_key: Option<String>,
+    ) -> Result<NearbyPage, PlacesError> {
+        let (endpoint, operation, body) = match search.normalized_keyword() {
+            Some(_) => (
+                "places:searchText",
+                GoogleApiOperation::TextSearch,
+                google_places_v1::search_text_body(search, page_token),
+            ),
+            None => (
+                "places:searchNearby",
+                GoogleApiOperation::NearbySearch,
+                google_places_v1::search_nearby_body(search),
+            ),
+        };
+
+        log::debug!(
+            "Google Places v1 {} (API call): lat={}, lng={}, radius={}, page_token={}",
+            endpoint,
+            search.latitude,
+            search.longitude,
+            search.radius,
+            page_token.is_some()
+        );
+
+        let request = self
+            .client
+            .post(format!("{}/{}", self.v1_base_url, endpoint))
+            .header("X-Goog-Api-Key", &self.api_key)
+            .header("X-Goog-FieldMask", V1_SEARCH_FIELD_MASK)
+            .json(&body);
+        let response = self.send(operation, request).await?;
+
+        if !response.status().is_success() {
+            return Err(Self::v1_error(response, None).await);
+        }
+
+        let api_response: V1SearchResponse = response.json().await.map_err(|e| {
+            log::error!("Failed to parse Google Places v1 response: {}", e);
+            GoogleApiError::Parse(e.to_string())
+        })?;
+
+        // v1 answers an empty area with `{}` instead of ZERO_RESULTS
+        if api_response.places.is_empty() {
+            log::info!("Google Places v1 search returned no places");
+            if let Some(cache_key) = cache_key {
+                self.cache
+                    .set_typed_with_ttl(cache_key, &CachedNearby::Empty, self.empty_results_ttl)
+                    .await;
+            }
+            return Ok(NearbyPage {
+                outcome: NearbyOutcome::Empty,
+                next_page_token: None,
+                cached: false,
+            });
+        }
+
+        log::info!("Google Places v1 search returned {} results", api_response.places.len());
+
+        let results = api_response
+            .places
+            .into_iter()
+            .map(|place| GooglePlace {
+                language: search.language.clone(),
+                ..place.into()
+            })
+            .collect();
+        let page = CachedNearby::Page {
+            results,
+            next_page_token: api_response.next_page_token,
+        };
+
+        if let Some(cache_key) = cache_key {
+            self.cache.set_typed(cache_key, &page).await;
+        }
+
+        Ok(page.into())
+    }
+
+    /// Place Details from the Places API (New)
+    async fn place_details_v1(
+        &self,
+        place_id: &str,
+        session_token: Option<&str>,
+        language: Option<&str>,
+        fields: DetailsFields,
+    ) -> Result<GooglePlace, PlacesError> {
+        let mut params = Vec::new();
+        if let Some(token) = session_token {
+            params.push(("sessionToken", token));
+        }
+        if let Some(language) = language {
+            params.push(("languageCode", language));
+        }
+
+        log::debug!("Google Places v1 details lookup: place_id={}", place_id);
+
+        let request = self
+            .client
+            .get(format!("{}/places/{}", self.v1_base_url, place_id))
+            .header("X-Goog-Api-Key", &self.api_key)
+            .header("X-Goog-FieldMask", fields.v1_field_mask())
+            .query(&params);
+        let response = self.send(GoogleApiOperation::PlaceDetails, request).await?;
+
+        if !response.status().is_success() {
+            return Err(Self::v1_error(response, Some(place_id)).await);
+        }
+
+        let place: V1Place = response
+            .json()
+            .await
+            .map_err(|e| GoogleApiError::Parse(e.to_string()))?;
+        Ok(place.into())
+    }
+
+    /// Map a non-2xx v1 response to a categorised Google error
+    /// DOCUMENTATION: Categorised by HTTP status (429 quota, 403 denied, 400 invalid,
+    /// 404 not found); the message keeps the gRPC status and Google's message
+    async fn v1_error(response: reqwest::Response, place_id: Option<&str>) -> PlacesError {
+        let status = response.status();
+        let error = response.json::<V1ErrorResponse>().await.ok().map(|body| body.error);
+        let message = error
+            .map(|e| {
+                format!(
+                    "{} {}",
+                    e.status.unwrap_or_default(),
+                    e.message.unwrap_or_default()
+                )
+            })
+            .unwrap_or_default();
+        log::error!("Google Places v1 error {}: {}", status, message.trim());
+
+        match (status.as_u16(), place_id) {
+            (404, Some(place_id)) => GoogleApiError::NotFound(place_id.to_string()).into(),
+            (status, _) => GoogleApiError::from_http(status, message.trim().to_string()).into(),
+        }
+    }
+
+    /// Get typeahead predictions for partial input
+    /// DOCUMENTATION: Calls Place Autocomplete. Predictions are cached per normalized
+    /// input and bias for `autocomplete_ttl`; the session token is forwarded but not
+    /// part of the key, since predictions do not depend on it
+    ///
+    /// # Arguments
+    /// * `input` - Text typed so far
+    /// * `session_token` - Client-generated token shared with the follow-up details call
+    /// * `location_bias` - Optional circle to prefer nearby predictions
+    /// * `region` - Country restriction (`components=country:xx`); client default when None
+    ///
+    /// # Returns
+    /// Predictions in Google's order (empty for ZERO_RESULTS)
+    pub async fn autocomplete(
+        &self,
+        input: &str,
+        session_token: Option<&str>,
+        location_bias: Option<&LocationBias>,
+        region: Option<&str>,
+    ) -> Result<Vec<AutocompletePrediction>, PlacesError> {
+        if let Some(region) = region {
+            Self::validate_region(region)?;
+        }
+        let region = self.effective_region(region).map(str::to_lowercase);
+
+        let cache_key = PlacesCache::autocomplete_key(input, location_bias, region.as_deref());
+        if let Some(predictions) = self
+            .cache
+            .get_typed::<Vec<AutocompletePrediction>>(&cache_key)
+            .await
+        {
+            log::debug!("Returning {} cached predictions", predictions.len());
+            return Ok(predictions);
+        }
+
+        let url = format!("{}/autocomplete/json", self.base_url);
+
+        let mut params = vec![
+            ("input", input.trim().to_string()),
+            ("key", self.api_key.clone()),
+        ];
+        if let Some(token) = session_token {
+            params.push(("sessiontoken", token.to_string()));
+        }
+        if let Some(bias) = location_bias {
+            params.push((
+                "locationbias",
+                format!("circle:{}@{},{}", bias.radius_m, bias.latitude, bias.longitude),
+            ));
+        }
+        if let Some(region) = &region {
+            params.push(("components", format!("country:{}", region)));
+        }
+
+        log::debug!("Google Places autocomplete: input={}", input);
+
+        let request = self.client.get(&url).query(&params);
+        let response = self.send(GoogleApiOperation::Autocomplete, request).await?;
+
+        if !response.status().is_success() {
+            return Err(GoogleApiError::from_http(
+                response.status().as_u16(),
+                "Autocomplete request failed".to_string(),
+            )
+            .into());
+        }
+
+        #[derive(Deserialize)]
+        struct AutocompleteResponse {
+            #[serde(default)]
+            predictions: Vec<AutocompletePrediction>,
+            status: String,
+            error_message: Option<String>,
+        }
+
+        let api_response: AutocompleteResponse = response
+            .json()
+            .await
+            .map_err(|e| GoogleApiError::Parse(e.to_string()))?;
+
+        match api_response.status.as_str() {
+            "OK" | "ZERO_RESULTS" => {
+                self.cache
+                    .set_typed_with_ttl(cache_key, &api_response.predictions, self.autocomplete_ttl)
+                    .await;
+                Ok(api_response.predictions)
+            }
+            other => Err(GoogleApiError::from_status(other, api_response.error_message).into()),
+        }
+    }
+
+    /// Resolve a single place from a name or phone number
+    /// DOCUMENTATION: Calls Find Place From Text requesting only basic fields, so the
+    /// lookup is cheap; callers fetch details for the candidate they keep
+    ///
+    /// # Arguments
+    /// * `input` - Name/address text or phone number
+    /// * `input_type` - How Google should interpret `input`
+    ///
+    /// # Returns
+    /// Candidates in Google's order (empty for ZERO_RESULTS)
+    pub async fn find_place(
+        &self,
+        input: &str,
+        input_type: FindPlaceInputType,
+    ) -> Result<Vec<FindPlaceCandidate>, PlacesError> {
+        let url = format!("{}/findplacefromtext/json", self.base_url);
+
+        let params = [
+            ("input", input.trim()),
+            ("inputtype", input_type.as_str()),
+            ("fields", "place_id,name,formatted_address"),
+            ("key", &self.api_key),
+        ];
+
+        log::debug!("Google Find Place: input={} type={}", input, input_type.as_str());
+
+        let request = self.client.get(&url).query(&params);
+        let response = self.send(GoogleApiOperation::FindPlace, request).await?;
+
+        if !response.status().is_success() {
+            return Err(GoogleApiError::from_http(
+                response.status().as_u16(),
+                "Find Place request failed".to_string(),
+            )
+            .into());
+        }
+
+        #[derive(Deserialize)]
+        struct FindPlaceResponse {
+            #[serde(default)]
+            candidates: Vec<FindPlaceCandidate>,
+            status: String,
+            error_message: Option<String>,
+        }
+
+        let api_response: FindPlaceResponse = response
+            .json()
+            .await
+            .map_err(|e| GoogleApiError::Parse(e.to_string()))?;
+
+        match api_response.status.as_str() {
+            "OK" | "ZERO_RESULTS" => Ok(api_response.candidates),
+            other => Err(GoogleApiError::from_status(other, api_response.error_message).into()),
+        }
+    }
+
+    /// Derive sync bounds for a city from the Geocoding API
+    /// DOCUMENTATION: Uses the viewport of the first geocoding result, biased to the
+    /// default region. Results are cached for `GEOCODE_TTL`; None when Google knows no
+    /// such place or the viewport is unusable (e.g. crosses the antimeridian).
+    pub async fn geocode_city(&self, name: &str) -> Result<Option<CityBounds>, PlacesError> {
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        let cache_key = PlacesCache::geocode_key(&name);
+        if let Some(bounds) = self.cache.get_typed::<CityBounds>(&cache_key).await {
+            log::debug!("Returning cached geocoded bounds for {}", name);
+            return Ok(Some(bounds));
+        }
+
+        let url = format!("{}/json", self.geocoding_base_url);
+        let mut params = vec![("address", name.as_str()), ("key", &self.api_key)];
+        if let Some(region) = self.default_region.as_deref() {
+            params.push(("region", region));
+        }
+
+        log::info!("Geocoding city bounds for {}", name);
+
+        let request = self.client.get(&url).query(&params);
+        let response = self.send(GoogleApiOperation::Geocoding, request).await?;
+
+        if !response.status().is_success() {
+            return Err(GoogleApiError::from_http(
+                response.status().as_u16(),
+                "Geocoding request failed".to_string(),
+            )
+            .into());
+        }
+
+        #[derive(Deserialize)]
+        struct Viewport {
+            northeast: GoogleLocation,
+            southwest: GoogleLocation,
+        }
+
+        #[derive(Deserialize)]
+        struct Geometry {
+            viewport: Viewport,
+        }
+
+        #[derive(Deserialize)]
+        struct GeocodeResult {
+            geometry: Geometry,
+        }
+
+        #[derive(Deserialize)]
+        struct GeocodeResponse {
+            #[serde(default)]
+            results: Vec<GeocodeResult>,
+            status: String,
+            error_message: Option<String>,
+        }
+
+        let api_response: GeocodeResponse = response
+            .json()
+            .await
+            .map_err(|e| GoogleApiError::Parse(e.to_string()))?;
+
+        match api_response.status.as_str() {
+            "OK" => {}
+            "ZERO_RESULTS" => return Ok(None),
+            other => return Err(GoogleApiError::from_status(other, api_response.error_message).into()),
+        }
+
+        let Some(result) = api_response.results.into_iter().next() else {
+            return Ok(None);
+        };
+        let viewport = result.geometry.viewport;
+        let bounds = CityBounds {
+            name: name.clone(),
+            min_lat: viewport.southwest.lat,
+            max_lat: viewport.northeast.lat,
+            min_lng: viewport.southwest.lng,
+            max_lng: viewport.northeast.lng,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: None,
+        };
+        if let Err(e) = bounds.validate() {
+            log::warn!("Ignoring geocoded viewport for {}: {}", name, e);
+            return Ok(None);
         }
+
+        self.cache
+            .set_typed_with_ttl(cache_key, &bounds, GEOCODE_TTL)
+            .await;
+        Ok(Some(bounds))
     }
 
     /// Convert GooglePlace to CreatePlaceRequest
@@ -415,9 +2141,9 @@ impl GooglePlacesClient {
     ///
     /// # Returns
     /// CreatePlaceRequest ready for database insertion
-    pub fn to_create_request(&self, google_place: &GooglePlace, city: &str) -> CreatePlaceRequest {
+    pub fn to_create_request(google_place: &GooglePlace, city: &str) -> CreatePlaceRequest {
         // Map Google place types to our internal type
-        let place_type = self.map_google_type_to_internal(&google_place.types);
+        let place_type = Self::map_google_type_to_internal(&google_place.types);
 
         // Extract main categories from Google types (excluding generic ones)
         let main_categories: Vec<String> = google_place
@@ -448,17 +2174,17 @@ impl GooglePlacesClient {
 
         // Extract cuisine types for restaurants
         let cuisine_types = if place_type == "restaurant" || place_type == "cafe" {
-            self.extract_cuisine_types(&google_place.types, &google_place.name)
+            Self::extract_cuisine_types(&google_place.types, &google_place.name)
         } else {
             Vec::new()
         };
 
         // Extract district and postal code from address components
-        let district = self.extract_district(&google_place.address_components);
-        let postal_code = self.extract_postal_code(&google_place.address_components);
+        let district = Self::extract_district(&google_place.address_components);
+        let postal_code = Self::extract_postal_code(&google_place.address_components);
 
         // Determine suitable_for tags
-        let suitable_for = self.determine_suitable_for(&google_place.types, google_place.price_level);
+        let suitable_for = Self::determine_suitable_for(&google_place.types, google_place.price_level);
 
         // Generate Google Maps URL if available, otherwise construct from place_id
         let google_place_url = google_place.url.clone().or_else(|| {
@@ -474,11 +2200,19 @@ impl GooglePlacesClient {
             .as_ref()
             .and_then(|hours| hours.open_now);
 
+        // Google's editorial summary; upserts keep a description we already have
+        let description = google_place
+            .editorial_summary
+            .as_ref()
+            .and_then(|summary| summary.overview.as_deref())
+            .map(str::trim)
+            .filter(|overview| !overview.is_empty())
+            .map(str::to_string);
+
         CreatePlaceRequest {
             name: google_place.name.clone(),
-            // Don't duplicate address in description - leave it None for now
-            // Can be enriched later with LLM-generated descriptions
-            description: None,
+            // Never the address: only Google's editorial summary fills the description
+            description,
             type_: place_type,
             location: [
                 google_place.geometry.location.lng,
@@ -508,19 +2242,20 @@ impl GooglePlacesClient {
             price_level: google_place.price_level,
             google_place_url,
             opening_hours: google_place
-                .opening_hours
-                .as_ref()
+                .opening_hours_with_offset()
                 .and_then(|hours| serde_json::to_value(hours).ok()),
             is_open_now,
             business_status: google_place.business_status.clone(),
             suitable_for,
+            language: google_place.language.clone(),
+            attributes: google_place.attributes.to_tags(),
         }
     }
 
     /// Map Google place types to internal place type
     /// DOCUMENTATION: Converts Google's type array to our single type field
     /// Priority order: restaurant > bar > cafe > club > museum > park > other
-    fn map_google_type_to_internal(&self, types: &[String]) -> String {
+    pub fn map_google_type_to_internal(types: &[String]) -> String {
         // Define priority mapping
         let type_map: Vec<(&str, &str)> = vec![
             ("restaurant", "restaurant"),
@@ -550,7 +2285,7 @@ impl GooglePlacesClient {
 
     /// Extract district/neighborhood from address components
     /// DOCUMENTATION: Looks for sublocality, neighborhood, or administrative_area_level_3
-    fn extract_district(&self, address_components: &Option<Vec<GoogleAddressComponent>>) -> Option<String> {
+    fn extract_district(address_components: &Option<Vec<GoogleAddressComponent>>) -> Option<String> {
         address_components.as_ref().and_then(|components| {
             for component in components {
                 if component.types.iter().any(|t| {
@@ -568,7 +2303,7 @@ impl GooglePlacesClient {
 
     /// Extract postal code from address components
     /// DOCUMENTATION: Looks for postal_code type
-    fn extract_postal_code(&self, address_components: &Option<Vec<GoogleAddressComponent>>) -> Option<String> {
+    fn extract_postal_code(address_components: &Option<Vec<GoogleAddressComponent>>) -> Option<String> {
         address_components.as_ref().and_then(|components| {
             for component in components {
                 if component.types.contains(&"postal_code".to_string()) {
@@ -579,27 +2314,50 @@ impl GooglePlacesClient {
         })
     }
 
+    /// Type and keyword sent to the legacy Nearby Search
+    /// DOCUMENTATION: The legacy API has no cuisine-typed restaurants, so a type such
+    /// as "italian_restaurant" is sent as type=restaurant with the cuisine added to
+    /// the keyword
+    fn legacy_type_and_keyword(search: &NearbySearchParams) -> (Option<String>, Option<String>) {
+        let place_type = search.normalized_type();
+        let keyword = search.normalized_keyword();
+        let cuisine = place_type.as_deref().and_then(|pt| {
+            CUISINE_GOOGLE_TYPES
+                .iter()
+                .find(|(_, google_type)| google_type.eq_ignore_ascii_case(pt))
+                .map(|(cuisine, _)| *cuisine)
+        });
+
+        match cuisine {
+            Some(cuisine) => (
+                Some("restaurant".to_string()),
+                Some(match keyword {
+                    Some(kw) => format!("{} {}", cuisine, kw),
+                    None => cuisine.to_string(),
+                }),
+            ),
+            None => (place_type, keyword),
+        }
+    }
+
+    /// Google restaurant type for a cuisine category
+    /// DOCUMENTATION: Matches the categories stored in `cuisine_types` (trimmed,
+    /// case-insensitive); None for cuisines this service does not know
+    pub fn cuisine_google_type(cuisine: &str) -> Option<&'static str> {
+        let cuisine = cuisine.trim();
+        CUISINE_GOOGLE_TYPES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(cuisine))
+            .map(|(_, google_type)| *google_type)
+    }
+
     /// Extract cuisine types from Google place types and name
     /// DOCUMENTATION: Maps Google types to cuisine categories
-    fn extract_cuisine_types(&self, types: &[String], name: &str) -> Vec<String> {
+    pub fn extract_cuisine_types(types: &[String], name: &str) -> Vec<String> {
         let mut cuisines = Vec::new();
         let name_lower = name.to_lowercase();
 
-        // Mapping from Google types to cuisine types
-        let cuisine_map: Vec<(&str, &str)> = vec![
-            ("italian_restaurant", "italian"),
-            ("chinese_restaurant", "chinese"),
-            ("japanese_restaurant", "japanese"),
-            ("mexican_restaurant", "mexican"),
-            ("indian_restaurant", "indian"),
-            ("spanish_restaurant", "spanish"),
-            ("french_restaurant", "french"),
-            ("thai_restaurant", "thai"),
-            ("american_restaurant", "american"),
-            ("mediterranean_restaurant", "mediterranean"),
-        ];
-
-        for (google_type, cuisine) in cuisine_map {
+        for (cuisine, google_type) in CUISINE_GOOGLE_TYPES {
             if types.iter().any(|t| t == google_type) {
                 cuisines.push(cuisine.to_string());
             }
@@ -636,7 +2394,7 @@ impl GooglePlacesClient {
 
     /// Determine suitable_for tags based on place characteristics
     /// DOCUMENTATION: Derives suitable_for tags from types and price level
-    fn determine_suitable_for(&self, types: &[String], price_level: Option<i32>) -> Vec<String> {
+    pub fn determine_suitable_for(types: &[String], price_level: Option<i32>) -> Vec<String> {
         let mut suitable = Vec::new();
 
         // Everyone can go to parks, museums, etc.
@@ -685,24 +2443,24 @@ impl GooglePlacesClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::GooglePlacesApi;
+    use crate::test_support::{nearby_body, MockHttpServer};
 
     #[test]
     fn test_type_mapping() {
-        let client = GooglePlacesClient::new("test_key".to_string());
 
         let types1 = vec!["restaurant".to_string(), "food".to_string()];
-        assert_eq!(client.map_google_type_to_internal(&types1), "restaurant");
+        assert_eq!(GooglePlacesClient::map_google_type_to_internal(&types1), "restaurant");
 
         let types2 = vec!["bar".to_string(), "night_club".to_string()];
-        assert_eq!(client.map_google_type_to_internal(&types2), "bar");
+        assert_eq!(GooglePlacesClient::map_google_type_to_internal(&types2), "bar");
 
         let types3 = vec!["store".to_string(), "shop".to_string()];
-        assert_eq!(client.map_google_type_to_internal(&types3), "other");
+        assert_eq!(GooglePlacesClient::map_google_type_to_internal(&types3), "other");
     }
 
     #[test]
     fn test_to_create_request() {
-        let client = GooglePlacesClient::new("test_key".to_string());
 
         let google_place = GooglePlace {
             place_id: "ChIJ123".to_string(),
@@ -736,6 +2494,7 @@ mod tests {
                 open_now: Some(true),
                 weekday_text: Some(vec!["Monday: 09:00 – 22:00".to_string()]),
                 periods: None,
+                utc_offset_minutes: None,
             }),
             formatted_phone_number: Some("+34 912 345 678".to_string()),
             international_phone_number: Some("+34 912 345 678".to_string()),
@@ -743,9 +2502,13 @@ mod tests {
             url: Some("https://maps.google.com/?cid=123".to_string()),
             reviews: None,
             photos: None,
+            utc_offset_minutes: Some(120),
+            editorial_summary: None,
+            attributes: GoogleServiceAttributes::default(),
+            language: Some("es".to_string()),
         };
 
-        let request = client.to_create_request(&google_place, "Madrid");
+        let request = GooglePlacesClient::to_create_request(&google_place, "Madrid");
 
         assert_eq!(request.name, "Test Restaurant");
         assert_eq!(request.city, "Madrid");
@@ -758,8 +2521,1213 @@ mod tests {
         assert_eq!(request.district, Some("Centro".to_string()));
         assert_eq!(request.postal_code, Some("28013".to_string()));
         assert_eq!(request.business_status, Some("OPERATIONAL".to_string()));
-        assert!(request.opening_hours.is_some());
+        assert_eq!(request.opening_hours.as_ref().unwrap()["utc_offset_minutes"], 120);
         assert_eq!(request.is_open_now, Some(true));
         assert!(request.description.is_none()); // Should not duplicate address
+        assert_eq!(request.language.as_deref(), Some("es"));
+    }
+
+    fn details_fixture(json: &str) -> GooglePlace {
+        let response: serde_json::Value = serde_json::from_str(json).unwrap();
+        serde_json::from_value(response["result"].clone()).unwrap()
+    }
+
+    #[test]
+    fn test_to_create_request_maps_editorial_summary_and_attributes() {
+        let place = details_fixture(include_str!("fixtures/place_details_with_attributes.json"));
+
+        assert_eq!(place.attributes.dine_in, Some(true));
+        assert_eq!(place.attributes.serves_brunch, None);
+
+        let request = GooglePlacesClient::to_create_request(&place, "Zaragoza");
+        assert_eq!(
+            request.description.as_deref(),
+            Some("Bar de tapas clásico en el Tubo con croquetas caseras y vermut.")
+        );
+        let attributes = request.attributes.unwrap();
+        assert_eq!(attributes["dine_in"], true);
+        assert_eq!(attributes["delivery"], false);
+        assert_eq!(attributes["serves_vegetarian_food"], true);
+        assert!(attributes.get("serves_brunch").is_none(), "unreported attributes are omitted");
+
+        // Attributes survive the details cache round trip
+        let cached: GooglePlace = serde_json::from_str(&serde_json::to_string(&place).unwrap()).unwrap();
+        assert_eq!(cached.attributes, place.attributes);
+    }
+
+    #[test]
+    fn test_to_create_request_without_editorial_summary_or_attributes() {
+        let place = details_fixture(include_str!("fixtures/place_details_without_attributes.json"));
+
+        let request = GooglePlacesClient::to_create_request(&place, "Zaragoza");
+        assert!(request.description.is_none());
+        assert!(request.attributes.is_none());
+        assert_eq!(place.attributes, GoogleServiceAttributes::default());
+    }
+
+    fn mock_client(base_url: &str) -> GooglePlacesClient {
+        GooglePlacesClient::new("test_key".to_string()).with_base_url(base_url)
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_reports_results() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a", "b"])]).await;
+        let client = mock_client(&server.base_url);
+
+        let outcome = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome;
+
+        assert!(!outcome.is_empty());
+        assert_eq!(outcome.into_places().len(), 2);
+        assert!(server.requests()[0].starts_with("GET /nearbysearch/json?"));
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_reports_empty_and_negative_caches_it() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&[])]).await;
+        let client = mock_client(&server.base_url);
+
+        let first = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome;
+        let second = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome;
+
+        assert!(first.is_empty());
+        assert!(second.is_empty());
+        assert_eq!(server.hits(), 1, "ZERO_RESULTS should be served from the negative cache");
+    }
+
+    #[tokio::test]
+    async fn test_shared_cache_serves_repeated_searches() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a", "b"])]).await;
+        let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+        let first_client = GooglePlacesClient::new_with_cache("test_key".to_string(), cache.clone())
+            .with_base_url(&server.base_url);
+        let second_client = GooglePlacesClient::new_with_cache("test_key".to_string(), cache)
+            .with_base_url(&server.base_url);
+
+        let fresh = first_client.nearby_search_pages(&NearbySearchParams {
+            keyword: Some("tapas".to_string()),
+            ..NearbySearchParams::new(41.65, -0.88, 1000)
+        }, 1).await.unwrap();
+        let cached = second_client
+            .nearby_search_pages(&NearbySearchParams {
+                keyword: Some("tapas".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+
+        assert_eq!((fresh.pages_fetched, fresh.api_requests), (1, 1));
+        assert_eq!((cached.pages_fetched, cached.api_requests), (1, 0));
+        assert_eq!(cached.outcome.into_places().len(), 2);
+        assert_eq!(server.hits(), 1, "identical search within the TTL must not reach Google");
+    }
+
+    #[tokio::test]
+    async fn test_place_details_are_cached() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJdetails",
+                "name": "Detalle",
+                "types": ["bar"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url);
+
+        let first = client.get_place_details("ChIJdetails").await.unwrap();
+        let second = client.get_place_details("ChIJdetails").await.unwrap();
+
+        assert_eq!(first.name, second.name);
+        assert_eq!(server.hits(), 1);
+        assert!(server.requests()[0].starts_with("GET /details/json?"));
+    }
+
+    #[tokio::test]
+    async fn test_place_details_use_their_own_ttl() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJttl",
+                "name": "Detalle",
+                "types": ["bar"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        // Regular entries expire immediately; details are kept for a minute
+        let cache = Arc::new(PlacesCache::new(0, DEFAULT_MAX_ENTRIES));
+        let client = GooglePlacesClient::new_with_cache("test_key".to_string(), cache.clone())
+            .with_base_url(&server.base_url)
+            .with_details_ttl(std::time::Duration::from_secs(60));
+
+        client.get_place_details("ChIJttl").await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        client.get_place_details("ChIJttl").await.unwrap();
+
+        assert_eq!(server.hits(), 1);
+        assert_eq!(cache.stats().await.active_by_namespace.get("details"), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_expired_empty_marker_does_not_hide_new_results() {
+        let server =
+            MockHttpServer::start_json(vec![nearby_body(&[]), nearby_body(&["new_place"])]).await;
+        let client = mock_client(&server.base_url)
+            .with_empty_results_ttl(std::time::Duration::from_millis(20));
+
+        assert!(client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome.is_empty());
+        assert!(client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome.is_empty());
+        assert_eq!(server.hits(), 1);
+
+        tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+        let refreshed = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome;
+        assert_eq!(refreshed.into_places().len(), 1);
+        assert_eq!(server.hits(), 2);
+
+        // The fresh results replace the marker and use the regular TTL
+        let cached = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap().outcome;
+        assert_eq!(cached.into_places().len(), 1);
+        assert_eq!(server.hits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_radius_limit() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"])]).await;
+        let client = mock_client(&server.base_url);
+
+        assert!(client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 50_000), 1).await.is_ok());
+        for radius in [0, 50_001] {
+            let result = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, radius), 1).await;
+            match result {
+                Err(PlacesError::InvalidInput(msg)) => assert!(msg.contains("50000")),
+                other => panic!("expected InvalidInput for radius {}, got {:?}", radius, other),
+            }
+        }
+        assert_eq!(server.hits(), 1, "rejected radii must not reach the API");
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_sends_open_now_and_price_filters() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"])]).await;
+        let client = mock_client(&server.base_url);
+
+        client.nearby_search_pages(&NearbySearchParams {
+            open_now: true,
+            min_price: Some(1),
+            max_price: Some(3),
+            ..NearbySearchParams::new(41.65, -0.88, 1000)
+        }, 1).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert!(request.contains("opennow=true"), "{}", request);
+        assert!(request.contains("minprice=1"), "{}", request);
+        assert!(request.contains("maxprice=3"), "{}", request);
+
+        // Unfiltered searches send none of them
+        client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await.unwrap();
+        let request = &server.requests()[1];
+        assert!(!request.contains("opennow") && !request.contains("price"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_rank_by_distance_omits_radius() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"])]).await;
+        let client = mock_client(&server.base_url);
+
+        client
+            .nearby_search_pages(&NearbySearchParams {
+                place_type: Some("cafe".to_string()),
+                rank_by: RankBy::Distance,
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+        let request = &server.requests()[0];
+        assert!(request.contains("rankby=distance"), "{}", request);
+        assert!(!request.contains("radius="), "{}", request);
+
+        client
+            .nearby_search_pages(&NearbySearchParams {
+                place_type: Some("cafe".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+        let request = &server.requests()[1];
+        assert!(request.contains("radius=1000"), "{}", request);
+        assert!(!request.contains("rankby"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_rank_by_distance_requires_type_or_keyword() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"])]).await;
+        let client = mock_client(&server.base_url);
+
+        for (place_type, keyword) in [(None, None), (Some(" "), Some("  "))] {
+            let result = client
+                .nearby_search_pages(&NearbySearchParams {
+                    place_type: place_type.map(str::to_string),
+                    keyword: keyword.map(str::to_string),
+                    rank_by: RankBy::Distance,
+                    ..NearbySearchParams::new(41.65, -0.88, 1000)
+                }, 1)
+                .await;
+            assert!(matches!(result, Err(PlacesError::InvalidInput(_))), "{:?}", result);
+        }
+        assert_eq!(server.hits(), 0);
+
+        let by_keyword = client
+            .nearby_search_pages(&NearbySearchParams {
+                keyword: Some("vermut".to_string()),
+                rank_by: RankBy::Distance,
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await;
+        assert!(by_keyword.is_ok());
+    }
+
+    #[test]
+    fn test_validate_price_range_boundaries() {
+        for (min, max) in [(Some(0), Some(4)), (Some(0), Some(0)), (Some(4), Some(4)), (Some(0), None), (None, Some(4)), (None, None)] {
+            assert!(GooglePlacesClient::validate_price_range(min, max).is_ok(), "{:?}-{:?}", min, max);
+        }
+        for (min, max) in [(Some(-1), None), (Some(5), None), (None, Some(-1)), (None, Some(5)), (Some(1), Some(0))] {
+            assert!(GooglePlacesClient::validate_price_range(min, max).is_err(), "{:?}-{:?}", min, max);
+        }
+    }
+
+    #[test]
+    fn test_cuisine_google_types() {
+        assert_eq!(GooglePlacesClient::cuisine_google_type(" Italian "), Some("italian_restaurant"));
+        assert_eq!(GooglePlacesClient::cuisine_google_type("korean"), Some("korean_restaurant"));
+        assert_eq!(GooglePlacesClient::cuisine_google_type("klingon"), None);
+
+        let types = vec!["vietnamese_restaurant".to_string(), "restaurant".to_string()];
+        assert_eq!(GooglePlacesClient::extract_cuisine_types(&types, "Pho Hanoi"), vec!["vietnamese"]);
+        assert_eq!(GooglePlacesClient::extract_cuisine_types(&[], "Pizza Roma"), vec!["italian"]);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_nearby_search_sends_cuisine_as_keyword() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"]), nearby_body(&["b"])]).await;
+        let client = mock_client(&server.base_url);
+
+        client
+            .nearby_search_pages(&NearbySearchParams {
+                place_type: Some("italian_restaurant".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+        client
+            .nearby_search_pages(&NearbySearchParams {
+                place_type: Some("japanese_restaurant".to_string()),
+                keyword: Some("ramen".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].contains("type=restaurant"), "{}", requests[0]);
+        assert!(requests[0].contains("keyword=italian"), "{}", requests[0]);
+        assert!(requests[1].contains("keyword=japanese+ramen") || requests[1].contains("keyword=japanese%20ramen"), "{}", requests[1]);
+        assert!(!requests.iter().any(|r| r.contains("_restaurant")));
+    }
+
+    #[test]
+    fn test_validate_suitable_for() {
+        let tags = |tags: &[&str]| tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        assert!(GooglePlacesClient::validate_suitable_for(&tags(&SUITABLE_FOR_TAGS)).is_ok());
+        assert!(GooglePlacesClient::validate_suitable_for(&tags(&[" Families "])).is_ok());
+        let err = GooglePlacesClient::validate_suitable_for(&tags(&["groups", "pets"])).unwrap_err();
+        assert!(matches!(&err, PlacesError::ValidationError(msg) if msg.ends_with("got pets")), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_rejects_invalid_price_range() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a"])]).await;
+        let client = mock_client(&server.base_url);
+
+        for (min, max) in [(Some(-1), None), (None, Some(5)), (Some(3), Some(1))] {
+            let result = client.nearby_search_pages(&NearbySearchParams {
+                min_price: min,
+                max_price: max,
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1).await;
+            assert!(matches!(result, Err(PlacesError::ValidationError(_))), "{:?}-{:?}", min, max);
+        }
+        assert_eq!(server.hits(), 0, "rejected price ranges must not reach the API");
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_error_status_is_not_empty() {
+        let body = serde_json::json!({
+            "status": "REQUEST_DENIED",
+            "results": [],
+            "error_message": "bad key"
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url);
+
+        let result = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1).await;
+
+        assert!(matches!(
+            result,
+            Err(PlacesError::GoogleApi(GoogleApiError::RequestDenied(ref message))) if message == "bad key"
+        ));
+    }
+
+    /// Nearby Search page with the given places and optional next_page_token
+    fn paged_body(place_ids: &[&str], next_page_token: Option<&str>) -> String {
+        let mut body: serde_json::Value = serde_json::from_str(&nearby_body(place_ids)).unwrap();
+        body["next_page_token"] = serde_json::json!(next_page_token);
+        body.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_follows_page_tokens() {
+        let server = MockHttpServer::start_json(vec![
+            paged_body(&["a", "b"], Some("token_2")),
+            paged_body(&["c", "d"], Some("token_3")),
+            paged_body(&["e"], None),
+        ])
+        .await;
+        let client = mock_client(&server.base_url).with_page_token_delay(std::time::Duration::ZERO);
+
+        let pages = client
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), MAX_NEARBY_PAGES)
+            .await
+            .unwrap();
+
+        assert_eq!(pages.pages_fetched, 3);
+        assert_eq!(pages.api_requests, 3);
+        let ids: Vec<String> = pages.outcome.into_places().into_iter().map(|p| p.place_id).collect();
+        assert_eq!(ids, ["a", "b", "c", "d", "e"]);
+        let requests = server.requests();
+        assert!(requests[1].contains("pagetoken=token_2"));
+        assert!(requests[2].contains("pagetoken=token_3"));
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_stops_at_max_pages() {
+        let server = MockHttpServer::start_json(vec![
+            paged_body(&["a"], Some("token_2")),
+            paged_body(&["b"], Some("token_3")),
+        ])
+        .await;
+        let client = mock_client(&server.base_url).with_page_token_delay(std::time::Duration::ZERO);
+
+        let outcome = client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 2).await.unwrap().outcome;
+
+        assert_eq!(outcome.into_places().len(), 2);
+        assert_eq!(server.hits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_keeps_results_when_follow_up_page_fails() {
+        let server = MockHttpServer::start(vec![
+            (200, paged_body(&["a", "b"], Some("token_2"))),
+            (500, "{}".to_string()),
+        ])
+        .await;
+        let client = mock_client(&server.base_url).with_page_token_delay(std::time::Duration::ZERO);
+
+        let pages = client
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 3)
+            .await
+            .unwrap();
+
+        assert_eq!(pages.pages_fetched, 2);
+        assert_eq!(pages.outcome.into_places().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_search_waits_before_using_page_token() {
+        let server = MockHttpServer::start_json(vec![
+            paged_body(&["a"], Some("token_2")),
+            paged_body(&["b"], None),
+        ])
+        .await;
+        let client = mock_client(&server.base_url)
+            .with_page_token_delay(std::time::Duration::from_millis(200));
+
+        let started = std::time::Instant::now();
+        client.nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 3).await.unwrap();
+
+        assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+        assert_eq!(server.hits(), 2);
+    }
+
+    fn autocomplete_body() -> String {
+        serde_json::json!({
+            "status": "OK",
+            "predictions": [
+                {
+                    "place_id": "ChIJbotanico",
+                    "description": "Café Botánico, Zaragoza, Spain",
+                    "types": ["cafe", "establishment"],
+                    "matched_substrings": []
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_forwards_session_and_bias_and_caches() {
+        let server = MockHttpServer::start_json(vec![autocomplete_body()]).await;
+        let client = mock_client(&server.base_url);
+        let bias = LocationBias { latitude: 41.65, longitude: -0.88, radius_m: 5000 };
+
+        let predictions = client.autocomplete("Cafe bot", Some("sess-1"), Some(&bias), None).await.unwrap();
+        assert_eq!(predictions.len(), 1);
+        assert_eq!(predictions[0].place_id, "ChIJbotanico");
+        assert_eq!(predictions[0].types, ["cafe", "establishment"]);
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /autocomplete/json?"));
+        assert!(request.contains("sessiontoken=sess-1"));
+        assert!(request.contains("locationbias=circle%3A5000%4041.65%2C-0.88"));
+
+        // Same normalized input and bias, different session: served from cache
+        client.autocomplete("  cafe   BOT ", Some("sess-2"), Some(&bias), None).await.unwrap();
+        assert_eq!(server.hits(), 1);
+
+        // A different bias is a different prediction set
+        client.autocomplete("cafe bot", None, None, None).await.unwrap();
+        assert_eq!(server.hits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_details_forward_session_token() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJsession",
+                "name": "Detalle",
+                "types": ["bar"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url);
+
+        let options = DetailsOptions { session_token: Some("sess-1"), ..Default::default() };
+        client.get_place_details_with("ChIJsession", options).await.unwrap();
+
+        assert!(server.requests()[0].contains("sessiontoken=sess-1"));
+    }
+
+    #[test]
+    fn test_partial_details_fields_only_request_their_part() {
+        for mask in [DetailsFields::Full.legacy_fields(), DetailsFields::Full.v1_field_mask()] {
+            assert!(mask.contains("photos") && mask.contains("reviews"));
+        }
+        for fields in [DetailsFields::Photos, DetailsFields::Reviews] {
+            assert!(fields.legacy_fields().len() < DetailsFields::Full.legacy_fields().len());
+            assert!(fields.v1_field_mask().len() < DetailsFields::Full.v1_field_mask().len());
+        }
+
+        let photos = DetailsFields::Photos;
+        assert!(photos.legacy_fields().split(',').any(|field| field == "photos"));
+        assert!(!photos.legacy_fields().contains("reviews"));
+        assert!(!photos.v1_field_mask().contains("reviews"));
+
+        let reviews = DetailsFields::Reviews;
+        assert!(reviews.legacy_fields().split(',').any(|field| field == "reviews"));
+        assert!(!reviews.legacy_fields().contains("photos"));
+        assert!(!reviews.v1_field_mask().contains("photos"));
+
+        // Neither partial mask asks for billed contact or atmosphere fields
+        for fields in [photos, reviews] {
+            assert!(!fields.legacy_fields().contains("website"));
+            assert!(!fields.v1_field_mask().contains("rating"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_partial_details_use_their_own_fields_and_cache_entry() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJfotos",
+                "name": "Fotos",
+                "types": ["bar"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url);
+
+        let photos = DetailsOptions { fields: DetailsFields::Photos, ..Default::default() };
+        client.get_place_details_with("ChIJfotos", photos).await.unwrap();
+        assert!(server.requests()[0].contains("fields=name%2Cplace_id%2Cgeometry%2Ctypes%2Cphotos&"));
+
+        // A cached partial response is never served as a full place
+        client.get_place_details_with("ChIJfotos", photos).await.unwrap();
+        assert_eq!(server.hits(), 1);
+        client.get_place_details("ChIJfotos").await.unwrap();
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests()[1].contains("formatted_address"));
+    }
+
+    #[tokio::test]
+    async fn test_details_request_untranslated_reviews() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJreviews",
+                "name": "Reseñas",
+                "types": ["bar"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } },
+                "reviews": [{
+                    "author_name": "Hans",
+                    "rating": 5,
+                    "text": "Sehr gute Tapas",
+                    "time": 1_700_000_000,
+                    "language": "de",
+                    "original_language": "de"
+                }]
+            }
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body.clone(), body]).await;
+        let client = mock_client(&server.base_url).with_default_language("es");
+
+        let place = client.get_place_details("ChIJreviews").await.unwrap();
+        let request = &server.requests()[0];
+        assert!(request.contains("reviews_no_translations=true"));
+        assert!(request.contains("reviews_sort=most_relevant"));
+        let review = &place.reviews.unwrap()[0];
+        assert_eq!(review.original_language.as_deref(), Some("de"));
+        assert_eq!(review.text_language(), Some("de"));
+
+        // Another sort order is a different review set
+        let newest = DetailsOptions { reviews_sort: ReviewsSort::Newest, ..Default::default() };
+        client.get_place_details_with("ChIJreviews", newest).await.unwrap();
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests()[1].contains("reviews_sort=newest"));
+    }
+
+    #[tokio::test]
+    async fn test_find_place_sends_input_type_and_parses_candidates() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "candidates": [
+                { "place_id": "ChIJbotanico", "name": "Café Botánico", "formatted_address": "C. Santiago, Zaragoza" }
+            ]
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url);
+
+        let candidates = client
+            .find_place("+34 976 00 00 00", FindPlaceInputType::PhoneNumber)
+            .await
+            .unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].place_id, "ChIJbotanico");
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /findplacefromtext/json?"));
+        assert!(request.contains("inputtype=phonenumber"));
+        assert!(request.contains("fields=place_id%2Cname%2Cformatted_address"));
+    }
+
+    fn geocode_body() -> String {
+        serde_json::json!({
+            "status": "OK",
+            "results": [{
+                "formatted_address": "Huesca, España",
+                "geometry": {
+                    "location": { "lat": 42.1401, "lng": -0.4089 },
+                    "viewport": {
+                        "northeast": { "lat": 42.1627, "lng": -0.3803 },
+                        "southwest": { "lat": 42.1201, "lng": -0.4331 }
+                    }
+                },
+                "types": ["locality", "political"]
+            }]
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_geocode_city_extracts_viewport_and_caches() {
+        let server = MockHttpServer::start_json(vec![geocode_body()]).await;
+        let client = mock_client(&server.base_url)
+            .with_geocoding_base_url(&server.base_url)
+            .with_region_bias("es");
+
+        let bounds = client.geocode_city("  Huesca ").await.unwrap().unwrap();
+        assert_eq!(bounds.name, "Huesca");
+        assert_eq!((bounds.min_lat, bounds.max_lat), (42.1201, 42.1627));
+        assert_eq!((bounds.min_lng, bounds.max_lng), (-0.4331, -0.3803));
+        assert_eq!(bounds.radius_m, DEFAULT_RADIUS_M);
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("GET /json?"));
+        assert!(request.contains("address=Huesca"));
+        assert!(request.contains("region=es"));
+
+        // Cached: the viewport of a city does not change
+        client.geocode_city("huesca").await.unwrap().unwrap();
+        assert_eq!(server.hits(), 1);
+        assert_eq!(client.usage().count(GoogleApiOperation::Geocoding), 1);
+    }
+
+    #[tokio::test]
+    async fn test_geocode_city_zero_results_and_errors() {
+        let server = MockHttpServer::start_json(vec![
+            r#"{"status":"ZERO_RESULTS","results":[]}"#.to_string(),
+            r#"{"status":"REQUEST_DENIED","error_message":"Geocoding API not enabled"}"#.to_string(),
+        ])
+        .await;
+        let client = mock_client(&server.base_url).with_geocoding_base_url(&server.base_url);
+
+        assert!(client.geocode_city("Nowhere").await.unwrap().is_none());
+        let err = client.geocode_city("Somewhere").await.unwrap_err();
+        assert!(matches!(err, PlacesError::GoogleApi(GoogleApiError::RequestDenied(_))));
+    }
+
+    #[tokio::test]
+    async fn test_find_place_zero_results_is_empty() {
+        let server =
+            MockHttpServer::start_json(vec![r#"{"status":"ZERO_RESULTS","candidates":[]}"#.to_string()]).await;
+        let client = mock_client(&server.base_url);
+
+        let candidates = client.find_place("nowhere at all", FindPlaceInputType::TextQuery).await.unwrap();
+        assert!(candidates.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hung_connection_times_out_fast() {
+        // Accepts connections but never writes a response
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut open = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                open.push(socket);
+            }
+        });
+
+        let client = mock_client(&base_url).with_timeouts(
+            std::time::Duration::from_millis(100),
+            std::time::Duration::from_millis(200),
+        );
+
+        let started = std::time::Instant::now();
+        let result = client.get_place_details("ChIJhung").await;
+
+        match result {
+            Err(PlacesError::GoogleApi(GoogleApiError::Network(message))) => {
+                assert!(message.contains("timed out after 200ms"), "{}", message)
+            }
+            other => panic!("expected a timeout error, got {:?}", other),
+        }
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_default_language_is_sent_and_recorded() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["ChIJes"])]).await;
+        let client = mock_client(&server.base_url).with_default_language("es");
+
+        let places = client
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), 1)
+            .await
+            .unwrap().outcome
+            .into_places();
+
+        assert!(server.requests()[0].contains("language=es"));
+        assert_eq!(places[0].language.as_deref(), Some("es"));
+    }
+
+    #[tokio::test]
+    async fn test_language_override_is_a_separate_cache_entry() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["ChIJlang"])]).await;
+        let client = mock_client(&server.base_url).with_default_language("es");
+        let search = NearbySearchParams::new(41.65, -0.88, 1000);
+
+        client.nearby_search_page(&search, None).await.unwrap();
+        // Explicit "es" equals the default, so it is served from cache
+        let explicit_es = NearbySearchParams { language: Some("es".to_string()), ..search.clone() };
+        client.nearby_search_page(&explicit_es, None).await.unwrap();
+        assert_eq!(server.hits(), 1);
+
+        let english = NearbySearchParams { language: Some("en".to_string()), ..search };
+        client.nearby_search_page(&english, None).await.unwrap();
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests()[1].contains("language=en"));
+    }
+
+    #[tokio::test]
+    async fn test_region_bias_is_sent_and_overridable() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["ChIJregion"])]).await;
+        let client = mock_client(&server.base_url).with_region_bias("ES");
+        let search = NearbySearchParams::new(41.65, -0.88, 1000);
+
+        client.nearby_search_page(&search, None).await.unwrap();
+        assert!(server.requests()[0].contains("region=es"));
+
+        // Explicit "es" equals the default, so it is served from cache
+        let explicit_es = NearbySearchParams { region: Some("es".to_string()), ..search.clone() };
+        client.nearby_search_page(&explicit_es, None).await.unwrap();
+        assert_eq!(server.hits(), 1);
+
+        let portugal = NearbySearchParams { region: Some("pt".to_string()), ..search.clone() };
+        client.nearby_search_page(&portugal, None).await.unwrap();
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests()[1].contains("region=pt"));
+
+        let invalid = NearbySearchParams { region: Some("spain".to_string()), ..search };
+        let err = client.nearby_search_page(&invalid, None).await.unwrap_err();
+        assert!(matches!(err, PlacesError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_restricts_to_country() {
+        let server = MockHttpServer::start_json(vec![autocomplete_body()]).await;
+        let client = mock_client(&server.base_url).with_region_bias("es");
+
+        client.autocomplete("cafe bot", None, None, None).await.unwrap();
+        assert!(server.requests()[0].contains("components=country%3Aes"));
+
+        // Same input restricted to another country is a separate entry
+        client.autocomplete("cafe bot", None, None, Some("fr")).await.unwrap();
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests()[1].contains("components=country%3Afr"));
+
+        let no_bias = mock_client(&server.base_url);
+        no_bias.autocomplete("cafe bot", None, None, None).await.unwrap();
+        assert!(!server.requests()[2].contains("components="));
+    }
+
+    #[test]
+    fn test_validate_language() {
+        for ok in ["es", "en", "pt-BR", "zh-Hant", "fil"] {
+            assert!(GooglePlacesClient::validate_language(ok).is_ok(), "{}", ok);
+        }
+        for bad in ["", "e", "spanish", "es_ES", "es-", "es-ES-x"] {
+            assert!(GooglePlacesClient::validate_language(bad).is_err(), "{}", bad);
+        }
+    }
+
+    fn v1_client(base_url: &str) -> GooglePlacesClient {
+        mock_client(base_url)
+            .with_api_version(GoogleApiVersion::V1)
+            .with_v1_base_url(base_url)
+    }
+
+    #[tokio::test]
+    async fn test_v1_nearby_search_maps_places_and_caches() {
+        let fixture = include_str!("fixtures/v1_search_nearby_restaurant.json").to_string();
+        let server = MockHttpServer::start_json(vec![fixture]).await;
+        let client = v1_client(&server.base_url);
+
+        let places = client
+            .nearby_search_pages(&NearbySearchParams {
+                place_type: Some("restaurant".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 3)
+            .await
+            .unwrap().outcome
+            .into_places();
+
+        assert_eq!(places.len(), 2);
+        assert_eq!(places[0].name, "El Tubo Tapas");
+        assert_eq!(places[0].price_level, Some(2));
+        assert!(server.requests()[0].starts_with("POST /places:searchNearby "));
+
+        client.nearby_search_pages(&NearbySearchParams {
+            place_type: Some("restaurant".to_string()),
+            ..NearbySearchParams::new(41.65, -0.88, 1000)
+        }, 3).await.unwrap();
+        assert_eq!(server.hits(), 1, "searchNearby has no pagination and the page is cached");
+    }
+
+    #[tokio::test]
+    async fn test_v1_keyword_uses_text_search_and_empty_is_zero_results() {
+        let server = MockHttpServer::start_json(vec!["{}".to_string()]).await;
+        let client = v1_client(&server.base_url);
+
+        let outcome = client
+            .nearby_search_pages(&NearbySearchParams {
+                keyword: Some("vermut".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap()
+            .outcome;
+
+        assert!(outcome.is_empty());
+        assert!(server.requests()[0].starts_with("POST /places:searchText "));
+    }
+
+    #[tokio::test]
+    async fn test_v1_details_and_errors() {
+        let fixture = include_str!("fixtures/v1_place_details_restaurant.json").to_string();
+        let not_found = r#"{"error":{"code":404,"message":"Not found","status":"NOT_FOUND"}}"#;
+        let quota = r#"{"error":{"code":429,"message":"Quota","status":"RESOURCE_EXHAUSTED"}}"#;
+        let server = MockHttpServer::start(vec![
+            (200, fixture),
+            (404, not_found.to_string()),
+            (429, quota.to_string()),
+        ])
+        .await;
+        let client = v1_client(&server.base_url);
+
+        let place = client.get_place_details("ChIJ2QdR5bEUWQ0RkN_n1cAQFh4").await.unwrap();
+        assert_eq!(place.reviews.as_ref().unwrap().len(), 2);
+        assert!(server.requests()[0].starts_with("GET /places/ChIJ2QdR5bEUWQ0RkN_n1cAQFh4"));
+
+        let reference = &place.photos.as_ref().unwrap()[0].photo_reference;
+        let upstream = client.upstream_photo_request(reference, 400).build().unwrap();
+        assert!(upstream.url().as_str().ends_with("/photos/AUc7tXXtHj3vYq/media?maxWidthPx=400"));
+        assert_eq!(upstream.headers()["X-Goog-Api-Key"], "test_key");
+        assert_eq!(
+            client.get_photo_url(reference, Some(400)),
+            "/places/photos/places/ChIJ2QdR5bEUWQ0RkN_n1cAQFh4/photos/AUc7tXXtHj3vYq?maxwidth=400"
+        );
+
+        assert!(matches!(
+            client.get_place_details("ChIJgone").await,
+            Err(PlacesError::GoogleApi(GoogleApiError::NotFound(_)))
+        ));
+        assert!(matches!(
+            client.get_place_details("ChIJbusy").await,
+            Err(PlacesError::GoogleApi(GoogleApiError::OverQueryLimit(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_paces_requests() {
+        let body = r#"{"status":"ZERO_RESULTS","predictions":[]}"#.to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = mock_client(&server.base_url).with_rate_limit(4);
+
+        // 4 permits are available at once, the other 2 refill at 250ms each
+        let started = std::time::Instant::now();
+        for i in 0..6 {
+            client.autocomplete(&format!("input {}", i), None, None, None).await.unwrap();
+        }
+
+        assert_eq!(server.hits(), 6);
+        assert!(
+            started.elapsed() >= std::time::Duration::from_millis(450),
+            "6 requests at 4/s finished in {:?}",
+            started.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_shared_by_concurrent_callers() {
+        let client = Arc::new(GooglePlacesClient::new("test_key".to_string()).with_rate_limit(5));
+
+        let started = std::time::Instant::now();
+        let workers: Vec<_> = (0..2)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    for _ in 0..4 {
+                        client.limiter.until_ready().await;
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.await.unwrap();
+        }
+
+        // 8 permits at 5/s with a burst of 5: the last 3 wait 200ms each
+        assert!(started.elapsed() >= std::time::Duration::from_millis(550));
+    }
+
+    fn bare_place(place_id: &str, lat: f64, lng: f64) -> GooglePlace {
+        GooglePlace {
+            place_id: place_id.to_string(),
+            name: String::new(),
+            types: Vec::new(),
+            geometry: GoogleGeometry {
+                location: GoogleLocation { lat, lng },
+            },
+            formatted_address: None,
+            vicinity: None,
+            address_components: None,
+            rating: None,
+            user_ratings_total: None,
+            price_level: None,
+            business_status: None,
+            opening_hours: None,
+            formatted_phone_number: None,
+            international_phone_number: None,
+            website: None,
+            url: None,
+            reviews: None,
+            photos: None,
+            utc_offset_minutes: None,
+            editorial_summary: None,
+            attributes: GoogleServiceAttributes::default(),
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_keeps_nearby_only_fields() {
+        let mut nearby = bare_place("ChIJ123", 41.65, -0.88);
+        nearby.name = "Nearby Name".to_string();
+        nearby.types = vec!["bar".to_string()];
+        nearby.vicinity = Some("Calle Alfonso I".to_string());
+        nearby.user_ratings_total = Some(250);
+        nearby.price_level = Some(1);
+        nearby.business_status = Some("OPERATIONAL".to_string());
+        nearby.photos = Some(vec![GooglePhoto {
+            photo_reference: "nearby_ref".to_string(),
+            width: Some(400),
+            height: Some(300),
+            html_attributions: None,
+        }]);
+
+        // Details response without geometry, photos, vicinity, or rating count
+        let mut details = bare_place("ChIJ123", 0.0, 0.0);
+        details.rating = Some(4.2);
+
+        let merged = GooglePlace::merge(nearby, details);
+
+        assert_eq!(merged.name, "Nearby Name");
+        assert_eq!(merged.types, vec!["bar".to_string()]);
+        assert_eq!(merged.geometry.location.lat, 41.65);
+        assert_eq!(merged.geometry.location.lng, -0.88);
+        assert_eq!(merged.vicinity.as_deref(), Some("Calle Alfonso I"));
+        assert_eq!(merged.user_ratings_total, Some(250));
+        assert_eq!(merged.price_level, Some(1));
+        assert_eq!(merged.business_status.as_deref(), Some("OPERATIONAL"));
+        assert_eq!(merged.rating, Some(4.2));
+        assert_eq!(merged.photos.unwrap()[0].photo_reference, "nearby_ref");
+    }
+
+    #[test]
+    fn test_merge_keeps_details_only_fields_and_prefers_details() {
+        let mut nearby = bare_place("ChIJ123", 41.65, -0.88);
+        nearby.name = "Old Name".to_string();
+        nearby.rating = Some(3.9);
+        nearby.reviews = Some(Vec::new());
+
+        let mut details = bare_place("ChIJ123", 41.651, -0.881);
+        details.name = "Fresh Name".to_string();
+        details.rating = Some(4.4);
+        details.formatted_address = Some("Calle Alfonso I 5, 50003 Zaragoza".to_string());
+        details.address_components = Some(vec![GoogleAddressComponent {
+            long_name: "Zaragoza".to_string(),
+            short_name: "Zaragoza".to_string(),
+            types: vec!["locality".to_string()],
+        }]);
+        details.formatted_phone_number = Some("976 123 456".to_string());
+        details.international_phone_number = Some("+34 976 123 456".to_string());
+        details.website = Some("https://example.com".to_string());
+        details.url = Some("https://maps.google.com/?cid=1".to_string());
+        details.opening_hours = Some(GoogleOpeningHours {
+            open_now: Some(false),
+            weekday_text: None,
+            periods: None,
+            utc_offset_minutes: None,
+        });
+        details.reviews = Some(vec![GoogleReview {
+            author_name: Some("Ana".to_string()),
+            rating: Some(5),
+            text: Some("Muy bueno".to_string()),
+            time: Some(1_700_000_000),
+            relative_time_description: None,
+            profile_photo_url: None,
+            language: None,
+            original_language: None,
+        }]);
+
+        let merged = GooglePlace::merge(nearby, details);
+
+        assert_eq!(merged.name, "Fresh Name");
+        assert_eq!(merged.rating, Some(4.4));
+        assert_eq!(merged.geometry.location.lat, 41.651);
+        assert!(merged.formatted_address.is_some());
+        assert_eq!(merged.address_components.unwrap().len(), 1);
+        assert!(merged.formatted_phone_number.is_some());
+        assert!(merged.international_phone_number.is_some());
+        assert!(merged.website.is_some());
+        assert!(merged.url.is_some());
+        assert_eq!(merged.opening_hours.unwrap().open_now, Some(false));
+        assert_eq!(merged.reviews.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_photo_urls_point_at_the_proxy() {
+        let client = GooglePlacesClient::new("secret_key".to_string());
+        assert_eq!(client.get_photo_url("ref1", None), "/places/photos/ref1?maxwidth=800");
+        assert_eq!(client.get_photo_thumbnail_url("ref1"), "/places/photos/ref1?maxwidth=400");
+
+        let client = client.with_photo_proxy_base_url("https://places.example.com/");
+        let url = client.get_photo_url("ref1", Some(1200));
+        assert_eq!(url, "https://places.example.com/places/photos/ref1?maxwidth=1200");
+        assert!(!url.contains("secret_key"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_photo_follows_redirect_and_caches_cdn_url() {
+        let server = MockHttpServer::start_with_headers(vec![
+            (302, vec![("Location", "/cdn/photo-1".to_string())], String::new()),
+            (200, vec![("Content-Type", "image/png".to_string())], "PNGDATA".to_string()),
+        ])
+        .await;
+        let client = mock_client(&server.base_url);
+
+        let photo = client.fetch_photo("ref1", 400).await.unwrap();
+        assert_eq!(photo.content_type, "image/png");
+        assert_eq!(photo.bytes, b"PNGDATA");
+
+        // The second view goes straight to the CDN
+        client.fetch_photo("ref1", 400).await.unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("GET /photo?maxwidth=400&photoreference=ref1&key=test_key "));
+        assert!(requests[1].starts_with("GET /cdn/photo-1 "));
+        assert!(requests[2].starts_with("GET /cdn/photo-1 "));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_photo_enforces_size_cap() {
+        let server = MockHttpServer::start_with_headers(vec![(
+            200,
+            vec![("Content-Type", "image/jpeg".to_string())],
+            "0123456789".to_string(),
+        )])
+        .await;
+        let client = mock_client(&server.base_url).with_max_photo_bytes(4);
+
+        match client.fetch_photo("ref1", 400).await {
+            Err(PlacesError::ExternalApiError(msg)) => assert!(msg.contains("4 byte limit"), "{}", msg),
+            other => panic!("expected size cap error, got {:?}", other.map(|p| p.bytes.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_usage_counts_google_requests_not_cache_hits() {
+        let server = MockHttpServer::start_json(vec![
+            serde_json::json!({
+                "status": "OK",
+                "result": {
+                    "place_id": "ChIJdetails",
+                    "name": "Detalle",
+                    "types": ["bar"],
+                    "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+                }
+            })
+            .to_string(),
+            paged_body(&["a"], Some("token_2")),
+            paged_body(&["b"], None),
+        ])
+        .await;
+        let client = mock_client(&server.base_url).with_page_token_delay(std::time::Duration::ZERO);
+
+        client.get_place_details("ChIJdetails").await.unwrap();
+        client.get_place_details("ChIJdetails").await.unwrap();
+        client
+            .nearby_search_pages(&NearbySearchParams::new(41.65, -0.88, 1000), MAX_NEARBY_PAGES)
+            .await
+            .unwrap();
+
+        let usage = client.usage();
+        assert_eq!(usage.count(GoogleApiOperation::PlaceDetails), 1);
+        assert_eq!(usage.count(GoogleApiOperation::NearbySearch), 2);
+        assert_eq!(usage.total(), server.hits() as u64);
+
+        let previous = client.reset_usage();
+        assert_eq!(previous.total(), 3);
+        assert_eq!(client.usage().total(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_usage_counts_photo_re_resolve_once() {
+        let server = MockHttpServer::start_with_headers(vec![
+            (302, vec![("Location", "/cdn/photo-1".to_string())], String::new()),
+            (200, vec![("Content-Type", "image/png".to_string())], "PNGDATA".to_string()),
+            // Cached CDN URL has expired: one failed download, then one new Google request
+            (404, Vec::new(), String::new()),
+            (302, vec![("Location", "/cdn/photo-2".to_string())], String::new()),
+            (200, vec![("Content-Type", "image/png".to_string())], "PNGDATA".to_string()),
+        ])
+        .await;
+        let client = mock_client(&server.base_url);
+
+        client.fetch_photo("ref1", 400).await.unwrap();
+        assert_eq!(client.usage().count(GoogleApiOperation::Photo), 1);
+
+        client.fetch_photo("ref1", 400).await.unwrap();
+        assert_eq!(server.hits(), 5);
+        assert_eq!(client.usage().count(GoogleApiOperation::Photo), 2);
+        assert_eq!(client.usage().total(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_usage_counts_v1_keyword_search_as_text_search() {
+        let server = MockHttpServer::start_json(vec!["{}".to_string()]).await;
+        let client = v1_client(&server.base_url);
+
+        client
+            .nearby_search_pages(&NearbySearchParams {
+                keyword: Some("vermut".to_string()),
+                ..NearbySearchParams::new(41.65, -0.88, 1000)
+            }, 1)
+            .await
+            .unwrap();
+
+        let usage = client.usage();
+        assert_eq!(usage.count(GoogleApiOperation::TextSearch), 1);
+        assert_eq!(usage.count(GoogleApiOperation::NearbySearch), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_photo_not_found() {
+        let server = MockHttpServer::start(vec![(404, String::new())]).await;
+        let client = mock_client(&server.base_url);
+
+        assert!(matches!(
+            client.fetch_photo("missing", 400).await,
+            Err(PlacesError::GoogleApi(GoogleApiError::NotFound(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_photo_rejects_malformed_references() {
+        let server = MockHttpServer::start(vec![(200, String::new())]).await;
+        let client = mock_client(&server.base_url);
+
+        for reference in ["ref1&key=other", "../photo", "places/abc/photos/", "places/a/b/c", ""] {
+            assert!(
+                matches!(client.fetch_photo(reference, 400).await, Err(PlacesError::ValidationError(_))),
+                "{:?} should be rejected",
+                reference
+            );
+        }
+        assert!(GooglePlacesClient::validate_photo_reference(&"a".repeat(MAX_PHOTO_REFERENCE_LEN + 1)).is_err());
+        assert_eq!(server.hits(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_photo_network_error_hides_api_key() {
+        // Nothing listens on port 1, so the send fails before any response
+        let client = GooglePlacesClient::new("secret_key".to_string()).with_base_url("http://127.0.0.1:1");
+
+        match client.fetch_photo("ref1", 400).await {
+            Err(e) => assert!(!e.to_string().contains("secret_key"), "{}", e),
+            Ok(_) => panic!("expected a network error"),
+        }
     }
 }
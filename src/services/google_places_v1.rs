@@ -0,0 +1,612 @@
+// src/services/google_places_v1.rs
+// DOCUMENTATION: Places API (New) v1 request bodies and response mapping
+// PURPOSE: Translate places.googleapis.com/v1 resources into the legacy GooglePlace shape
+
+use crate::services::google_places_client::{
+    GoogleAddressComponent, GoogleEditorialSummary, GoogleGeometry, GoogleLocation,
+    GoogleOpeningHours, GoogleOpeningPeriod, GoogleOpeningTime, GooglePhoto, GooglePlace,
+    GoogleReview, GoogleServiceAttributes, NearbySearchParams, RankBy,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Default base URL of the Places API (New)
+pub const V1_BASE_URL: &str = "https://places.googleapis.com/v1";
+
+/// Field mask for searchNearby/searchText
+/// DOCUMENTATION: Mirrors what legacy Nearby Search returned (basic + rating fields),
+/// so the v1 calls stay in the same billing tier
+pub const V1_SEARCH_FIELD_MASK: &str = "places.id,places.displayName,places.types,\
+places.location,places.shortFormattedAddress,places.rating,places.userRatingCount,\
+places.priceLevel,places.businessStatus,places.currentOpeningHours.openNow,places.photos,\
+nextPageToken";
+
+/// Field mask for Place Details (GET /places/{id})
+/// DOCUMENTATION: Same information as the legacy `fields` list used by get_place_details
+pub const V1_DETAILS_FIELD_MASK: &str = "id,displayName,types,location,formattedAddress,\
+shortFormattedAddress,addressComponents,rating,userRatingCount,priceLevel,businessStatus,\
+regularOpeningHours,currentOpeningHours.openNow,nationalPhoneNumber,internationalPhoneNumber,\
+websiteUri,googleMapsUri,reviews,photos,utcOffsetMinutes,editorialSummary,dineIn,takeout,\
+delivery,reservable,servesBreakfast,servesBrunch,servesLunch,servesDinner,servesBeer,servesWine,\
+servesVegetarianFood,accessibilityOptions.wheelchairAccessibleEntrance";
+
+/// Field mask for photo-only Place Details refreshes
+/// DOCUMENTATION: Identity fields plus photos, see DetailsFields::Photos
+pub const V1_PHOTOS_FIELD_MASK: &str = "id,displayName,types,location,photos";
+
+/// Field mask for review-only Place Details refreshes
+/// DOCUMENTATION: Identity fields plus reviews, see DetailsFields::Reviews
+pub const V1_REVIEWS_FIELD_MASK: &str = "id,displayName,types,location,reviews";
+
+/// Results per v1 search request (the API maximum)
+const V1_PAGE_SIZE: u32 = 20;
+
+/// Which Google Places API generation the client talks to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GoogleApiVersion {
+    /// maps.googleapis.com/maps/api/place (Nearby Search, Place Details)
+    #[default]
+    Legacy,
+    /// places.googleapis.com/v1 with X-Goog-FieldMask
+    V1,
+}
+
+impl GoogleApiVersion {
+    /// Parse the GOOGLE_API_VERSION value ("legacy" or "v1")
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "legacy" => Some(Self::Legacy),
+            "v1" => Some(Self::V1),
+            _ => None,
+        }
+    }
+}
+
+/// Response of places:searchNearby and places:searchText
+/// DOCUMENTATION: Google omits `places` entirely when nothing matched
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1SearchResponse {
+    #[serde(default)]
+    pub places: Vec<V1Place>,
+    /// Only searchText paginates
+    pub next_page_token: Option<String>,
+}
+
+/// Error envelope returned with non-2xx v1 responses
+#[derive(Debug, Deserialize)]
+pub struct V1ErrorResponse {
+    pub error: V1Error,
+}
+
+/// Error details (`status` is the gRPC code name, e.g. RESOURCE_EXHAUSTED)
+#[derive(Debug, Deserialize)]
+pub struct V1Error {
+    pub message: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Place resource of the Places API (New)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1Place {
+    pub id: String,
+    pub display_name: Option<V1LocalizedText>,
+    #[serde(default)]
+    pub types: Vec<String>,
+    pub location: Option<V1LatLng>,
+    pub formatted_address: Option<String>,
+    pub short_formatted_address: Option<String>,
+    pub address_components: Option<Vec<V1AddressComponent>>,
+    pub rating: Option<f32>,
+    pub user_rating_count: Option<i32>,
+    pub price_level: Option<String>,
+    pub business_status: Option<String>,
+    pub regular_opening_hours: Option<V1OpeningHours>,
+    pub current_opening_hours: Option<V1OpeningHours>,
+    pub national_phone_number: Option<String>,
+    pub international_phone_number: Option<String>,
+    pub website_uri: Option<String>,
+    pub google_maps_uri: Option<String>,
+    pub reviews: Option<Vec<V1Review>>,
+    pub photos: Option<Vec<V1Photo>>,
+    pub utc_offset_minutes: Option<i32>,
+    pub editorial_summary: Option<V1LocalizedText>,
+    pub dine_in: Option<bool>,
+    pub takeout: Option<bool>,
+    pub delivery: Option<bool>,
+    pub reservable: Option<bool>,
+    pub serves_breakfast: Option<bool>,
+    pub serves_brunch: Option<bool>,
+    pub serves_lunch: Option<bool>,
+    pub serves_dinner: Option<bool>,
+    pub serves_beer: Option<bool>,
+    pub serves_wine: Option<bool>,
+    pub serves_vegetarian_food: Option<bool>,
+    pub accessibility_options: Option<V1AccessibilityOptions>,
+}
+
+/// Accessibility attributes
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1AccessibilityOptions {
+    pub wheelchair_accessible_entrance: Option<bool>,
+}
+
+/// Text with its language
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1LocalizedText {
+    pub text: String,
+    pub language_code: Option<String>,
+}
+
+/// Coordinates
+#[derive(Debug, Deserialize)]
+pub struct V1LatLng {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Address component (longText/shortText instead of long_name/short_name)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1AddressComponent {
+    pub long_text: String,
+    pub short_text: String,
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// Opening hours
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1OpeningHours {
+    pub open_now: Option<bool>,
+    pub periods: Option<Vec<V1Period>>,
+    pub weekday_descriptions: Option<Vec<String>>,
+}
+
+/// Opening period
+#[derive(Debug, Deserialize)]
+pub struct V1Period {
+    pub open: Option<V1Point>,
+    pub close: Option<V1Point>,
+}
+
+/// Day and time of an opening period boundary
+#[derive(Debug, Deserialize)]
+pub struct V1Point {
+    pub day: Option<i32>,
+    pub hour: Option<u32>,
+    pub minute: Option<u32>,
+}
+
+/// User review
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1Review {
+    pub relative_publish_time_description: Option<String>,
+    pub rating: Option<i32>,
+    pub text: Option<V1LocalizedText>,
+    /// Untranslated text; `text` may be translated to the request language
+    pub original_text: Option<V1LocalizedText>,
+    pub author_attribution: Option<V1AuthorAttribution>,
+    /// RFC 3339 timestamp
+    pub publish_time: Option<String>,
+}
+
+/// Review or photo author
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1AuthorAttribution {
+    pub display_name: Option<String>,
+    pub uri: Option<String>,
+    pub photo_uri: Option<String>,
+}
+
+/// Photo; `name` ("places/{id}/photos/{ref}") is the handle for the media endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct V1Photo {
+    pub name: String,
+    pub width_px: Option<i32>,
+    pub height_px: Option<i32>,
+    pub author_attributions: Option<Vec<V1AuthorAttribution>>,
+}
+
+/// v1 PRICE_LEVEL_* enum values indexed by the legacy 0-4 scale
+const PRICE_LEVELS: [&str; 5] = [
+    "PRICE_LEVEL_FREE",
+    "PRICE_LEVEL_INEXPENSIVE",
+    "PRICE_LEVEL_MODERATE",
+    "PRICE_LEVEL_EXPENSIVE",
+    "PRICE_LEVEL_VERY_EXPENSIVE",
+];
+
+/// Map the v1 PRICE_LEVEL_* enum onto the legacy 0-4 scale
+fn price_level(value: &str) -> Option<i32> {
+    PRICE_LEVELS
+        .iter()
+        .position(|level| *level == value)
+        .map(|index| index as i32)
+}
+
+/// Legacy-style HTML attribution link for an author
+fn html_attribution(author: &V1AuthorAttribution) -> Option<String> {
+    let name = author.display_name.as_deref()?;
+    Some(match &author.uri {
+        Some(uri) => format!("<a href=\"{}\">{}</a>", uri, name),
+        None => name.to_string(),
+    })
+}
+
+impl From<V1Point> for GoogleOpeningTime {
+    fn from(point: V1Point) -> Self {
+        GoogleOpeningTime {
+            day: point.day,
+            time: Some(format!(
+                "{:02}{:02}",
+                point.hour.unwrap_or(0),
+                point.minute.unwrap_or(0)
+            )),
+        }
+    }
+}
+
+impl From<V1Review> for GoogleReview {
+    fn from(review: V1Review) -> Self {
+        let author = review.author_attribution;
+        // v1 has no reviews_no_translations switch; keep the original text instead
+        let original_language = review
+            .original_text
+            .as_ref()
+            .and_then(|t| t.language_code.clone());
+        let text = review.original_text.or(review.text);
+        GoogleReview {
+            author_name: author.as_ref().and_then(|a| a.display_name.clone()),
+            rating: review.rating,
+            language: text.as_ref().and_then(|t| t.language_code.clone()),
+            original_language,
+            text: text.map(|t| t.text),
+            time: review
+                .publish_time
+                .as_deref()
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .map(|t| t.timestamp()),
+            relative_time_description: review.relative_publish_time_description,
+            profile_photo_url: author.and_then(|a| a.photo_uri),
+        }
+    }
+}
+
+impl From<V1Photo> for GooglePhoto {
+    fn from(photo: V1Photo) -> Self {
+        GooglePhoto {
+            photo_reference: photo.name,
+            width: photo.width_px,
+            height: photo.height_px,
+            html_attributions: photo
+                .author_attributions
+                .map(|authors| authors.iter().filter_map(html_attribution).collect()),
+        }
+    }
+}
+
+impl From<V1Place> for GooglePlace {
+    fn from(place: V1Place) -> Self {
+        // Regular hours carry the schedule; current hours are requested only for openNow
+        let open_now = place
+            .current_opening_hours
+            .as_ref()
+            .and_then(|hours| hours.open_now);
+        let opening_hours = match place.regular_opening_hours {
+            Some(hours) => Some(GoogleOpeningHours {
+                open_now: open_now.or(hours.open_now),
+                weekday_text: hours.weekday_descriptions,
+                periods: hours.periods.map(|periods| {
+                    periods
+                        .into_iter()
+                        .map(|period| GoogleOpeningPeriod {
+                            open: period.open.map(Into::into),
+                            close: period.close.map(Into::into),
+                        })
+                        .collect()
+                }),
+                utc_offset_minutes: None,
+            }),
+            None => open_now.map(|open_now| GoogleOpeningHours {
+                open_now: Some(open_now),
+                weekday_text: None,
+                periods: None,
+                utc_offset_minutes: None,
+            }),
+        };
+
+        let location = place.location.unwrap_or(V1LatLng { latitude: 0.0, longitude: 0.0 });
+
+        GooglePlace {
+            place_id: place.id,
+            name: place.display_name.map(|name| name.text).unwrap_or_default(),
+            types: place.types,
+            geometry: GoogleGeometry {
+                location: GoogleLocation { lat: location.latitude, lng: location.longitude },
+            },
+            formatted_address: place.formatted_address,
+            vicinity: place.short_formatted_address,
+            address_components: place.address_components.map(|components| {
+                components
+                    .into_iter()
+                    .map(|c| GoogleAddressComponent {
+                        long_name: c.long_text,
+                        short_name: c.short_text,
+                        types: c.types,
+                    })
+                    .collect()
+            }),
+            rating: place.rating,
+            user_ratings_total: place.user_rating_count,
+            price_level: place.price_level.as_deref().and_then(price_level),
+            business_status: place.business_status,
+            opening_hours,
+            formatted_phone_number: place.national_phone_number,
+            international_phone_number: place.international_phone_number,
+            website: place.website_uri,
+            url: place.google_maps_uri,
+            reviews: place
+                .reviews
+                .map(|reviews| reviews.into_iter().map(Into::into).collect()),
+            photos: place
+                .photos
+                .map(|photos| photos.into_iter().map(Into::into).collect()),
+            utc_offset_minutes: place.utc_offset_minutes,
+            editorial_summary: place.editorial_summary.map(|summary| GoogleEditorialSummary {
+                overview: Some(summary.text),
+                language: summary.language_code,
+            }),
+            attributes: GoogleServiceAttributes {
+                dine_in: place.dine_in,
+                takeout: place.takeout,
+                delivery: place.delivery,
+                reservable: place.reservable,
+                serves_breakfast: place.serves_breakfast,
+                serves_brunch: place.serves_brunch,
+                serves_lunch: place.serves_lunch,
+                serves_dinner: place.serves_dinner,
+                serves_beer: place.serves_beer,
+                serves_wine: place.serves_wine,
+                serves_vegetarian_food: place.serves_vegetarian_food,
+                wheelchair_accessible_entrance: place
+                    .accessibility_options
+                    .and_then(|options| options.wheelchair_accessible_entrance),
+            },
+            language: None,
+        }
+    }
+}
+
+/// Request body for places:searchNearby
+/// DOCUMENTATION: Used when the search has no keyword; the circle is a hard restriction
+/// like the legacy `location` + `radius` pair. searchNearby has no open-now or price
+/// parameters, so those filters are only applied to the results by the caller
+pub fn search_nearby_body(search: &NearbySearchParams) -> Value {
+    let mut body = json!({
+        "maxResultCount": V1_PAGE_SIZE,
+        "locationRestriction": { "circle": circle(search) },
+    });
+    if let Some(place_type) = search.normalized_type() {
+        body["includedTypes"] = json!([place_type]);
+    }
+    if search.rank_by == RankBy::Distance {
+        body["rankPreference"] = json!("DISTANCE");
+    }
+    if let Some(language) = &search.language {
+        body["languageCode"] = json!(language);
+    }
+    if let Some(region) = &search.region {
+        body["regionCode"] = json!(region.to_lowercase());
+    }
+    body
+}
+
+/// Request body for places:searchText
+/// DOCUMENTATION: searchNearby has no keyword parameter, so keyword searches use
+/// searchText biased to the same circle; `page_token` continues a previous search
+pub fn search_text_body(search: &NearbySearchParams, page_token: Option<&str>) -> Value {
+    let mut body = json!({
+        "textQuery": search.normalized_keyword().unwrap_or_default(),
+        "pageSize": V1_PAGE_SIZE,
+        "locationBias": { "circle": circle(search) },
+    });
+    if let Some(place_type) = search.normalized_type() {
+        body["includedType"] = json!(place_type);
+    }
+    if let Some(language) = &search.language {
+        body["languageCode"] = json!(language);
+    }
+    if let Some(region) = &search.region {
+        body["regionCode"] = json!(region.to_lowercase());
+    }
+    if search.rank_by == RankBy::Distance {
+        body["rankPreference"] = json!("DISTANCE");
+    }
+    if search.open_now {
+        body["openNow"] = json!(true);
+    }
+    if search.min_price.is_some() || search.max_price.is_some() {
+        let min = search.min_price.unwrap_or(0).max(0) as usize;
+        let max = search.max_price.unwrap_or(4).min(4) as usize;
+        body["priceLevels"] = json!(PRICE_LEVELS.get(min..=max).unwrap_or_default());
+    }
+    if let Some(token) = page_token {
+        body["pageToken"] = json!(token);
+    }
+    body
+}
+
+fn circle(search: &NearbySearchParams) -> Value {
+    json!({
+        "center": { "latitude": search.latitude, "longitude": search.longitude },
+        "radius": search.radius as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEARCH_FIXTURE: &str = include_str!("fixtures/v1_search_nearby_restaurant.json");
+    const DETAILS_FIXTURE: &str = include_str!("fixtures/v1_place_details_restaurant.json");
+
+    #[test]
+    fn test_search_fixture_maps_to_google_places() {
+        let response: V1SearchResponse = serde_json::from_str(SEARCH_FIXTURE).unwrap();
+        assert!(response.next_page_token.is_none());
+
+        let places: Vec<GooglePlace> = response.places.into_iter().map(Into::into).collect();
+        assert_eq!(places.len(), 2);
+
+        let restaurant = &places[0];
+        assert_eq!(restaurant.place_id, "ChIJ2QdR5bEUWQ0RkN_n1cAQFh4");
+        assert_eq!(restaurant.name, "El Tubo Tapas");
+        assert_eq!(restaurant.geometry.location.lat, 41.6549281);
+        assert_eq!(restaurant.vicinity.as_deref(), Some("C. de los Estébanes, 4, Zaragoza"));
+        assert_eq!(restaurant.price_level, Some(2));
+        assert_eq!(restaurant.user_ratings_total, Some(3187));
+        assert_eq!(restaurant.opening_hours.as_ref().unwrap().open_now, Some(true));
+
+        let bar = &places[1];
+        assert!(bar.rating.is_none());
+        assert!(bar.opening_hours.is_none());
+        assert!(bar.photos.is_none());
+        assert_eq!(bar.business_status.as_deref(), Some("CLOSED_TEMPORARILY"));
+    }
+
+    #[test]
+    fn test_details_fixture_maps_photos_reviews_and_hours() {
+        let place: GooglePlace = serde_json::from_str::<V1Place>(DETAILS_FIXTURE).unwrap().into();
+
+        let components = place.address_components.as_ref().unwrap();
+        let locality = components.iter().find(|c| c.types.contains(&"locality".to_string()));
+        assert_eq!(locality.unwrap().long_name, "Zaragoza");
+        assert_eq!(place.formatted_phone_number.as_deref(), Some("976 39 76 62"));
+        assert_eq!(place.website.as_deref(), Some("https://eltubotapas.es/"));
+        assert_eq!(place.url.as_deref(), Some("https://maps.google.com/?cid=2167416046487658384"));
+
+        let hours = place.opening_hours.as_ref().unwrap();
+        assert_eq!(hours.weekday_text.as_ref().unwrap().len(), 7);
+        let first = &hours.periods.as_ref().unwrap()[0];
+        assert_eq!(first.open.as_ref().unwrap().time.as_deref(), Some("1200"));
+        assert_eq!(first.close.as_ref().unwrap().time.as_deref(), Some("2330"));
+        assert_eq!(place.utc_offset_minutes, Some(120));
+        let stored = place.opening_hours_with_offset().unwrap();
+        assert!(crate::models::OpeningHours::from_google(&stored)
+            .and_then(|schedule| schedule.is_open_now())
+            .is_some());
+
+        let reviews = place.reviews.as_ref().unwrap();
+        assert_eq!(reviews.len(), 2);
+        assert_eq!(reviews[0].author_name.as_deref(), Some("Marta G."));
+        assert_eq!(reviews[0].text.as_deref(), Some("Las mejores croquetas del Tubo."));
+        assert_eq!(reviews[0].text_language(), Some("es"));
+
+        let summary = place.editorial_summary.as_ref().unwrap();
+        assert_eq!(summary.overview.as_deref(), Some("Barra de tapas con raciones clásicas en pleno Tubo."));
+        assert_eq!(place.attributes.dine_in, Some(true));
+        assert_eq!(place.attributes.delivery, Some(false));
+        assert_eq!(place.attributes.wheelchair_accessible_entrance, Some(true));
+        assert_eq!(place.attributes.serves_brunch, None);
+        assert_eq!(reviews[0].time, Some(1_715_365_351));
+        assert_eq!(reviews[0].relative_time_description.as_deref(), Some("hace un mes"));
+        assert!(reviews[0].profile_photo_url.is_some());
+        assert!(reviews[1].text.is_none());
+
+        let photos = place.photos.as_ref().unwrap();
+        assert_eq!(photos.len(), 2);
+        assert_eq!(
+            photos[0].photo_reference,
+            "places/ChIJ2QdR5bEUWQ0RkN_n1cAQFh4/photos/AUc7tXXtHj3vYq"
+        );
+        assert_eq!(photos[0].width, Some(4032));
+        assert_eq!(
+            photos[0].html_attributions.as_ref().unwrap()[0],
+            "<a href=\"https://maps.google.com/maps/contrib/104711835/photos\">Lucía M.</a>"
+        );
+        assert!(photos[1].html_attributions.is_none());
+    }
+
+    #[test]
+    fn test_price_levels() {
+        assert_eq!(price_level("PRICE_LEVEL_FREE"), Some(0));
+        assert_eq!(price_level("PRICE_LEVEL_VERY_EXPENSIVE"), Some(4));
+        assert_eq!(price_level("PRICE_LEVEL_UNSPECIFIED"), None);
+    }
+
+    #[test]
+    fn test_request_bodies() {
+        let search = NearbySearchParams {
+            place_type: Some(" bar ".to_string()),
+            keyword: Some("vermut  solera".to_string()),
+            language: Some("es".to_string()),
+            region: Some("ES".to_string()),
+            ..NearbySearchParams::new(41.65, -0.88, 1500)
+        };
+
+        let nearby = search_nearby_body(&search);
+        assert_eq!(nearby["includedTypes"], json!(["bar"]));
+        assert_eq!(nearby["locationRestriction"]["circle"]["radius"], json!(1500.0));
+        assert_eq!(nearby["languageCode"], "es");
+        assert_eq!(nearby["regionCode"], "es");
+
+        let text = search_text_body(&search, Some("next"));
+        assert_eq!(text["textQuery"], "vermut solera");
+        assert_eq!(text["regionCode"], "es");
+        assert_eq!(text["includedType"], "bar");
+        assert_eq!(text["pageToken"], "next");
+        assert!(text.get("openNow").is_none());
+        assert!(text.get("priceLevels").is_none());
+    }
+
+    #[test]
+    fn test_bodies_rank_by_distance() {
+        let search = NearbySearchParams {
+            place_type: Some("cafe".to_string()),
+            rank_by: RankBy::Distance,
+            ..NearbySearchParams::new(41.65, -0.88, 1500)
+        };
+
+        assert_eq!(search_nearby_body(&search)["rankPreference"], "DISTANCE");
+        let text = NearbySearchParams { keyword: Some("cortado".to_string()), ..search };
+        assert_eq!(search_text_body(&text, None)["rankPreference"], "DISTANCE");
+    }
+
+    #[test]
+    fn test_text_body_filters() {
+        let search = NearbySearchParams {
+            keyword: Some("tapas".to_string()),
+            open_now: true,
+            min_price: Some(1),
+            max_price: Some(2),
+            ..NearbySearchParams::new(41.65, -0.88, 1500)
+        };
+
+        let text = search_text_body(&search, None);
+        assert_eq!(text["openNow"], json!(true));
+        assert_eq!(
+            text["priceLevels"],
+            json!(["PRICE_LEVEL_INEXPENSIVE", "PRICE_LEVEL_MODERATE"])
+        );
+
+        assert!(text.get("rankPreference").is_none());
+
+        let cheap = search_text_body(&NearbySearchParams { min_price: None, max_price: Some(0), ..search }, None);
+        assert_eq!(cheap["priceLevels"], json!(["PRICE_LEVEL_FREE"]));
+    }
+
+    #[test]
+    fn test_parse_api_version() {
+        assert_eq!(GoogleApiVersion::parse("v1"), Some(GoogleApiVersion::V1));
+        assert_eq!(GoogleApiVersion::parse(" Legacy "), Some(GoogleApiVersion::Legacy));
+        assert_eq!(GoogleApiVersion::parse("v2"), None);
+    }
+}
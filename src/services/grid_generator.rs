@@ -2,7 +2,71 @@
 // DOCUMENTATION: Geographic grid generation for city coverage
 // PURPOSE: Generate search grid cells to systematically cover a city area
 
+use crate::db::CityBoundsRepository;
+use crate::errors::PlacesError;
+use crate::services::GooglePlacesApi;
 use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Grid cell size used when neither the request nor the city sets one
+pub const DEFAULT_CELL_SIZE_KM: f64 = 1.5;
+
+/// Per-cell search radius used when neither the request nor the city sets one
+pub const DEFAULT_RADIUS_M: u32 = 1000;
+
+/// Alternative spellings accepted for registered cities (lowercase alias, name)
+const CITY_ALIASES: &[(&str, &str)] = &[("seville", "sevilla"), ("malaga", "málaga")];
+
+/// Built-in bounds merged with the CITIES_CONFIG_PATH file, keyed by lowercase name
+static CITY_REGISTRY: OnceLock<HashMap<String, RegisteredCity>> = OnceLock::new();
+
+/// City bounds known without the database
+/// DOCUMENTATION: `source` is "builtin" or "config" (CITIES_CONFIG_PATH)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredCity {
+    pub bounds: CityBounds,
+    pub source: &'static str,
+}
+
+/// Registry built at startup, or from the built-ins alone if nothing initialized it
+fn city_registry() -> &'static HashMap<String, RegisteredCity> {
+    CITY_REGISTRY.get_or_init(|| merge_city_bounds(GridGenerator::builtin_city_bounds(), Vec::new()))
+}
+
+/// Key cities by lowercase name; configured entries replace built-ins of the same name
+fn merge_city_bounds(
+    builtin: Vec<CityBounds>,
+    configured: Vec<CityBounds>,
+) -> HashMap<String, RegisteredCity> {
+    let builtin = builtin.into_iter().map(|bounds| (bounds, "builtin"));
+    let configured = configured.into_iter().map(|bounds| (bounds, "config"));
+    builtin
+        .chain(configured)
+        .map(|(bounds, source)| (bounds.name.to_lowercase(), RegisteredCity { bounds, source }))
+        .collect()
+}
+
+fn default_cell_size_km() -> f64 {
+    DEFAULT_CELL_SIZE_KM
+}
+
+fn default_radius_m() -> u32 {
+    DEFAULT_RADIUS_M
+}
+
+/// Layout of the search points covering a city
+/// DOCUMENTATION: `Square` places centers on a lat/lng grid spaced `cell_size_km`
+/// apart. `Hex` packs them in offset rows derived from the radius alone, which covers
+/// the same area with fewer overlapping circles (see `generate_hex_grid`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GridPattern {
+    #[default]
+    Square,
+    Hex,
+}
 
 /// Represents a single grid cell for searching
 /// DOCUMENTATION: Each cell represents a search area for Google Places API
@@ -19,8 +83,9 @@ pub struct GridCell {
 }
 
 /// City boundary definition
-/// DOCUMENTATION: Defines geographic boundaries for a city
-#[derive(Debug, Clone)]
+/// DOCUMENTATION: Defines geographic boundaries for a city, stored in the
+/// `city_bounds` table or compiled in (see `GridGenerator::get_city_bounds`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CityBounds {
     /// City name
     pub name: String,
@@ -32,6 +97,161 @@ pub struct CityBounds {
     pub min_lng: f64,
     /// Maximum longitude (east)
     pub max_lng: f64,
+    /// Default grid cell size in kilometers
+    #[serde(default = "default_cell_size_km")]
+    pub cell_size_km: f64,
+    /// Default search radius per cell in meters
+    #[serde(default = "default_radius_m")]
+    pub radius_m: u32,
+    /// IANA time zone of the city (e.g. "Europe/Madrid"), stored on its places so
+    /// opening hours are evaluated with daylight saving time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+impl CityBounds {
+    /// Whether a point lies inside the bounds, widened by `margin_deg` on every side
+    pub fn contains(&self, lat: f64, lng: f64, margin_deg: f64) -> bool {
+        lat >= self.min_lat - margin_deg
+            && lat <= self.max_lat + margin_deg
+            && lng >= self.min_lng - margin_deg
+            && lng <= self.max_lng + margin_deg
+    }
+
+    /// Check ranges and ordering of the bounds and grid defaults
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("city name must not be empty".to_string());
+        }
+        let lats_valid = [self.min_lat, self.max_lat]
+            .iter()
+            .all(|lat| (-90.0..=90.0).contains(lat));
+        let lngs_valid = [self.min_lng, self.max_lng]
+            .iter()
+            .all(|lng| (-180.0..=180.0).contains(lng));
+        if !lats_valid || !lngs_valid {
+            return Err("latitudes must be within ±90 and longitudes within ±180".to_string());
+        }
+        if self.min_lat >= self.max_lat || self.min_lng >= self.max_lng {
+            return Err("min_lat/min_lng must be lower than max_lat/max_lng".to_string());
+        }
+        if !self.cell_size_km.is_finite() || self.cell_size_km <= 0.0 {
+            return Err("cell_size_km must be greater than 0".to_string());
+        }
+        if self.radius_m == 0 {
+            return Err("radius_m must be greater than 0".to_string());
+        }
+        if let Some(timezone) = &self.timezone {
+            if !is_iana_timezone_name(timezone) {
+                return Err(format!("timezone must be an IANA name like Europe/Madrid, got {}", timezone));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `name` is shaped like an IANA zone ("Area/Location[/Sub]" or "UTC")
+/// DOCUMENTATION: Offsets and abbreviations ("+01:00", "CET") are rejected since they
+/// ignore daylight saving time; the database checks the zone actually exists
+pub fn is_iana_timezone_name(name: &str) -> bool {
+    let segment = |part: &str| {
+        !part.is_empty()
+            && part.starts_with(|c: char| c.is_ascii_alphabetic())
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    };
+    name == "UTC" || (name.len() <= 64 && name.contains('/') && name.split('/').all(segment))
+}
+
+/// City outline used to drop grid cells outside the municipality
+/// DOCUMENTATION: Parsed from a GeoJSON Polygon, MultiPolygon or a Feature wrapping
+/// one. Each polygon is a list of rings of (lng, lat) points; the first ring is the
+/// outline and later rings are holes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CityPolygon {
+    polygons: Vec<Vec<Vec<(f64, f64)>>>,
+}
+
+/// GeoJSON objects accepted as a city outline
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum PolygonGeoJson {
+    Polygon { coordinates: Vec<Vec<Vec<f64>>> },
+    MultiPolygon { coordinates: Vec<Vec<Vec<Vec<f64>>>> },
+    Feature { geometry: Box<PolygonGeoJson> },
+}
+
+impl CityPolygon {
+    /// Parse and validate a GeoJSON outline ([lng, lat] positions)
+    pub fn from_geojson(value: &serde_json::Value) -> Result<Self, String> {
+        let geojson: PolygonGeoJson = serde_json::from_value(value.clone())
+            .map_err(|e| format!("expected a GeoJSON Polygon or MultiPolygon: {}", e))?;
+
+        let mut geometry = geojson;
+        let raw = loop {
+            match geometry {
+                PolygonGeoJson::Polygon { coordinates } => break vec![coordinates],
+                PolygonGeoJson::MultiPolygon { coordinates } => break coordinates,
+                PolygonGeoJson::Feature { geometry: inner } => geometry = *inner,
+            }
+        };
+        if raw.is_empty() {
+            return Err("polygon has no coordinates".to_string());
+        }
+
+        let mut polygons = Vec::with_capacity(raw.len());
+        for rings in raw {
+            if rings.is_empty() {
+                return Err("polygon has no rings".to_string());
+            }
+            let mut parsed = Vec::with_capacity(rings.len());
+            for ring in rings {
+                if ring.len() < 4 {
+                    return Err("each ring needs at least 4 positions".to_string());
+                }
+                let mut points = Vec::with_capacity(ring.len());
+                for position in ring {
+                    let (lng, lat) = match position[..] {
+                        [lng, lat, ..] => (lng, lat),
+                        _ => return Err("positions must be [lng, lat]".to_string()),
+                    };
+                    if !(-180.0..=180.0).contains(&lng) || !(-90.0..=90.0).contains(&lat) {
+                        return Err(format!("position [{}, {}] is out of range", lng, lat));
+                    }
+                    points.push((lng, lat));
+                }
+                parsed.push(points);
+            }
+            polygons.push(parsed);
+        }
+
+        Ok(Self { polygons })
+    }
+
+    /// Whether a point lies inside the outline (even-odd rule, so holes are excluded)
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        self.polygons.iter().any(|rings| {
+            rings
+                .iter()
+                .filter(|ring| Self::ring_contains(ring, lat, lng))
+                .count()
+                % 2
+                == 1
+        })
+    }
+
+    /// Ray casting towards +lng: count the edges crossed
+    fn ring_contains(ring: &[(f64, f64)], lat: f64, lng: f64) -> bool {
+        let mut inside = false;
+        let mut previous = ring[ring.len() - 1];
+        for &(x, y) in ring {
+            let (px, py) = previous;
+            if (y > lat) != (py > lat) && lng < (px - x) * (lat - y) / (py - y) + x {
+                inside = !inside;
+            }
+            previous = (x, y);
+        }
+        inside
+    }
 }
 
 /// Grid generator service
@@ -105,113 +325,279 @@ impl GridGenerator {
         cells
     }
 
-    /// Get predefined city bounds
-    /// DOCUMENTATION: Returns geographic boundaries for known cities
+    /// Generate a hexagonal grid of cells for bounds
+    /// DOCUMENTATION: Coverage math. Centers form a triangular lattice whose Voronoi
+    /// cells are regular hexagons with circumradius `radius_m`; every point of a
+    /// hexagon is therefore within `radius_m` of its center. Such hexagons tile the
+    /// plane when centers in a row are `radius * √3` apart and rows are `radius * 1.5`
+    /// apart, with every other row shifted by half a spacing.
+    ///
+    /// A hexagon reaches `radius` above and below its center and `radius * √3 / 2`
+    /// (half a spacing) to each side. So the first row sits half a radius inside the
+    /// south bound and rows continue until one is within half a radius of the north
+    /// bound; likewise each row starts within half a spacing of the west bound and
+    /// ends within half a spacing of the east bound. The hexagons of the omitted
+    /// centers then lie outside the bounds, so nothing inside is left out.
+    /// The square grid needs a spacing of `radius * √2` for gap-free coverage, so
+    /// the hex lattice uses about 2.0 / 2.6 ≈ 77% as many cells.
+    ///
+    /// Longitude spacing uses the latitude closest to the equator, where a degree of
+    /// longitude is widest, so centers are never more than `radius * √3` apart on the
+    /// ground anywhere in the bounds.
+    pub fn generate_hex_grid(bounds: &CityBounds, radius_m: u32) -> Vec<GridCell> {
+        let radius_km = f64::from(radius_m) / 1000.0;
+        let lat_step = radius_km * 1.5 / 111.0;
+
+        let widest_lat = if bounds.min_lat <= 0.0 && bounds.max_lat >= 0.0 {
+            0.0
+        } else {
+            bounds.min_lat.abs().min(bounds.max_lat.abs())
+        };
+        let lng_step = radius_km * 3f64.sqrt() / (111.0 * widest_lat.to_radians().cos());
+
+        let half_radius_lat = lat_step / 3.0;
+        let half_spacing_lng = lng_step / 2.0;
+
+        let mut cells = Vec::new();
+        let mut lat = bounds.min_lat + half_radius_lat;
+        let mut row = 0;
+        loop {
+            let mut lng = if row % 2 == 0 {
+                bounds.min_lng + half_spacing_lng
+            } else {
+                bounds.min_lng
+            };
+            loop {
+                cells.push(GridCell {
+                    latitude: lat,
+                    longitude: lng,
+                    radius: radius_m,
+                    cell_id: format!("{}-{}", bounds.name, cells.len() + 1),
+                });
+                if lng >= bounds.max_lng - half_spacing_lng {
+                    break;
+                }
+                lng += lng_step;
+            }
+
+            if lat >= bounds.max_lat - half_radius_lat {
+                break;
+            }
+            lat += lat_step;
+            row += 1;
+        }
+
+        log::info!(
+            "Generated {} hex grid cells for {} (radius {} m)",
+            cells.len(),
+            bounds.name,
+            radius_m
+        );
+
+        cells
+    }
+
+    /// Get registered city bounds
+    /// DOCUMENTATION: Returns compiled-in geographic boundaries merged with the
+    /// CITIES_CONFIG_PATH entries; other cities live in the `city_bounds` table
+    /// (see `resolve_city_bounds`)
     ///
     /// # Arguments
-    /// * `city_name` - Name of the city (case-insensitive)
+    /// * `city_name` - Name of the city (case-insensitive, "Seville"/"Malaga" accepted)
     ///
     /// # Returns
     /// Option containing CityBounds if city is known
     pub fn get_city_bounds(city_name: &str) -> Option<CityBounds> {
-        let city_lower = city_name.to_lowercase();
-
-        match city_lower.as_str() {
-            // ACTIVE CITY FOR TESTING
-            "zaragoza" => Some(CityBounds {
-                name: "Zaragoza".to_string(),
-                min_lat: 41.6000, // South boundary
-                max_lat: 41.7000, // North boundary
-                min_lng: -0.9500, // West boundary
-                max_lng: -0.8200, // East boundary
-            }),
-
-            // OTHER CITIES (commented out for testing phase)
-            // Uncomment these when ready to expand to other cities
-            /*
-            "madrid" => Some(CityBounds {
-                name: "Madrid".to_string(),
-                min_lat: 40.3119,
-                max_lat: 40.5615,
-                min_lng: -3.8871,
-                max_lng: -3.5179,
-            }),
-            "barcelona" => Some(CityBounds {
-                name: "Barcelona".to_string(),
-                min_lat: 41.3200,
-                max_lat: 41.4695,
-                min_lng: 2.0524,
-                max_lng: 2.2280,
-            }),
-            "valencia" => Some(CityBounds {
-                name: "Valencia".to_string(),
-                min_lat: 39.4200,
-                max_lat: 39.5200,
-                min_lng: -0.4300,
-                max_lng: -0.3000,
-            }),
-            "sevilla" | "seville" => Some(CityBounds {
-                name: "Sevilla".to_string(),
-                min_lat: 37.3200,
-                max_lat: 37.4300,
-                min_lng: -6.0500,
-                max_lng: -5.9200,
-            }),
-            "bilbao" => Some(CityBounds {
-                name: "Bilbao".to_string(),
-                min_lat: 43.2300,
-                max_lat: 43.2900,
-                min_lng: -2.9800,
-                max_lng: -2.9000,
-            }),
-            "malaga" | "málaga" => Some(CityBounds {
-                name: "Málaga".to_string(),
-                min_lat: 36.6800,
-                max_lat: 36.7600,
-                min_lng: -4.4800,
-                max_lng: -4.3800,
-            }),
-            */
-            _ => {
-                log::warn!(
-                    "Unknown city: {}. Only Zaragoza is configured for testing.",
-                    city_name
-                );
-                None
+        Self::get_registered_city(city_name).map(|city| city.bounds.clone())
+    }
+
+    /// IANA time zone of a registered city
+    /// DOCUMENTATION: Stored on places when they are written; a `city_bounds` row with
+    /// a time zone takes precedence in the database (migration 018)
+    pub fn city_timezone(city_name: &str) -> Option<String> {
+        Self::get_registered_city(city_name).and_then(|city| city.bounds.timezone.clone())
+    }
+
+    /// Registry entry of a city, with where its bounds came from
+    pub fn get_registered_city(city_name: &str) -> Option<&'static RegisteredCity> {
+        let key = city_name.trim().to_lowercase();
+        let key = CITY_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == key)
+            .map_or(key.as_str(), |(_, name)| name);
+        city_registry().get(key)
+    }
+
+    /// Every registered city (built-in and config file), alphabetically
+    pub fn registered_cities() -> Vec<&'static RegisteredCity> {
+        let mut cities: Vec<_> = city_registry().values().collect();
+        cities.sort_by_key(|city| city.bounds.name.to_lowercase());
+        cities
+    }
+
+    /// Compiled-in bounds of the cities synced out of the box
+    fn builtin_city_bounds() -> Vec<CityBounds> {
+        let city = |name: &str, min_lat, max_lat, min_lng, max_lng| CityBounds {
+            name: name.to_string(),
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: Some("Europe/Madrid".to_string()),
+        };
+
+        vec![
+            city("Zaragoza", 41.6000, 41.7000, -0.9500, -0.8200),
+            city("Madrid", 40.3119, 40.5615, -3.8871, -3.5179),
+            city("Barcelona", 41.3200, 41.4695, 2.0524, 2.2280),
+            city("Valencia", 39.4200, 39.5200, -0.4300, -0.3000),
+            city("Sevilla", 37.3200, 37.4300, -6.0500, -5.9200),
+            city("Bilbao", 43.2300, 43.2900, -2.9800, -2.9000),
+            city("Málaga", 36.6800, 36.7600, -4.4800, -4.3800),
+        ]
+    }
+
+    /// Read the cities file named by CITIES_CONFIG_PATH
+    /// DOCUMENTATION: A JSON array of bounds objects (`name`, `min_lat`, `max_lat`,
+    /// `min_lng`, `max_lng`, optional `cell_size_km` and `radius_m`); every entry is
+    /// validated
+    pub fn load_cities_file(path: &str) -> Result<Vec<CityBounds>, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+        let cities: Vec<CityBounds> =
+            serde_json::from_str(&content).map_err(|e| format!("invalid cities file {}: {}", path, e))?;
+
+        for city in &cities {
+            city.validate().map_err(|e| format!("{} in {}: {}", city.name, path, e))?;
+        }
+        Ok(cities)
+    }
+
+    /// Merge config file cities over the built-ins; call once at startup
+    /// DOCUMENTATION: Returns false (and changes nothing) if the registry was already
+    /// built, e.g. by an earlier lookup
+    pub fn init_city_registry(configured: Vec<CityBounds>) -> bool {
+        CITY_REGISTRY
+            .set(merge_city_bounds(Self::builtin_city_bounds(), configured))
+            .is_ok()
+    }
+
+    /// Look up city bounds: the `city_bounds` table first, then the registered entries
+    /// DOCUMENTATION: A failing database lookup is logged and falls back to the
+    /// registered bounds, so syncing a built-in city does not depend on the table
+    pub async fn resolve_city_bounds(pool: &PgPool, city_name: &str) -> Option<CityBounds> {
+        match CityBoundsRepository::get_by_name(pool, city_name).await {
+            Ok(Some(bounds)) => return Some(bounds),
+            Ok(None) => {}
+            Err(e) => log::warn!("City bounds lookup failed, using built-in bounds: {}", e),
+        }
+        Self::get_city_bounds(city_name)
+    }
+
+    /// Look up the stored outline of a city
+    /// DOCUMENTATION: Missing, unreadable or invalid outlines are logged and ignored, so
+    /// the sync then covers the whole grid
+    pub async fn resolve_city_polygon(pool: &PgPool, city_name: &str) -> Option<CityPolygon> {
+        let value = match CityBoundsRepository::get_polygon(pool, city_name).await {
+            Ok(value) => value?,
+            Err(e) => {
+                log::warn!("City polygon lookup failed, syncing the whole grid: {}", e);
+                return None;
+            }
+        };
+        CityPolygon::from_geojson(&value)
+            .map_err(|e| log::warn!("Ignoring invalid polygon stored for {}: {}", city_name, e))
+            .ok()
+    }
+
+    /// Names of all cities that can be synced (stored and registered), alphabetically
+    pub async fn known_cities(pool: &PgPool) -> Vec<String> {
+        let mut names = CityBoundsRepository::list_names(pool).await.unwrap_or_default();
+        for city in Self::registered_cities() {
+            if !names.iter().any(|name| name.eq_ignore_ascii_case(&city.bounds.name)) {
+                names.push(city.bounds.name.clone());
+            }
+        }
+        names.sort_by_key(|name| name.to_lowercase());
+        names
+    }
+
+    /// Generate grid cells for bounds, using the city's defaults for missing values
+    /// DOCUMENTATION: The hex pattern derives its spacing from the radius, so
+    /// `cell_size_km` only applies to the square pattern
+    pub fn generate_for_bounds(
+        bounds: &CityBounds,
+        pattern: GridPattern,
+        cell_size_km: Option<f64>,
+        radius_m: Option<u32>,
+    ) -> Vec<GridCell> {
+        let radius_m = radius_m.unwrap_or(bounds.radius_m);
+        match pattern {
+            GridPattern::Square => {
+                Self::generate_grid(bounds, cell_size_km.unwrap_or(bounds.cell_size_km), radius_m)
             }
+            GridPattern::Hex => Self::generate_hex_grid(bounds, radius_m),
         }
     }
 
     /// Generate grid for a known city
-    /// DOCUMENTATION: Convenience method to generate grid using predefined city bounds
+    /// DOCUMENTATION: Convenience method to generate grid using stored or built-in bounds,
+    /// falling back to the geocoded viewport when a `geocoder` is given
     ///
     /// # Arguments
+    /// * `pool` - Database pool holding the `city_bounds` table
+    /// * `geocoder` - Google client used when no bounds are known (None disables geocoding)
     /// * `city_name` - Name of the city
-    /// * `cell_size_km` - Optional cell size (defaults to 1.5 km)
-    /// * `radius_m` - Optional radius (defaults to 1000 m)
+    /// * `pattern` - Square or hexagonal layout
+    /// * `cell_size_km` - Optional cell size (defaults to the city's, 1.5 km built in)
+    /// * `radius_m` - Optional radius (defaults to the city's, 1000 m built in)
     ///
     /// # Returns
-    /// Result containing grid cells or error if city is unknown
-    pub fn generate_for_city(
+    /// Grid cells, or InvalidInput listing the known cities if the city is unknown
+    pub async fn generate_for_city(
+        pool: &PgPool,
+        geocoder: Option<&dyn GooglePlacesApi>,
         city_name: &str,
+        pattern: GridPattern,
         cell_size_km: Option<f64>,
         radius_m: Option<u32>,
-    ) -> Result<Vec<GridCell>, String> {
-        let bounds = Self::get_city_bounds(city_name)
-            .ok_or_else(|| format!("Unknown city: {}", city_name))?;
-
-        let cells = Self::generate_grid(
-            &bounds,
-            cell_size_km.unwrap_or(1.5),
-            radius_m.unwrap_or(1000),
-        );
+    ) -> Result<Vec<GridCell>, PlacesError> {
+        let mut bounds = Self::resolve_city_bounds(pool, city_name).await;
+        if let (None, Some(geocoder)) = (&bounds, geocoder) {
+            bounds = geocoder.geocode_city(city_name).await?;
+            if let Some(bounds) = &bounds {
+                log::info!(
+                    "Using geocoded bounds for {}: lat {:.4}..{:.4}, lng {:.4}..{:.4}",
+                    city_name,
+                    bounds.min_lat,
+                    bounds.max_lat,
+                    bounds.min_lng,
+                    bounds.max_lng
+                );
+            }
+        }
 
-        Ok(cells)
+        let Some(bounds) = bounds else {
+            let known = Self::known_cities(pool).await;
+            return Err(Self::unknown_city_error(city_name, &known));
+        };
+
+        Ok(Self::generate_for_bounds(&bounds, pattern, cell_size_km, radius_m))
+    }
+
+    /// InvalidInput error for a city without bounds
+    fn unknown_city_error(city_name: &str, known: &[String]) -> PlacesError {
+        PlacesError::InvalidInput(format!(
+            "Unknown city: '{}'. Known cities: {}. Add bounds with PUT /admin/cities/{{name}}/bounds",
+            city_name,
+            known.join(", ")
+        ))
     }
 
     /// Calculate approximate area coverage in km²
     /// DOCUMENTATION: Estimates the geographic area covered by bounds
-    fn calculate_area_coverage(bounds: &CityBounds) -> f64 {
+    pub fn calculate_area_coverage(bounds: &CityBounds) -> f64 {
         let lat_diff = bounds.max_lat - bounds.min_lat;
         let lng_diff = bounds.max_lng - bounds.min_lng;
 
@@ -223,20 +609,68 @@ impl GridGenerator {
         lat_km * lng_km
     }
 
+    /// Split a cell into four quadrant cells
+    /// DOCUMENTATION: Used when a cell saturates Google's result cap. Each child is
+    /// centered on one quadrant of the parent's circle with radius r/√2, so the four
+    /// children still cover the whole parent circle. Child ids append ".1" to ".4".
+    pub fn subdivide(cell: &GridCell) -> Vec<GridCell> {
+        let offset_m = f64::from(cell.radius) / 2.0;
+        let lat_offset = offset_m / 111_000.0;
+        let lng_offset = offset_m / (111_000.0 * cell.latitude.to_radians().cos());
+        let radius = Self::subdivided_radius(cell.radius);
+
+        [(1.0, -1.0), (1.0, 1.0), (-1.0, -1.0), (-1.0, 1.0)]
+            .iter()
+            .enumerate()
+            .map(|(idx, (lat_sign, lng_sign))| GridCell {
+                latitude: cell.latitude + lat_sign * lat_offset,
+                longitude: cell.longitude + lng_sign * lng_offset,
+                radius,
+                cell_id: format!("{}.{}", cell.cell_id, idx + 1),
+            })
+            .collect()
+    }
+
+    /// Radius of the cells produced by `subdivide` for a parent of `radius_m`
+    pub fn subdivided_radius(radius_m: u32) -> u32 {
+        (f64::from(radius_m) * std::f64::consts::FRAC_1_SQRT_2).ceil() as u32
+    }
+
+    /// GeoJSON FeatureCollection with one Point per cell center
+    /// DOCUMENTATION: Feature properties carry `cell_id` and `radius_m`, enough to draw
+    /// the search circles on a map
+    pub fn cells_to_geojson(cells: &[GridCell]) -> serde_json::Value {
+        let features: Vec<serde_json::Value> = cells
+            .iter()
+            .map(|cell| {
+                serde_json::json!({
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [cell.longitude, cell.latitude] },
+                    "properties": { "cell_id": cell.cell_id, "radius_m": cell.radius }
+                })
+            })
+            .collect();
+
+        serde_json::json!({ "type": "FeatureCollection", "features": features })
+    }
+
     /// Optimize grid by removing cells outside actual city boundaries
-    /// DOCUMENTATION: Future enhancement - filter cells using actual city polygon
-    /// Currently returns all cells (no filtering implemented)
-    #[allow(dead_code)]
-    pub fn optimize_grid(cells: Vec<GridCell>) -> Vec<GridCell> {
-        // TODO: Implement polygon-based filtering
-        // For now, return all cells
+    /// DOCUMENTATION: Keeps the cells whose center lies inside the city polygon. A cell
+    /// centered just outside still reaches into the city, but its search would mostly
+    /// cover land the polygon excludes.
+    pub fn optimize_grid(cells: Vec<GridCell>, polygon: &CityPolygon) -> Vec<GridCell> {
         cells
+            .into_iter()
+            .filter(|cell| polygon.contains(cell.latitude, cell.longitude))
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::GooglePlacesClient;
+    use crate::test_support::{lazy_pool, test_pool, MockHttpServer};
 
     #[test]
     fn test_grid_generation() {
@@ -246,6 +680,9 @@ mod tests {
             max_lat: 40.1,
             min_lng: -3.7,
             max_lng: -3.6,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: None,
         };
 
         let cells = GridGenerator::generate_grid(&bounds, 1.5, 1000);
@@ -265,6 +702,191 @@ mod tests {
         }
     }
 
+    /// Distance in meters between a point and a cell center (equirectangular)
+    fn distance_m(lat: f64, lng: f64, cell: &GridCell) -> f64 {
+        let dy = (lat - cell.latitude) * 111_000.0;
+        let dx = (lng - cell.longitude) * 111_000.0 * lat.to_radians().cos();
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    fn hex_test_bounds() -> CityBounds {
+        CityBounds {
+            name: "Hex City".to_string(),
+            min_lat: 41.60,
+            max_lat: 41.70,
+            min_lng: -0.95,
+            max_lng: -0.80,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: None,
+        }
+    }
+
+    #[test]
+    fn test_hex_grid_covers_every_point() {
+        let bounds = hex_test_bounds();
+        let cells = GridGenerator::generate_hex_grid(&bounds, 1000);
+        assert!(cells.iter().all(|c| c.radius == 1000));
+
+        // Sample a 60 x 60 mesh, edges included
+        for i in 0..=60 {
+            for j in 0..=60 {
+                let lat = bounds.min_lat + (bounds.max_lat - bounds.min_lat) * f64::from(i) / 60.0;
+                let lng = bounds.min_lng + (bounds.max_lng - bounds.min_lng) * f64::from(j) / 60.0;
+                assert!(
+                    cells.iter().any(|c| distance_m(lat, lng, c) <= 1000.0),
+                    "({}, {}) is not covered",
+                    lat,
+                    lng
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hex_grid_needs_fewer_cells_than_square() {
+        // A ~33 km box, so edge rows do not dominate the count
+        let bounds = CityBounds { max_lat: 41.90, max_lng: -0.55, ..hex_test_bounds() };
+        let hex = GridGenerator::generate_hex_grid(&bounds, 1000);
+        // A square grid only avoids gaps when its spacing is at most radius * √2
+        let square = GridGenerator::generate_grid(&bounds, 2f64.sqrt(), 1000);
+
+        assert!(
+            (hex.len() as f64) < square.len() as f64 * 0.9,
+            "hex {} vs square {}",
+            hex.len(),
+            square.len()
+        );
+        assert_eq!(
+            GridGenerator::generate_for_bounds(&bounds, GridPattern::Hex, Some(0.5), None).len(),
+            hex.len(),
+            "cell size does not apply to the hex pattern"
+        );
+    }
+
+    #[test]
+    fn test_grid_pattern_serde() {
+        assert_eq!(serde_json::from_str::<GridPattern>("\"hex\"").unwrap(), GridPattern::Hex);
+        assert_eq!(GridPattern::default(), GridPattern::Square);
+    }
+
+    /// L-shaped outline over the bounds of `hex_test_bounds`, without the north-east
+    /// quarter (the notch)
+    fn l_shaped_polygon() -> CityPolygon {
+        CityPolygon::from_geojson(&serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [[
+                [-0.95, 41.60], [-0.80, 41.60], [-0.80, 41.65],
+                [-0.875, 41.65], [-0.875, 41.70], [-0.95, 41.70], [-0.95, 41.60]
+            ]]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_optimize_grid_drops_cells_in_the_notch() {
+        let bounds = hex_test_bounds();
+        let polygon = l_shaped_polygon();
+        let cells = GridGenerator::generate_grid(&bounds, 1.0, 700);
+        let in_notch = |c: &GridCell| c.latitude > 41.65 && c.longitude > -0.875;
+        assert!(cells.iter().any(in_notch));
+
+        let kept = GridGenerator::optimize_grid(cells.clone(), &polygon);
+
+        assert!(!kept.is_empty());
+        assert!(kept.len() < cells.len());
+        assert!(!kept.iter().any(in_notch));
+        // Cells clearly inside both arms of the L survive
+        assert!(polygon.contains(41.62, -0.82));
+        assert!(polygon.contains(41.68, -0.92));
+        assert!(!polygon.contains(41.68, -0.82));
+    }
+
+    #[test]
+    fn test_city_polygon_geojson_variants() {
+        let square = serde_json::json!([[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [0.0, 0.0]]]);
+        let hole = serde_json::json!([[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0], [1.0, 1.0]]);
+        let mut with_hole = square.clone();
+        with_hole.as_array_mut().unwrap().push(hole);
+
+        let polygon = CityPolygon::from_geojson(&serde_json::json!({
+            "type": "Feature",
+            "properties": {},
+            "geometry": { "type": "Polygon", "coordinates": with_hole }
+        }))
+        .unwrap();
+        assert!(polygon.contains(0.5, 0.5));
+        assert!(!polygon.contains(2.0, 2.0), "holes are outside");
+
+        let multi = CityPolygon::from_geojson(&serde_json::json!({
+            "type": "MultiPolygon",
+            "coordinates": [square, [[[10.0, 10.0, 200.0], [11.0, 10.0], [11.0, 11.0], [10.0, 10.0]]]]
+        }))
+        .unwrap();
+        assert!(multi.contains(10.2, 10.8));
+
+        for invalid in [
+            serde_json::json!({ "type": "Point", "coordinates": [0.0, 0.0] }),
+            serde_json::json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 1.0], [0.0, 0.0]]] }),
+            serde_json::json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [200.0, 0.0], [1.0, 1.0], [0.0, 0.0]]] }),
+            serde_json::json!({ "type": "MultiPolygon", "coordinates": [] }),
+        ] {
+            assert!(CityPolygon::from_geojson(&invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_cells_to_geojson() {
+        let cells = GridGenerator::generate_grid(&hex_test_bounds(), 5.0, 3000);
+
+        let geojson = GridGenerator::cells_to_geojson(&cells);
+
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(geojson["type"], "FeatureCollection");
+        assert_eq!(features.len(), cells.len());
+        assert_eq!(features[0]["geometry"]["coordinates"][0], cells[0].longitude);
+        assert_eq!(features[0]["geometry"]["coordinates"][1], cells[0].latitude);
+        assert_eq!(features[0]["properties"]["radius_m"], 3000);
+    }
+
+    #[test]
+    fn test_subdivide_covers_parent_cell() {
+        let parent = GridCell {
+            latitude: 41.65,
+            longitude: -0.88,
+            radius: 1000,
+            cell_id: "Zaragoza-7".to_string(),
+        };
+
+        let children = GridGenerator::subdivide(&parent);
+
+        assert_eq!(children.len(), 4);
+        assert!(children.iter().all(|c| c.radius == 708));
+        assert_eq!(children[0].cell_id, "Zaragoza-7.1");
+        assert_eq!(children[3].cell_id, "Zaragoza-7.4");
+
+        // Every point of the parent circle lies within some child circle
+        let meters = |lat: f64, lng: f64, cell: &GridCell| {
+            let dy = (lat - cell.latitude) * 111_000.0;
+            let dx = (lng - cell.longitude) * 111_000.0 * cell.latitude.to_radians().cos();
+            (dx * dx + dy * dy).sqrt()
+        };
+        for step in 0..36 {
+            let angle = f64::from(step * 10).to_radians();
+            for fraction in [0.0, 0.5, 1.0] {
+                let lat = parent.latitude + fraction * 1000.0 * angle.sin() / 111_000.0;
+                let lng = parent.longitude
+                    + fraction * 1000.0 * angle.cos() / (111_000.0 * parent.latitude.to_radians().cos());
+                assert!(
+                    children.iter().any(|c| meters(lat, lng, c) <= f64::from(c.radius) + 1.0),
+                    "point at {}° x{} uncovered",
+                    step * 10,
+                    fraction
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_city_bounds_madrid() {
         let bounds = GridGenerator::get_city_bounds("Madrid");
@@ -285,21 +907,177 @@ mod tests {
         assert!(bounds1.is_some());
         assert!(bounds2.is_some());
         assert!(bounds3.is_some());
+        assert_eq!(GridGenerator::get_city_bounds("Seville").unwrap().name, "Sevilla");
+        assert_eq!(GridGenerator::get_city_bounds("malaga").unwrap().name, "Málaga");
     }
 
     #[test]
-    fn test_generate_for_city() {
-        let result = GridGenerator::generate_for_city("Barcelona", None, None);
-        assert!(result.is_ok());
+    fn test_builtin_cities_are_valid() {
+        let cities = GridGenerator::registered_cities();
+        assert_eq!(cities.len(), 7);
+        for city in cities {
+            assert_eq!(city.source, "builtin");
+            assert!(city.bounds.validate().is_ok(), "{}", city.bounds.name);
+        }
+    }
 
-        let cells = result.unwrap();
-        assert!(cells.len() > 0);
+    #[test]
+    fn test_configured_cities_merge_over_builtins() {
+        let path = std::env::temp_dir().join(format!("cities-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            serde_json::json!([
+                { "name": "Huesca", "min_lat": 42.12, "max_lat": 42.16, "min_lng": -0.43, "max_lng": -0.38 },
+                { "name": "zaragoza", "min_lat": 41.58, "max_lat": 41.72, "min_lng": -0.97,
+                  "max_lng": -0.80, "cell_size_km": 1.0, "radius_m": 700 }
+            ])
+            .to_string(),
+        )
+        .unwrap();
+
+        let configured = GridGenerator::load_cities_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(configured[0].radius_m, DEFAULT_RADIUS_M, "optional fields default");
+
+        let merged = merge_city_bounds(GridGenerator::builtin_city_bounds(), configured);
+        assert_eq!(merged.len(), 8);
+        assert_eq!(merged["huesca"].source, "config");
+        assert_eq!(merged["zaragoza"].source, "config");
+        assert_eq!(merged["zaragoza"].bounds.radius_m, 700);
+        assert_eq!(merged["madrid"].source, "builtin");
+    }
+
+    #[test]
+    fn test_load_cities_file_rejects_invalid_entries() {
+        let path = std::env::temp_dir().join(format!("cities-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(
+            &path,
+            r#"[{ "name": "Inverted", "min_lat": 42.16, "max_lat": 42.12, "min_lng": -0.43, "max_lng": -0.38 }]"#,
+        )
+        .unwrap();
+
+        let err = GridGenerator::load_cities_file(path.to_str().unwrap()).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.contains("Inverted"));
+        assert!(GridGenerator::load_cities_file("/nonexistent/cities.json").is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_generate_for_city() {
+        let pool = test_pool().await;
+        let bounds = CityBounds {
+            name: "Barcelona".to_string(),
+            min_lat: 41.3200,
+            max_lat: 41.4695,
+            min_lng: 2.0524,
+            max_lng: 2.2280,
+            cell_size_km: 2.0,
+            radius_m: 1400,
+            timezone: None,
+        };
+        CityBoundsRepository::upsert(&pool, &bounds).await.unwrap();
+
+        let cells = GridGenerator::generate_for_city(&pool, None, "barcelona", GridPattern::Square, None, None).await.unwrap();
+        assert!(!cells.is_empty());
+        assert!(cells.iter().all(|c| c.radius == 1400));
+        assert!(GridGenerator::known_cities(&pool).await.contains(&"Barcelona".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_builtin_city_without_database() {
+        // The lazy pool never connects, so the lookup falls back to built-in bounds
+        let cells = GridGenerator::generate_for_city(&lazy_pool(), None, "ZARAGOZA", GridPattern::Square, None, None)
+            .await
+            .unwrap();
+
+        assert!(!cells.is_empty());
+        assert!(cells.iter().all(|c| c.radius == DEFAULT_RADIUS_M));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_city() {
+        let result = GridGenerator::generate_for_city(&lazy_pool(), None, "UnknownCity", GridPattern::Square, None, None).await;
+
+        match result {
+            Err(PlacesError::InvalidInput(message)) => {
+                assert!(message.contains("UnknownCity"));
+                assert!(message.contains("Known cities: Barcelona, Bilbao, Madrid"));
+                assert!(message.contains("Zaragoza"));
+            }
+            other => panic!("expected InvalidInput, got {:?}", other.map(|cells| cells.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_city_uses_geocoded_bounds() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "results": [{
+                "geometry": {
+                    "viewport": {
+                        "northeast": { "lat": 42.16, "lng": -0.38 },
+                        "southwest": { "lat": 42.12, "lng": -0.43 }
+                    }
+                }
+            }]
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_geocoding_base_url(&server.base_url);
+
+        let cells = GridGenerator::generate_for_city(&lazy_pool(), Some(&client), "Huesca", GridPattern::Square, None, None)
+            .await
+            .unwrap();
+
+        assert!(!cells.is_empty());
+        assert!(cells.iter().all(|c| (42.12..=42.16).contains(&c.latitude)));
+        assert!(cells.iter().all(|c| (-0.43..=-0.38).contains(&c.longitude)));
+        assert!(cells[0].cell_id.starts_with("Huesca-"));
+
+        // Built-in cities never reach the geocoder
+        GridGenerator::generate_for_city(&lazy_pool(), Some(&client), "Zaragoza", GridPattern::Square, None, None)
+            .await
+            .unwrap();
+        assert_eq!(server.hits(), 1);
     }
 
     #[test]
-    fn test_unknown_city() {
-        let result = GridGenerator::generate_for_city("UnknownCity", None, None);
-        assert!(result.is_err());
+    fn test_generate_for_bounds_uses_city_defaults() {
+        let bounds = CityBounds {
+            cell_size_km: 3.0,
+            radius_m: 2000,
+            ..GridGenerator::get_city_bounds("Zaragoza").unwrap()
+        };
+
+        let coarse = GridGenerator::generate_for_bounds(&bounds, GridPattern::Square, None, None);
+        assert!(coarse.iter().all(|c| c.radius == 2000));
+        let fine = GridGenerator::generate_for_bounds(&bounds, GridPattern::Square, Some(1.0), Some(700));
+        assert!(fine.iter().all(|c| c.radius == 700));
+        assert!(fine.len() > coarse.len());
+    }
+
+    #[test]
+    fn test_validate_bounds() {
+        let zaragoza = GridGenerator::get_city_bounds("Zaragoza").unwrap();
+        assert!(zaragoza.validate().is_ok());
+
+        let inverted = CityBounds { min_lat: 41.8, ..zaragoza.clone() };
+        assert!(inverted.validate().unwrap_err().contains("lower than"));
+        let out_of_range = CityBounds { max_lng: 181.0, ..zaragoza.clone() };
+        assert!(out_of_range.validate().is_err());
+        let no_radius = CityBounds { radius_m: 0, ..zaragoza.clone() };
+        assert!(no_radius.validate().is_err());
+
+        // Fixed offsets and abbreviations ignore daylight saving time
+        for timezone in ["+01:00", "CET", "Europe/", "Europe/Madrid; DROP"] {
+            let bounds = CityBounds { timezone: Some(timezone.to_string()), ..zaragoza.clone() };
+            assert!(bounds.validate().unwrap_err().contains("IANA"), "{}", timezone);
+        }
+        assert!(is_iana_timezone_name("America/Argentina/Buenos_Aires"));
+        assert!(is_iana_timezone_name("UTC"));
+        assert_eq!(GridGenerator::city_timezone("seville").as_deref(), Some("Europe/Madrid"));
+        assert_eq!(GridGenerator::city_timezone("Nowhere"), None);
     }
 
     #[test]
@@ -310,6 +1088,9 @@ mod tests {
             max_lat: 40.1,
             min_lng: -3.7,
             max_lng: -3.6,
+            cell_size_km: DEFAULT_CELL_SIZE_KM,
+            radius_m: DEFAULT_RADIUS_M,
+            timezone: None,
         };
 
         let area = GridGenerator::calculate_area_coverage(&bounds);
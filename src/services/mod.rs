@@ -3,13 +3,27 @@
 // PURPOSE: Re-export service components
 
 pub mod cache;
+pub mod google_places_api;
 pub mod google_places_client;
+pub mod google_places_v1;
 pub mod grid_generator;
 pub mod place_service;
+#[cfg(feature = "redis")]
+pub mod redis_cache;
+pub mod sync_jobs;
+pub mod sync_scheduler;
 pub mod sync_service;
+pub mod webhook;
 
 pub use cache::*;
+pub use google_places_api::GooglePlacesApi;
 pub use google_places_client::*;
+pub use google_places_v1::GoogleApiVersion;
 pub use grid_generator::*;
 pub use place_service::*;
+#[cfg(feature = "redis")]
+pub use redis_cache::*;
+pub use sync_jobs::*;
+pub use sync_scheduler::*;
 pub use sync_service::*;
+pub use webhook::*;
@@ -2,18 +2,251 @@
 // DOCUMENTATION: Business logic for places
 // PURPOSE: Intermediary between handlers and repository, handles extra logic
 
-use crate::db::{PhotoRepository, PlaceRepository, ReviewRepository};
-use crate::errors::PlacesError;
+use crate::config::Config;
+use crate::db::{
+    HardDeleteReport, NameMatch, PhotoRepository, PlaceRepository, ReviewRepository, SimilarityWeights, DEFAULT_SEARCH_LANGUAGE,
+};
+use crate::errors::{GoogleApiError, PlacesError};
 use crate::models::{
-    CreatePlaceRequest, Place, PlaceDetailResponse, PlaceResponse, SearchQuery, SearchResponse,
-    UpdatePlaceRequest, FrontendPlaceResponse, FrontendSearchResponse, FrontendCustomAttributes,
-    FrontendPhotoResponse, FrontendReviewResponse,
+    AutocompleteQuery, AutocompleteResponse, BatchPlacesResponse, BoundingBox, CityPlacesQuery, ClusterQuery, ClustersResponse, CreatePlaceRequest, FeaturedPlacesQuery,
+    FeaturedPlacesResponse, NearbyPlacesQuery, NearbyPlacesResponse, Place, PlacePhotosQuery, PlacePhotosResponse, PHOTO_SOURCES,
+    AddPlacePhotoRequest, CreatePhotoRequest, PhotoResponse, PlacePhotoListResponse, ReorderPhotosRequest, MANUAL_PHOTO_SOURCE, PlaceCluster, SearchFacets, PlaceDetailResponse, PlaceResponse, SearchQuery, SearchResponse,
+    SimilarPlacesQuery, SimilarPlacesResponse, SuggestQuery, SuggestResponse, UpdatePlaceRequest, FrontendPlaceResponse, FrontendSearchResponse, FrontendCustomAttributes,
+    FrontendPhotoResponse, FrontendReviewResponse, OpeningHours, SearchSort, SearchStatus, SortOrder,
+};
+use crate::services::{GooglePlacesApi, GooglePlacesClient, PersistedPlace, PlacesCache, SyncService};
+use crate::services::google_places_client::{
+    DetailsOptions, FindPlaceInputType, GooglePlace, GooglePhoto, GoogleReview, LocationBias, NearbySearchParams, RankBy,
+    MAX_NEARBY_RADIUS_M, MAX_NEARBY_RESULTS,
 };
-use crate::services::GooglePlacesClient;
-use crate::services::google_places_client::{GooglePlace, GooglePhoto, GoogleReview};
+use chrono::{DateTime, Utc};
+use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use serde::Serialize;
 use sqlx::PgPool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use uuid::Uuid;
 
+/// Cache scope whose generation invalidates searches without a city filter
+const ALL_CITIES_SCOPE: &str = "*";
+
+/// Shortest autocomplete input worth sending to Google (and worth suggesting for)
+pub const MIN_AUTOCOMPLETE_INPUT_CHARS: usize = 2;
+
+/// Photos attached to each search result with include_photos=true
+pub const MAX_SEARCH_PHOTOS_PER_PLACE: i64 = 5;
+
+/// Most values per facet in search responses (include_facets=true)
+pub const MAX_FACET_VALUES: i64 = 50;
+
+/// Highest map zoom level accepted by GET /places/clusters
+pub const MAX_CLUSTER_ZOOM: u8 = 22;
+
+/// Cluster cells along a 256 px map tile at the requested zoom
+pub const CLUSTER_CELLS_PER_TILE: f64 = 4.0;
+
+/// Most cluster cells along either side of the viewport, which bounds the
+/// response to about MAX_CLUSTER_GRID² clusters at any zoom
+pub const MAX_CLUSTER_GRID: f64 = 32.0;
+
+/// Places per page of GET /places/featured when limit is not given
+pub const DEFAULT_FEATURED_LIMIT: i64 = 12;
+
+/// Most places per page of GET /places/featured; larger limits are capped
+pub const MAX_FEATURED_LIMIT: i64 = 50;
+
+/// Suggestions returned by GET /places/suggest when limit is not given
+pub const DEFAULT_SUGGEST_LIMIT: i64 = 8;
+
+/// Most suggestions returned by GET /places/suggest; larger limits are capped
+pub const MAX_SUGGEST_LIMIT: i64 = 20;
+
+/// Autocomplete bias radius when lat/lon are given without radius_m
+const DEFAULT_AUTOCOMPLETE_BIAS_M: u32 = 5_000;
+
+/// Largest accepted `min_rating_count`; busier places than this barely exist
+pub const MAX_MIN_RATING_COUNT: i32 = 100_000;
+
+/// Most identifiers accepted by POST /places/batch
+pub const MAX_BATCH_IDS: usize = 100;
+
+/// Radius of GET /places/{id}/nearby when radius_km is not given
+pub const DEFAULT_NEARBY_RADIUS_KM: f64 = 1.0;
+
+/// Largest radius_km of GET /places/{id}/nearby; larger values are capped
+pub const MAX_NEARBY_RADIUS_KM: f64 = 10.0;
+
+/// Places returned by GET /places/{id}/nearby when limit is not given
+pub const DEFAULT_NEARBY_LIMIT: i64 = 10;
+
+/// Most places returned by GET /places/{id}/nearby; larger limits are capped
+pub const MAX_NEARBY_LIMIT: i64 = 50;
+
+/// Photos per page of GET /places/{id}/photos when limit is not given
+pub const DEFAULT_PLACE_PHOTOS_LIMIT: i64 = 20;
+
+/// Most photos per page of GET /places/{id}/photos; larger limits are capped
+pub const MAX_PLACE_PHOTOS_LIMIT: i64 = 100;
+
+/// Radius around the place searched by GET /places/{id}/similar, in kilometers
+pub const SIMILAR_RADIUS_KM: f64 = 3.0;
+
+/// Places returned by GET /places/{id}/similar when limit is not given
+pub const DEFAULT_SIMILAR_LIMIT: i64 = 6;
+
+/// Most places returned by GET /places/{id}/similar; larger limits are capped
+pub const MAX_SIMILAR_LIMIT: i64 = 20;
+
+/// Scoring of GET /places/{id}/similar: each shared cuisine type counts twice as much
+/// as a shared main category, a rating star as much as a shared category, and every
+/// kilometer away costs one point
+pub const SIMILARITY_WEIGHTS: SimilarityWeights = SimilarityWeights {
+    shared_cuisine: 2.0,
+    shared_category: 1.0,
+    rating: 1.0,
+    rating_count: 0.25,
+    distance_km: 1.0,
+};
+
+/// Position in Google results carried by `cursor` and `next_cursor`
+/// DOCUMENTATION: "{offset}:{token}" resumes `offset` results into the Google page
+/// fetched with `token` (the first page when empty), so windows smaller than a
+/// Google page skip nothing. A bare page token starts at the top of its page
+#[derive(Debug, Clone, PartialEq)]
+struct GoogleCursor {
+    offset: usize,
+    token: Option<String>,
+}
+
+impl GoogleCursor {
+    fn parse(cursor: &str) -> Self {
+        if let Some((offset, token)) = cursor.split_once(':') {
+            if let Ok(offset) = offset.parse::<usize>() {
+                let token = (!token.is_empty()).then(|| token.to_string());
+                return GoogleCursor { offset, token };
+            }
+        }
+        GoogleCursor { offset: 0, token: Some(cursor.to_string()) }
+    }
+
+    fn encode(&self) -> String {
+        match (self.offset, &self.token) {
+            (0, Some(token)) => token.clone(),
+            (offset, token) => format!("{}:{}", offset, token.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+/// One identifier of a batch lookup
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BatchIdentifier {
+    /// Place UUID (case-insensitive)
+    Id(Uuid),
+    /// Anything that is not a UUID is a Google place id
+    GooglePlaceId(String),
+}
+
+/// Name similarity above which fuzzy search matches, when SEARCH_FUZZY_THRESHOLD is not set
+pub const DEFAULT_FUZZY_THRESHOLD: f64 = 0.3;
+
+/// Text matching settings of database search
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchSettings {
+    /// Text search configuration for `q` (SEARCH_LANGUAGE)
+    pub language: String,
+    /// Minimum name similarity of the fuzzy fallback (SEARCH_FUZZY_THRESHOLD)
+    pub fuzzy_threshold: f64,
+}
+
+impl SearchSettings {
+    /// Settings from SEARCH_LANGUAGE and SEARCH_FUZZY_THRESHOLD
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            language: config.search_language.clone(),
+            fuzzy_threshold: config.search_fuzzy_threshold,
+        }
+    }
+}
+
+impl Default for SearchSettings {
+    fn default() -> Self {
+        Self {
+            language: DEFAULT_SEARCH_LANGUAGE.to_string(),
+            fuzzy_threshold: DEFAULT_FUZZY_THRESHOLD,
+        }
+    }
+}
+
+/// Process-wide counters for the freshness-aware Google place resolver
+static RESOLVED_FROM_DB: AtomicU64 = AtomicU64::new(0);
+static RESOLVED_FROM_GOOGLE: AtomicU64 = AtomicU64::new(0);
+static ON_DEMAND_IMPORTS: AtomicU64 = AtomicU64::new(0);
+
+/// Rate limiter shared by on-demand Google imports
+pub type OnDemandImportLimiter = DefaultDirectRateLimiter;
+
+/// Dependencies for importing unknown Google place ids on demand
+/// DOCUMENTATION: Built per request by the handler; `None` disables the fallback
+#[derive(Clone, Copy)]
+pub struct OnDemandImport<'a> {
+    pub google_client: &'a dyn GooglePlacesApi,
+    pub limiter: &'a OnDemandImportLimiter,
+    /// Autocomplete session closed by the Place Details call
+    pub session_token: Option<&'a str>,
+}
+
+impl<'a> OnDemandImport<'a> {
+    /// Enable on-demand imports only when the flag is on and a Google key is configured
+    pub fn from_config(
+        config: &Config,
+        google_client: &'a dyn GooglePlacesApi,
+        limiter: &'a OnDemandImportLimiter,
+    ) -> Option<Self> {
+        (config.on_demand_import_enabled && !config.google_places_api_key.is_empty()).then_some(
+            Self {
+                google_client,
+                limiter,
+                session_token: None,
+            },
+        )
+    }
+
+    /// Attach the autocomplete session token the client used to find this place
+    pub fn with_session_token(mut self, session_token: Option<&'a str>) -> Self {
+        self.session_token = session_token;
+        self
+    }
+}
+
+/// Outcome of resolving a Google place
+/// DOCUMENTATION: Either the stored record was fresh enough, or Google was
+/// called and the record refreshed (the raw Google data is kept for callers that need it)
+#[derive(Debug)]
+pub enum ResolvedPlace {
+    /// Served from the database without a Place Details call
+    Stored(Place),
+    /// Refreshed from Google Place Details and upserted
+    Refreshed {
+        place: Place,
+        google_place: Box<GooglePlace>,
+        created: bool,
+    },
+}
+
+/// Resolver counters exposed in admin stats
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolverStats {
+    /// Lookups answered from a fresh database record
+    pub served_from_db: u64,
+    /// Lookups that required a Place Details call
+    pub refreshed: u64,
+    /// Unknown Google place ids imported from GET /places/{id}
+    pub on_demand_imports: u64,
+}
+
 pub struct PlaceService;
 
 impl PlaceService {
@@ -27,29 +260,257 @@ impl PlaceService {
         Ok(place.to_response())
     }
 
+    /// Find an active place by ID (UUID or Google Place ID)
+    pub async fn find_place(pool: &PgPool, identifier: &str) -> Result<Place, PlacesError> {
+        Self::lookup_place(pool, identifier, false).await
+    }
+
+    /// Resolve a UUID or Google place id, optionally including deactivated places
+    pub async fn lookup_place(pool: &PgPool, identifier: &str, include_inactive: bool) -> Result<Place, PlacesError> {
+        // Try to parse as UUID first
+        if let Ok(uuid) = Uuid::parse_str(identifier) {
+            PlaceRepository::fetch_by_id(pool, uuid, include_inactive).await
+        } else {
+            // If not a UUID, treat as Google Place ID
+            PlaceRepository::fetch_by_google_place_id(pool, identifier, include_inactive).await
+        }
+    }
+
     /// Get a place by ID (UUID or Google Place ID)
+    /// DOCUMENTATION: `include_inactive` (admin reads) also finds deactivated places
+    /// and reports `is_active` in the response
     pub async fn get_place_by_id_or_google_id(
         pool: &PgPool,
         identifier: &str,
+        include_inactive: bool,
     ) -> Result<PlaceDetailResponse, PlacesError> {
-        // Try to parse as UUID first
-        let place = if let Ok(uuid) = Uuid::parse_str(identifier) {
-            PlaceRepository::get_by_id(pool, uuid).await?
-        } else {
-            // If not a UUID, treat as Google Place ID
-            PlaceRepository::get_by_google_place_id(pool, identifier).await?
-        };
-        
+        let place = Self::lookup_place(pool, identifier, include_inactive).await?;
+
         let photos = PhotoRepository::get_photos_by_place(pool, &place.id, None).await?;
         let reviews = ReviewRepository::get_reviews_by_place(pool, &place.id, None).await?;
 
+        let mut response = place.to_response();
+        if include_inactive {
+            response.is_active = Some(place.is_active.unwrap_or(true));
+        }
+
         Ok(PlaceDetailResponse {
-            place: place.to_response(),
+            place: response,
             photos: photos.into_iter().map(|p| p.to_response()).collect(),
             reviews: reviews.into_iter().map(|r| r.to_response()).collect(),
         })
     }
 
+    /// Split batch identifiers into UUIDs and Google place ids
+    /// DOCUMENTATION: Identifiers are trimmed; repeats (including the same UUID in
+    /// another case) are dropped, keeping request order. Returns the distinct
+    /// identifiers with the text first given for each. Blank identifiers and more than
+    /// MAX_BATCH_IDS identifiers (counted before dropping repeats) are rejected
+    pub fn parse_batch_identifiers(raw: &[String]) -> Result<Vec<(BatchIdentifier, String)>, PlacesError> {
+        if raw.len() > MAX_BATCH_IDS {
+            return Err(PlacesError::ValidationError(format!(
+                "at most {} ids can be requested at once, got {}",
+                MAX_BATCH_IDS,
+                raw.len()
+            )));
+        }
+
+        let mut identifiers: Vec<(BatchIdentifier, String)> = Vec::with_capacity(raw.len());
+        for value in raw {
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(PlacesError::ValidationError("ids must not contain blank identifiers".to_string()));
+            }
+            let identifier = match Uuid::parse_str(value) {
+                Ok(id) => BatchIdentifier::Id(id),
+                Err(_) => BatchIdentifier::GooglePlaceId(value.to_string()),
+            };
+            if !identifiers.iter().any(|(seen, _)| *seen == identifier) {
+                identifiers.push((identifier, value.to_string()));
+            }
+        }
+        Ok(identifiers)
+    }
+
+    /// Look up several places at once by UUID and/or Google place id
+    /// DOCUMENTATION: One query for every identifier (see PlaceRepository::get_many).
+    /// A place requested both by UUID and by Google place id is returned once
+    pub async fn get_places_batch(pool: &PgPool, raw_ids: &[String]) -> Result<BatchPlacesResponse, PlacesError> {
+        let identifiers = Self::parse_batch_identifiers(raw_ids)?;
+        if identifiers.is_empty() {
+            return Ok(BatchPlacesResponse { data: Vec::new(), missing: Vec::new() });
+        }
+
+        let mut ids = Vec::new();
+        let mut google_place_ids = Vec::new();
+        for (identifier, _) in &identifiers {
+            match identifier {
+                BatchIdentifier::Id(id) => ids.push(*id),
+                BatchIdentifier::GooglePlaceId(google_place_id) => google_place_ids.push(google_place_id.clone()),
+            }
+        }
+        let places = PlaceRepository::get_many(pool, &ids, &google_place_ids).await?;
+
+        let mut data: Vec<PlaceResponse> = Vec::with_capacity(places.len());
+        let mut missing = Vec::new();
+        for (identifier, text) in identifiers {
+            let found = places.iter().find(|place| match &identifier {
+                BatchIdentifier::Id(id) => place.id == *id,
+                BatchIdentifier::GooglePlaceId(google_place_id) => {
+                    place.google_place_id.as_deref() == Some(google_place_id.as_str())
+                }
+            });
+            match found {
+                Some(place) if data.iter().any(|response| response.id == place.id) => {}
+                Some(place) => data.push(place.to_response()),
+                None => missing.push(text),
+            }
+        }
+
+        Ok(BatchPlacesResponse { data, missing })
+    }
+
+    /// Get a place by ID, importing unknown Google place ids on demand
+    /// DOCUMENTATION: Falls back to Place Details when the identifier looks like a
+    /// Google place id that is not stored yet. Returns the response and whether an
+    /// import happened (callers invalidate search caches for the place's city).
+    /// `include_inactive` is passed on to get_place_by_id_or_google_id
+    pub async fn get_or_import_place(
+        pool: &PgPool,
+        identifier: &str,
+        import: Option<OnDemandImport<'_>>,
+        include_inactive: bool,
+    ) -> Result<(PlaceDetailResponse, bool), PlacesError> {
+        match Self::get_place_by_id_or_google_id(pool, identifier, include_inactive).await {
+            Ok(response) => Ok((response, false)),
+            Err(PlacesError::NotFound(missing)) => match import {
+                Some(import) if Self::looks_like_google_place_id(identifier) => {
+                    let place = Self::import_google_place(pool, import, identifier).await?;
+                    Ok((Self::get_place(pool, place.id).await?, true))
+                }
+                _ => Err(PlacesError::NotFound(missing)),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetch a Google place by id and persist it with photos and reviews
+    /// DOCUMENTATION: Google NOT_FOUND surfaces as GoogleApiError::NotFound (404)
+    async fn import_google_place(
+        pool: &PgPool,
+        import: OnDemandImport<'_>,
+        google_place_id: &str,
+    ) -> Result<Place, PlacesError> {
+        if import.limiter.check().is_err() {
+            log::warn!("On-demand import rate limit reached, rejecting {}", google_place_id);
+            return Err(PlacesError::RateLimitExceeded);
+        }
+
+        let persisted = Self::fetch_and_persist_google_place(
+            pool,
+            import.google_client,
+            google_place_id,
+            import.session_token,
+            None,
+        )
+        .await?;
+
+        ON_DEMAND_IMPORTS.fetch_add(1, Ordering::Relaxed);
+        log::info!(
+            "Imported Google place {} on demand ({} reviews, {} photos)",
+            google_place_id,
+            persisted.reviews_created,
+            persisted.photos_created
+        );
+
+        Ok(persisted.place)
+    }
+
+    /// Import one place found by name or phone number
+    /// DOCUMENTATION: Resolves the top Find Place candidate, then stores it exactly
+    /// like the sync path (details, upsert, reviews, photos). No candidate is a
+    /// NotFound; `created == false` means the place already existed and was refreshed
+    pub async fn import_place_by_name(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        input: &str,
+        input_type: FindPlaceInputType,
+        city: Option<&str>,
+    ) -> Result<PersistedPlace, PlacesError> {
+        let candidate = google_client
+            .find_place(input, input_type)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| PlacesError::NotFound(format!("No Google candidate for '{}'", input.trim())))?;
+
+        log::info!("Find Place resolved '{}' to {}", input.trim(), candidate.place_id);
+
+        Self::fetch_and_persist_google_place(pool, google_client, &candidate.place_id, None, city)
+            .await
+    }
+
+    /// Pull in or refresh exactly one Google place
+    /// DOCUMENTATION: Stores the place like the sync loop (details, upsert, reviews,
+    /// photos) whether or not it lies inside known city bounds. Google NOT_FOUND is
+    /// reported as an ExternalApiError (502): the id came from the caller, so the
+    /// upstream answer is the failure, not a missing local resource
+    pub async fn sync_google_place(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        google_place_id: &str,
+        city: Option<&str>,
+    ) -> Result<PersistedPlace, PlacesError> {
+        Self::fetch_and_persist_google_place(pool, google_client, google_place_id, None, city)
+            .await
+            .map_err(|e| match e {
+                PlacesError::GoogleApi(GoogleApiError::NotFound(_)) => PlacesError::ExternalApiError(format!(
+                    "Google Places returned NOT_FOUND for place id '{}'",
+                    google_place_id
+                )),
+                e => e,
+            })
+    }
+
+    /// Fetch Place Details and persist the place with photos and reviews
+    /// DOCUMENTATION: `city` overrides the city parsed from the address components
+    async fn fetch_and_persist_google_place(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        google_place_id: &str,
+        session_token: Option<&str>,
+        city: Option<&str>,
+    ) -> Result<PersistedPlace, PlacesError> {
+        let options = DetailsOptions { session_token, ..Default::default() };
+        let google_place = google_client.get_place_details_with(google_place_id, options).await?;
+        let city = match city {
+            Some(city) => city.to_string(),
+            None => Self::extract_city_and_district(&google_place.address_components, None)
+                .0
+                .ok_or_else(|| {
+                    PlacesError::InvalidInput(format!(
+                        "Could not determine city for Google place {}",
+                        google_place_id
+                    ))
+                })?,
+        };
+
+        let mut persisted =
+            SyncService::persist_google_place(pool, google_client, &google_place, &city).await?;
+        persisted.place.last_verified_at = Some(PlaceRepository::mark_verified(pool, persisted.place.id).await?);
+        Ok(persisted)
+    }
+
+    /// Heuristic for identifiers that are Google place ids
+    /// DOCUMENTATION: Google ids are long URL-safe base64-like tokens (e.g. "ChIJ...")
+    fn looks_like_google_place_id(identifier: &str) -> bool {
+        (20..=512).contains(&identifier.len())
+            && Uuid::parse_str(identifier).is_err()
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
     /// Get a place by ID (UUID only)
     pub async fn get_place(pool: &PgPool, id: Uuid) -> Result<PlaceDetailResponse, PlacesError> {
         let place = PlaceRepository::get_by_id(pool, id).await?;
@@ -63,105 +524,992 @@ impl PlaceService {
         })
     }
 
-    /// Search for places (from database)
-    pub async fn search_places(
+    /// Resolve a Google place, preferring fresh stored data
+    /// DOCUMENTATION: Returns the stored record when last_verified_at is within
+    /// `freshness`; otherwise calls Place Details, upserts and marks the record verified.
+    /// `fallback_city` is used for places we have never stored when Google's
+    /// address components don't name a locality
+    pub async fn resolve_google_place(
         pool: &PgPool,
-        query: SearchQuery,
-    ) -> Result<SearchResponse, PlacesError> {
-        let (places, total_count) = PlaceRepository::search(pool, &query).await?;
+        google_client: &dyn GooglePlacesApi,
+        google_place_id: &str,
+        fallback_city: Option<&str>,
+        freshness: chrono::Duration,
+    ) -> Result<ResolvedPlace, PlacesError> {
+        let existing = match PlaceRepository::get_by_google_place_id(pool, google_place_id).await {
+            Ok(place) if Self::is_fresh(place.last_verified_at, Utc::now(), freshness) => {
+                RESOLVED_FROM_DB.fetch_add(1, Ordering::Relaxed);
+                log::debug!("Serving fresh stored data for {}", google_place_id);
+                return Ok(ResolvedPlace::Stored(place));
+            }
+            Ok(place) => Some(place),
+            Err(PlacesError::NotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
 
-        // Calculate pagination metadata
-        let limit = query.limit.unwrap_or(20).max(1);
+        let google_place = google_client.get_place_details(google_place_id).await?;
+
+        let city = match existing.as_ref() {
+            Some(place) => place.city.clone(),
+            None => Self::extract_city_and_district(&google_place.address_components, fallback_city)
+                .0
+                .ok_or_else(|| {
+                    PlacesError::InvalidInput(format!(
+                        "Could not determine city for Google place {}",
+                        google_place_id
+                    ))
+                })?,
+        };
+
+        let create_req = GooglePlacesClient::to_create_request(&google_place, &city);
+        let upserted = PlaceRepository::upsert_google_place(pool, &create_req).await?;
+        let (mut place, created) = (upserted.place, upserted.created);
+        place.last_verified_at = Some(PlaceRepository::mark_verified(pool, place.id).await?);
+
+        RESOLVED_FROM_GOOGLE.fetch_add(1, Ordering::Relaxed);
+        log::debug!("Refreshed {} from Google Place Details", google_place_id);
+
+        Ok(ResolvedPlace::Refreshed {
+            place,
+            google_place: Box::new(google_place),
+            created,
+        })
+    }
+
+    /// Whether a record verified at `last_verified_at` is still within `freshness`
+    /// Records verified exactly `freshness` ago still count as fresh
+    fn is_fresh(
+        last_verified_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+        freshness: chrono::Duration,
+    ) -> bool {
+        matches!(last_verified_at, Some(verified) if now - verified <= freshness)
+    }
+
+    /// Build the limiter for on-demand imports (at least one per minute)
+    pub fn on_demand_limiter(per_minute: u32) -> OnDemandImportLimiter {
+        let per_minute = NonZeroU32::new(per_minute).unwrap_or(NonZeroU32::MIN);
+        RateLimiter::direct(Quota::per_minute(per_minute))
+    }
+
+    /// Snapshot of resolver counters
+    pub fn resolver_stats() -> ResolverStats {
+        ResolverStats {
+            served_from_db: RESOLVED_FROM_DB.load(Ordering::Relaxed),
+            refreshed: RESOLVED_FROM_GOOGLE.load(Ordering::Relaxed),
+            on_demand_imports: ON_DEMAND_IMPORTS.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Grid cell size in degrees for clustering `bbox` at `zoom`
+    /// DOCUMENTATION: A quarter of a map tile at the zoom, enlarged when the viewport
+    /// would need more than MAX_CLUSTER_GRID cells on a side (a zoom that does not
+    /// match the viewport)
+    pub fn cluster_cell_size(bbox: &BoundingBox, zoom: u8) -> f64 {
+        let tile_deg = 360.0 / 2f64.powi(i32::from(zoom));
+        let span = (bbox.ne_lat - bbox.sw_lat).max(bbox.ne_lng - bbox.sw_lng);
+        (tile_deg / CLUSTER_CELLS_PER_TILE).max(span / MAX_CLUSTER_GRID)
+    }
+
+    /// Active places in a map viewport grouped into grid clusters
+    /// DOCUMENTATION: Used for GET /places/clusters. Single-place clusters carry
+    /// the place summary, fetched in one batch
+    pub async fn place_clusters(pool: &PgPool, query: ClusterQuery) -> Result<ClustersResponse, PlacesError> {
+        let zoom = query
+            .zoom
+            .ok_or_else(|| PlacesError::ValidationError("zoom is required".to_string()))?;
+        if zoom > MAX_CLUSTER_ZOOM {
+            return Err(PlacesError::ValidationError(format!(
+                "zoom must be between 0 and {}, got {}",
+                MAX_CLUSTER_ZOOM, zoom
+            )));
+        }
+        let search = SearchQuery {
+            ne_lat: query.ne_lat,
+            ne_lng: query.ne_lng,
+            sw_lat: query.sw_lat,
+            sw_lng: query.sw_lng,
+            type_: query.type_,
+            ..Default::default()
+        };
+        let bbox = search
+            .bounding_box()
+            .map_err(PlacesError::ValidationError)?
+            .ok_or_else(|| PlacesError::ValidationError("sw_lat, sw_lng, ne_lat and ne_lng are required".to_string()))?;
+
+        let cell_size_deg = Self::cluster_cell_size(&bbox, zoom);
+        let rows = PlaceRepository::clusters(pool, &search, cell_size_deg).await?;
+
+        let single_ids: Vec<Uuid> = rows.iter().filter_map(|row| row.place_id).collect();
+        let places: HashMap<Uuid, Place> = if single_ids.is_empty() {
+            HashMap::new()
+        } else {
+            PlaceRepository::get_many(pool, &single_ids, &[])
+                .await?
+                .into_iter()
+                .map(|place| (place.id, place))
+                .collect()
+        };
+
+        Ok(ClustersResponse {
+            zoom,
+            cell_size_deg,
+            total_count: rows.iter().map(|row| row.count).sum(),
+            clusters: rows
+                .into_iter()
+                .map(|row| PlaceCluster {
+                    latitude: row.latitude,
+                    longitude: row.longitude,
+                    count: row.count,
+                    place: row.place_id.and_then(|id| places.get(&id)).map(|place| place.to_response()),
+                })
+                .collect(),
+        })
+    }
+
+    /// Random sample of well-rated places for discovery feeds
+    /// DOCUMENTATION: Used for GET /places/featured. Only places with at least
+    /// `min_rating` and `min_rating_count` (FEATURED_MIN_RATING[_COUNT]) qualify.
+    /// Without a seed a random one is drawn and returned, so clients can page
+    /// through the same shuffle
+    pub async fn featured_places(
+        pool: &PgPool,
+        query: FeaturedPlacesQuery,
+        min_rating: f64,
+        min_rating_count: u32,
+    ) -> Result<FeaturedPlacesResponse, PlacesError> {
+        let seed = query.seed.unwrap_or_else(|| Uuid::new_v4().as_u128() as u32);
         let page = query.page.unwrap_or(1).max(1);
-        let has_more = total_count > (page * limit);
+        let limit = query.limit.unwrap_or(DEFAULT_FEATURED_LIMIT).clamp(1, MAX_FEATURED_LIMIT);
+        let offset = (page - 1).saturating_mul(limit);
+        let city = query.city.as_deref().map(str::trim).filter(|city| !city.is_empty());
 
-        Ok(SearchResponse {
-            data: places.iter().map(|p| p.to_response()).collect(),
-            total_count,
+        let filters = SearchQuery {
+            type_: query.type_.clone(),
+            min_rating: Some(min_rating as f32),
+            min_rating_count: Some(i32::try_from(min_rating_count).unwrap_or(i32::MAX)),
+            ..Default::default()
+        };
+        let places = PlaceRepository::featured(pool, city, &filters, seed, limit, offset).await?;
+
+        Ok(FeaturedPlacesResponse {
+            seed,
             page,
             limit,
-            has_more,
+            data: places.iter().map(|p| p.to_response()).collect(),
         })
     }
 
-    /// Search places directly from Google Places API
-    /// DOCUMENTATION: Fetches places from Google Places API and transforms to frontend format
-    pub async fn search_places_from_google(
-        google_client: &GooglePlacesClient,
-        query: SearchQuery,
-    ) -> Result<FrontendSearchResponse, PlacesError> {
-        // Extract search parameters
-        let latitude = query.lat;
-        let longitude = query.lon;
-        let radius_meters = query.radius_km.map(|km| (km * 1000.0) as u32).unwrap_or(5000);
-        let place_type = query.type_.as_deref();
-        let keyword = query.q.as_deref();
-        
-        // Validate that we have coordinates for nearby search
-        let (lat, lon) = match (latitude, longitude) {
-            (Some(lat), Some(lon)) => (lat, lon),
+    /// Suggest stored place names for typeahead
+    /// DOCUMENTATION: Free alternative to `autocomplete` for places we already have.
+    /// Rejects inputs shorter than MIN_AUTOCOMPLETE_INPUT_CHARS; a blank city is ignored
+    pub async fn suggest_places(pool: &PgPool, query: SuggestQuery) -> Result<SuggestResponse, PlacesError> {
+        let q = query.q.trim();
+        if q.chars().count() < MIN_AUTOCOMPLETE_INPUT_CHARS {
+            return Err(PlacesError::ValidationError(format!(
+                "q must be at least {} characters",
+                MIN_AUTOCOMPLETE_INPUT_CHARS
+            )));
+        }
+        let city = query.city.as_deref().map(str::trim).filter(|city| !city.is_empty());
+        let limit = query.limit.unwrap_or(DEFAULT_SUGGEST_LIMIT).clamp(1, MAX_SUGGEST_LIMIT);
+
+        let data = PlaceRepository::suggest(pool, q, city, limit).await?;
+        Ok(SuggestResponse { data })
+    }
+
+    /// Autocomplete place names through Google
+    /// DOCUMENTATION: Rejects inputs shorter than MIN_AUTOCOMPLETE_INPUT_CHARS before
+    /// spending a request; lat/lon (both or neither) bias predictions towards an area
+    pub async fn autocomplete(
+        google_client: &dyn GooglePlacesApi,
+        query: AutocompleteQuery,
+    ) -> Result<AutocompleteResponse, PlacesError> {
+        let input = query.input.trim();
+        if input.chars().count() < MIN_AUTOCOMPLETE_INPUT_CHARS {
+            return Err(PlacesError::ValidationError(format!(
+                "input must be at least {} characters",
+                MIN_AUTOCOMPLETE_INPUT_CHARS
+            )));
+        }
+
+        let bias = match (query.lat, query.lon) {
+            (Some(latitude), Some(longitude)) => Some(LocationBias {
+                latitude,
+                longitude,
+                radius_m: query
+                    .radius_m
+                    .unwrap_or(DEFAULT_AUTOCOMPLETE_BIAS_M)
+                    .min(MAX_NEARBY_RADIUS_M),
+            }),
+            (None, None) => None,
             _ => {
-                // If no coordinates, try to use city name for text search
-                // For now, return error - we need coordinates for nearby search
                 return Err(PlacesError::ValidationError(
-                    "Latitude and longitude are required for search".to_string()
-                ));
+                    "lat and lon must be provided together".to_string(),
+                ))
             }
         };
 
-        // Perform nearby search
-        let google_places = google_client
-            .nearby_search(lat, lon, radius_meters, place_type, keyword)
+        if let Some(region) = query.region.as_deref() {
+            GooglePlacesClient::validate_region(region)?;
+        }
+
+        let predictions = google_client
+            .autocomplete(input, query.session.as_deref(), bias.as_ref(), query.region.as_deref())
             .await?;
+        Ok(AutocompleteResponse { predictions })
+    }
 
-        // Transform places to frontend format
-        // ⚠️ OPTIMIZATION: Removed get_place_details call to reduce API usage by 50%
-        // The nearby_search already provides sufficient data for listing
-        // Details (photos, reviews) are fetched only when user clicks on a place
-        let mut frontend_places = Vec::new();
-        for google_place in google_places.iter() {
-            // Transform to frontend format using data from nearby_search
-            let frontend_place = Self::transform_google_place_to_frontend(
-                &google_place,
-                google_client,
-                latitude,
-                longitude,
-                query.city.as_deref(),
-            )?;
-            frontend_places.push(frontend_place);
+    /// Search for places (from database)
+    /// DOCUMENTATION: `q` uses full-text search in the configured language; when that
+    /// finds nothing the search is retried by name similarity (pg_trgm), so typos still
+    /// match. `fuzzy=true` goes straight to similarity and `fuzzy=false` disables the
+    /// fallback. The response's `fuzzy` flag tells which matching produced the results
+    pub async fn search_places(
+        pool: &PgPool,
+        query: SearchQuery,
+        settings: &SearchSettings,
+    ) -> Result<SearchResponse, PlacesError> {
+        GooglePlacesClient::validate_price_range(query.min_price, query.max_price)?;
+        GooglePlacesClient::validate_suitable_for(&query.suitable_for_tags())?;
+        Self::validate_min_rating_count(query.min_rating_count)?;
+        query.tag_filters().map_err(PlacesError::ValidationError)?;
+        let bounding_box = query.bounding_box().map_err(PlacesError::ValidationError)?;
+        if bounding_box.is_some() && query.radius_km.is_some() {
+            return Err(PlacesError::ValidationError(
+                "the ne_*/sw_* viewport and radius_km cannot be combined".to_string(),
+            ));
+        }
+        if Self::parse_sort(&query)? == Some(SearchSort::Distance) && (query.lat.is_none() || query.lon.is_none()) {
+            return Err(PlacesError::ValidationError(
+                "sort=distance requires lat and lon".to_string(),
+            ));
         }
+        let filters = query.applied_filters();
+        let has_text = query.q.as_deref().is_some_and(|q| !q.trim().is_empty());
+        let full_text = NameMatch::FullText { language: &settings.language };
+        let fuzzy_match = NameMatch::Fuzzy { threshold: settings.fuzzy_threshold };
 
-        // Calculate pagination
-        let per_page = query.limit.unwrap_or(20).max(1).min(100);
-        let page = query.page.unwrap_or(1).max(1);
-        let total = frontend_places.len() as i64;
-        let total_pages = (total as f64 / per_page as f64).ceil() as i64;
+        let (places, total_count, fuzzy) = if has_text && query.fuzzy == Some(true) {
+            let (places, total_count) = PlaceRepository::search_matching(pool, &query, fuzzy_match).await?;
+            (places, total_count, true)
+        } else {
+            let (places, total_count) = PlaceRepository::search_matching(pool, &query, full_text).await?;
+            if total_count == 0 && has_text && query.fuzzy != Some(false) {
+                log::debug!("No full-text matches, retrying search by name similarity");
+                let (places, total_count) = PlaceRepository::search_matching(pool, &query, fuzzy_match).await?;
+                (places, total_count, true)
+            } else {
+                (places, total_count, false)
+            }
+        };
 
-        // Apply pagination
-        let start = ((page - 1) * per_page) as usize;
-        let end = (start + per_page as usize).min(frontend_places.len());
-        let paginated_places = if start < frontend_places.len() {
-            frontend_places[start..end].to_vec()
+        // Facets count the matches of the name matching that produced the results
+        let facets = if query.include_facets != Some(true) {
+            None
+        } else if total_count == 0 {
+            Some(SearchFacets::default())
         } else {
-            Vec::new()
+            let name_match = if fuzzy { fuzzy_match } else { full_text };
+            Some(PlaceRepository::search_facets(pool, &query, name_match, MAX_FACET_VALUES).await?)
         };
 
-        Ok(FrontendSearchResponse {
-            places: paginated_places,
-            total,
+        let mut data: Vec<PlaceResponse> = places.iter().map(|p| p.to_response()).collect();
+        if query.include_inactive {
+            for (response, place) in data.iter_mut().zip(&places) {
+                response.is_active = Some(place.is_active.unwrap_or(true));
+            }
+        }
+        if query.include_photos == Some(true) {
+            Self::attach_photos(pool, &mut data).await?;
+        }
+
+        // Calculate pagination metadata
+        let limit = query.limit.unwrap_or(20).max(1);
+        let page = query.page.unwrap_or(1).max(1);
+        let has_more = total_count > (page * limit);
+
+        Ok(SearchResponse {
+            data,
+            total_count,
             page,
-            per_page,
-            total_pages,
+            limit,
+            has_more,
+            filters,
+            fuzzy,
+            facets,
         })
     }
 
-    /// Transform Google Place to Frontend format
-    fn transform_google_place_to_frontend(
-        google_place: &GooglePlace,
-        google_client: &GooglePlacesClient,
-        search_lat: Option<f64>,
-        search_lon: Option<f64>,
-        city: Option<&str>,
+    /// Fill in `photos` of each place with its first photos
+    /// DOCUMENTATION: One query for the whole page (PhotoRepository::get_photos_by_places);
+    /// places without photos get an empty list
+    async fn attach_photos(pool: &PgPool, places: &mut [PlaceResponse]) -> Result<(), PlacesError> {
+        if places.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<Uuid> = places.iter().map(|place| place.id).collect();
+        let mut photos = PhotoRepository::get_photos_by_places(pool, &ids, MAX_SEARCH_PHOTOS_PER_PLACE).await?;
+        for place in places {
+            let place_photos = photos.remove(&place.id).unwrap_or_default();
+            place.photos = Some(place_photos.iter().map(|photo| photo.to_response()).collect());
+        }
+        Ok(())
+    }
+
+    /// List the active places of a city, paginated
+    /// DOCUMENTATION: Exact, case-insensitive city match (see PlaceRepository::list_by_city)
+    /// in the search response envelope. There is no search point, so sort=distance is
+    /// rejected
+    pub async fn list_city_places(
+        pool: &PgPool,
+        city: &str,
+        query: CityPlacesQuery,
+    ) -> Result<SearchResponse, PlacesError> {
+        let city = city.trim();
+        if city.is_empty() {
+            return Err(PlacesError::ValidationError("city must not be empty".to_string()));
+        }
+        let query = query.to_search_query(city);
+        if Self::parse_sort(&query)? == Some(SearchSort::Distance) {
+            return Err(PlacesError::ValidationError(
+                "sort=distance is not available when listing a city".to_string(),
+            ));
+        }
+
+        let filters = query.applied_filters();
+        let (places, total_count) = PlaceRepository::list_by_city(pool, city, &query).await?;
+
+        let limit = query.limit.unwrap_or(20).clamp(1, 100);
+        let page = query.page.unwrap_or(1).max(1);
+        Ok(SearchResponse {
+            data: places.iter().map(|p| p.to_response()).collect(),
+            total_count,
+            page,
+            limit,
+            has_more: total_count > page * limit,
+            filters,
+            fuzzy: false,
+            facets: None,
+        })
+    }
+
+    /// Radius and limit of a nearby query after defaults and caps
+    /// DOCUMENTATION: Values above MAX_NEARBY_RADIUS_KM / MAX_NEARBY_LIMIT are capped
+    /// and limits below 1 raised to 1; a radius that is not a positive number is rejected
+    pub fn nearby_bounds(query: &NearbyPlacesQuery) -> Result<(f64, i64), PlacesError> {
+        let radius_km = query.radius_km.unwrap_or(DEFAULT_NEARBY_RADIUS_KM);
+        if !radius_km.is_finite() || radius_km <= 0.0 {
+            return Err(PlacesError::ValidationError(format!(
+                "radius_km must be a positive number, got {}",
+                radius_km
+            )));
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_NEARBY_LIMIT).clamp(1, MAX_NEARBY_LIMIT);
+        Ok((radius_km.min(MAX_NEARBY_RADIUS_KM), limit))
+    }
+
+    /// Active places around a place, closest first
+    /// DOCUMENTATION: Used for GET /places/{id}/nearby. `identifier` is a UUID or a
+    /// Google place id of a stored active place; the place itself is left out
+    pub async fn nearby_places(
+        pool: &PgPool,
+        identifier: &str,
+        query: NearbyPlacesQuery,
+    ) -> Result<NearbyPlacesResponse, PlacesError> {
+        let (radius_km, limit) = Self::nearby_bounds(&query)?;
+        let anchor = Self::find_place(pool, identifier.trim()).await?;
+
+        let search = SearchQuery {
+            lat: Some(anchor.latitude),
+            lon: Some(anchor.longitude),
+            radius_km: Some(radius_km),
+            type_: query.type_,
+            sort: Some("distance".to_string()),
+            ..Default::default()
+        };
+        let places = PlaceRepository::nearby(pool, anchor.id, &search, limit).await?;
+
+        Ok(NearbyPlacesResponse {
+            place_id: anchor.id,
+            radius_km,
+            limit,
+            data: places.iter().map(|p| p.to_response()).collect(),
+        })
+    }
+
+    /// One page of the photos of a place
+    /// DOCUMENTATION: Used for GET /places/{id}/photos. `identifier` is a UUID or a
+    /// Google place id of a stored active place (404 otherwise). `source` is matched
+    /// case-insensitively against PHOTO_SOURCES; a blank source lists every source
+    pub async fn place_photos(
+        pool: &PgPool,
+        identifier: &str,
+        query: PlacePhotosQuery,
+    ) -> Result<PlacePhotosResponse, PlacesError> {
+        let source = query
+            .source
+            .as_deref()
+            .map(|source| source.trim().to_lowercase())
+            .filter(|source| !source.is_empty());
+        if let Some(source) = &source {
+            if !PHOTO_SOURCES.contains(&source.as_str()) {
+                return Err(PlacesError::ValidationError(format!(
+                    "source must be one of {}, got {}",
+                    PHOTO_SOURCES.join(", "),
+                    source
+                )));
+            }
+        }
+        let limit = query.limit.unwrap_or(DEFAULT_PLACE_PHOTOS_LIMIT).clamp(1, MAX_PLACE_PHOTOS_LIMIT);
+        let page = query.page.unwrap_or(1).max(1);
+        let place = Self::find_place(pool, identifier.trim()).await?;
+
+        let total_count = PhotoRepository::count_photos_by_place(pool, &place.id, source.as_deref()).await?;
+        let photos =
+            PhotoRepository::get_photo_page(pool, &place.id, source.as_deref(), limit, (page - 1) * limit).await?;
+
+        Ok(PlacePhotosResponse {
+            place_id: place.id,
+            data: photos.iter().map(|photo| photo.to_response()).collect(),
+            total_count,
+            page,
+            limit,
+            has_more: total_count > page * limit,
+        })
+    }
+
+    /// Attach a photo to a place by URL
+    /// DOCUMENTATION: Used for POST /places/{id}/photos. The photo is stored with source
+    /// "manual"; a primary photo demotes the place's other primaries in the same
+    /// transaction. Returns the photo and the place's city, whose cached searches show
+    /// the primary photo
+    pub async fn add_place_photo(
+        pool: &PgPool,
+        identifier: &str,
+        request: AddPlacePhotoRequest,
+    ) -> Result<(PhotoResponse, String), PlacesError> {
+        let place = Self::find_place(pool, identifier.trim()).await?;
+
+        let photo = PhotoRepository::add_photo_to_place(
+            pool,
+            &CreatePhotoRequest {
+                place_id: place.id,
+                source: MANUAL_PHOTO_SOURCE.to_string(),
+                source_photo_reference: None,
+                photo_url: request.photo_url,
+                thumbnail_url: request.thumbnail_url,
+                width: None,
+                height: None,
+                attribution: request.attribution,
+                is_primary: Some(request.is_primary.unwrap_or(false)),
+                display_order: request.display_order,
+            },
+        )
+        .await?;
+
+        Ok((photo.to_response(), place.city))
+    }
+
+    /// Delete one photo of a place
+    /// DOCUMENTATION: Used for DELETE /places/{place_id}/photos/{photo_id}. `identifier`
+    /// is a UUID or a Google place id, inactive places included; a photo of another
+    /// place is NotFound. Returns the place's city, whose cached searches may show the
+    /// photo
+    pub async fn delete_place_photo(pool: &PgPool, identifier: &str, photo_id: Uuid) -> Result<String, PlacesError> {
+        let place = Self::lookup_place(pool, identifier.trim(), true).await?;
+        let promoted = PhotoRepository::delete_photo(pool, &place.id, &photo_id).await?;
+        if let Some(promoted) = promoted {
+            log::info!("Photo {} of place {} promoted to primary", promoted, place.id);
+        }
+        Ok(place.city)
+    }
+
+    /// Make one photo the primary photo of a place
+    /// DOCUMENTATION: Used for PUT /places/{place_id}/photos/{photo_id}/primary.
+    /// `identifier` is a UUID or a Google place id, inactive places included; a photo
+    /// of another place is NotFound and changes nothing. Returns the place's photos
+    /// and its city, whose cached searches show the primary photo
+    pub async fn set_primary_place_photo(
+        pool: &PgPool,
+        identifier: &str,
+        photo_id: Uuid,
+    ) -> Result<(PlacePhotoListResponse, String), PlacesError> {
+        let place = Self::lookup_place(pool, identifier.trim(), true).await?;
+        PhotoRepository::set_primary_photo(pool, &place.id, &photo_id).await?;
+
+        let photos = PhotoRepository::get_photos_by_place(pool, &place.id, None).await?;
+        let response = PlacePhotoListResponse {
+            place_id: place.id,
+            data: photos.iter().map(|photo| photo.to_response()).collect(),
+        };
+        Ok((response, place.city))
+    }
+
+    /// Set the display order of a place's photos
+    /// DOCUMENTATION: Used for PUT /places/{place_id}/photos/order. `identifier` is a
+    /// UUID or a Google place id, inactive places included. Ids of other places'
+    /// photos are a ValidationError (see PhotoRepository::reorder_photos). Returns the
+    /// reordered photos and the place's city, whose cached searches may show the
+    /// first photo
+    pub async fn reorder_place_photos(
+        pool: &PgPool,
+        identifier: &str,
+        request: ReorderPhotosRequest,
+    ) -> Result<(PlacePhotoListResponse, String), PlacesError> {
+        let place = Self::lookup_place(pool, identifier.trim(), true).await?;
+        let photos = PhotoRepository::reorder_photos(pool, &place.id, &request.photo_ids).await?;
+
+        let response = PlacePhotoListResponse {
+            place_id: place.id,
+            data: photos.iter().map(|photo| photo.to_response()).collect(),
+        };
+        Ok((response, place.city))
+    }
+
+    /// Active places similar to a place, best match first
+    /// DOCUMENTATION: Used for GET /places/{id}/similar. Candidates are active places
+    /// of the same type within SIMILAR_RADIUS_KM sharing a cuisine type or main
+    /// category, scored with SIMILARITY_WEIGHTS;
+    /// `identifier` is a UUID or a Google place id of a stored active place
+    pub async fn similar_places(
+        pool: &PgPool,
+        identifier: &str,
+        query: SimilarPlacesQuery,
+    ) -> Result<SimilarPlacesResponse, PlacesError> {
+        let limit = query.limit.unwrap_or(DEFAULT_SIMILAR_LIMIT).clamp(1, MAX_SIMILAR_LIMIT);
+        let anchor = Self::find_place(pool, identifier.trim()).await?;
+
+        let search = SearchQuery {
+            lat: Some(anchor.latitude),
+            lon: Some(anchor.longitude),
+            radius_km: Some(SIMILAR_RADIUS_KM),
+            type_: Some(anchor.type_field.clone()),
+            ..Default::default()
+        };
+        let places = PlaceRepository::similar(pool, &anchor, &search, &SIMILARITY_WEIGHTS, limit).await?;
+
+        Ok(SimilarPlacesResponse {
+            place_id: anchor.id,
+            limit,
+            data: places.iter().map(|p| p.to_response()).collect(),
+        })
+    }
+
+    /// Search for places (from database) with response caching
+    /// DOCUMENTATION: Serves identical queries from PlacesCache for `ttl`.
+    /// Keys embed the generation of the query's city scope, which mutations bump.
+    /// City filters are substring matches, so a query for "zara" is not invalidated
+    /// by a change in "Zaragoza" - that staleness is bounded by the TTL
+    pub async fn search_places_cached(
+        pool: &PgPool,
+        cache: &PlacesCache,
+        query: SearchQuery,
+        ttl: Duration,
+        settings: &SearchSettings,
+    ) -> Result<SearchResponse, PlacesError> {
+        let fetch_query = query.clone();
+        Self::cached_search(cache, &query, ttl, || Self::search_places(pool, fetch_query, settings)).await
+    }
+
+    /// Cache lookup/store around an arbitrary search fetcher
+    async fn cached_search<F, Fut>(
+        cache: &PlacesCache,
+        query: &SearchQuery,
+        ttl: Duration,
+        fetch: F,
+    ) -> Result<SearchResponse, PlacesError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<SearchResponse, PlacesError>>,
+    {
+        let scope = query.cache_scope();
+        let generation = cache.generation(&scope).await;
+        let cache_key = format!("db_search:{}:{}:{}", scope, generation, query.normalized_key());
+
+        if let Some(response) = cache.get_typed::<SearchResponse>(&cache_key).await {
+            return Ok(response);
+        }
+
+        let response = fetch().await?;
+        cache.set_typed_with_ttl(cache_key, &response, ttl).await;
+
+        Ok(response)
+    }
+
+    /// Invalidate cached database searches affected by a change in `city`
+    /// DOCUMENTATION: Bumps the city generation plus the all-cities generation
+    pub async fn invalidate_search_cache(cache: &PlacesCache, city: &str) {
+        cache.bump_generation(&city.trim().to_lowercase()).await;
+        cache.bump_generation(ALL_CITIES_SCOPE).await;
+    }
+
+    /// Search places directly from Google Places API
+    /// DOCUMENTATION: Fetches places from Google Places API and transforms to frontend format
+    ///
+    /// Google returns at most 20 results per call and 60 in total via next_page_token.
+    /// Enough pages are fetched to cover the requested page window; `total` counts what
+    /// was actually retrieved and `capped` marks it as a lower bound (more results exist,
+    /// or the 60-result ceiling was reached). Clients continue past the window with
+    /// `next_cursor`, which is passed back as `cursor` and returns up to `limit` results
+    /// from a single Google page (see GoogleCursor). Pages past the ceiling are empty
+    /// and cost no Google requests.
+    pub async fn search_places_from_google(
+        google_client: &dyn GooglePlacesApi,
+        query: SearchQuery,
+    ) -> Result<FrontendSearchResponse, PlacesError> {
+        // Extract search parameters
+        let latitude = query.lat;
+        let longitude = query.lon;
+        let radius_meters = Self::google_radius_m(query.radius_km)?;
+        
+        // Validate that we have coordinates for nearby search
+        let (lat, lon) = match (latitude, longitude) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => {
+                // If no coordinates, try to use city name for text search
+                // For now, return error - we need coordinates for nearby search
+                return Err(PlacesError::ValidationError(
+                    "Latitude and longitude are required for search".to_string()
+                ));
+            }
+        };
+
+        if let Some(language) = query.language.as_deref() {
+            GooglePlacesClient::validate_language(language)?;
+        }
+        if let Some(region) = query.region.as_deref() {
+            GooglePlacesClient::validate_region(region)?;
+        }
+        GooglePlacesClient::validate_price_range(query.min_price, query.max_price)?;
+        GooglePlacesClient::validate_suitable_for(&query.suitable_for_tags())?;
+        Self::validate_min_rating_count(query.min_rating_count)?;
+
+        let sort = Self::parse_sort(&query)?;
+        if sort == Some(SearchSort::CreatedAt) {
+            return Err(PlacesError::ValidationError(
+                "sort=created_at is only available for database search (source=db)".to_string(),
+            ));
+        }
+        if query.tags.as_ref().is_some_and(|tags| !tags.is_empty()) {
+            return Err(PlacesError::ValidationError(
+                "tags is only available for database search (source=db)".to_string(),
+            ));
+        }
+        if [query.ne_lat, query.ne_lng, query.sw_lat, query.sw_lng].iter().any(Option::is_some) {
+            return Err(PlacesError::ValidationError(
+                "ne_lat, ne_lng, sw_lat and sw_lng are only available for database search (source=db)".to_string(),
+            ));
+        }
+        let filters = query.applied_filters();
+
+        let mut search = NearbySearchParams {
+            place_type: Self::google_place_type(&query),
+            keyword: query.q.clone(),
+            language: query.language.clone(),
+            region: query.region.clone(),
+            open_now: query.open_now == Some(true),
+            min_price: query.min_price,
+            max_price: query.max_price,
+            ..NearbySearchParams::new(lat, lon, radius_meters)
+        };
+        // sort=distance asks Google for the nearest places instead of re-sorting the
+        // most prominent ones; without a type or keyword Google cannot rank by
+        // distance, so those searches keep the radius and are sorted in memory
+        if sort == Some(SearchSort::Distance)
+            && (search.normalized_type().is_some() || search.normalized_keyword().is_some())
+        {
+            search.rank_by = RankBy::Distance;
+        }
+
+        let per_page = query.limit.unwrap_or(20).clamp(1, 100);
+        let page = query.page.unwrap_or(1).max(1);
+        let cursor = query.cursor.as_deref().map(GoogleCursor::parse);
+        // Unsorted, unfiltered results keep Google's pages, so positions map to cursors
+        let google_order = sort.is_none() && !Self::has_post_filters(&query);
+
+        // A cursor continues from a previous response: one Google page.
+        // Filters and sorts need every reachable result so pages stay consistent
+        let window_start = match &cursor {
+            Some(cursor) => cursor.offset,
+            None => (page - 1).saturating_mul(per_page) as usize,
+        };
+        let wanted = if cursor.is_some() {
+            usize::MAX
+        } else if google_order {
+            window_start.saturating_add(per_page as usize).min(MAX_NEARBY_RESULTS)
+        } else {
+            MAX_NEARBY_RESULTS
+        };
+
+        // Google serves at most MAX_NEARBY_RESULTS, so later pages are empty without
+        // spending requests on them
+        if cursor.is_none() && window_start >= MAX_NEARBY_RESULTS {
+            return Ok(FrontendSearchResponse {
+                status: SearchStatus::Ok,
+                places: Vec::new(),
+                total: 0,
+                page,
+                per_page,
+                total_pages: 0,
+                capped: true,
+                has_more: false,
+                next_cursor: None,
+                filters,
+            });
+        }
+
+        // Fetch pages until the window is covered, Google runs out, or the ceiling is
+        // hit, remembering where each Google page starts and the token that fetched it
+        let mut google_places = Vec::new();
+        let mut google_pages: Vec<(usize, Option<String>)> = Vec::new();
+        let mut page_token = cursor.as_ref().and_then(|cursor| cursor.token.clone());
+        let mut any_results = false;
+        loop {
+            let result_page = google_client
+                .nearby_search_page(&search, page_token.as_deref())
+                .await?;
+
+            google_pages.push((google_places.len(), page_token.take()));
+            any_results |= !result_page.outcome.is_empty();
+            google_places.extend(result_page.outcome.into_places());
+            page_token = result_page.next_page_token;
+
+            if cursor.is_some()
+                || page_token.is_none()
+                || google_places.len() >= wanted
+                || google_places.len() >= MAX_NEARBY_RESULTS
+            {
+                break;
+            }
+        }
+
+        let status = if any_results {
+            SearchStatus::Ok
+        } else {
+            log::info!("Google search at ({}, {}) returned no places", lat, lon);
+            SearchStatus::ZeroResults
+        };
+
+        // Transform places to frontend format
+        // ⚠️ OPTIMIZATION: Removed get_place_details call to reduce API usage by 50%
+        // The nearby_search already provides sufficient data for listing
+        // Details (photos, reviews) are fetched only when user clicks on a place
+        let mut frontend_places = Vec::new();
+        for google_place in google_places.iter() {
+            // Transform to frontend format using data from nearby_search
+            let frontend_place = Self::transform_google_place_to_frontend(
+                google_place,
+                google_client,
+                latitude,
+                longitude,
+                query.city.as_deref(),
+            )?;
+            frontend_places.push(frontend_place);
+        }
+
+        let capped = page_token.is_some() || frontend_places.len() >= MAX_NEARBY_RESULTS;
+        let frontend_places = Self::filter_and_sort_places(frontend_places, &query, sort);
+
+        // Pagination over what was retrieved and survived the filters
+        let retrieved = frontend_places.len();
+        let total = retrieved as i64;
+        let total_pages = (total as f64 / per_page as f64).ceil() as i64;
+        let window_end = window_start.saturating_add(per_page as usize).min(retrieved);
+
+        // A window ending inside the retrieved results resumes within its Google page;
+        // one ending at their end continues with Google's next page
+        let next_cursor = if window_end < retrieved {
+            match &cursor {
+                Some(cursor) => Some(GoogleCursor { offset: window_end, token: cursor.token.clone() }),
+                None if google_order => google_pages
+                    .iter()
+                    .rev()
+                    .find(|(first, _)| *first <= window_end)
+                    .map(|(first, token)| GoogleCursor { offset: window_end - first, token: token.clone() }),
+                // Sorted or filtered results only continue by page number
+                None => None,
+            }
+        } else if cursor.is_some() || window_start < retrieved {
+            page_token.map(|token| GoogleCursor { offset: 0, token: Some(token) })
+        } else {
+            // Beyond the available data: empty page, no misleading continuation
+            None
+        };
+        let has_more = window_end < retrieved || next_cursor.is_some();
+
+        let paginated_places = frontend_places
+            .into_iter()
+            .skip(window_start)
+            .take(per_page as usize)
+            .collect();
+
+        Ok(FrontendSearchResponse {
+            status,
+            places: paginated_places,
+            total,
+            page,
+            per_page,
+            total_pages,
+            capped,
+            has_more,
+            next_cursor: next_cursor.map(|cursor| cursor.encode()),
+            filters,
+        })
+    }
+
+    /// Parse and validate the `sort` and `order` parameters
+    fn parse_sort(query: &SearchQuery) -> Result<Option<SearchSort>, PlacesError> {
+        if let Some(order) = query.order.as_deref() {
+            SortOrder::parse(order).ok_or_else(|| {
+                PlacesError::ValidationError(format!("order must be asc or desc; got '{}'", order))
+            })?;
+        }
+        match query.sort.as_deref() {
+            Some(value) => Ok(Some(SearchSort::parse(value).ok_or_else(|| {
+                PlacesError::ValidationError(format!(
+                    "sort must be one of distance, rating, rating_count, created_at, name; got '{}'",
+                    value
+                ))
+            })?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Google type sent for a search
+    /// DOCUMENTATION: A single known cuisine on an untyped or restaurant search becomes
+    /// Google's typed category (e.g. "italian_restaurant") so Google returns matching
+    /// restaurants instead of the nearest ones of any cuisine
+    fn google_place_type(query: &SearchQuery) -> Option<String> {
+        let cuisines = query.known_cuisines();
+        let restaurant_search = query
+            .type_
+            .as_deref()
+            .map(str::trim)
+            .is_none_or(|t| t.is_empty() || t.eq_ignore_ascii_case("restaurant"));
+
+        match cuisines.as_slice() {
+            [cuisine] if restaurant_search => {
+                GooglePlacesClient::cuisine_google_type(cuisine).map(str::to_string)
+            }
+            _ => query.type_.clone(),
+        }
+    }
+
+    /// Whether the query carries filters that run on the retrieved results
+    /// DOCUMENTATION: Open-now and price are also sent to Google, but v1 searchNearby
+    /// ignores them and places without a price level slip through, so they are
+    /// re-applied in memory like the rating filters
+    fn has_post_filters(query: &SearchQuery) -> bool {
+        query.min_rating.is_some()
+            || query.min_rating_count.is_some()
+            || query.open_now == Some(true)
+            || query.min_price.is_some()
+            || query.max_price.is_some()
+            || !query.known_cuisines().is_empty()
+            || !query.suitable_for_tags().is_empty()
+            || !query.included_categories().is_empty()
+            || !query.excluded_categories().is_empty()
+    }
+
+    /// Apply category, cuisine, audience, rating, open-now and price filters, then the requested sort
+    /// DOCUMENTATION: Categories match the Google types of each result. Cuisines and suitable_for tags are derived like the stored
+    /// `cuisine_types` and `suitable_for`, since Google knows neither. Google has no rating parameters and does not apply open-now and
+    /// price on every endpoint, so they run in memory on the transformed results. Places missing the filtered field are
+    /// excluded, matching the SQL filters. Sorting is stable: without a sort, and
+    /// among ties, Google's relevance order is preserved.
+    pub fn filter_and_sort_places(
+        places: Vec<FrontendPlaceResponse>,
+        query: &SearchQuery,
+        sort: Option<SearchSort>,
+    ) -> Vec<FrontendPlaceResponse> {
+        let cuisines = query.known_cuisines();
+        let suitable_for = query.suitable_for_tags();
+        let categories = query.included_categories();
+        let excluded = query.excluded_categories();
+        let mut places: Vec<FrontendPlaceResponse> = places
+            .into_iter()
+            .filter(|p| categories.is_empty() || p.types.iter().any(|t| categories.contains(t)))
+            .filter(|p| !p.types.iter().any(|t| excluded.contains(t)))
+            .filter(|p| {
+                cuisines.is_empty()
+                    || GooglePlacesClient::extract_cuisine_types(&p.types, &p.name)
+                        .iter()
+                        .any(|cuisine| cuisines.contains(cuisine))
+            })
+            .filter(|p| {
+                suitable_for.is_empty()
+                    || GooglePlacesClient::determine_suitable_for(&p.types, p.price_level)
+                        .iter()
+                        .any(|tag| suitable_for.contains(tag))
+            })
+            .filter(|p| query.min_rating.is_none_or(|min| p.rating.is_some_and(|r| r >= min)))
+            .filter(|p| {
+                query
+                    .min_rating_count
+                    .is_none_or(|min| p.user_ratings_total.is_some_and(|c| c >= min))
+            })
+            .filter(|p| query.open_now != Some(true) || p.is_open == Some(true))
+            .filter(|p| query.min_price.is_none_or(|min| p.price_level.is_some_and(|l| l >= min)))
+            .filter(|p| query.max_price.is_none_or(|max| p.price_level.is_some_and(|l| l <= max)))
+            .collect();
+
+        // Missing values sort last in every order
+        let Some(sort) = sort else {
+            return places;
+        };
+        let order = query.order.as_deref().and_then(SortOrder::parse).unwrap_or(sort.default_order());
+        match sort {
+            SearchSort::Distance => sort_missing_last(&mut places, order, |p| p.distance_km, f64::total_cmp),
+            SearchSort::Rating => sort_missing_last(&mut places, order, |p| p.rating, f32::total_cmp),
+            SearchSort::RatingCount => sort_missing_last(&mut places, order, |p| p.user_ratings_total, i32::cmp),
+            SearchSort::Name => sort_missing_last(&mut places, order, |p| Some(p.name.to_lowercase()), String::cmp),
+            // Google results carry no creation date
+            SearchSort::CreatedAt => {}
+        }
+
+        places
+    }
+
+    /// Validate the `min_rating_count` filter (0 to MAX_MIN_RATING_COUNT)
+    fn validate_min_rating_count(min_rating_count: Option<i32>) -> Result<(), PlacesError> {
+        match min_rating_count {
+            Some(count) if !(0..=MAX_MIN_RATING_COUNT).contains(&count) => Err(PlacesError::ValidationError(format!(
+                "min_rating_count must be between 0 and {}, got {}",
+                MAX_MIN_RATING_COUNT, count
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Convert a search radius in kilometers to a Google Nearby Search radius
+    /// DOCUMENTATION: Defaults to 5 km. Out-of-range values (<= 0 or above 50 km) are
+    /// rejected rather than clamped so clients learn their radius was not honored.
+    fn google_radius_m(radius_km: Option<f64>) -> Result<u32, PlacesError> {
+        let Some(km) = radius_km else {
+            return Ok(5000);
+        };
+
+        let max_km = MAX_NEARBY_RADIUS_M as f64 / 1000.0;
+        if !km.is_finite() || km <= 0.0 || km > max_km {
+            return Err(PlacesError::ValidationError(format!(
+                "radius_km must be greater than 0 and at most {} (Google limit of {} meters), got {}",
+                max_km, MAX_NEARBY_RADIUS_M, km
+            )));
+        }
+
+        let meters = (km * 1000.0).round() as u32;
+        GooglePlacesClient::validate_radius(meters)?;
+        Ok(meters)
+    }
+
+    /// Transform Google Place to Frontend format
+    pub fn transform_google_place_to_frontend(
+        google_place: &GooglePlace,
+        google_client: &dyn GooglePlacesApi,
+        search_lat: Option<f64>,
+        search_lon: Option<f64>,
+        city: Option<&str>,
     ) -> Result<FrontendPlaceResponse, PlacesError> {
         // Extract city and district from address components
         let (city_name, district) = Self::extract_city_and_district(
@@ -210,6 +1558,8 @@ impl PlaceService {
             _ => None,
         };
 
+        let opening_hours = google_place.opening_hours_with_offset();
+
         Ok(FrontendPlaceResponse {
             place_id: google_place.place_id.clone(),
             name: google_place.name.clone(),
@@ -226,14 +1576,15 @@ impl PlaceService {
                 .clone()
                 .or_else(|| google_place.international_phone_number.clone()),
             website: google_place.website.clone(),
-            opening_hours: google_place
-                .opening_hours
+            opening_hours: opening_hours
                 .as_ref()
                 .and_then(|hours| serde_json::to_value(hours).ok()),
-            is_open: google_place
-                .opening_hours
-                .as_ref()
-                .and_then(|hours| hours.open_now),
+            // Prefer the schedule; Nearby Search results only carry Google's flag
+            is_open: opening_hours.as_ref().and_then(|hours| {
+                OpeningHours::from_google(hours)
+                    .and_then(|schedule| schedule.is_open_now())
+                    .or(hours.open_now)
+            }),
             distance_km,
             custom_attributes: FrontendCustomAttributes {
                 city: city_name,
@@ -258,22 +1609,22 @@ impl PlaceService {
         if let Some(components) = address_components {
             for component in components {
                 // Extract city
-                if component.types.iter().any(|t| t == "locality" || t == "administrative_area_level_2") {
-                    if city.is_none() {
-                        city = Some(component.long_name.clone());
-                    }
+                if city.is_none()
+                    && component.types.iter().any(|t| t == "locality" || t == "administrative_area_level_2")
+                {
+                    city = Some(component.long_name.clone());
                 }
                 
                 // Extract district/neighborhood
-                if component.types.iter().any(|t| {
-                    t == "sublocality"
-                        || t == "sublocality_level_1"
-                        || t == "neighborhood"
-                        || t == "administrative_area_level_3"
-                }) {
-                    if district.is_none() {
-                        district = Some(component.long_name.clone());
-                    }
+                if district.is_none()
+                    && component.types.iter().any(|t| {
+                        t == "sublocality"
+                            || t == "sublocality_level_1"
+                            || t == "neighborhood"
+                            || t == "administrative_area_level_3"
+                    })
+                {
+                    district = Some(component.long_name.clone());
                 }
             }
         }
@@ -287,7 +1638,7 @@ impl PlaceService {
     /// Transform Google Photo to Frontend format
     fn transform_google_photo(
         photo: &GooglePhoto,
-        google_client: &GooglePlacesClient,
+        google_client: &dyn GooglePlacesApi,
     ) -> FrontendPhotoResponse {
         let photo_url = google_client.get_photo_url(&photo.photo_reference, Some(800));
         let thumbnail_url = Some(google_client.get_photo_thumbnail_url(&photo.photo_reference));
@@ -310,12 +1661,13 @@ impl PlaceService {
             rating: review.rating,
             text: review.text.clone(),
             relative_time_description: review.relative_time_description.clone(),
+            language: review.text_language().map(str::to_string),
         }
     }
 
     /// Calculate distance between two coordinates in kilometers
     /// Uses Haversine formula
-    fn calculate_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    pub fn calculate_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
         const EARTH_RADIUS_KM: f64 = 6371.0;
 
         let d_lat = (lat2 - lat1).to_radians();
@@ -342,15 +1694,1486 @@ impl PlaceService {
     }
 
     /// Delete a place
-    pub async fn delete_place(pool: &PgPool, id: Uuid) -> Result<(), PlacesError> {
+    /// Returns the city of the deleted place
+    pub async fn delete_place(pool: &PgPool, id: Uuid) -> Result<String, PlacesError> {
         PlaceRepository::delete_place(pool, id).await
     }
 
+    /// Permanently delete a place with its reviews and photos
+    /// Fails with Conflict for subscribed places
+    pub async fn hard_delete_place(pool: &PgPool, id: Uuid) -> Result<HardDeleteReport, PlacesError> {
+        PlaceRepository::hard_delete_place(pool, id).await
+    }
+
     /// Upsert a place (create or update based on google_place_id)
     pub async fn upsert_place(
         pool: &PgPool,
         req: CreatePlaceRequest,
     ) -> Result<(Place, bool), PlacesError> {
-        PlaceRepository::upsert_google_place(pool, &req).await
+        let upserted = PlaceRepository::upsert_google_place(pool, &req).await?;
+        Ok((upserted.place, upserted.created))
+    }
+}
+
+/// Stable sort on an optional key in `order`; places without the key go last
+fn sort_missing_last<K>(
+    places: &mut [FrontendPlaceResponse],
+    order: SortOrder,
+    key: impl Fn(&FrontendPlaceResponse) -> Option<K>,
+    compare: impl Fn(&K, &K) -> std::cmp::Ordering,
+) {
+    use std::cmp::Ordering::{Greater, Less};
+
+    places.sort_by(|a, b| match (key(a), key(b)) {
+        (Some(a), Some(b)) => match order {
+            SortOrder::Asc => compare(&a, &b),
+            SortOrder::Desc => compare(&b, &a),
+        },
+        (Some(_), None) => Less,
+        (None, Some(_)) => Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::GoogleApiError;
+    use crate::services::DEFAULT_MAX_ENTRIES;
+    use actix_web::ResponseError;
+    use crate::test_support::{
+        lazy_pool, nearby_body, test_config, test_pool, MockGooglePlacesClient, MockHttpServer,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn empty_response() -> SearchResponse {
+        SearchResponse {
+            data: Vec::new(),
+            total_count: 0,
+            page: 1,
+            limit: 20,
+            has_more: false,
+            filters: Default::default(),
+            fuzzy: false,
+            facets: None,
+        }
+    }
+
+    async fn counted_search(
+        cache: &PlacesCache,
+        query: &SearchQuery,
+        calls: &AtomicUsize,
+    ) -> SearchResponse {
+        PlaceService::cached_search(cache, query, Duration::from_secs(60), || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(empty_response())
+        })
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_fresh_within_window() {
+        let now = Utc::now();
+        let window = chrono::Duration::hours(24);
+
+        assert!(PlaceService::is_fresh(Some(now - chrono::Duration::hours(1)), now, window));
+    }
+
+    #[test]
+    fn test_is_fresh_outside_window() {
+        let now = Utc::now();
+        let window = chrono::Duration::hours(24);
+
+        assert!(!PlaceService::is_fresh(Some(now - chrono::Duration::hours(25)), now, window));
+        // Never verified always requires a refresh
+        assert!(!PlaceService::is_fresh(None, now, window));
+    }
+
+    #[test]
+    fn test_is_fresh_window_boundary() {
+        let now = Utc::now();
+        let window = chrono::Duration::hours(24);
+
+        assert!(PlaceService::is_fresh(Some(now - window), now, window));
+        assert!(!PlaceService::is_fresh(
+            Some(now - window - chrono::Duration::seconds(1)),
+            now,
+            window
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_search_served_from_cache() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        let calls = AtomicUsize::new(0);
+        let query = SearchQuery {
+            city: Some("Zaragoza".to_string()),
+            q: Some("tapas".to_string()),
+            ..Default::default()
+        };
+
+        counted_search(&cache, &query, &calls).await;
+        counted_search(&cache, &query, &calls).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_equivalent_queries_share_cache_entry() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        let calls = AtomicUsize::new(0);
+        let query1 = SearchQuery {
+            city: Some("Zaragoza".to_string()),
+            ..Default::default()
+        };
+        let query2 = SearchQuery {
+            city: Some(" zaragoza ".to_string()),
+            page: Some(1),
+            limit: Some(20),
+            ..Default::default()
+        };
+
+        counted_search(&cache, &query1, &calls).await;
+        counted_search(&cache, &query2, &calls).await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mutation_invalidates_city_and_global_searches() {
+        let cache = PlacesCache::new(60, DEFAULT_MAX_ENTRIES);
+        let calls = AtomicUsize::new(0);
+        let city_query = SearchQuery {
+            city: Some("Zaragoza".to_string()),
+            ..Default::default()
+        };
+        let global_query = SearchQuery::default();
+        let other_city_query = SearchQuery {
+            city: Some("Madrid".to_string()),
+            ..Default::default()
+        };
+
+        counted_search(&cache, &city_query, &calls).await;
+        counted_search(&cache, &global_query, &calls).await;
+        counted_search(&cache, &other_city_query, &calls).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        PlaceService::invalidate_search_cache(&cache, "Zaragoza").await;
+        assert_eq!(cache.generation("zaragoza").await, 1);
+
+        counted_search(&cache, &city_query, &calls).await;
+        counted_search(&cache, &global_query, &calls).await;
+        counted_search(&cache, &other_city_query, &calls).await;
+
+        // City and all-cities searches refetch, other cities stay cached
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    fn google_query() -> SearchQuery {
+        SearchQuery {
+            lat: Some(41.65),
+            lon: Some(-0.88),
+            city: Some("Zaragoza".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_google_search_reports_ok_with_places() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&["a", "b", "c"])]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let response = PlaceService::search_places_from_google(&client, google_query())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, SearchStatus::Ok);
+        assert_eq!(response.places.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_reports_zero_results() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&[])]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let response = PlaceService::search_places_from_google(&client, google_query())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, SearchStatus::ZeroResults);
+        assert!(response.places.is_empty());
+        assert_eq!(response.total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_failure_is_an_error() {
+        let server = MockHttpServer::start(vec![(500, "{}".to_string())]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let result = PlaceService::search_places_from_google(&client, google_query()).await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(error.google_error(), Some(GoogleApiError::Unknown(_))));
+        assert_eq!(error.status_code(), actix_web::http::StatusCode::BAD_GATEWAY);
+    }
+
+    #[test]
+    fn test_google_radius_conversion_limits() {
+        assert_eq!(PlaceService::google_radius_m(None).unwrap(), 5000);
+        assert_eq!(PlaceService::google_radius_m(Some(50.0)).unwrap(), 50_000);
+        assert_eq!(PlaceService::google_radius_m(Some(0.001)).unwrap(), 1);
+
+        for km in [50.001, 0.0, -1.0, f64::NAN] {
+            assert!(
+                matches!(
+                    PlaceService::google_radius_m(Some(km)),
+                    Err(PlacesError::ValidationError(_))
+                ),
+                "radius_km={} should be rejected",
+                km
+            );
+        }
+    }
+
+    fn details_body(status: &str) -> String {
+        if status != "OK" {
+            return serde_json::json!({ "status": status }).to_string();
+        }
+        serde_json::json!({
+            "status": "OK",
+            "result": {
+                "place_id": "ChIJremoteOnlyPlace0001",
+                "name": "Remote Place",
+                "types": ["restaurant"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } },
+                "address_components": [
+                    { "long_name": "Zaragoza", "short_name": "Zaragoza", "types": ["locality"] }
+                ]
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_google_place_id_heuristic() {
+        assert!(PlaceService::looks_like_google_place_id("ChIJN1t_tDeuEmsRUsoyG83frY4"));
+        assert!(!PlaceService::looks_like_google_place_id("550e8400-e29b-41d4-a716-446655440000"));
+        assert!(!PlaceService::looks_like_google_place_id("short"));
+        assert!(!PlaceService::looks_like_google_place_id("has spaces and is long enough"));
+    }
+
+    #[test]
+    fn test_on_demand_import_disabled_by_flag_or_missing_key() {
+        let client = GooglePlacesClient::new("test_key".to_string());
+        let limiter = PlaceService::on_demand_limiter(30);
+
+        let mut config = test_config();
+        config.google_places_api_key = "key".to_string();
+        assert!(OnDemandImport::from_config(&config, &client, &limiter).is_some());
+
+        config.on_demand_import_enabled = false;
+        assert!(OnDemandImport::from_config(&config, &client, &limiter).is_none());
+
+        config.on_demand_import_enabled = true;
+        config.google_places_api_key.clear();
+        assert!(OnDemandImport::from_config(&config, &client, &limiter).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_rejects_short_input_without_calling_google() {
+        let server = MockHttpServer::start_json(vec![r#"{"status":"ZERO_RESULTS"}"#.to_string()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        for input in ["", "a", " é "] {
+            let query = AutocompleteQuery { input: input.to_string(), ..Default::default() };
+            let result = PlaceService::autocomplete(&client, query).await;
+            assert!(matches!(result, Err(PlacesError::ValidationError(_))), "{:?}", input);
+        }
+        assert_eq!(server.hits(), 0);
+
+        let query = AutocompleteQuery { input: "ca".to_string(), ..Default::default() };
+        let response = PlaceService::autocomplete(&client, query).await.unwrap();
+        assert!(response.predictions.is_empty());
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_against_mock_client() {
+        let client = MockGooglePlacesClient::from_fixtures();
+        let query = AutocompleteQuery { input: "tubo".to_string(), ..Default::default() };
+
+        let response = PlaceService::autocomplete(&client, query).await.unwrap();
+
+        assert_eq!(response.predictions.len(), 1);
+        assert_eq!(response.predictions[0].place_id, "ChIJ2QdR5bEUWQ0RkN_n1cAQFh4");
+        assert_eq!(client.calls(), vec!["autocomplete:tubo"]);
+    }
+
+    #[tokio::test]
+    async fn test_autocomplete_requires_lat_and_lon_together() {
+        let client = GooglePlacesClient::new("test_key".to_string());
+        let query = AutocompleteQuery {
+            input: "cafe".to_string(),
+            lat: Some(41.65),
+            ..Default::default()
+        };
+
+        let result = PlaceService::autocomplete(&client, query).await;
+        assert!(matches!(result, Err(PlacesError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_on_demand_import_forwards_session_token() {
+        let server = MockHttpServer::start_json(vec![details_body("NOT_FOUND")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let limiter = PlaceService::on_demand_limiter(30);
+        let mut config = test_config();
+        config.google_places_api_key = "key".to_string();
+        let import = OnDemandImport::from_config(&config, &client, &limiter)
+            .unwrap()
+            .with_session_token(Some("sess-42"));
+
+        let _ = PlaceService::import_google_place(&lazy_pool(), import, "ChIJmissingEverywhere01").await;
+
+        assert!(server.requests()[0].contains("sessiontoken=sess-42"));
+    }
+
+    #[tokio::test]
+    async fn test_import_by_name_without_candidate_is_not_found() {
+        let server =
+            MockHttpServer::start_json(vec![r#"{"status":"ZERO_RESULTS","candidates":[]}"#.to_string()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let result = PlaceService::import_place_by_name(
+            &lazy_pool(),
+            &client,
+            "Café Inexistente Zaragoza",
+            FindPlaceInputType::TextQuery,
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(PlacesError::NotFound(_))));
+        assert_eq!(server.hits(), 1, "no details call without a candidate");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_import_by_name_reports_existing_place() {
+        let pool = test_pool().await;
+        sqlx::query("DELETE FROM places WHERE google_place_id = 'ChIJremoteOnlyPlace0001'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let candidates = serde_json::json!({
+            "status": "OK",
+            "candidates": [{ "place_id": "ChIJremoteOnlyPlace0001" }]
+        })
+        .to_string();
+        let server =
+            MockHttpServer::start_json(vec![candidates.clone(), details_body("OK"), candidates]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let first = PlaceService::import_place_by_name(&pool, &client, "Remote Place", FindPlaceInputType::TextQuery, None)
+            .await
+            .unwrap();
+        assert!(first.created);
+        assert_eq!(first.place.city, "Zaragoza");
+
+        // Details are cached, so the second import only repeats Find Place
+        let second = PlaceService::import_place_by_name(&pool, &client, "Remote Place", FindPlaceInputType::TextQuery, None)
+            .await
+            .unwrap();
+        assert!(!second.created);
+        assert_eq!(second.place.id, first.place.id);
+    }
+
+    #[tokio::test]
+    async fn test_on_demand_import_google_not_found_is_404() {
+        let server = MockHttpServer::start_json(vec![details_body("NOT_FOUND")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let limiter = PlaceService::on_demand_limiter(30);
+        let import = OnDemandImport {
+            google_client: &client,
+            limiter: &limiter,
+            session_token: None,
+        };
+
+        let result =
+            PlaceService::import_google_place(&lazy_pool(), import, "ChIJmissingEverywhere01").await;
+
+        let error = result.unwrap_err();
+        assert!(error.is_not_found());
+        assert_eq!(error.status_code(), actix_web::http::StatusCode::NOT_FOUND);
+        assert_eq!(server.hits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_one_google_not_found_is_502() {
+        let server = MockHttpServer::start_json(vec![details_body("NOT_FOUND")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let error = PlaceService::sync_google_place(&lazy_pool(), &client, "ChIJmissingEverywhere01", Some("Zaragoza"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, PlacesError::ExternalApiError(ref message) if message.contains("NOT_FOUND")));
+        assert_eq!(error.status_code(), actix_web::http::StatusCode::BAD_GATEWAY);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_sync_one_creates_then_updates() {
+        let pool = test_pool().await;
+        sqlx::query("DELETE FROM places WHERE google_place_id = 'ChIJremoteOnlyPlace0001'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let server = MockHttpServer::start_json(vec![details_body("OK")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        // A city without configured bounds is accepted as given
+        let first = PlaceService::sync_google_place(&pool, &client, "ChIJremoteOnlyPlace0001", Some("Calatayud"))
+            .await
+            .unwrap();
+        assert!(first.created);
+        assert_eq!(first.place.city, "Calatayud");
+        // The returned record carries the verification the stored row got
+        let stored = PlaceRepository::get_by_id(&pool, first.place.id).await.unwrap();
+        assert!(first.place.last_verified_at.is_some());
+        assert_eq!(first.place.last_verified_at, stored.last_verified_at);
+
+        let second = PlaceService::sync_google_place(&pool, &client, "ChIJremoteOnlyPlace0001", Some("Calatayud"))
+            .await
+            .unwrap();
+        assert!(!second.created);
+        assert_eq!(second.place.id, first.place.id);
+    }
+
+    #[tokio::test]
+    async fn test_on_demand_import_respects_rate_limit() {
+        let server = MockHttpServer::start_json(vec![details_body("NOT_FOUND")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let limiter = PlaceService::on_demand_limiter(1);
+        let import = OnDemandImport {
+            google_client: &client,
+            limiter: &limiter,
+            session_token: None,
+        };
+        let pool = lazy_pool();
+
+        let _ = PlaceService::import_google_place(&pool, import, "ChIJmissingEverywhere01").await;
+        let second = PlaceService::import_google_place(&pool, import, "ChIJmissingEverywhere02").await;
+
+        assert!(matches!(second, Err(PlacesError::RateLimitExceeded)));
+        assert_eq!(server.hits(), 1, "rate-limited imports must not call Google");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_on_demand_import_found_remotely() {
+        let pool = test_pool().await;
+        sqlx::query("DELETE FROM places WHERE google_place_id = 'ChIJremoteOnlyPlace0001'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let server = MockHttpServer::start_json(vec![details_body("OK")]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let limiter = PlaceService::on_demand_limiter(30);
+        let import = OnDemandImport {
+            google_client: &client,
+            limiter: &limiter,
+            session_token: None,
+        };
+
+        let (response, imported) =
+            PlaceService::get_or_import_place(&pool, "ChIJremoteOnlyPlace0001", Some(import), false)
+                .await
+                .unwrap();
+        assert!(imported);
+        assert_eq!(response.place.name, "Remote Place");
+
+        let (_, imported_again) =
+            PlaceService::get_or_import_place(&pool, "ChIJremoteOnlyPlace0001", Some(import), false)
+                .await
+                .unwrap();
+        assert!(!imported_again, "second lookup is served from the database");
+        assert_eq!(server.hits(), 1);
+    }
+
+    /// Three Google pages of 20 results each, chained by page tokens
+    fn paged_bodies() -> Vec<String> {
+        (0..3)
+            .map(|page| {
+                let results: Vec<serde_json::Value> = (0..20)
+                    .map(|i| {
+                        serde_json::json!({
+                            "place_id": format!("p{}_{}", page, i),
+                            "name": format!("Place {}-{}", page, i),
+                            "types": ["restaurant"],
+                            "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+                        })
+                    })
+                    .collect();
+                let token = (page < 2).then(|| format!("token_{}", page + 1));
+                serde_json::json!({ "status": "OK", "results": results, "next_page_token": token })
+                    .to_string()
+            })
+            .collect()
+    }
+
+    async fn paged_search(page: i64, cursor: Option<&str>) -> (FrontendSearchResponse, MockHttpServer) {
+        let server = MockHttpServer::start_json(paged_bodies()).await;
+        let client = GooglePlacesClient::new("test_key".to_string())
+            .with_base_url(&server.base_url)
+            .with_page_token_delay(Duration::ZERO);
+        let query = SearchQuery {
+            page: Some(page),
+            cursor: cursor.map(str::to_string),
+            ..google_query()
+        };
+
+        let response = PlaceService::search_places_from_google(&client, query).await.unwrap();
+        (response, server)
+    }
+
+    #[tokio::test]
+    async fn test_google_pagination_first_page() {
+        let (response, server) = paged_search(1, None).await;
+
+        assert_eq!(server.hits(), 1, "page 1 needs a single Google call");
+        assert_eq!(response.places.len(), 20);
+        assert_eq!(response.places[0].place_id, "p0_0");
+        assert_eq!(response.total, 20);
+        assert!(response.capped && response.has_more);
+        assert_eq!(response.next_cursor.as_deref(), Some("token_1"));
+    }
+
+    #[tokio::test]
+    async fn test_google_pagination_follows_tokens_for_later_pages() {
+        let (second, server) = paged_search(2, None).await;
+        assert_eq!(server.hits(), 2);
+        assert_eq!(second.places[0].place_id, "p1_0");
+        assert!(second.has_more);
+        assert!(server.requests()[1].contains("pagetoken=token_1"));
+
+        let (third, server) = paged_search(3, None).await;
+        assert_eq!(server.hits(), 3);
+        assert_eq!(third.places.len(), 20);
+        assert_eq!(third.places[0].place_id, "p2_0");
+        assert_eq!(third.total, 60);
+        assert!(third.capped, "60 results is Google's ceiling");
+        assert!(!third.has_more);
+        assert!(third.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_google_pagination_beyond_ceiling_is_empty() {
+        let (response, server) = paged_search(4, None).await;
+
+        assert_eq!(server.hits(), 0, "pages past the ceiling must not call Google");
+        assert!(response.places.is_empty());
+        assert!(!response.has_more);
+        assert!(response.next_cursor.is_none());
+        assert_eq!(response.total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_google_pagination_small_limit_cursor_skips_nothing() {
+        let search = |cursor: Option<String>| SearchQuery { limit: Some(10), cursor, ..google_query() };
+        let ids = |response: &FrontendSearchResponse| -> Vec<String> {
+            response.places.iter().map(|place| place.place_id.clone()).collect()
+        };
+        let expected = |page: usize, range: std::ops::Range<usize>| -> Vec<String> {
+            range.map(|i| format!("p{}_{}", page, i)).collect()
+        };
+        let server = MockHttpServer::start_json(paged_bodies()).await;
+        let client = GooglePlacesClient::new("test_key".to_string())
+            .with_base_url(&server.base_url)
+            .with_page_token_delay(Duration::ZERO);
+
+        let first = PlaceService::search_places_from_google(&client, search(None)).await.unwrap();
+        assert_eq!(ids(&first), expected(0, 0..10));
+        assert!(first.has_more);
+        assert_eq!(first.next_cursor.as_deref(), Some("10:"));
+
+        // The rest of Google's first page (from the response cache), then its next page
+        let second = PlaceService::search_places_from_google(&client, search(first.next_cursor)).await.unwrap();
+        assert_eq!(ids(&second), expected(0, 10..20));
+        assert_eq!(second.next_cursor.as_deref(), Some("token_1"));
+
+        let third = PlaceService::search_places_from_google(&client, search(second.next_cursor)).await.unwrap();
+        assert_eq!(ids(&third), expected(1, 0..10));
+        assert_eq!(third.next_cursor.as_deref(), Some("10:token_1"));
+        assert_eq!(server.hits(), 2);
+        assert!(server.requests().last().unwrap().contains("pagetoken=token_1"));
+    }
+
+    #[test]
+    fn test_google_cursor_round_trips() {
+        for cursor in ["token_1", "10:", "10:token_1"] {
+            assert_eq!(GoogleCursor::parse(cursor).encode(), cursor);
+        }
+        assert_eq!(GoogleCursor::parse("10:"), GoogleCursor { offset: 10, token: None });
+        assert_eq!(GoogleCursor::parse("abc:def"), GoogleCursor { offset: 0, token: Some("abc:def".to_string()) });
+    }
+
+    #[tokio::test]
+    async fn test_google_pagination_cursor_fetches_single_page() {
+        let (response, server) = paged_search(1, Some("token_1")).await;
+
+        assert_eq!(server.hits(), 1);
+        assert!(server.requests()[0].contains("pagetoken=token_1"));
+        assert_eq!(response.places.len(), 20);
+        assert!(response.has_more);
+    }
+
+    /// Fixed result set in Google's relevance order:
+    /// (id, lat offset, rating, rating count, price level, open now)
+    fn filter_fixture_body() -> String {
+        let rows = [
+            ("far_top", 0.030, Some(4.9), Some(40), Some(3), Some(true)),
+            ("near_mid", 0.001, Some(4.2), Some(900), Some(1), Some(false)),
+            ("mid_low", 0.010, Some(3.1), Some(15), Some(2), Some(true)),
+            ("unrated", 0.005, None, None, None, None),
+        ];
+        let results: Vec<serde_json::Value> = rows
+            .iter()
+            .map(|(id, offset, rating, count, price, open)| {
+                serde_json::json!({
+                    "place_id": id,
+                    "name": id,
+                    "types": ["restaurant"],
+                    "geometry": { "location": { "lat": 41.65 + offset, "lng": -0.88 } },
+                    "rating": rating,
+                    "user_ratings_total": count,
+                    "price_level": price,
+                    "opening_hours": open.map(|o| serde_json::json!({ "open_now": o }))
+                })
+            })
+            .collect();
+        serde_json::json!({ "status": "OK", "results": results }).to_string()
+    }
+
+    async fn filtered_ids(query: SearchQuery) -> (Vec<String>, i64) {
+        let server = MockHttpServer::start_json(vec![filter_fixture_body()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let response = PlaceService::search_places_from_google(&client, query)
+            .await
+            .unwrap();
+        let ids = response.places.into_iter().map(|p| p.place_id).collect();
+        (ids, response.total)
+    }
+
+    #[tokio::test]
+    async fn test_google_search_keeps_relevance_order_without_sort() {
+        let (ids, total) = filtered_ids(google_query()).await;
+        assert_eq!(ids, ["far_top", "near_mid", "mid_low", "unrated"]);
+        assert_eq!(total, 4);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_applies_filters_in_memory() {
+        let (ids, total) = filtered_ids(SearchQuery { min_rating: Some(4.0), ..google_query() }).await;
+        assert_eq!(ids, ["far_top", "near_mid"]);
+        assert_eq!(total, 2, "total reflects the filtered set");
+
+        let (ids, _) = filtered_ids(SearchQuery { min_rating_count: Some(20), ..google_query() }).await;
+        assert_eq!(ids, ["far_top", "near_mid"]);
+
+        let (ids, _) = filtered_ids(SearchQuery { open_now: Some(true), ..google_query() }).await;
+        assert_eq!(ids, ["far_top", "mid_low"]);
+
+        let (ids, _) = filtered_ids(SearchQuery { max_price: Some(2), ..google_query() }).await;
+        assert_eq!(ids, ["near_mid", "mid_low"]);
+
+        let (ids, _) = filtered_ids(SearchQuery { min_price: Some(2), ..google_query() }).await;
+        assert_eq!(ids, ["far_top", "mid_low"]);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_forwards_open_now_and_price() {
+        let server = MockHttpServer::start_json(vec![filter_fixture_body()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let query = SearchQuery {
+            open_now: Some(true),
+            min_price: Some(2),
+            max_price: Some(3),
+            ..google_query()
+        };
+
+        let response = PlaceService::search_places_from_google(&client, query).await.unwrap();
+
+        let request = &server.requests()[0];
+        assert!(request.contains("opennow=true"), "{}", request);
+        assert!(request.contains("minprice=2"), "{}", request);
+        assert!(request.contains("maxprice=3"), "{}", request);
+        let ids: Vec<String> = response.places.into_iter().map(|p| p.place_id).collect();
+        assert_eq!(ids, ["far_top", "mid_low"]);
+    }
+
+    fn cuisine_query(cuisines: &[&str]) -> SearchQuery {
+        SearchQuery {
+            cuisines: Some(cuisines.iter().map(|c| c.to_string()).collect()),
+            ..google_query()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_google_search_filters_and_echoes_cuisines() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "results": [
+                { "place_id": "trattoria", "name": "Trattoria", "types": ["italian_restaurant", "restaurant"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } },
+                { "place_id": "sushi", "name": "Sushi Kyoto", "types": ["restaurant"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } },
+                { "place_id": "casa", "name": "Casa Pepe", "types": ["restaurant"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } }
+            ]
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![body]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let response = PlaceService::search_places_from_google(&client, cuisine_query(&["Italian", "japanese", "klingon"]))
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = response.places.into_iter().map(|p| p.place_id).collect();
+        assert_eq!(ids, ["trattoria", "sushi"]);
+        assert_eq!(response.filters.cuisines, ["italian", "japanese"]);
+        assert_eq!(response.filters.ignored_cuisines, ["klingon"]);
+        assert!(!server.requests()[0].contains("_restaurant"), "two cuisines are not sent as a type");
+    }
+
+    #[tokio::test]
+    async fn test_google_search_filters_suitable_for() {
+        let tags = |tags: &[&str]| Some(tags.iter().map(|t| t.to_string()).collect());
+
+        let (ids, _) = filtered_ids(SearchQuery { suitable_for: tags(&["budget"]), ..google_query() }).await;
+        assert_eq!(ids, ["near_mid"]);
+
+        // Expensive restaurants are not tagged for families
+        let (ids, _) = filtered_ids(SearchQuery { suitable_for: tags(&["Families"]), ..google_query() }).await;
+        assert_eq!(ids, ["near_mid", "mid_low", "unrated"]);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_filters_categories() {
+        let body = serde_json::json!({
+            "status": "OK",
+            "results": [
+                { "place_id": "bakery", "name": "Horno", "types": ["bakery", "food"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } },
+                { "place_id": "delivery", "name": "Reparto", "types": ["bakery", "meal_delivery"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } },
+                { "place_id": "cafe", "name": "Café", "types": ["cafe"],
+                  "geometry": { "location": { "lat": 41.65, "lng": -0.88 } } }
+            ]
+        })
+        .to_string();
+        let search = |categories: Option<&str>, exclude: Option<&str>| {
+            let body = body.clone();
+            let list = |value: Option<&str>| value.map(|v| v.split(',').map(str::to_string).collect());
+            let query = SearchQuery { categories: list(categories), exclude_categories: list(exclude), ..google_query() };
+            async move {
+                let server = MockHttpServer::start_json(vec![body]).await;
+                let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+                let response = PlaceService::search_places_from_google(&client, query).await.unwrap();
+                response.places.into_iter().map(|p| p.place_id).collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(search(Some("Bakery"), None).await, ["bakery", "delivery"]);
+        assert_eq!(search(None, Some("meal_delivery")).await, ["bakery", "cafe"]);
+        assert_eq!(search(Some("bakery,cafe"), Some("meal_delivery")).await, ["bakery", "cafe"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_rejects_unknown_suitable_for() {
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url("http://127.0.0.1:9");
+        let suitable_for = Some(vec!["families".to_string(), "pets".to_string()]);
+
+        let query = SearchQuery { suitable_for: suitable_for.clone(), ..google_query() };
+        let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
+
+        let query = SearchQuery { suitable_for, ..Default::default() };
+        let err = PlaceService::search_places(&lazy_pool(), query, &SearchSettings::default()).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
+    }
+
+    #[test]
+    fn test_single_cuisine_maps_to_google_type() {
+        assert_eq!(
+            PlaceService::google_place_type(&cuisine_query(&["italian", "klingon"])).as_deref(),
+            Some("italian_restaurant")
+        );
+        let restaurant = SearchQuery { type_: Some("restaurant".to_string()), ..cuisine_query(&["thai"]) };
+        assert_eq!(PlaceService::google_place_type(&restaurant).as_deref(), Some("thai_restaurant"));
+
+        let bar = SearchQuery { type_: Some("bar".to_string()), ..cuisine_query(&["thai"]) };
+        assert_eq!(PlaceService::google_place_type(&bar).as_deref(), Some("bar"));
+        assert_eq!(PlaceService::google_place_type(&cuisine_query(&["thai", "indian"])), None);
+        assert_eq!(PlaceService::google_place_type(&cuisine_query(&["klingon"])), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_rejects_price_outside_range() {
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url("http://127.0.0.1:9");
+
+        for (min_price, max_price) in [(Some(5), None), (None, Some(-1)), (Some(4), Some(2))] {
+            let query = SearchQuery { min_price, max_price, ..google_query() };
+            let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}-{:?}", min_price, max_price);
+
+            let query = SearchQuery { min_price, max_price, ..Default::default() };
+            let err = PlaceService::search_places(&lazy_pool(), query, &SearchSettings::default()).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}-{:?}", min_price, max_price);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_google_search_sorts_with_missing_values_last() {
+        let sorted = |sort: &str| SearchQuery { sort: Some(sort.to_string()), ..google_query() };
+
+        let (ids, _) = filtered_ids(sorted("distance")).await;
+        assert_eq!(ids, ["near_mid", "unrated", "mid_low", "far_top"]);
+
+        let (ids, _) = filtered_ids(sorted("rating")).await;
+        assert_eq!(ids, ["far_top", "near_mid", "mid_low", "unrated"]);
+
+        let (ids, _) = filtered_ids(sorted("rating_count")).await;
+        assert_eq!(ids, ["near_mid", "far_top", "mid_low", "unrated"]);
+
+        let (ids, _) = filtered_ids(SearchQuery {
+            open_now: Some(true),
+            ..sorted("distance")
+        })
+        .await;
+        assert_eq!(ids, ["mid_low", "far_top"]);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_sort_order_and_name() {
+        let sorted = |sort: &str, order: &str| SearchQuery {
+            sort: Some(sort.to_string()),
+            order: Some(order.to_string()),
+            ..google_query()
+        };
+
+        // Missing values stay last when the order is reversed
+        let (ids, _) = filtered_ids(sorted("rating", "asc")).await;
+        assert_eq!(ids, ["mid_low", "near_mid", "far_top", "unrated"]);
+
+        let (ids, _) = filtered_ids(sorted("name", "asc")).await;
+        assert_eq!(ids, ["far_top", "mid_low", "near_mid", "unrated"]);
+        let (ids, _) = filtered_ids(sorted("name", "desc")).await;
+        assert_eq!(ids, ["unrated", "near_mid", "mid_low", "far_top"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_rejects_invalid_sorts() {
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url("http://127.0.0.1:9");
+
+        for (sort, order) in [(Some("created_at"), None), (Some("newest"), None), (Some("rating"), Some("up"))] {
+            let query = SearchQuery { sort: sort.map(str::to_string), order: order.map(str::to_string), ..google_query() };
+            let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?} {:?}", sort, order);
+        }
+
+        let query = SearchQuery { tags: Some(vec!["terrace".to_string()]), ..google_query() };
+        let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
+
+        for min_rating_count in [-1, MAX_MIN_RATING_COUNT + 1] {
+            let query = SearchQuery { min_rating_count: Some(min_rating_count), ..google_query() };
+            let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{}", min_rating_count);
+        }
+
+        // Database search: distance needs coordinates, tags must parse
+        for query in [
+            SearchQuery { min_rating_count: Some(MAX_MIN_RATING_COUNT + 1), ..Default::default() },
+            SearchQuery { tags: Some(vec!["music:".to_string()]), ..Default::default() },
+            SearchQuery { sort: Some("distance".to_string()), ..Default::default() },
+            SearchQuery { sort: Some("distance".to_string()), lat: Some(41.65), ..Default::default() },
+            SearchQuery { sort: Some("newest".to_string()), ..Default::default() },
+        ] {
+            let err = PlaceService::search_places(&lazy_pool(), query, &SearchSettings::default()).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)));
+        }
+    }
+
+    #[test]
+    fn test_batch_identifiers_mix_kinds_and_drop_repeats() {
+        let id = Uuid::new_v4();
+        let raw: Vec<String> = vec![
+            id.to_string(),
+            " ChIJ_bodega ".to_string(),
+            id.to_string().to_uppercase(),
+            "ChIJ_bodega".to_string(),
+            "ChIJ_Bodega".to_string(),
+        ];
+
+        let identifiers = PlaceService::parse_batch_identifiers(&raw).unwrap();
+        assert_eq!(
+            identifiers,
+            [
+                (BatchIdentifier::Id(id), id.to_string()),
+                (BatchIdentifier::GooglePlaceId("ChIJ_bodega".to_string()), "ChIJ_bodega".to_string()),
+                // Google place ids are case-sensitive
+                (BatchIdentifier::GooglePlaceId("ChIJ_Bodega".to_string()), "ChIJ_Bodega".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_validates_before_querying() {
+        let at_cap: Vec<String> = (0..MAX_BATCH_IDS).map(|i| format!("ChIJ_{}", i)).collect();
+        assert_eq!(PlaceService::parse_batch_identifiers(&at_cap).unwrap().len(), MAX_BATCH_IDS);
+
+        // The cap counts repeats: 101 copies of one id are still too many
+        let over_cap = vec!["ChIJ_same".to_string(); MAX_BATCH_IDS + 1];
+        for raw in [over_cap, vec!["ChIJ_a".to_string(), "  ".to_string()]] {
+            let err = PlaceService::get_places_batch(&lazy_pool(), &raw).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}", err);
+        }
+
+        // Nothing to look up: answered without touching the (absent) database
+        let empty = PlaceService::get_places_batch(&lazy_pool(), &[]).await.unwrap();
+        assert!(empty.data.is_empty() && empty.missing.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_batch_returns_found_places_once_and_missing_ids() {
+        let pool = test_pool().await;
+        let google_id = format!("batch_{}", Uuid::new_v4());
+        let first = crate::test_support::insert_test_place(&pool, &google_id).await;
+        let second = crate::test_support::insert_test_place(&pool, &format!("batch_{}", Uuid::new_v4())).await;
+        let unknown_uuid = Uuid::new_v4().to_string();
+
+        let raw = vec![
+            second.to_string(),
+            unknown_uuid.clone(),
+            google_id.clone(),
+            first.to_string(),
+            "ChIJ_not_stored".to_string(),
+            second.to_string(),
+        ];
+        let response = PlaceService::get_places_batch(&pool, &raw).await.unwrap();
+
+        let ids: Vec<Uuid> = response.data.iter().map(|place| place.id).collect();
+        assert_eq!(ids, [second, first]);
+        assert_eq!(response.missing, [unknown_uuid, "ChIJ_not_stored".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_nearby_caps_radius_and_limit() {
+        let bounds = |radius_km: Option<f64>, limit: Option<i64>| {
+            PlaceService::nearby_bounds(&NearbyPlacesQuery { radius_km, limit, ..Default::default() })
+        };
+        assert_eq!(bounds(None, None).unwrap(), (DEFAULT_NEARBY_RADIUS_KM, DEFAULT_NEARBY_LIMIT));
+        assert_eq!(bounds(Some(2.5), Some(5)).unwrap(), (2.5, 5));
+        assert_eq!(bounds(Some(500.0), Some(1_000)).unwrap(), (MAX_NEARBY_RADIUS_KM, MAX_NEARBY_LIMIT));
+        assert_eq!(bounds(None, Some(0)).unwrap().1, 1);
+
+        for radius_km in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let query = NearbyPlacesQuery { radius_km: Some(radius_km), ..Default::default() };
+            // Rejected before the (absent) database is asked for the place
+            let err = PlaceService::nearby_places(&lazy_pool(), "ChIJ_anchor", query).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{}", radius_km);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_suggest_requires_two_characters() {
+        for q in ["", "a", " é ", "   "] {
+            let query = SuggestQuery { q: q.to_string(), ..Default::default() };
+            // Rejected before the (absent) database is queried
+            let err = PlaceService::suggest_places(&lazy_pool(), query).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}", q);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_nearby_places_around_a_place() {
+        let pool = test_pool().await;
+        let run = Uuid::new_v4();
+        let place = |name: &str, place_type: &str, location: [f64; 2]| -> crate::models::CreatePlaceRequest {
+            serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": place_type,
+                "location": location,
+                "city": "Zaragoza",
+                "google_place_id": format!("nearby_{}_{}", name, run),
+                "main_categories": []
+            }))
+            .unwrap()
+        };
+        // Roughly 110 m per 0.001 degrees of latitude
+        let anchor = PlaceRepository::create_place(&pool, &place("anchor", "restaurant", [-0.9500, 45.0000])).await.unwrap();
+        for (name, place_type, lat) in [("close", "bar", 45.0010), ("closer", "restaurant", 45.0005), ("far", "bar", 45.0500)] {
+            PlaceRepository::create_place(&pool, &place(name, place_type, [-0.9500, lat])).await.unwrap();
+        }
+        let names = |response: &NearbyPlacesResponse| -> Vec<String> {
+            response
+                .data
+                .iter()
+                .map(|place| place.name.clone())
+                .filter(|name| ["anchor", "close", "closer", "far"].contains(&name.as_str()))
+                .collect()
+        };
+
+        let nearby = PlaceService::nearby_places(&pool, &anchor.id.to_string(), NearbyPlacesQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(nearby.place_id, anchor.id);
+        assert_eq!(names(&nearby), ["closer", "close"]);
+        assert!(nearby.data.iter().all(|place| place.distance_km.is_some()));
+
+        // Google place ids resolve to the same place; type and radius narrow the results
+        let google_id = anchor.google_place_id.clone().unwrap();
+        let query = NearbyPlacesQuery { radius_km: Some(10.0), type_: Some("bar".to_string()), limit: None };
+        let bars = PlaceService::nearby_places(&pool, &google_id, query).await.unwrap();
+        assert_eq!(names(&bars), ["close", "far"]);
+
+        let missing = PlaceService::nearby_places(&pool, &Uuid::new_v4().to_string(), NearbyPlacesQuery::default()).await;
+        assert!(matches!(missing, Err(PlacesError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_place_photos_rejects_unknown_source() {
+        let query = PlacePhotosQuery { source: Some("Flickr".to_string()), ..Default::default() };
+        // Rejected before the (absent) database is asked for the place
+        let err = PlaceService::place_photos(&lazy_pool(), "ChIJ_anchor", query).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_place_photos_are_paginated_by_source() {
+        let pool = test_pool().await;
+        let run = Uuid::new_v4();
+        let place = |name: &str| -> crate::models::CreatePlaceRequest {
+            serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": "restaurant",
+                "location": [-0.8891, 41.6488],
+                "city": "Zaragoza",
+                "google_place_id": format!("photos_{}_{}", name, run),
+                "main_categories": []
+            }))
+            .unwrap()
+        };
+        let pictured = PlaceRepository::create_place(&pool, &place("pictured")).await.unwrap();
+        let bare = PlaceRepository::create_place(&pool, &place("bare")).await.unwrap();
+        // Google photos 0-3 with the primary one last by display order, one user upload
+        for (order, source) in [(0, "google"), (1, "google"), (2, "user_upload"), (3, "google"), (4, "google")] {
+            let photo = crate::models::CreatePhotoRequest {
+                place_id: pictured.id,
+                source: source.to_string(),
+                source_photo_reference: Some(format!("{}-{}", pictured.id, order)),
+                photo_url: format!("https://example.com/{}/{}.jpg", pictured.id, order),
+                thumbnail_url: None,
+                width: None,
+                height: None,
+                attribution: None,
+                is_primary: Some(order == 4),
+                display_order: Some(order),
+            };
+            PhotoRepository::create_photo(&pool, &photo).await.unwrap();
+        }
+        let orders = |response: &PlacePhotosResponse| -> Vec<i32> {
+            response.data.iter().filter_map(|photo| photo.display_order).collect()
+        };
+
+        let all = PlaceService::place_photos(&pool, &pictured.id.to_string(), PlacePhotosQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(all.place_id, pictured.id);
+        assert_eq!((all.total_count, all.page, all.limit, all.has_more), (5, 1, DEFAULT_PLACE_PHOTOS_LIMIT, false));
+        assert_eq!(orders(&all), [4, 0, 1, 2, 3]);
+
+        // Google place ids resolve to the same place; pages follow the same order
+        let google_id = pictured.google_place_id.clone().unwrap();
+        let query = PlacePhotosQuery { source: Some(" Google ".to_string()), page: Some(2), limit: Some(2) };
+        let second = PlaceService::place_photos(&pool, &google_id, query).await.unwrap();
+        assert_eq!((second.total_count, second.page, second.limit, second.has_more), (4, 2, 2, false));
+        assert_eq!(orders(&second), [1, 3]);
+        assert!(second.data.iter().all(|photo| photo.source == "google"));
+
+        let empty = PlaceService::place_photos(&pool, &bare.id.to_string(), PlacePhotosQuery::default()).await.unwrap();
+        assert_eq!((empty.total_count, empty.data.len()), (0, 0));
+
+        let missing = PlaceService::place_photos(&pool, &Uuid::new_v4().to_string(), PlacePhotosQuery::default()).await;
+        assert!(matches!(missing, Err(PlacesError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_similar_places_are_scored() {
+        let pool = test_pool().await;
+        let run = Uuid::new_v4();
+        // Roughly 110 m per 0.001 degrees of latitude, around a spot of its own per
+        // run so earlier runs stay out of the radius
+        let lon = -0.9700;
+        let base = 46.0 + (run.as_u128() % 300) as f64 * 0.1;
+        let place = |name: &str, place_type: &str, lat: f64, cuisines: &[&str], rating: Option<(f32, i32)>| {
+            let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "type": place_type,
+                "location": [lon, base + lat],
+                "city": "Zaragoza",
+                "google_place_id": format!("similar_{}_{}", name, run),
+                "main_categories": ["food"],
+                "cuisine_types": cuisines,
+                "google_rating": rating.map(|(stars, _)| stars),
+                "google_rating_count": rating.map(|(_, count)| count)
+            }))
+            .unwrap();
+            request
+        };
+        let anchor = PlaceRepository::create_place(&pool, &place("anchor", "restaurant", 0.0, &["italian", "pizza"], None))
+            .await
+            .unwrap();
+        // Scores: twin 4 + 1 + 4.0 + 0.25 ln 51 - 0.2, cousin 2 + 1 + 4.8 + 0.25 ln 501 - 0.5,
+        // stranger 0 + 1 + 4.9 + 0.25 ln 2001 - 0.3, unrated 2 + 1 - 0.1
+        let seeded = [
+            ("twin", "restaurant", 0.0020, &["italian", "pizza"][..], Some((4.0, 50))),
+            ("cousin", "restaurant", 0.0045, &["italian"][..], Some((4.8, 500))),
+            ("stranger", "restaurant", 0.0027, &["japanese"][..], Some((4.9, 2000))),
+            ("unrated", "restaurant", -0.0009, &["italian"][..], None),
+            ("bar", "bar", 0.0010, &["italian", "pizza"][..], Some((5.0, 900))),
+            ("far", "restaurant", 0.0500, &["italian", "pizza"][..], Some((5.0, 900))),
+            ("closed", "restaurant", 0.0005, &["italian", "pizza"][..], Some((5.0, 900))),
+        ];
+        for (name, place_type, lat, cuisines, rating) in seeded {
+            let created = PlaceRepository::create_place(&pool, &place(name, place_type, lat, cuisines, rating)).await.unwrap();
+            if name == "closed" {
+                PlaceRepository::delete_place(&pool, created.id).await.unwrap();
+            }
+        }
+        // Close and well rated, but sharing neither a cuisine nor a category
+        let mut unrelated = place("unrelated", "restaurant", 0.0001, &["japanese"], Some((5.0, 900)));
+        unrelated.main_categories = vec!["museum".to_string()];
+        PlaceRepository::create_place(&pool, &unrelated).await.unwrap();
+        let names = |response: &SimilarPlacesResponse| -> Vec<String> {
+            response
+                .data
+                .iter()
+                .filter(|place| place.google_place_id.as_deref().is_some_and(|id| id.ends_with(&run.to_string())))
+                .map(|place| place.name.clone())
+                .collect()
+        };
+
+        let similar = PlaceService::similar_places(&pool, &anchor.id.to_string(), SimilarPlacesQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(similar.place_id, anchor.id);
+        assert_eq!(similar.limit, DEFAULT_SIMILAR_LIMIT);
+        assert_eq!(names(&similar), ["twin", "cousin", "stranger", "unrated"]);
+        assert!(!names(&similar).contains(&"unrelated".to_string()));
+        assert!(similar.data.iter().all(|place| place.distance_km.is_some()));
+
+        let google_id = anchor.google_place_id.clone().unwrap();
+        let top = PlaceService::similar_places(&pool, &google_id, SimilarPlacesQuery { limit: Some(1) }).await.unwrap();
+        assert_eq!(top.data.len(), 1);
+        assert_eq!(top.data[0].name, "twin");
+
+        let missing = PlaceService::similar_places(&pool, &Uuid::new_v4().to_string(), SimilarPlacesQuery::default()).await;
+        assert!(matches!(missing, Err(PlacesError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_falls_back_to_fuzzy_names() {
+        let pool = test_pool().await;
+        let city = format!("Fallback {}", Uuid::new_v4());
+        let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+            "name": "Cafe Botanico",
+            "type": "cafe",
+            "location": [-0.8891, 41.6488],
+            "city": city,
+            "main_categories": []
+        }))
+        .unwrap();
+        PlaceRepository::create_place(&pool, &request).await.unwrap();
+
+        let settings = SearchSettings::default();
+        let search = |q: &str, fuzzy: Option<bool>| {
+            let query = SearchQuery { q: Some(q.to_string()), city: Some(city.clone()), fuzzy, ..Default::default() };
+            PlaceService::search_places(&pool, query, &settings)
+        };
+
+        let exact = search("botanico", None).await.unwrap();
+        assert_eq!((exact.total_count, exact.fuzzy), (1, false));
+
+        let typo = search("cafe botanco", None).await.unwrap();
+        assert_eq!((typo.total_count, typo.fuzzy), (1, true));
+        assert_eq!(typo.data[0].name, "Cafe Botanico");
+
+        let strict = search("cafe botanco", Some(false)).await.unwrap();
+        assert_eq!((strict.total_count, strict.fuzzy), (0, false));
+
+        let forced = search("botanico", Some(true)).await.unwrap();
+        assert!(forced.fuzzy);
+    }
+
+    #[test]
+    fn test_cluster_cell_size_follows_zoom_within_grid_bound() {
+        let bbox = |span: f64| BoundingBox { ne_lat: 41.6 + span, ne_lng: -0.9 + span, sw_lat: 41.6, sw_lng: -0.9 };
+
+        // A city viewport at city zoom: a quarter of a tile
+        let city = PlaceService::cluster_cell_size(&bbox(0.2), 12);
+        assert!((city - 360.0 / 4096.0 / 4.0).abs() < 1e-12);
+        assert!(PlaceService::cluster_cell_size(&bbox(0.2), 13) < city);
+
+        // A zoom far too high for the viewport still yields at most 32 cells a side
+        let capped = PlaceService::cluster_cell_size(&bbox(10.0), 22);
+        assert!((capped - 10.0 / MAX_CLUSTER_GRID).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_clusters_validate_viewport_and_zoom() {
+        let viewport = ClusterQuery {
+            sw_lat: Some(41.60),
+            sw_lng: Some(-0.95),
+            ne_lat: Some(41.70),
+            ne_lng: Some(-0.80),
+            zoom: Some(12),
+            type_: None,
+        };
+        for query in [
+            ClusterQuery { zoom: None, ..viewport.clone() },
+            ClusterQuery { zoom: Some(MAX_CLUSTER_ZOOM + 1), ..viewport.clone() },
+            ClusterQuery { ne_lng: None, ..viewport.clone() },
+            ClusterQuery { sw_lat: None, sw_lng: None, ne_lat: None, ne_lng: None, ..viewport.clone() },
+            ClusterQuery { sw_lat: Some(41.80), ..viewport.clone() },
+        ] {
+            // Rejected before the (absent) database is queried
+            let err = PlaceService::place_clusters(&lazy_pool(), query.clone()).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}", query);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_cluster_counts_match_viewport_search() {
+        let pool = test_pool().await;
+        let run = Uuid::new_v4();
+        // Three places within ~100 m and one ~5 km away
+        for (i, location) in [[12.000, -60.000], [12.0005, -60.0005], [12.001, -60.001], [12.060, -60.030]].into_iter().enumerate() {
+            let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": format!("Cluster {} {}", i, run),
+                "type": "bar",
+                "location": location,
+                "city": format!("Clusters {}", run),
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+        let viewport = ClusterQuery {
+            sw_lat: Some(-60.1),
+            sw_lng: Some(11.9),
+            ne_lat: Some(-59.9),
+            ne_lng: Some(12.1),
+            zoom: Some(10),
+            type_: None,
+        };
+        let search = SearchQuery { sw_lat: Some(-60.1), sw_lng: Some(11.9), ne_lat: Some(-59.9), ne_lng: Some(12.1), ..Default::default() };
+        let (_, viewport_total) = PlaceRepository::search(&pool, &search).await.unwrap();
+
+        for zoom in [2, 10, 14, 22] {
+            let response = PlaceService::place_clusters(&pool, ClusterQuery { zoom: Some(zoom), ..viewport.clone() })
+                .await
+                .unwrap();
+            assert_eq!(response.total_count, viewport_total, "zoom {}", zoom);
+            assert_eq!(response.clusters.iter().map(|cluster| cluster.count).sum::<i64>(), viewport_total);
+            assert!(response.clusters.len() as f64 <= (MAX_CLUSTER_GRID + 1.0).powi(2));
+            for cluster in &response.clusters {
+                assert_eq!(cluster.place.is_some(), cluster.count == 1, "zoom {}", zoom);
+            }
+        }
+
+        // Zoomed out the nearby places share a cell; zoomed in they split apart
+        let out = PlaceService::place_clusters(&pool, viewport.clone()).await.unwrap();
+        let inside = PlaceService::place_clusters(&pool, ClusterQuery { zoom: Some(22), ..viewport }).await.unwrap();
+        assert!(out.clusters.len() < inside.clusters.len());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_photos_take_one_query_per_page() {
+        let pool = test_pool().await;
+        let city = format!("Photos {}", Uuid::new_v4());
+        let mut ids = Vec::new();
+        for i in 0..50 {
+            let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": format!("Photo place {:02}", i),
+                "type": "bar",
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "main_categories": []
+            }))
+            .unwrap();
+            ids.push(PlaceRepository::create_place(&pool, &request).await.unwrap().id);
+        }
+        // The first place has more photos than are attached, the second one photo
+        for (place_id, count) in [(ids[0], 7), (ids[1], 1)] {
+            for order in 0..count {
+                let photo = crate::models::CreatePhotoRequest {
+                    place_id,
+                    source: "google".to_string(),
+                    source_photo_reference: Some(format!("{}-{}", place_id, order)),
+                    photo_url: format!("https://example.com/{}/{}.jpg", place_id, order),
+                    thumbnail_url: None,
+                    width: None,
+                    height: None,
+                    attribution: None,
+                    is_primary: Some(order == 3),
+                    display_order: Some(order),
+                };
+                PhotoRepository::create_photo(&pool, &photo).await.unwrap();
+            }
+        }
+        let settings = SearchSettings::default();
+        let query = SearchQuery { city: Some(city), limit: Some(50), sort: Some("name".to_string()), ..Default::default() };
+
+        let counter = crate::test_support::SqlStatementCounter::start();
+        let plain = PlaceService::search_places(&pool, query.clone(), &settings).await.unwrap();
+        let plain_statements = counter.count();
+        assert!(plain.data.iter().all(|place| place.photos.is_none()));
+
+        let counter = crate::test_support::SqlStatementCounter::start();
+        let with_photos = PlaceService::search_places(&pool, SearchQuery { include_photos: Some(true), ..query }, &settings)
+            .await
+            .unwrap();
+        // Count and page of places, plus one photo query for the 50 places
+        assert_eq!(with_photos.data.len(), 50);
+        assert_eq!((plain_statements, counter.count()), (2, 3));
+
+        let photos = |i: usize| with_photos.data[i].photos.as_ref().unwrap();
+        assert_eq!(photos(0).len(), MAX_SEARCH_PHOTOS_PER_PLACE as usize);
+        assert_eq!(photos(0)[0].display_order, Some(3));
+        assert_eq!(photos(0)[1].display_order, Some(0));
+        assert_eq!(photos(1).len(), 1);
+        assert!(photos(2).is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_search_facets_sum_to_total_count() {
+        let pool = test_pool().await;
+        let city = format!("Facets {}", Uuid::new_v4());
+        let places = [
+            ("bar", "Centro", 1),
+            ("bar", "Centro", 2),
+            ("bar", "Delicias", 1),
+            ("restaurant", "Centro", 3),
+            ("cafe", "Delicias", 1),
+        ];
+        for (i, (place_type, district, price_level)) in places.into_iter().enumerate() {
+            let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+                "name": format!("Facet {}", i),
+                "type": place_type,
+                "location": [-0.8891, 41.6488],
+                "city": city,
+                "district": district,
+                "price_level": price_level,
+                "main_categories": []
+            }))
+            .unwrap();
+            PlaceRepository::create_place(&pool, &request).await.unwrap();
+        }
+        let settings = SearchSettings::default();
+        let query = SearchQuery { city: Some(city.clone()), limit: Some(2), include_facets: Some(true), ..Default::default() };
+
+        let response = PlaceService::search_places(&pool, query.clone(), &settings).await.unwrap();
+        let facets = response.facets.unwrap();
+        assert_eq!(response.total_count, 5);
+        assert_eq!(facets.types, [("bar".to_string(), 3), ("cafe".to_string(), 1), ("restaurant".to_string(), 1)].into());
+        assert_eq!(facets.districts, [("Centro".to_string(), 3), ("Delicias".to_string(), 2)].into());
+        for counts in [&facets.types, &facets.districts, &facets.price_levels] {
+            assert_eq!(counts.values().sum::<i64>(), response.total_count);
+        }
+        assert_eq!(facets.price_levels["1"], 3);
+
+        // Facets follow the filters; without include_facets there are none
+        let bars = SearchQuery { type_: Some("bar".to_string()), ..query.clone() };
+        let facets = PlaceService::search_places(&pool, bars, &settings).await.unwrap().facets.unwrap();
+        assert_eq!(facets.types.len(), 1);
+        assert_eq!(facets.districts["Delicias"], 1);
+
+        let plain = SearchQuery { include_facets: None, ..query };
+        assert!(PlaceService::search_places(&pool, plain, &settings).await.unwrap().facets.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_rejects_invalid_viewports() {
+        let viewport = SearchQuery {
+            ne_lat: Some(41.66),
+            ne_lng: Some(-0.87),
+            sw_lat: Some(41.64),
+            sw_lng: Some(-0.90),
+            ..Default::default()
+        };
+        for query in [
+            SearchQuery { sw_lng: None, ..viewport.clone() },
+            SearchQuery { ne_lat: Some(91.0), ..viewport.clone() },
+            SearchQuery { sw_lat: Some(41.70), ..viewport.clone() },
+            // Crossing the antimeridian
+            SearchQuery { ne_lng: Some(-179.5), sw_lng: Some(179.5), ..viewport.clone() },
+            SearchQuery { lat: Some(41.65), lon: Some(-0.88), radius_km: Some(2.0), ..viewport.clone() },
+        ] {
+            let err = PlaceService::search_places(&lazy_pool(), query.clone(), &SearchSettings::default()).await.unwrap_err();
+            assert!(matches!(err, PlacesError::ValidationError(_)), "{:?}", query);
+        }
+
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url("http://127.0.0.1:9");
+        let query = SearchQuery { ne_lat: viewport.ne_lat, ne_lng: viewport.ne_lng, sw_lat: viewport.sw_lat, sw_lng: viewport.sw_lng, ..google_query() };
+        let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(message) if message.contains("source=db")));
+    }
+
+    #[tokio::test]
+    async fn test_google_search_sort_distance_ranks_by_distance() {
+        let server = MockHttpServer::start_json(vec![filter_fixture_body()]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let query = SearchQuery {
+            type_: Some("restaurant".to_string()),
+            sort: Some("distance".to_string()),
+            ..google_query()
+        };
+        let response = PlaceService::search_places_from_google(&client, query).await.unwrap();
+        let request = &server.requests()[0];
+        assert!(request.contains("rankby=distance"), "{}", request);
+        assert!(!request.contains("radius="), "{}", request);
+        assert_eq!(response.places[0].place_id, "near_mid");
+
+        // Without type or keyword the radius search is kept and sorted in memory
+        let query = SearchQuery { sort: Some("distance".to_string()), ..google_query() };
+        PlaceService::search_places_from_google(&client, query).await.unwrap();
+        let request = &server.requests()[1];
+        assert!(request.contains("radius=5000"), "{}", request);
+        assert!(!request.contains("rankby"), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_google_search_rejects_unknown_sort() {
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url("http://127.0.0.1:9");
+        let query = SearchQuery { sort: Some("popularity".to_string()), ..google_query() };
+
+        let err = PlaceService::search_places_from_google(&client, query).await.unwrap_err();
+        assert!(matches!(err, PlacesError::ValidationError(_)));
     }
 }
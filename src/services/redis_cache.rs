@@ -0,0 +1,230 @@
+// src/services/redis_cache.rs
+// DOCUMENTATION: Redis-backed CacheBackend (enabled with the `redis` feature)
+// PURPOSE: Share cached Google responses and invalidation generations across
+// replicas so each instance does not pay for its own Google calls
+
+use crate::errors::PlacesError;
+use crate::services::cache::{count_by_namespace, CacheBackend, CacheLookup, CacheStats};
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use std::time::Duration;
+
+/// Prefix for cached values
+const DATA_PREFIX: &str = "places:cache:";
+
+/// Prefix for invalidation generation counters (never expire, never cleared)
+const GENERATION_PREFIX: &str = "places:generation:";
+
+/// Keys deleted per round trip when clearing
+const SCAN_BATCH: usize = 500;
+
+/// Redis cache backend
+/// DOCUMENTATION: Values are stored with PSETEX so Redis expires them itself;
+/// connection errors are logged and treated as misses
+pub struct RedisBackend {
+    connection: ConnectionManager,
+}
+
+impl RedisBackend {
+    /// Connect to Redis at `url` (e.g. redis://localhost:6379)
+    pub async fn connect(url: &str) -> Result<Self, PlacesError> {
+        let client = redis::Client::open(url).map_err(|e| {
+            PlacesError::InvalidInput(format!("Invalid REDIS_URL: {}", e))
+        })?;
+        let connection = ConnectionManager::new(client).await.map_err(|e| {
+            log::error!("Failed to connect to Redis: {}", e);
+            PlacesError::ServiceUnavailable
+        })?;
+
+        log::info!("Connected to Redis cache backend");
+        Ok(Self { connection })
+    }
+
+    fn data_key(key: &str) -> String {
+        format!("{}{}", DATA_PREFIX, key)
+    }
+
+    /// Escape glob metacharacters so SCAN MATCH treats the prefix literally
+    fn escape_glob(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for c in value.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// All data keys (without DATA_PREFIX) starting with `prefix`
+    async fn scan_keys(&self, prefix: &str) -> Vec<String> {
+        let pattern = format!("{}{}*", DATA_PREFIX, Self::escape_glob(prefix));
+        let mut connection = self.connection.clone();
+        let mut keys = Vec::new();
+        match connection.scan_match::<_, String>(&pattern).await {
+            Ok(mut iter) => {
+                while let Some(key) = iter.next_item().await {
+                    keys.push(key);
+                }
+            }
+            Err(e) => log::warn!("Redis SCAN failed: {}", e),
+        }
+        keys.into_iter()
+            .filter_map(|key| key.strip_prefix(DATA_PREFIX).map(str::to_string))
+            .collect()
+    }
+
+    async fn delete_keys(&self, keys: &[String]) -> usize {
+        let mut connection = self.connection.clone();
+        let mut removed = 0;
+        for chunk in keys.chunks(SCAN_BATCH) {
+            let full: Vec<String> = chunk.iter().map(|k| Self::data_key(k)).collect();
+            match connection.del::<_, usize>(full).await {
+                Ok(count) => removed += count,
+                Err(e) => log::warn!("Redis DEL failed: {}", e),
+            }
+        }
+        removed
+    }
+}
+
+#[async_trait]
+impl CacheBackend for RedisBackend {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn get(&self, key: &str) -> CacheLookup {
+        let mut connection = self.connection.clone();
+        // Redis drops expired keys itself, so an expired entry reads as a miss
+        match connection.get::<_, Option<String>>(Self::data_key(key)).await {
+            Ok(Some(value)) => CacheLookup::Hit(value),
+            Ok(None) => CacheLookup::Miss,
+            Err(e) => {
+                log::warn!("Redis GET failed for key {}: {}", key, e);
+                CacheLookup::Miss
+            }
+        }
+    }
+
+    async fn set(&self, key: String, value: String, ttl: Duration) {
+        let mut connection = self.connection.clone();
+        // PSETEX rejects 0; the shortest TTL still expires immediately in practice
+        let ttl_ms = (ttl.as_millis() as u64).max(1);
+        if let Err(e) = connection
+            .pset_ex::<_, _, ()>(Self::data_key(&key), value, ttl_ms)
+            .await
+        {
+            log::warn!("Redis PSETEX failed for key {}: {}", key, e);
+        }
+    }
+
+    async fn delete(&self, key: &str) -> bool {
+        let mut connection = self.connection.clone();
+        connection
+            .del::<_, usize>(Self::data_key(key))
+            .await
+            .map(|count| count > 0)
+            .unwrap_or(false)
+    }
+
+    async fn cleanup(&self) {
+        // Redis expires keys on its own
+    }
+
+    async fn stats(&self) -> CacheStats {
+        let keys = self.scan_keys("").await;
+        CacheStats {
+            backend: self.name().to_string(),
+            total_entries: keys.len(),
+            expired_entries: 0,
+            active_entries: keys.len(),
+            max_entries: None,
+            evictions: 0,
+            active_by_namespace: count_by_namespace(keys.iter()),
+            ..CacheStats::default()
+        }
+    }
+
+    async fn clear(&self) -> usize {
+        self.clear_prefix("").await
+    }
+
+    async fn clear_prefix(&self, prefix: &str) -> usize {
+        let keys = self.scan_keys(prefix).await;
+        self.delete_keys(&keys).await
+    }
+
+    async fn generation(&self, scope: &str) -> u64 {
+        let mut connection = self.connection.clone();
+        connection
+            .get::<_, Option<u64>>(format!("{}{}", GENERATION_PREFIX, scope))
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(0)
+    }
+
+    async fn bump_generation(&self, scope: &str) -> u64 {
+        let mut connection = self.connection.clone();
+        match connection
+            .incr::<_, _, u64>(format!("{}{}", GENERATION_PREFIX, scope), 1)
+            .await
+        {
+            Ok(generation) => generation,
+            Err(e) => {
+                log::warn!("Redis INCR failed for scope {}: {}", scope, e);
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn test_backend() -> RedisBackend {
+        let url = std::env::var("TEST_REDIS_URL").expect("TEST_REDIS_URL must point at Redis");
+        RedisBackend::connect(&url).await.expect("connect to test Redis")
+    }
+
+    #[test]
+    fn test_escape_glob() {
+        assert_eq!(RedisBackend::escape_glob("search:*"), "search:\\*");
+        assert_eq!(RedisBackend::escape_glob("a[1]?"), "a\\[1\\]\\?");
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Redis (TEST_REDIS_URL)"]
+    async fn test_redis_ttl_expiry() {
+        let backend = test_backend().await;
+        let key = format!("test:ttl:{}", uuid::Uuid::new_v4());
+
+        backend
+            .set(key.clone(), "value".to_string(), Duration::from_millis(200))
+            .await;
+        assert_eq!(backend.get(&key).await, CacheLookup::Hit("value".to_string()));
+
+        tokio::time::sleep(Duration::from_millis(400)).await;
+        assert_eq!(backend.get(&key).await, CacheLookup::Miss);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Redis (TEST_REDIS_URL)"]
+    async fn test_redis_clear_prefix_and_generations() {
+        let backend = test_backend().await;
+        let run = uuid::Uuid::new_v4();
+        let ttl = Duration::from_secs(60);
+        backend.set(format!("test{}:a", run), "1".to_string(), ttl).await;
+        backend.set(format!("test{}:b", run), "1".to_string(), ttl).await;
+
+        assert_eq!(backend.clear_prefix(&format!("test{}:", run)).await, 2);
+        assert_eq!(backend.get(&format!("test{}:a", run)).await, CacheLookup::Miss);
+
+        let scope = format!("scope-{}", run);
+        let before = backend.generation(&scope).await;
+        assert_eq!(backend.bump_generation(&scope).await, before + 1);
+    }
+}
@@ -0,0 +1,598 @@
+// src/services/sync_jobs.rs
+// DOCUMENTATION: Background sync jobs
+// PURPOSE: Run city, bounding-box and batch syncs on tokio tasks and keep their state for the status endpoint,
+// so an admin request returns immediately instead of holding a worker for minutes
+
+use crate::config::Config;
+use crate::db::SyncLogRepository;
+use crate::errors::PlacesError;
+use crate::services::sync_service::{SyncProgress, SyncStats};
+use crate::services::webhook::{WebhookDelivery, WebhookSender};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use uuid::Uuid;
+
+/// How long finished jobs stay visible to the status endpoint
+pub const FINISHED_JOB_RETENTION: Duration = Duration::from_secs(3600);
+
+/// Lifecycle of a sync job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncJobState {
+    /// Waiting for a free sync slot
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// What a job syncs; also its `data_sync_log.sync_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncJobKind {
+    City,
+    Bbox,
+    /// Several cities; the job's `city` lists them
+    Batch,
+}
+
+impl SyncJobKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::City => "city",
+            Self::Bbox => "bbox",
+            Self::Batch => "batch",
+        }
+    }
+}
+
+/// Snapshot of a job returned by GET /admin/sync/jobs/{job_id}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncJobStatus {
+    pub job_id: String,
+    pub kind: SyncJobKind,
+    pub city: String,
+    pub state: SyncJobState,
+    /// Stats so far while running, final stats once completed
+    pub stats: SyncStats,
+    /// Why the sync failed (per-cell errors are in `stats.errors`)
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Final stats per city (batch syncs only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<SyncStats>,
+    /// Completion notification, once sent (failures never fail the job)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookDelivery>,
+}
+
+/// What a finished sync hands back to its job
+/// DOCUMENTATION: `stats` become the job's final stats; a batch sync adds the stats of
+/// each city in `details`
+#[derive(Debug, Clone)]
+pub struct SyncJobResult {
+    pub stats: SyncStats,
+    pub details: Vec<SyncStats>,
+}
+
+impl From<SyncStats> for SyncJobResult {
+    fn from(stats: SyncStats) -> Self {
+        Self { stats, details: Vec::new() }
+    }
+}
+
+/// Body POSTed to the webhook URL when a job finishes
+#[derive(Debug, Serialize)]
+struct SyncWebhookPayload<'a> {
+    /// "sync.completed" or "sync.failed"
+    event: &'static str,
+    run_id: &'a str,
+    city: &'a str,
+    state: SyncJobState,
+    stats: &'a SyncStats,
+    #[serde(skip_serializing_if = "<[SyncStats]>::is_empty")]
+    details: &'a [SyncStats],
+    error: Option<&'a str>,
+}
+
+/// A registered job; `progress` is shared with the running sync
+struct SyncJob {
+    kind: SyncJobKind,
+    city: String,
+    state: SyncJobState,
+    progress: SyncProgress,
+    details: Vec<SyncStats>,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+    webhook_url: Option<String>,
+    webhook: Option<WebhookDelivery>,
+}
+
+/// In-memory registry of sync jobs
+/// DOCUMENTATION: Holds at most `max_jobs` jobs. Finished jobs are dropped after
+/// FINISHED_JOB_RETENTION, or earlier (oldest first) to make room for a new job; when
+/// every slot holds a queued or running job, new submissions are refused. At most
+/// `max_concurrent` syncs run at once, later jobs wait as `queued`.
+/// Jobs live in this process only: a restart forgets them (resume the sync instead).
+/// With a pool, each job also keeps a `data_sync_log` row (id = job id) with its final
+/// status, counts and webhook delivery
+pub struct SyncJobRegistry {
+    jobs: Mutex<HashMap<String, SyncJob>>,
+    max_jobs: usize,
+    retention: Duration,
+    slots: Arc<Semaphore>,
+    webhooks: WebhookSender,
+    pool: Option<PgPool>,
+}
+
+impl SyncJobRegistry {
+    /// Create a registry
+    pub fn new(max_jobs: usize, max_concurrent: usize, retention: Duration) -> Self {
+        Self {
+            jobs: Mutex::new(HashMap::new()),
+            max_jobs,
+            retention,
+            slots: Arc::new(Semaphore::new(max_concurrent)),
+            webhooks: WebhookSender::new(None),
+            pool: None,
+        }
+    }
+
+    /// Registry sized from SYNC_MAX_JOBS and SYNC_MAX_CONCURRENT_JOBS, signing
+    /// webhooks with WEBHOOK_SECRET
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.sync_max_jobs, config.sync_max_concurrent_jobs, FINISHED_JOB_RETENTION)
+            .with_webhooks(WebhookSender::from_config(config))
+    }
+
+    /// Use `webhooks` to send completion notifications
+    pub fn with_webhooks(mut self, webhooks: WebhookSender) -> Self {
+        self.webhooks = webhooks;
+        self
+    }
+
+    /// Record every job in `data_sync_log` through `pool`
+    pub fn with_pool(mut self, pool: PgPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Register a job and run `run` on a tokio task once a sync slot is free
+    /// DOCUMENTATION: `run` receives the job's progress slot to pass on in
+    /// `SyncOptions::progress`; its result (stats, or a `SyncJobResult` with per-city
+    /// details) becomes the job's final state, then
+    /// `webhook_url` (if any) is notified. Returns the generated job id, or
+    /// ServiceUnavailable when the registry is full of active jobs
+    pub fn submit<F, Fut, R>(
+        self: &Arc<Self>,
+        kind: SyncJobKind,
+        city: &str,
+        webhook_url: Option<String>,
+        run: F,
+    ) -> Result<String, PlacesError>
+    where
+        F: FnOnce(SyncProgress) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<R, PlacesError>> + Send + 'static,
+        R: Into<SyncJobResult> + Send + 'static,
+    {
+        let run_id = Uuid::new_v4();
+        let job_id = run_id.to_string();
+        let progress: SyncProgress = Arc::new(Mutex::new(SyncStats::new(city.to_string())));
+
+        {
+            let mut jobs = self.lock();
+            self.make_room(&mut jobs)?;
+            jobs.insert(
+                job_id.clone(),
+                SyncJob {
+                    kind,
+                    city: city.to_string(),
+                    state: SyncJobState::Queued,
+                    progress: progress.clone(),
+                    details: Vec::new(),
+                    error: None,
+                    created_at: Utc::now(),
+                    started_at: None,
+                    finished_at: None,
+                    webhook_url,
+                    webhook: None,
+                },
+            );
+        }
+
+        let registry = Arc::clone(self);
+        let id = job_id.clone();
+        let (row_city, row_notes) = match kind {
+            SyncJobKind::Batch => (None, Some(format!("cities: {}", city))),
+            _ => (Some(city.to_string()), None),
+        };
+        tokio::spawn(async move {
+            if let Some(pool) = &registry.pool {
+                let written =
+                    SyncLogRepository::insert_run(pool, run_id, kind.as_str(), row_city.as_deref(), row_notes.as_deref())
+                        .await;
+                warn_unrecorded(&id, written);
+            }
+
+            // Held for the whole sync; later jobs stay queued until it is released
+            let _slot = registry.slots.clone().acquire_owned().await;
+            registry.update(&id, |job| {
+                job.state = SyncJobState::Running;
+                job.started_at = Some(Utc::now());
+            });
+            log::info!("Sync job {} started", id);
+            if let Some(pool) = &registry.pool {
+                warn_unrecorded(&id, SyncLogRepository::mark_started(pool, run_id).await);
+            }
+
+            // Run on its own task so a panicking sync fails the job instead of
+            // leaving it running forever
+            let result = tokio::spawn(run(progress)).await;
+            registry.update(&id, |job| {
+                job.finished_at = Some(Utc::now());
+                match result {
+                    Ok(Ok(result)) => {
+                        let result = result.into();
+                        *lock_progress(&job.progress) = result.stats;
+                        job.details = result.details;
+                        job.state = SyncJobState::Completed;
+                    }
+                    Ok(Err(e)) => {
+                        job.state = SyncJobState::Failed;
+                        job.error = Some(e.to_string());
+                    }
+                    Err(e) => {
+                        job.state = SyncJobState::Failed;
+                        job.error = Some(format!("Sync task aborted: {}", e));
+                    }
+                }
+                log::info!("Sync job {} {:?}", id, job.state);
+            });
+
+            registry.record_finished(&id, run_id).await;
+            registry.notify(&id, run_id).await;
+        });
+
+        Ok(job_id)
+    }
+
+    /// Current state of a job, None if unknown or expired
+    pub fn status(&self, job_id: &str) -> Option<SyncJobStatus> {
+        let mut jobs = self.lock();
+        self.prune(&mut jobs);
+        jobs.get(job_id).map(|job| SyncJobStatus {
+            job_id: job_id.to_string(),
+            kind: job.kind,
+            city: job.city.clone(),
+            state: job.state,
+            stats: lock_progress(&job.progress).clone(),
+            details: job.details.clone(),
+            error: job.error.clone(),
+            created_at: job.created_at,
+            started_at: job.started_at,
+            finished_at: job.finished_at,
+            webhook: job.webhook.clone(),
+        })
+    }
+
+    /// Write the finished job's status and counts to its `data_sync_log` row
+    async fn record_finished(&self, job_id: &str, run_id: Uuid) {
+        let (Some(pool), Some(status)) = (&self.pool, self.status(job_id)) else {
+            return;
+        };
+        let written =
+            SyncLogRepository::finish_run(pool, run_id, run_status(&status), &status.stats, status.error.as_deref())
+                .await;
+        warn_unrecorded(job_id, written);
+    }
+
+    /// POST the finished job to its webhook URL and record the delivery on the job and
+    /// its `data_sync_log` row
+    async fn notify(&self, job_id: &str, run_id: Uuid) {
+        let Some(status) = self.status(job_id) else {
+            return;
+        };
+        let Some(url) = self.lock().get(job_id).and_then(|job| job.webhook_url.clone()) else {
+            return;
+        };
+
+        let payload = SyncWebhookPayload {
+            event: if status.state == SyncJobState::Completed { "sync.completed" } else { "sync.failed" },
+            run_id: job_id,
+            city: &status.city,
+            state: status.state,
+            stats: &status.stats,
+            details: &status.details,
+            error: status.error.as_deref(),
+        };
+        let delivery = self.webhooks.send(&url, &payload).await;
+        if let Some(pool) = &self.pool {
+            warn_unrecorded(job_id, SyncLogRepository::record_webhook(pool, run_id, &delivery).await);
+        }
+        self.update(job_id, |job| job.webhook = Some(delivery));
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SyncJob>> {
+        self.jobs.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn update(&self, job_id: &str, apply: impl FnOnce(&mut SyncJob)) {
+        if let Some(job) = self.lock().get_mut(job_id) {
+            apply(job);
+        }
+    }
+
+    /// Drop finished jobs older than the retention period
+    fn prune(&self, jobs: &mut HashMap<String, SyncJob>) {
+        let retention = chrono::Duration::from_std(self.retention).unwrap_or(chrono::Duration::MAX);
+        let now = Utc::now();
+        jobs.retain(|_, job| job.finished_at.is_none_or(|finished| now - finished < retention));
+    }
+
+    /// Make space for one more job, evicting the oldest finished jobs if needed
+    fn make_room(&self, jobs: &mut HashMap<String, SyncJob>) -> Result<(), PlacesError> {
+        self.prune(jobs);
+        while jobs.len() >= self.max_jobs {
+            let oldest = jobs
+                .iter()
+                .filter_map(|(id, job)| job.finished_at.map(|finished| (finished, id.clone())))
+                .min();
+            match oldest {
+                Some((_, id)) => {
+                    jobs.remove(&id);
+                }
+                None => {
+                    log::warn!("Sync job registry full: {} jobs queued or running", jobs.len());
+                    return Err(PlacesError::ServiceUnavailable);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `data_sync_log.status` of a finished job: a completed sync that reported errors
+/// or ran out of budget is "partial"
+fn run_status(status: &SyncJobStatus) -> &'static str {
+    match status.state {
+        SyncJobState::Completed if status.stats.errors.is_empty() && !status.stats.budget_exhausted => "completed",
+        SyncJobState::Completed => "partial",
+        _ => "failed",
+    }
+}
+
+/// Log a `data_sync_log` write that failed; the job itself goes on
+fn warn_unrecorded(job_id: &str, written: Result<(), PlacesError>) {
+    if let Err(e) = written {
+        log::warn!("Sync job {} not recorded in data_sync_log: {}", job_id, e);
+    }
+}
+
+fn lock_progress(progress: &SyncProgress) -> MutexGuard<'_, SyncStats> {
+    progress.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Poll until the job reaches `state` (jobs run on background tasks)
+    async fn wait_for(registry: &SyncJobRegistry, job_id: &str, state: SyncJobState) -> SyncJobStatus {
+        for _ in 0..200 {
+            let status = registry.status(job_id).unwrap();
+            if status.state == state {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("job {} never reached {:?}", job_id, state);
+    }
+
+    #[tokio::test]
+    async fn test_job_reports_live_and_final_stats() {
+        let registry = Arc::new(SyncJobRegistry::new(10, 1, FINISHED_JOB_RETENTION));
+        let (release, wait) = tokio::sync::oneshot::channel::<()>();
+
+        let job_id = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |progress| async move {
+                progress.lock().unwrap().cells_processed = 3;
+                wait.await.unwrap();
+                let mut stats = SyncStats::new("Zaragoza".to_string());
+                stats.cells_processed = 7;
+                Ok(stats)
+            })
+            .unwrap();
+
+        let running = wait_for(&registry, &job_id, SyncJobState::Running).await;
+        assert!(running.started_at.is_some());
+        while registry.status(&job_id).unwrap().stats.cells_processed != 3 {
+            tokio::task::yield_now().await;
+        }
+
+        release.send(()).unwrap();
+        let completed = wait_for(&registry, &job_id, SyncJobState::Completed).await;
+        assert_eq!(completed.stats.cells_processed, 7);
+        assert!(completed.error.is_none());
+        assert!(completed.finished_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_failed_and_panicking_jobs_are_failed() {
+        let registry = Arc::new(SyncJobRegistry::new(10, 2, FINISHED_JOB_RETENTION));
+
+        let failing = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |_| async {
+                Err::<SyncStats, _>(PlacesError::InvalidInput("Unknown city".to_string()))
+            })
+            .unwrap();
+        let panicking = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |_| async { panic!("boom") as Result<SyncStats, PlacesError> })
+            .unwrap();
+
+        let status = wait_for(&registry, &failing, SyncJobState::Failed).await;
+        assert!(status.error.unwrap().contains("Unknown city"));
+        let status = wait_for(&registry, &panicking, SyncJobState::Failed).await;
+        assert!(status.error.unwrap().contains("aborted"));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_beyond_concurrency_stay_queued() {
+        let registry = Arc::new(SyncJobRegistry::new(10, 1, FINISHED_JOB_RETENTION));
+        let (release, wait) = tokio::sync::oneshot::channel::<()>();
+
+        let first = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |_| async move {
+                wait.await.unwrap();
+                Ok(SyncStats::new("Zaragoza".to_string()))
+            })
+            .unwrap();
+        let second = registry
+            .submit(SyncJobKind::City, "Huesca", None, |_| async { Ok(SyncStats::new("Huesca".to_string())) })
+            .unwrap();
+
+        wait_for(&registry, &first, SyncJobState::Running).await;
+        assert_eq!(registry.status(&second).unwrap().state, SyncJobState::Queued);
+
+        release.send(()).unwrap();
+        wait_for(&registry, &second, SyncJobState::Completed).await;
+    }
+
+    #[tokio::test]
+    async fn test_registry_is_bounded() {
+        let registry = Arc::new(SyncJobRegistry::new(2, 1, FINISHED_JOB_RETENTION));
+        let (release, wait) = tokio::sync::oneshot::channel::<()>();
+
+        let finished = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |_| async { Ok(SyncStats::new("Zaragoza".to_string())) })
+            .unwrap();
+        wait_for(&registry, &finished, SyncJobState::Completed).await;
+
+        let running = registry
+            .submit(SyncJobKind::City, "Huesca", None, |_| async move {
+                wait.await.unwrap();
+                Ok(SyncStats::new("Huesca".to_string()))
+            })
+            .unwrap();
+        // The finished job makes room for the third one
+        let queued = registry
+            .submit(SyncJobKind::City, "Teruel", None, |_| async { Ok(SyncStats::new("Teruel".to_string())) })
+            .unwrap();
+        assert!(registry.status(&finished).is_none());
+
+        // Both remaining jobs are active, so nothing can be evicted
+        let full = registry.submit(SyncJobKind::City, "Jaca", None, |_| async { Ok(SyncStats::new("Jaca".to_string())) });
+        assert!(matches!(full, Err(PlacesError::ServiceUnavailable)));
+
+        release.send(()).unwrap();
+        wait_for(&registry, &running, SyncJobState::Completed).await;
+        wait_for(&registry, &queued, SyncJobState::Completed).await;
+    }
+
+    #[tokio::test]
+    async fn test_finished_jobs_notify_the_webhook() {
+        let hook = crate::test_support::MockHttpServer::start(vec![
+            (500, "{}".to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+        let registry = Arc::new(
+            SyncJobRegistry::new(10, 1, FINISHED_JOB_RETENTION)
+                .with_webhooks(WebhookSender::new(Some("secret".to_string())).with_retry_delay(Duration::ZERO)),
+        );
+
+        let job_id = registry
+            .submit(SyncJobKind::City, "Zaragoza", Some(hook.base_url.clone()), |_| async {
+                Err::<SyncStats, _>(PlacesError::InvalidInput("Unknown city".to_string()))
+            })
+            .unwrap();
+        wait_for(&registry, &job_id, SyncJobState::Failed).await;
+
+        for _ in 0..200 {
+            if let Some(delivery) = registry.status(&job_id).unwrap().webhook {
+                assert!(delivery.delivered);
+                assert_eq!(delivery.attempts, 2);
+                assert_eq!(hook.hits(), 2);
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("webhook delivery was never recorded");
+    }
+
+    #[tokio::test]
+    async fn test_undeliverable_webhook_keeps_the_job_completed() {
+        let hook = crate::test_support::MockHttpServer::start(vec![(404, "{}".to_string())]).await;
+        let registry = Arc::new(SyncJobRegistry::new(10, 1, FINISHED_JOB_RETENTION));
+
+        let job_id = registry
+            .submit(SyncJobKind::City, "Zaragoza", Some(hook.base_url.clone()), |_| async {
+                Ok(SyncStats::new("Zaragoza".to_string()))
+            })
+            .unwrap();
+
+        for _ in 0..200 {
+            let status = registry.status(&job_id).unwrap();
+            if let Some(delivery) = status.webhook {
+                assert_eq!(status.state, SyncJobState::Completed);
+                assert!(!delivery.delivered);
+                assert!(delivery.error.unwrap().contains("404"));
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("webhook delivery was never recorded");
+    }
+
+    #[test]
+    fn test_run_status() {
+        let status = |state: SyncJobState, stats: SyncStats| SyncJobStatus {
+            job_id: "job".to_string(),
+            kind: SyncJobKind::City,
+            city: "Zaragoza".to_string(),
+            state,
+            stats,
+            details: Vec::new(),
+            error: None,
+            created_at: Utc::now(),
+            started_at: None,
+            finished_at: None,
+            webhook: None,
+        };
+        let clean = SyncStats::new("Zaragoza".to_string());
+        let mut with_errors = clean.clone();
+        with_errors.errors.push("Cell Zaragoza-3 failed".to_string());
+        let exhausted = SyncStats { budget_exhausted: true, ..clean.clone() };
+
+        assert_eq!(run_status(&status(SyncJobState::Completed, clean.clone())), "completed");
+        assert_eq!(run_status(&status(SyncJobState::Completed, with_errors)), "partial");
+        assert_eq!(run_status(&status(SyncJobState::Completed, exhausted)), "partial");
+        assert_eq!(run_status(&status(SyncJobState::Failed, clean)), "failed");
+    }
+
+    #[tokio::test]
+    async fn test_finished_jobs_expire() {
+        let registry = Arc::new(SyncJobRegistry::new(10, 1, Duration::ZERO));
+
+        let job_id = registry
+            .submit(SyncJobKind::City, "Zaragoza", None, |_| async { Ok(SyncStats::new("Zaragoza".to_string())) })
+            .unwrap();
+
+        for _ in 0..200 {
+            if registry.status(&job_id).is_none() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("finished job was never dropped");
+    }
+}
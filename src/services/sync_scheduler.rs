@@ -0,0 +1,293 @@
+// src/services/sync_scheduler.rs
+// DOCUMENTATION: Scheduled place refresh
+// PURPOSE: Refresh ratings, business status and opening hours of stored places on a
+// fixed interval without an external cron calling the admin endpoints
+
+use crate::config::Config;
+use crate::services::sync_service::{RefreshMode, RefreshStats, SyncService};
+use crate::services::{GooglePlacesApi, PlaceService, PlacesCache};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// Schedule state reported by GET /admin/sync/status
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleStatus {
+    pub enabled: bool,
+    pub interval_hours: u64,
+    /// Cities refreshed each run (empty: every city)
+    pub cities: Vec<String>,
+    /// Places refreshed per city each run
+    pub limit: u32,
+    /// Whether a scheduled run is in progress
+    pub running: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_run_finished_at: Option<DateTime<Utc>>,
+    pub next_run_at: Option<DateTime<Utc>>,
+    /// Per-city results of the last finished run
+    pub last_run: Vec<RefreshStats>,
+    /// Triggers skipped because the previous run was still active
+    pub runs_skipped: u64,
+}
+
+#[derive(Debug, Default)]
+struct ScheduleState {
+    last_run_at: Option<DateTime<Utc>>,
+    last_run_finished_at: Option<DateTime<Utc>>,
+    next_run_at: Option<DateTime<Utc>>,
+    last_run: Vec<RefreshStats>,
+    runs_skipped: u64,
+}
+
+/// Periodic refresh of stored places
+/// DOCUMENTATION: Every `interval` the scheduler refreshes up to `limit` places per
+/// configured city (least recently verified first, skipping places verified within
+/// the interval), so each run costs at most `limit` Place Details calls per city.
+/// A trigger that fires while the previous run is still active is logged and skipped
+pub struct SyncScheduler {
+    enabled: bool,
+    interval: Duration,
+    cities: Vec<String>,
+    limit: u32,
+    running: AtomicBool,
+    state: Mutex<ScheduleState>,
+}
+
+/// Clears the running flag when a run ends, even if it panicked
+struct RunGuard<'a>(&'a AtomicBool);
+
+impl Drop for RunGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+impl SyncScheduler {
+    /// Create a scheduler
+    pub fn new(enabled: bool, interval: Duration, cities: Vec<String>, limit: u32) -> Self {
+        Self {
+            enabled,
+            interval,
+            cities,
+            limit,
+            running: AtomicBool::new(false),
+            state: Mutex::new(ScheduleState::default()),
+        }
+    }
+
+    /// Scheduler configured by the SYNC_SCHEDULE_* variables
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(
+            config.sync_schedule_enabled,
+            Duration::from_secs(config.sync_schedule_interval_hours.saturating_mul(3600)),
+            config.sync_schedule_cities.clone(),
+            config.sync_schedule_limit,
+        )
+    }
+
+    /// Spawn the timer task; does nothing when the schedule is disabled
+    /// DOCUMENTATION: The first run happens one interval after startup. Runs execute on
+    /// their own tasks so the timer keeps ticking (and skipping) during long runs
+    pub fn start(
+        self: &Arc<Self>,
+        pool: PgPool,
+        google_client: Arc<dyn GooglePlacesApi>,
+        cache: Arc<PlacesCache>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let scheduler = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + scheduler.interval, scheduler.interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            scheduler.set_next_run();
+
+            loop {
+                interval.tick().await;
+                scheduler.set_next_run();
+                log::info!("Scheduled refresh triggered");
+
+                let scheduler = Arc::clone(&scheduler);
+                let pool = pool.clone();
+                let google_client = Arc::clone(&google_client);
+                let cache = Arc::clone(&cache);
+                tokio::spawn(async move {
+                    scheduler.run_once(&pool, google_client.as_ref(), &cache).await;
+                });
+            }
+        });
+    }
+
+    /// Run one refresh pass unless another one is active
+    /// DOCUMENTATION: Returns the per-city stats, or None when skipped. Errors are
+    /// recorded in the stats and logged; they never stop the schedule
+    pub async fn run_once(
+        &self,
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        cache: &Arc<PlacesCache>,
+    ) -> Option<Vec<RefreshStats>> {
+        let Some(_guard) = self.try_begin() else {
+            log::warn!("Scheduled refresh skipped: the previous run is still active");
+            self.lock().runs_skipped += 1;
+            return None;
+        };
+        self.lock().last_run_at = Some(Utc::now());
+
+        let cities: Vec<Option<&str>> = if self.cities.is_empty() {
+            vec![None]
+        } else {
+            self.cities.iter().map(|city| Some(city.as_str())).collect()
+        };
+        let older_than = chrono::Duration::from_std(self.interval).ok();
+
+        let mut results = Vec::with_capacity(cities.len());
+        for city in cities {
+            let stats = match SyncService::refresh_existing_places(
+                pool,
+                google_client,
+                city,
+                self.limit,
+                older_than,
+                RefreshMode::Full,
+            )
+            .await
+            {
+                Ok(stats) => stats,
+                Err(e) => {
+                    let mut stats = RefreshStats::new(city.map(str::to_string));
+                    stats.errors.push(format!("Refresh failed: {}", e));
+                    stats
+                }
+            };
+
+            for refreshed in &stats.cities {
+                PlaceService::invalidate_search_cache(cache, refreshed).await;
+            }
+            log::info!(
+                "Scheduled refresh of {}: {} refreshed, {} deactivated, {} failed, {} errors",
+                city.unwrap_or("all cities"),
+                stats.places_refreshed,
+                stats.places_deactivated,
+                stats.places_failed,
+                stats.errors.len()
+            );
+            results.push(stats);
+        }
+
+        let mut state = self.lock();
+        state.last_run_finished_at = Some(Utc::now());
+        state.last_run = results.clone();
+        Some(results)
+    }
+
+    /// Current schedule state
+    pub fn status(&self) -> ScheduleStatus {
+        let state = self.lock();
+        ScheduleStatus {
+            enabled: self.enabled,
+            interval_hours: self.interval.as_secs() / 3600,
+            cities: self.cities.clone(),
+            limit: self.limit,
+            running: self.running.load(Ordering::SeqCst),
+            last_run_at: state.last_run_at,
+            last_run_finished_at: state.last_run_finished_at,
+            next_run_at: state.next_run_at,
+            last_run: state.last_run.clone(),
+            runs_skipped: state.runs_skipped,
+        }
+    }
+
+    fn try_begin(&self) -> Option<RunGuard<'_>> {
+        self.running
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .ok()
+            .map(|_| RunGuard(&self.running))
+    }
+
+    fn set_next_run(&self) {
+        let next = chrono::Duration::from_std(self.interval)
+            .ok()
+            .and_then(|interval| Utc::now().checked_add_signed(interval));
+        self.lock().next_run_at = next;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ScheduleState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::DEFAULT_MAX_ENTRIES;
+    use crate::test_support::{lazy_pool, MockGooglePlacesClient};
+
+    fn scheduler(cities: &[&str]) -> SyncScheduler {
+        SyncScheduler::new(
+            true,
+            Duration::from_secs(24 * 3600),
+            cities.iter().map(|city| city.to_string()).collect(),
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_run_records_each_city_and_survives_errors() {
+        let scheduler = scheduler(&["Zaragoza", "Huesca"]);
+        let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+
+        // The lazy pool has no database: every city fails, the run still finishes
+        let results = scheduler
+            .run_once(&lazy_pool(), &MockGooglePlacesClient::new(), &cache)
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].city.as_deref(), Some("Huesca"));
+        assert!(results.iter().all(|stats| !stats.errors.is_empty()));
+
+        let status = scheduler.status();
+        assert!(!status.running);
+        assert!(status.last_run_at.is_some());
+        assert!(status.last_run_finished_at.is_some());
+        assert_eq!(status.last_run.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_run_is_skipped() {
+        let scheduler = scheduler(&[]);
+        let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+
+        let guard = scheduler.try_begin().unwrap();
+        assert!(scheduler.status().running);
+        let skipped = scheduler
+            .run_once(&lazy_pool(), &MockGooglePlacesClient::new(), &cache)
+            .await;
+        assert!(skipped.is_none());
+        assert_eq!(scheduler.status().runs_skipped, 1);
+        assert!(scheduler.status().last_run_at.is_none());
+
+        drop(guard);
+        assert!(!scheduler.status().running);
+        assert!(scheduler.try_begin().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_schedule_has_no_next_run() {
+        let scheduler = Arc::new(SyncScheduler::new(false, Duration::from_secs(3600), Vec::new(), 10));
+        let cache = Arc::new(PlacesCache::new(60, DEFAULT_MAX_ENTRIES));
+
+        scheduler.start(lazy_pool(), Arc::new(MockGooglePlacesClient::new()), cache);
+
+        let status = scheduler.status();
+        assert!(!status.enabled);
+        assert!(status.next_run_at.is_none());
+    }
+}
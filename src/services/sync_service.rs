@@ -2,14 +2,273 @@
 // DOCUMENTATION: Google Places synchronization service
 // PURPOSE: Orchestrate bulk data import from Google Places API
 
-use crate::db::{PhotoRepository, PlaceRepository, ReviewRepository};
-use crate::errors::PlacesError;
-use crate::models::{CreatePhotoRequest, CreateReviewRequest};
-use crate::services::{GooglePlacesClient, GridGenerator};
+use crate::config::Config;
+use crate::db::{PhotoRepository, PlaceRepository, ReviewRepository, SyncProgressRepository, UpsertedPlace};
+use crate::errors::{GoogleApiError, PlacesError};
+use crate::models::{
+    detect_swapped_coordinates, google_review_source_id, legacy_google_review_source_id, CreatePhotoRequest,
+    CreateReviewRequest, FieldChange, Place,
+};
+use crate::services::google_places_client::{
+    DetailsFields, DetailsOptions, GooglePlace, NearbyOutcome, NearbyPages, NearbySearchParams,
+    MAX_NEARBY_PAGES, MAX_NEARBY_RESULTS,
+};
+use crate::services::{
+    CityBounds, GooglePlacesApi, GooglePlacesClient, GridCell, GridGenerator, GridPattern,
+};
 use chrono::{TimeZone, Utc};
+use futures::stream::{self, FuturesUnordered, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use std::time::Instant;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Nearby Search pages requested per grid cell (Google's maximum)
+const SYNC_PAGES_PER_CELL: u8 = MAX_NEARBY_PAGES;
+
+/// Extra attempts for a grid cell that failed with a transient Google error
+const CELL_RETRIES: u32 = 2;
+
+/// Pause before retrying a cell (doubled on each further attempt)
+const CELL_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Passes over cells that failed with a transient error, when no configuration is given
+pub const DEFAULT_FAILED_CELL_RETRIES: u32 = 1;
+
+/// Largest accepted SYNC_FAILED_CELL_RETRIES
+pub const MAX_FAILED_CELL_RETRIES: u32 = 5;
+
+/// Pause before each pass over failed cells when no configuration is given
+pub const DEFAULT_FAILED_CELL_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Cell retries after OVER_QUERY_LIMIT when no configuration is given
+pub const DEFAULT_QUOTA_BACKOFF_RETRIES: u32 = 3;
+
+/// Largest accepted SYNC_QUOTA_BACKOFF_RETRIES
+pub const MAX_QUOTA_BACKOFF_RETRIES: u32 = 10;
+
+/// First wait after OVER_QUERY_LIMIT (doubled on each further backoff)
+const QUOTA_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Longest single wait after OVER_QUERY_LIMIT when no configuration is given
+pub const DEFAULT_QUOTA_MAX_WAIT: Duration = Duration::from_secs(120);
+
+/// Results at which a cell is considered saturated: Google returned every place it
+/// will give for one search, so more may be hidden
+const SATURATED_CELL_RESULTS: usize =
+    MAX_NEARBY_RESULTS / MAX_NEARBY_PAGES as usize * SYNC_PAGES_PER_CELL as usize;
+
+/// Place Details requests in flight per cell when no configuration is given
+pub const DEFAULT_DETAILS_CONCURRENCY: usize = 5;
+
+/// Grid cells searched at once when the request does not ask for more
+pub const DEFAULT_CELL_CONCURRENCY: usize = 1;
+
+/// Cities synced at once by a batch when the request does not ask for more
+pub const DEFAULT_CITY_CONCURRENCY: usize = 1;
+
+/// Smallest radius a saturated cell is subdivided down to when no configuration is given
+pub const DEFAULT_MIN_CELL_RADIUS_M: u32 = 150;
+
+/// Cells searched per city sync, subdivisions included, when no configuration is given
+pub const DEFAULT_MAX_CELLS: usize = 500;
+
+/// Places refreshed per run when no limit is given
+pub const DEFAULT_REFRESH_LIMIT: u32 = 100;
+
+/// Largest `limit` accepted for a refresh run (each place is one Place Details request)
+pub const MAX_REFRESH_LIMIT: u32 = 1000;
+
+/// Days since the last verification after which a stored place counts as stale
+pub const DEFAULT_STALE_AFTER_DAYS: u32 = 30;
+
+/// Days a stored place's details stay fresh enough to skip Place Details in a sync
+pub const DEFAULT_DETAILS_REFRESH_DAYS: u32 = 30;
+
+/// Google requests a sync run may make when no configuration is given
+pub const DEFAULT_MAX_API_REQUESTS: u32 = 10_000;
+
+/// Per-cell entries kept in SyncStats::cells when no configuration is given
+pub const DEFAULT_MAX_CELL_STATS: usize = 500;
+
+/// Place names kept per cell in a dry run's samples
+pub const DRY_RUN_SAMPLE_SIZE: usize = 5;
+
+/// Age up to which a completed cell is skipped by a resumed sync when no configuration
+/// is given
+pub const DEFAULT_RESUME_WINDOW: Duration = Duration::from_secs(24 * 3600);
+
+/// Live copy of a running sync's stats, shared with whoever started it
+pub type SyncProgress = Arc<Mutex<SyncStats>>;
+
+/// Tuning for a sync run
+/// DOCUMENTATION: Built from Config by the admin handlers; `Default` matches the
+/// documented environment defaults
+#[derive(Debug, Clone)]
+pub struct SyncOptions {
+    /// Place Details requests in flight at once (SYNC_DETAILS_CONCURRENCY).
+    /// Pacing is still done by the client's shared rate limiter
+    pub details_concurrency: usize,
+    /// Grid cells searched at once (chosen per request, capped by
+    /// SYNC_MAX_CELL_CONCURRENCY); they share the same rate limiter
+    pub cell_concurrency: usize,
+    /// Geocode cities without stored or built-in bounds (GEOCODING_FALLBACK_ENABLED)
+    pub geocoding_fallback: bool,
+    /// Saturated cells are only split while the children keep at least this radius
+    /// (SYNC_MIN_CELL_RADIUS_M)
+    pub min_cell_radius_m: u32,
+    /// Hard cap on cells searched per city, subdivisions included (SYNC_MAX_CELLS)
+    pub max_cells: usize,
+    /// Layout of the city grid (chosen per request, square by default)
+    pub grid_pattern: GridPattern,
+    /// Skip cells completed within `resume_window` (chosen per request)
+    pub resume: bool,
+    /// How recent a completed cell must be to be skipped (SYNC_RESUME_WINDOW_HOURS)
+    pub resume_window: Duration,
+    /// Updated with the stats so far before each cell (background jobs)
+    pub progress: Option<SyncProgress>,
+    /// Search and count as usual but write nothing to the database (chosen per request)
+    pub dry_run: bool,
+    /// In a dry run, count new places without fetching their Place Details
+    pub skip_details: bool,
+    /// Stored places verified fewer days ago than this skip Place Details (chosen per
+    /// request)
+    pub details_refresh_days: u32,
+    /// Google requests (nearby pages and details) the run may make before it stops
+    /// (per request, capped by SYNC_MAX_API_REQUESTS)
+    pub max_api_requests: u32,
+    /// First pause between immediate retries of a cell (doubled on each attempt)
+    pub cell_retry_delay: Duration,
+    /// Passes over cells that still failed with a transient error once every other
+    /// cell was searched (SYNC_FAILED_CELL_RETRIES)
+    pub failed_cell_retries: u32,
+    /// Pause before each of those passes (SYNC_FAILED_CELL_RETRY_DELAY_SECONDS)
+    pub failed_cell_retry_delay: Duration,
+    /// Times a cell is searched again after backing off from OVER_QUERY_LIMIT
+    /// before the sync stops (SYNC_QUOTA_BACKOFF_RETRIES)
+    pub quota_backoff_retries: u32,
+    /// First backoff wait, doubled on each further one
+    pub quota_backoff: Duration,
+    /// Longest single backoff wait (SYNC_QUOTA_MAX_WAIT_SECONDS)
+    pub quota_max_wait: Duration,
+    /// Searched cells listed in SyncStats::cells (SYNC_MAX_CELL_STATS)
+    pub max_cell_stats: usize,
+    /// List the field changes of each updated place in SyncStats::changes (chosen per
+    /// request); the per-field counts are always kept
+    pub verbose_changes: bool,
+}
+
+impl Default for SyncOptions {
+    fn default() -> Self {
+        Self {
+            details_concurrency: DEFAULT_DETAILS_CONCURRENCY,
+            cell_concurrency: DEFAULT_CELL_CONCURRENCY,
+            geocoding_fallback: false,
+            min_cell_radius_m: DEFAULT_MIN_CELL_RADIUS_M,
+            max_cells: DEFAULT_MAX_CELLS,
+            grid_pattern: GridPattern::Square,
+            resume: false,
+            resume_window: DEFAULT_RESUME_WINDOW,
+            progress: None,
+            dry_run: false,
+            skip_details: false,
+            details_refresh_days: DEFAULT_DETAILS_REFRESH_DAYS,
+            max_api_requests: DEFAULT_MAX_API_REQUESTS,
+            cell_retry_delay: CELL_RETRY_DELAY,
+            failed_cell_retries: DEFAULT_FAILED_CELL_RETRIES,
+            failed_cell_retry_delay: DEFAULT_FAILED_CELL_RETRY_DELAY,
+            quota_backoff_retries: DEFAULT_QUOTA_BACKOFF_RETRIES,
+            quota_backoff: QUOTA_BACKOFF,
+            quota_max_wait: DEFAULT_QUOTA_MAX_WAIT,
+            max_cell_stats: DEFAULT_MAX_CELL_STATS,
+            verbose_changes: false,
+        }
+    }
+}
+
+impl SyncOptions {
+    /// Options from the application configuration
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            details_concurrency: config.sync_details_concurrency,
+            cell_concurrency: DEFAULT_CELL_CONCURRENCY,
+            geocoding_fallback: config.geocoding_fallback_enabled,
+            min_cell_radius_m: config.sync_min_cell_radius_m,
+            max_cells: config.sync_max_cells,
+            grid_pattern: GridPattern::Square,
+            resume: false,
+            resume_window: Duration::from_secs(config.sync_resume_window_hours * 3600),
+            progress: None,
+            dry_run: false,
+            skip_details: false,
+            details_refresh_days: DEFAULT_DETAILS_REFRESH_DAYS,
+            max_api_requests: config.sync_max_api_requests,
+            cell_retry_delay: CELL_RETRY_DELAY,
+            failed_cell_retries: config.sync_failed_cell_retries,
+            failed_cell_retry_delay: Duration::from_secs(config.sync_failed_cell_retry_delay_seconds),
+            quota_backoff_retries: config.sync_quota_backoff_retries,
+            quota_backoff: QUOTA_BACKOFF,
+            quota_max_wait: Duration::from_secs(config.sync_quota_max_wait_seconds),
+            max_cell_stats: config.sync_max_cell_stats,
+            verbose_changes: false,
+        }
+    }
+
+    /// Wait before quota backoff number `attempt` (from 1): `quota_backoff` doubled
+    /// each time, at most `quota_max_wait`
+    fn quota_wait(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.quota_backoff.saturating_mul(factor).min(self.quota_max_wait)
+    }
+
+    /// Publish the stats so far to `progress`, if anyone is watching
+    fn report(&self, stats: &SyncStats) {
+        if let Some(progress) = &self.progress {
+            *progress.lock().unwrap_or_else(PoisonError::into_inner) = stats.clone();
+        }
+    }
+}
+
+/// Place names found in one cell during a dry run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellSample {
+    pub cell_id: String,
+    /// First DRY_RUN_SAMPLE_SIZE names, in Google's order
+    pub places: Vec<String>,
+}
+
+/// Results of one searched grid cell
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellStat {
+    pub cell_id: String,
+    /// Places Google returned for the cell
+    pub retrieved: u32,
+    pub created: u32,
+    pub skipped: u32,
+    pub failed: u32,
+}
+
+/// Field changes of one stored place updated by a sync
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceChanges {
+    pub place_id: Uuid,
+    pub name: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// A wait after Google reported OVER_QUERY_LIMIT for a cell
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaBackoff {
+    pub cell_id: String,
+    /// Backoff number for this cell, starting at 1
+    pub attempt: u32,
+    pub wait_seconds: u64,
+    pub error: String,
+    pub at: String,
+}
 
 /// Synchronization statistics
 /// DOCUMENTATION: Tracks results of a sync operation
@@ -21,16 +280,63 @@ pub struct SyncStats {
     pub api_requests: u32,
     /// Total places retrieved from API
     pub places_retrieved: u32,
+    /// Grid cells searched in this run, subdivisions included
+    pub cells_processed: u32,
+    /// Grid cells skipped because a recent run already completed them (resume)
+    pub cells_skipped_resume: u32,
+    /// Grid cells for which Google reported ZERO_RESULTS
+    pub cells_empty: u32,
+    /// Saturated cells that were split into four smaller cells
+    pub cells_subdivided: u32,
+    /// Grid cells dropped because their center lies outside the city polygon
+    pub cells_skipped_outside_polygon: u32,
     /// Places successfully created in database
     pub places_created: u32,
     /// Places skipped (already exist)
     pub places_skipped: u32,
+    /// Stored places whose Place Details were recent enough not to fetch again
+    pub details_skipped: u32,
     /// Places that failed to insert
     pub places_failed: u32,
+    /// Stored places deactivated because Google reports them permanently closed
+    #[serde(default)]
+    pub places_deactivated: u32,
+    /// Stored places whose data changed (counted within places_skipped)
+    #[serde(default)]
+    pub places_changed: u32,
+    /// Times each field changed on stored places
+    #[serde(default)]
+    pub changed_fields: HashMap<String, u32>,
+    /// Changes per updated place (only with `verbose_changes`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<PlaceChanges>,
     /// Reviews successfully created
     pub reviews_created: u32,
     /// Photos successfully created
     pub photos_created: u32,
+    /// Nothing was written: place, review and photo counts are what a real sync
+    /// would have stored
+    #[serde(default)]
+    pub dry_run: bool,
+    /// The run stopped early because it used up its API request budget
+    #[serde(default)]
+    pub budget_exhausted: bool,
+    /// Cells left unsearched (or only partly searched) when the budget ran out
+    #[serde(default)]
+    pub cells_remaining: u32,
+    /// Place names per searched cell (dry runs only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub samples: Vec<CellSample>,
+    /// Places created per internal type (restaurant, bar, ...)
+    #[serde(default)]
+    pub by_type: HashMap<String, u32>,
+    /// Counts per searched cell, in the order cells finished (at most
+    /// SYNC_MAX_CELL_STATS entries)
+    #[serde(default)]
+    pub cells: Vec<CellStat>,
+    /// Waits after OVER_QUERY_LIMIT before searching a cell again
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quota_backoffs: Vec<QuotaBackoff>,
     /// Error messages encountered
     pub errors: Vec<String>,
     /// Total sync duration in seconds
@@ -48,11 +354,28 @@ impl SyncStats {
             city,
             api_requests: 0,
             places_retrieved: 0,
+            cells_processed: 0,
+            cells_skipped_resume: 0,
+            cells_empty: 0,
+            cells_subdivided: 0,
+            cells_skipped_outside_polygon: 0,
             places_created: 0,
             places_skipped: 0,
+            details_skipped: 0,
             places_failed: 0,
+            places_deactivated: 0,
+            places_changed: 0,
             reviews_created: 0,
             photos_created: 0,
+            dry_run: false,
+            budget_exhausted: false,
+            cells_remaining: 0,
+            samples: Vec::new(),
+            by_type: HashMap::new(),
+            cells: Vec::new(),
+            changed_fields: HashMap::new(),
+            changes: Vec::new(),
+            quota_backoffs: Vec::new(),
             errors: Vec::new(),
             duration_seconds: 0,
             started_at: Utc::now().to_rfc3339(),
@@ -60,6 +383,31 @@ impl SyncStats {
         }
     }
 
+    /// Add the counts, samples and errors of one searched cell
+    fn merge(&mut self, cell: SyncStats) {
+        self.api_requests += cell.api_requests;
+        self.places_retrieved += cell.places_retrieved;
+        self.cells_empty += cell.cells_empty;
+        self.places_created += cell.places_created;
+        self.places_skipped += cell.places_skipped;
+        self.details_skipped += cell.details_skipped;
+        self.places_failed += cell.places_failed;
+        self.places_deactivated += cell.places_deactivated;
+        self.places_changed += cell.places_changed;
+        self.reviews_created += cell.reviews_created;
+        self.photos_created += cell.photos_created;
+        self.samples.extend(cell.samples);
+        for (place_type, created) in cell.by_type {
+            *self.by_type.entry(place_type).or_default() += created;
+        }
+        for (field, changed) in cell.changed_fields {
+            *self.changed_fields.entry(field).or_default() += changed;
+        }
+        self.changes.extend(cell.changes);
+        self.quota_backoffs.extend(cell.quota_backoffs);
+        self.errors.extend(cell.errors);
+    }
+
     /// Mark sync as completed
     pub fn complete(&mut self, duration: u64) {
         self.duration_seconds = duration;
@@ -67,6 +415,293 @@ impl SyncStats {
     }
 }
 
+/// What a refresh rewrites for each place
+/// DOCUMENTATION: `full` updates the Google fields of the place row (ratings, status,
+/// hours, contact data). `photos` and `reviews` request only that part of Place
+/// Details and upsert it through PhotoRepository/ReviewRepository, leaving the place
+/// row and its last verification time untouched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RefreshMode {
+    #[default]
+    Full,
+    Photos,
+    Reviews,
+}
+
+impl RefreshMode {
+    /// Name used in requests and logs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Photos => "photos",
+            Self::Reviews => "reviews",
+        }
+    }
+
+    /// Place Details fields requested in this mode
+    pub fn details_fields(&self) -> DetailsFields {
+        match self {
+            Self::Full => DetailsFields::Full,
+            Self::Photos => DetailsFields::Photos,
+            Self::Reviews => DetailsFields::Reviews,
+        }
+    }
+}
+
+/// Results of `SyncService::refresh_existing_places`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshStats {
+    /// City filter, None when every city was eligible
+    pub city: Option<String>,
+    /// What was refreshed
+    #[serde(default)]
+    pub mode: RefreshMode,
+    /// Place Details requests made
+    pub api_requests: u32,
+    /// Places picked for refresh
+    pub places_checked: u32,
+    /// Places updated with fresh Google data
+    pub places_refreshed: u32,
+    /// Places deactivated because Google reported NOT_FOUND
+    pub places_deactivated: u32,
+    /// Places whose details or update failed
+    pub places_failed: u32,
+    /// Photos written (photos mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photos_created: Option<u32>,
+    /// Reviews written (reviews mode only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reviews_created: Option<u32>,
+    /// Cities with refreshed or deactivated places (for cache invalidation)
+    pub cities: BTreeSet<String>,
+    /// Error messages encountered
+    pub errors: Vec<String>,
+    /// Total refresh duration in seconds
+    pub duration_seconds: u64,
+    /// Timestamp when the refresh started
+    pub started_at: String,
+    /// Timestamp when the refresh completed
+    pub completed_at: Option<String>,
+}
+
+impl RefreshStats {
+    /// Create new refresh statistics tracker for a full refresh
+    pub fn new(city: Option<String>) -> Self {
+        Self::for_mode(city, RefreshMode::Full)
+    }
+
+    /// Create new refresh statistics tracker reporting the counters of `mode`
+    pub fn for_mode(city: Option<String>, mode: RefreshMode) -> Self {
+        Self {
+            city,
+            mode,
+            api_requests: 0,
+            places_checked: 0,
+            places_refreshed: 0,
+            places_deactivated: 0,
+            places_failed: 0,
+            photos_created: (mode == RefreshMode::Photos).then_some(0),
+            reviews_created: (mode == RefreshMode::Reviews).then_some(0),
+            cities: BTreeSet::new(),
+            errors: Vec::new(),
+            duration_seconds: 0,
+            started_at: Utc::now().to_rfc3339(),
+            completed_at: None,
+        }
+    }
+}
+
+/// Cells still to be searched during a city sync
+/// DOCUMENTATION: Seeded with the city grid; saturated cells push their four
+/// subdivisions while they stay above the minimum radius and within the cell cap.
+/// Cells whose search failed are set aside and re-queued by `start_retry_pass` once
+/// the pending cells run out, up to `retry_passes` times
+struct CellQueue {
+    pending: VecDeque<GridCell>,
+    /// Cells queued so far, searched or not
+    total: usize,
+    min_radius_m: u32,
+    max_cells: usize,
+    cap_reached: bool,
+    /// Failed cells with their last error, waiting for a retry pass
+    failed: Vec<(GridCell, PlacesError)>,
+    /// Cells re-queued by a retry pass (already counted as processed)
+    retrying: HashSet<String>,
+    retry_passes: u32,
+    passes_done: u32,
+}
+
+impl CellQueue {
+    fn new(cells: Vec<GridCell>, options: &SyncOptions) -> Self {
+        Self {
+            total: cells.len(),
+            pending: cells.into(),
+            min_radius_m: options.min_cell_radius_m,
+            max_cells: options.max_cells,
+            cap_reached: false,
+            failed: Vec::new(),
+            retrying: HashSet::new(),
+            retry_passes: options.failed_cell_retries,
+            passes_done: 0,
+        }
+    }
+
+    fn pop(&mut self) -> Option<GridCell> {
+        self.pending.pop_front()
+    }
+
+    /// Put a popped cell back at the front of the queue
+    fn requeue(&mut self, cell: GridCell) {
+        self.pending.push_front(cell);
+    }
+
+    /// Cells queued or failed but not searched successfully yet
+    fn remaining(&self) -> usize {
+        self.pending.len() + self.failed.len()
+    }
+
+    /// Set a cell aside for the next retry pass
+    fn fail(&mut self, cell: GridCell, error: PlacesError) {
+        self.failed.push((cell, error));
+    }
+
+    /// Whether `cell` is being searched again by a retry pass
+    fn is_retry(&self, cell: &GridCell) -> bool {
+        self.retrying.contains(&cell.cell_id)
+    }
+
+    /// Re-queue the failed cells; false when there are none or no passes are left
+    fn start_retry_pass(&mut self) -> bool {
+        if self.failed.is_empty() || self.passes_done >= self.retry_passes {
+            return false;
+        }
+        self.passes_done += 1;
+        for (cell, _) in self.failed.drain(..) {
+            self.retrying.insert(cell.cell_id.clone());
+            self.pending.push_back(cell);
+        }
+        true
+    }
+
+    /// Cells that still failed when the run ended, with their last error
+    fn take_failed(&mut self) -> Vec<(GridCell, PlacesError)> {
+        std::mem::take(&mut self.failed)
+    }
+
+    /// Queue the four subdivisions of a saturated cell
+    /// DOCUMENTATION: Returns false when the cell is already at the minimum radius or
+    /// the children would exceed the cell cap
+    fn subdivide(&mut self, cell: &GridCell) -> bool {
+        if GridGenerator::subdivided_radius(cell.radius) < self.min_radius_m {
+            return false;
+        }
+
+        let children = GridGenerator::subdivide(cell);
+        if self.total + children.len() > self.max_cells {
+            if !self.cap_reached {
+                log::warn!(
+                    "Cell cap of {} reached; saturated cells are no longer subdivided",
+                    self.max_cells
+                );
+                self.cap_reached = true;
+            }
+            return false;
+        }
+
+        self.total += children.len();
+        self.pending.extend(children);
+        true
+    }
+}
+
+/// State shared by the cells of one grid sync
+struct CellRun<'a> {
+    pool: &'a PgPool,
+    google_client: &'a dyn GooglePlacesApi,
+    label: &'a str,
+    city: &'a str,
+    place_type: Option<&'a str>,
+    language: Option<&'a str>,
+    options: &'a SyncOptions,
+    /// Google ids whose details were already requested in this run
+    detailed: Mutex<HashSet<String>>,
+    /// Google requests made or reserved by the cells so far
+    api_requests: AtomicU32,
+}
+
+impl CellRun<'_> {
+    /// Reserve `requests` from the budget, all or nothing
+    fn try_reserve(&self, requests: u32) -> bool {
+        let max = self.options.max_api_requests;
+        self.api_requests
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                (max.saturating_sub(used) >= requests).then_some(used + requests)
+            })
+            .is_ok()
+    }
+
+    /// Reserve as many of `requests` as the budget allows, returning how many
+    fn reserve_up_to(&self, requests: u32) -> u32 {
+        let max = self.options.max_api_requests;
+        let mut granted = 0;
+        let _ = self.api_requests.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+            granted = requests.min(max.saturating_sub(used));
+            Some(used + granted)
+        });
+        granted
+    }
+
+    /// Give back reserved requests that were not made
+    fn release(&self, requests: u32) {
+        self.api_requests.fetch_sub(requests, Ordering::SeqCst);
+    }
+}
+
+/// What searching one cell produced, for `sync_grid` to merge
+struct CellOutcome {
+    cell: GridCell,
+    stats: SyncStats,
+    /// Google returned a full result set; the cell should be subdivided
+    saturated: bool,
+    /// The budget ran out before every new place got its details
+    over_budget: bool,
+    /// The search failed; the caller decides between retrying and stopping
+    error: Option<PlacesError>,
+}
+
+/// Result of persisting a single Google place
+/// DOCUMENTATION: Returned by `SyncService::persist_google_place`
+#[derive(Debug, Clone)]
+pub struct PersistedPlace {
+    /// Stored place record
+    pub place: Place,
+    /// Whether the place was newly inserted
+    pub created: bool,
+    /// Whether an active place was deactivated (permanently closed)
+    pub deactivated: bool,
+    /// Reviews written for the place
+    pub reviews_created: u32,
+    /// Photos written for the place
+    pub photos_created: u32,
+    /// Fields the upsert changed on an existing place
+    pub changes: Vec<FieldChange>,
+}
+
+impl From<UpsertedPlace> for PersistedPlace {
+    /// An upserted place with no reviews or photos written
+    fn from(upserted: UpsertedPlace) -> Self {
+        PersistedPlace {
+            place: upserted.place,
+            created: upserted.created,
+            deactivated: upserted.deactivated,
+            reviews_created: 0,
+            photos_created: 0,
+            changes: upserted.changes,
+        }
+    }
+}
+
 /// Sync service for Google Places integration
 /// DOCUMENTATION: Handles bulk synchronization of places from Google Places API
 pub struct SyncService;
@@ -88,229 +723,285 @@ impl SyncService {
     /// * `google_client` - Google Places API client
     /// * `city` - City name to sync
     /// * `place_type` - Optional filter (e.g., "restaurant", "bar")
-    /// * `cell_size_km` - Grid cell size (default 1.5 km)
+    /// * `cell_size_km` - Grid cell size (default 1.5 km, square grid only)
     /// * `radius_m` - Search radius per cell (default 1000 m)
+    /// * `language` - Google response language
+    /// * `options` - Concurrency tuning, cell limits and grid pattern
     ///
     /// # Returns
     /// SyncStats with operation results
+    #[allow(clippy::too_many_arguments)]
     pub async fn sync_city(
         pool: &PgPool,
-        google_client: &GooglePlacesClient,
+        google_client: &dyn GooglePlacesApi,
         city: &str,
         place_type: Option<&str>,
         cell_size_km: Option<f64>,
         radius_m: Option<u32>,
+        language: Option<&str>,
+        options: &SyncOptions,
     ) -> Result<SyncStats, PlacesError> {
-        let start_time = Instant::now();
-        let mut stats = SyncStats::new(city.to_string());
-
         log::info!("Starting sync for city: {}", city);
 
-        // Generate grid cells for the city
-        let cells =
-            GridGenerator::generate_for_city(city, cell_size_km, radius_m).map_err(|e| {
-                log::error!("Failed to generate grid for {}: {}", city, e);
-                PlacesError::InvalidInput(e)
-            })?;
+        Self::validate_sync_params(radius_m, language)?;
+
+        // Generate grid cells for the city (stored bounds, built-in, then geocoding)
+        let geocoder = options.geocoding_fallback.then_some(google_client);
+        let cells = GridGenerator::generate_for_city(
+            pool,
+            geocoder,
+            city,
+            options.grid_pattern,
+            cell_size_km,
+            radius_m,
+        )
+        .await
+        .map_err(|e| {
+            log::error!("Failed to generate grid for {}: {}", city, e);
+            e
+        })?;
 
         log::info!("Generated {} grid cells for {}", cells.len(), city);
 
-        // Process each grid cell
-        for (idx, cell) in cells.iter().enumerate() {
-            log::debug!(
-                "Processing cell {}/{}: lat={}, lng={}, radius={}",
-                idx + 1,
+        Self::sync_grid(pool, google_client, city, city, cells, place_type, language, options).await
+    }
+
+    /// Synchronize places inside an arbitrary bounding box
+    /// DOCUMENTATION: Same pipeline as `sync_city` for a neighborhood or any other area
+    /// without stored bounds. The stats are reported under `bounds.name`; synced places
+    /// are assigned to `city`.
+    ///
+    /// # Arguments
+    /// * `bounds` - Area to cover (name used as the stats label)
+    /// * `city` - City stored on the synced places
+    /// * other arguments as in `sync_city`; missing cell size and radius fall back to
+    ///   the defaults carried by `bounds`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn sync_bounds(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        bounds: &CityBounds,
+        city: &str,
+        place_type: Option<&str>,
+        cell_size_km: Option<f64>,
+        radius_m: Option<u32>,
+        language: Option<&str>,
+        options: &SyncOptions,
+    ) -> Result<SyncStats, PlacesError> {
+        log::info!("Starting sync for {} (city {})", bounds.name, city);
+
+        Self::validate_sync_params(radius_m, language)?;
+        bounds.validate().map_err(PlacesError::InvalidInput)?;
+
+        let cells = GridGenerator::generate_for_bounds(bounds, options.grid_pattern, cell_size_km, radius_m);
+        log::info!("Generated {} grid cells for {}", cells.len(), bounds.name);
+
+        Self::sync_grid(pool, google_client, &bounds.name, city, cells, place_type, language, options).await
+    }
+
+    /// Reject out-of-range radii and bad languages before any API call is made or counted
+    fn validate_sync_params(radius_m: Option<u32>, language: Option<&str>) -> Result<(), PlacesError> {
+        if let Some(radius) = radius_m {
+            GooglePlacesClient::validate_radius(radius)?;
+        }
+        if let Some(language) = language {
+            GooglePlacesClient::validate_language(language)?;
+        }
+        Ok(())
+    }
+
+    /// Search every grid cell and persist the places found
+    /// DOCUMENTATION: Shared by `sync_city` and `sync_bounds`. `label` names the run in
+    /// stats and logs; `city` is stored on the places. Up to `cell_concurrency` cells
+    /// are searched at once; the totals do not depend on the order they finish in.
+    #[allow(clippy::too_many_arguments)]
+    async fn sync_grid(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        label: &str,
+        city: &str,
+        cells: Vec<GridCell>,
+        place_type: Option<&str>,
+        language: Option<&str>,
+        options: &SyncOptions,
+    ) -> Result<SyncStats, PlacesError> {
+        let start_time = Instant::now();
+        let mut stats = SyncStats::new(label.to_string());
+        stats.dry_run = options.dry_run;
+
+        // Drop cells over farmland and rivers outside the city's outline, if it has one
+        let cells = match GridGenerator::resolve_city_polygon(pool, city).await {
+            Some(polygon) => {
+                let total = cells.len();
+                let cells = GridGenerator::optimize_grid(cells, &polygon);
+                stats.cells_skipped_outside_polygon = (total - cells.len()) as u32;
+                log::info!(
+                    "Skipping {} of {} cells outside the {} polygon",
+                    stats.cells_skipped_outside_polygon,
+                    total,
+                    city
+                );
+                cells
+            }
+            None => cells,
+        };
+
+        // The cap bounds the whole run, so a grid already above it is refused up front
+        if cells.len() > options.max_cells {
+            return Err(PlacesError::InvalidInput(format!(
+                "Grid for {} has {} cells, above the limit of {}; use a larger cell_size_km",
+                label,
                 cells.len(),
-                cell.latitude,
-                cell.longitude,
-                cell.radius
-            );
-
-            // Query Google Places API for this cell
-            match google_client
-                .nearby_search(cell.latitude, cell.longitude, cell.radius, place_type, None)
-                .await
-            {
-                Ok(google_places) => {
-                    stats.api_requests += 1;
-                    stats.places_retrieved += google_places.len() as u32;
-
-                    log::info!(
-                        "Cell {}/{}: Retrieved {} places",
-                        idx + 1,
-                        cells.len(),
-                        google_places.len()
-                    );
+                options.max_cells
+            )));
+        }
 
-                    // Process each place from this cell
-                    for google_place in google_places {
-                        // ⚠️ OPTIMIZATION: Only fetch details if we need photos/reviews
-                        // For initial population, nearby_search data is sufficient
-                        // This reduces API calls by 50% during sync operations
-                        
-                        // Check if place already exists in DB
-                        let place_exists = PlaceRepository::get_by_google_place_id(
-                            pool, 
-                            &google_place.place_id
-                        ).await.is_ok();
-                        
-                        let detailed_place = if !place_exists {
-                            // New place: fetch full details including photos and reviews
-                            log::debug!("Fetching full details for new place: {}", google_place.name);
-                            match google_client.get_place_details(&google_place.place_id).await {
-                                Ok(details) => {
-                                    stats.api_requests += 1; // Count Place Details API call
-                                    details
-                                }
-                                Err(e) => {
-                                    log::warn!(
-                                        "Could not fetch details for {}: {}. Using basic info.",
-                                        google_place.name,
-                                        e
-                                    );
-                                    google_place.clone()
-                                }
-                            }
-                        } else {
-                            // Existing place: skip details fetch, just use nearby data for update
-                            log::debug!("Place exists, skipping details fetch: {}", google_place.name);
-                            google_place.clone()
-                        };
-
-                        // Convert to CreatePlaceRequest
-                        let create_req = google_client.to_create_request(&detailed_place, city);
-
-                        // Upsert into database
-                        let (place, created) =
-                            match PlaceRepository::upsert_google_place(pool, &create_req).await {
-                                Ok(result) => result,
-                                Err(e) => {
-                                    stats.places_failed += 1;
-                                    let error_msg =
-                                        format!("Failed to store {}: {}", create_req.name, e);
-                                    log::warn!("{}", error_msg);
-                                    stats.errors.push(error_msg);
-                                    continue;
-                                }
-                            };
-
-                        if created {
-                            stats.places_created += 1;
-                        } else {
-                            stats.places_skipped += 1;
-                        }
+        // Cells a recent run already searched are skipped when resuming
+        let completed = if options.resume {
+            let window = chrono::Duration::from_std(options.resume_window).unwrap_or(chrono::Duration::MAX);
+            let since = Utc::now().checked_sub_signed(window).unwrap_or(chrono::DateTime::<Utc>::MIN_UTC);
+            SyncProgressRepository::completed_since(pool, label, place_type, since).await?
+        } else {
+            HashMap::new()
+        };
 
-                        log::debug!("Upserted place: {}", create_req.name);
-
-                        // Save reviews (if available)
-                        if let Some(ref reviews) = detailed_place.reviews {
-                            for review in reviews {
-                                if let Some(rating) = review.rating {
-                                    let review_req = CreateReviewRequest {
-                                        place_id: place.id,
-                                        source: "google".to_string(),
-                                        source_id: Some(format!(
-                                            "{}_{}",
-                                            detailed_place.place_id,
-                                            review.time.unwrap_or(0)
-                                        )),
-                                        author: review.author_name.clone(),
-                                        rating: rating as f32,
-                                        text: review.text.clone(),
-                                        posted_at: review
-                                            .time
-                                            .and_then(|t| Utc.timestamp_opt(t, 0).single())
-                                            .unwrap_or_else(|| Utc::now()),
-                                        is_verified: Some(false),
-                                        has_photo: review
-                                            .profile_photo_url
-                                            .is_some()
-                                            .then_some(true),
-                                    };
-
-                                    match ReviewRepository::create_review(pool, &review_req).await {
-                                        Ok(_) => {
-                                            stats.reviews_created += 1;
-                                        }
-                                        Err(e) => {
-                                            log::warn!(
-                                                "Failed to save review for {}: {}",
-                                                create_req.name,
-                                                e
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
+        let run = CellRun {
+            pool,
+            google_client,
+            label,
+            city,
+            place_type,
+            language,
+            options,
+            detailed: Mutex::new(HashSet::new()),
+            api_requests: AtomicU32::new(0),
+        };
+        let concurrency = options.cell_concurrency.max(1);
 
-                        // Save photos (if available)
-                        if let Some(ref photos) = detailed_place.photos {
-                            for (idx, photo) in photos.iter().enumerate() {
-                                // Construct Google Places Photo URL
-                                let photo_url = format!(
-                                    "https://maps.googleapis.com/maps/api/place/photo?maxwidth=800&photoreference={}&key={}",
-                                    photo.photo_reference,
-                                    google_client.get_api_key()
-                                );
-
-                                let thumbnail_url = format!(
-                                    "https://maps.googleapis.com/maps/api/place/photo?maxwidth=400&photoreference={}&key={}",
-                                    photo.photo_reference,
-                                    google_client.get_api_key()
-                                );
-
-                                let photo_req = CreatePhotoRequest {
-                                    place_id: place.id,
-                                    source: "google".to_string(),
-                                    source_photo_reference: Some(photo.photo_reference.clone()),
-                                    photo_url,
-                                    thumbnail_url: Some(thumbnail_url),
-                                    width: photo.width,
-                                    height: photo.height,
-                                    attribution: photo
-                                        .html_attributions
-                                        .as_ref()
-                                        .and_then(|attrs| attrs.first().cloned()),
-                                    is_primary: Some(idx == 0), // First photo is primary
-                                    display_order: Some(idx as i32),
-                                };
-
-                                match PhotoRepository::create_photo(pool, &photo_req).await {
-                                    Ok(_) => {
-                                        stats.photos_created += 1;
-                                    }
-                                    Err(e) => {
-                                        log::warn!(
-                                            "Failed to save photo for {}: {}",
-                                            create_req.name,
-                                            e
-                                        );
-                                    }
-                                }
-                            }
-                        }
+        // Process the grid cells `concurrency` at a time; saturated cells queue their
+        // subdivisions and each finished cell's stats are merged into the run total
+        let mut queue = CellQueue::new(cells, options);
+        let mut in_flight = FuturesUnordered::new();
+        let mut searched = 0;
+        let mut stopping = false;
+        let mut budget_exhausted = false;
+        // Cells stopped by the budget before all their places were handled
+        let mut unfinished = 0;
+        loop {
+            while !stopping && in_flight.len() < concurrency {
+                let Some(cell) = queue.pop() else {
+                    break;
+                };
+                searched += 1;
+                options.report(&stats);
+
+                if let Some(done) = completed.get(&cell.cell_id).filter(|done| done.matches(&cell)) {
+                    stats.cells_skipped_resume += 1;
+                    log::debug!("Cell {} completed at {}, skipping", cell.cell_id, done.completed_at);
 
-                        // Rate limiting: small delay between Place Details calls
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                    // A saturated cell was subdivided last time; its smaller cells may be pending
+                    if done.results_count as usize >= SATURATED_CELL_RESULTS {
+                        queue.subdivide(&cell);
                     }
+                    continue;
                 }
-                Err(e) => {
-                    let error_msg = format!("API error for cell {}: {}", cell.cell_id, e);
+                // A cell is only started with room for all of its nearby pages. Cells
+                // in flight give back the pages they did not use, so the budget is
+                // only exhausted once none are left
+                if !run.try_reserve(u32::from(SYNC_PAGES_PER_CELL)) {
+                    if in_flight.is_empty() {
+                        unfinished += 1;
+                        budget_exhausted = true;
+                        stopping = true;
+                    } else {
+                        searched -= 1;
+                        queue.requeue(cell);
+                    }
+                    break;
+                }
+                // A retried cell was counted when it first failed
+                if !queue.is_retry(&cell) {
+                    stats.cells_processed += 1;
+                }
+
+                log::debug!(
+                    "Processing cell {}/{}: lat={}, lng={}, radius={}",
+                    searched,
+                    queue.total,
+                    cell.latitude,
+                    cell.longitude,
+                    cell.radius
+                );
+                in_flight.push(Self::sync_cell(&run, cell, searched, queue.total));
+            }
+
+            let Some(outcome) = in_flight.next().await else {
+                if stopping || !queue.start_retry_pass() {
+                    break;
+                }
+                log::info!(
+                    "Retrying {} failed cells for {} in {:?} (pass {}/{})",
+                    queue.pending.len(),
+                    label,
+                    options.failed_cell_retry_delay,
+                    queue.passes_done,
+                    queue.retry_passes
+                );
+                tokio::time::sleep(options.failed_cell_retry_delay).await;
+                continue;
+            };
+
+            if outcome.error.is_none() && stats.cells.len() < options.max_cell_stats {
+                stats.cells.push(CellStat {
+                    cell_id: outcome.cell.cell_id.clone(),
+                    retrieved: outcome.stats.places_retrieved,
+                    created: outcome.stats.places_created,
+                    skipped: outcome.stats.places_skipped,
+                    failed: outcome.stats.places_failed,
+                });
+            }
+            stats.merge(outcome.stats);
+            // A full result set means Google may be hiding places in this cell
+            if outcome.saturated && queue.subdivide(&outcome.cell) {
+                stats.cells_subdivided += 1;
+                log::info!("Cell {} saturated, subdivided into 4 cells", outcome.cell.cell_id);
+            }
+            if outcome.over_budget {
+                unfinished += 1;
+                budget_exhausted = true;
+                stopping = true;
+            }
+            match outcome.error {
+                None => {}
+                // Transient failures get another chance once the other cells are done
+                Some(e) if e.google_error().is_some_and(GoogleApiError::is_transient) => {
+                    log::warn!("Cell {} failed, will retry at the end of the run: {}", outcome.cell.cell_id, e);
+                    queue.fail(outcome.cell, e);
+                }
+                Some(e) => {
+                    let error_msg = format!("API error for cell {}: {}", outcome.cell.cell_id, e);
                     log::error!("{}", error_msg);
                     stats.errors.push(error_msg);
 
-                    // Check if it's a rate limit error
-                    if matches!(e, PlacesError::RateLimitExceeded) {
-                        log::error!("Rate limit exceeded, stopping sync");
-                        break;
+                    // Quota and key problems fail every remaining cell the same way;
+                    // cells already in flight are left to finish
+                    if e.google_error().is_some_and(GoogleApiError::is_fatal) {
+                        log::error!("Stopping sync for {}: {}", label, e);
+                        stopping = true;
                     }
                 }
             }
+        }
+
+        if budget_exhausted {
+            Self::stop_for_budget(&mut stats, options, unfinished + queue.remaining());
+        }
 
-            // Add small delay between requests to respect API rate limits
-            // Google allows 100 req/sec, we'll be more conservative
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // Only cells that failed on every pass are reported
+        for (cell, e) in queue.take_failed() {
+            let error_msg = format!("API error for cell {}: {}", cell.cell_id, e);
+            log::error!("{}", error_msg);
+            stats.errors.push(error_msg);
         }
 
         let duration = start_time.elapsed().as_secs();
@@ -318,7 +1009,7 @@ impl SyncService {
 
         log::info!(
             "Sync completed for {}: {} created, {} skipped, {} failed in {}s",
-            city,
+            label,
             stats.places_created,
             stats.places_skipped,
             stats.places_failed,
@@ -328,94 +1019,773 @@ impl SyncService {
         Ok(stats)
     }
 
-    /// Synchronize places for multiple cities
-    /// DOCUMENTATION: Batch sync operation for multiple cities
-    ///
-    /// # Arguments
-    /// * `pool` - Database connection pool
-    /// * `google_client` - Google Places API client
-    /// * `cities` - List of city names to sync
-    /// * `place_type` - Optional place type filter
-    ///
-    /// # Returns
-    /// Vector of SyncStats, one per city
-    pub async fn sync_cities(
-        pool: &PgPool,
-        google_client: &GooglePlacesClient,
-        cities: &[String],
-        place_type: Option<&str>,
-    ) -> Vec<SyncStats> {
-        let mut all_stats = Vec::new();
+    /// Record that the API request budget ended the run with `cells_remaining` cells
+    /// (the current one included) not fully searched
+    fn stop_for_budget(stats: &mut SyncStats, options: &SyncOptions, cells_remaining: usize) {
+        stats.budget_exhausted = true;
+        stats.cells_remaining = cells_remaining as u32;
+        let message = format!(
+            "API request budget of {} exhausted after {} requests; {} cells not searched (run again with resume to continue)",
+            options.max_api_requests, stats.api_requests, cells_remaining
+        );
+        log::warn!("Stopping sync for {}: {}", stats.city, message);
+        stats.errors.push(message);
+    }
 
-        for city in cities {
-            log::info!("Starting sync for city: {}", city);
+    /// Search one grid cell and handle the places found
+    /// DOCUMENTATION: Runs concurrently with other cells of the same run. The cell's
+    /// counts are collected in its own SyncStats for the caller to merge; the only
+    /// state shared with other cells is the run's details set and request budget,
+    /// and request pacing is left to the client's shared rate limiter.
+    /// SYNC_PAGES_PER_CELL requests must be reserved before the call; the unused ones
+    /// are given back once the search is done
+    async fn sync_cell(run: &CellRun<'_>, cell: GridCell, searched: usize, total: usize) -> CellOutcome {
+        let options = run.options;
+        let mut outcome = CellOutcome {
+            stats: SyncStats::new(run.label.to_string()),
+            saturated: false,
+            over_budget: false,
+            error: None,
+            cell,
+        };
+        let stats = &mut outcome.stats;
+        let cell = &outcome.cell;
 
-            match Self::sync_city(pool, google_client, city, place_type, None, None).await {
-                Ok(stats) => {
-                    all_stats.push(stats);
-                }
-                Err(e) => {
-                    log::error!("Failed to sync city {}: {}", city, e);
+        // Query Google Places API for this cell, following next_page_token so
+        // dense cells yield up to 60 places instead of the first 20
+        let search = NearbySearchParams {
+            place_type: run.place_type.map(str::to_string),
+            language: run.language.map(str::to_string),
+            ..NearbySearchParams::new(cell.latitude, cell.longitude, cell.radius)
+        };
+        let google_places = match Self::search_cell_with_backoff(run, &search, cell, stats).await {
+            Ok(NearbyPages { outcome: NearbyOutcome::Empty, api_requests, .. }) => {
+                run.release(u32::from(SYNC_PAGES_PER_CELL).saturating_sub(api_requests));
+                stats.api_requests += api_requests;
+                stats.cells_empty += 1;
 
-                    let mut stats = SyncStats::new(city.clone());
-                    stats.errors.push(format!("Sync failed: {}", e));
-                    stats.complete(0);
-                    all_stats.push(stats);
+                log::info!("Cell {}/{}: No places (ZERO_RESULTS)", searched, total);
+                if !options.dry_run {
+                    Self::record_progress(run.pool, run.label, run.place_type, cell, 0).await;
                 }
+                return outcome;
+            }
+            Ok(NearbyPages { outcome: NearbyOutcome::Results(google_places), api_requests, .. }) => {
+                // First pages served from the client cache cost no request
+                run.release(u32::from(SYNC_PAGES_PER_CELL).saturating_sub(api_requests));
+                stats.api_requests += api_requests;
+                google_places
+            }
+            Err(e) => {
+                run.release(u32::from(SYNC_PAGES_PER_CELL));
+                outcome.error = Some(e);
+                return outcome;
             }
+        };
+
+        let results_count = google_places.len();
+        stats.places_retrieved += results_count as u32;
+        log::info!("Cell {}/{}: Retrieved {} places", searched, total, results_count);
 
-            // Add delay between cities to avoid overwhelming the API
-            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+        if options.dry_run {
+            stats.samples.push(CellSample {
+                cell_id: cell.cell_id.clone(),
+                places: google_places
+                    .iter()
+                    .take(DRY_RUN_SAMPLE_SIZE)
+                    .map(|place| place.name.clone())
+                    .collect(),
+            });
         }
+        outcome.saturated = results_count >= SATURATED_CELL_RESULTS;
 
-        all_stats
-    }
+        // Places verified within details_refresh_days are left alone; new and stale
+        // places get Place Details (photos and reviews), at most once per run
+        let verified = Self::stored_verification(run.pool, &google_places).await;
+        let fresh_since = Utc::now() - chrono::Duration::days(i64::from(options.details_refresh_days));
+        let mut to_detail = Vec::new();
+        let mut to_refresh = Vec::new();
+        {
+            let mut detailed = run.detailed.lock().unwrap_or_else(PoisonError::into_inner);
+            for google_place in google_places {
+                let is_fresh = matches!(
+                    verified.get(&google_place.place_id),
+                    Some(Some(verified_at)) if *verified_at >= fresh_since
+                );
+                if is_fresh || !detailed.insert(google_place.place_id.clone()) {
+                    log::debug!("Place is fresh, skipping details fetch: {}", google_place.name);
+                    stats.details_skipped += 1;
+                    to_refresh.push(google_place);
+                } else {
+                    to_detail.push(google_place);
+                }
+            }
+        }
 
-    /// Get sync summary across multiple city syncs
-    /// DOCUMENTATION: Aggregates statistics from multiple sync operations
-    ///
-    /// # Arguments
-    /// * `stats_list` - List of sync statistics from multiple operations
-    ///
-    /// # Returns
-    /// Aggregated SyncStats representing the total
-    pub fn aggregate_stats(stats_list: &[SyncStats]) -> SyncStats {
-        let mut aggregated = SyncStats::new("Multiple Cities".to_string());
+        // Skipped places are still refreshed from the nearby result (rating, status,
+        // open_now, permanent closure) without touching their reviews and photos
+        if options.dry_run {
+            stats.places_skipped += to_refresh.len() as u32;
+        } else {
+            let refreshed =
+                Self::persist_google_places(run.pool, run.google_client, &to_refresh, run.city, false).await;
+            for (google_place, refreshed) in to_refresh.iter().zip(refreshed) {
+                Self::record_persist(stats, &google_place.name, refreshed, options.verbose_changes);
+            }
+        }
 
-        for stats in stats_list {
-            aggregated.api_requests += stats.api_requests;
-            aggregated.places_retrieved += stats.places_retrieved;
-            aggregated.places_created += stats.places_created;
-            aggregated.places_skipped += stats.places_skipped;
-            aggregated.places_failed += stats.places_failed;
-            aggregated.duration_seconds += stats.duration_seconds;
-            aggregated.errors.extend(stats.errors.clone());
+        // A dry run may count new places without paying for their details
+        if options.dry_run && options.skip_details {
+            for google_place in to_detail.drain(..) {
+                let created = !verified.contains_key(&google_place.place_id);
+                Self::record_dry_run(stats, &google_place, created);
+            }
         }
 
-        aggregated.completed_at = Some(Utc::now().to_rfc3339());
-        aggregated
-    }
+        // Places beyond the budget are left for the next run, which searches this
+        // cell again since it is not recorded as completed
+        let details_budget = run.reserve_up_to(to_detail.len() as u32) as usize;
+        outcome.over_budget = to_detail.len() > details_budget;
+        to_detail.truncate(details_budget);
 
-    /// Update existing places with fresh data from Google
-    /// DOCUMENTATION: Refresh existing places with current Google data
-    /// Future enhancement - not yet implemented
-    #[allow(dead_code)]
-    pub async fn refresh_existing_places(
-        _pool: &PgPool,
-        _google_client: &GooglePlacesClient,
-    ) -> Result<SyncStats, PlacesError> {
-        // TODO: Implement refresh logic
-        // 1. Query all places with google_place_id
-        // 2. For each place, call Google Places Details API
-        // 3. Update rating, status, and other dynamic fields
+        // Details are fetched concurrently; the cell's places are then stored together
+        let mut details = Self::fetch_details(
+            run.google_client,
+            to_detail,
+            run.language,
+            options.details_concurrency,
+        );
+        let mut detailed_places = Vec::new();
+        let mut has_details_flags = Vec::new();
+        while let Some((google_place, result)) = details.next().await {
+            stats.api_requests += 1; // Count Place Details API call
+            let (detailed_place, has_details) = match result {
+                Ok(details) => (GooglePlace::merge(google_place, details), true),
+                Err(e) => {
+                    log::warn!(
+                        "Could not fetch details for {}: {}. Using basic info.",
+                        google_place.name,
+                        e
+                    );
+                    (google_place, false)
+                }
+            };
+
+            if options.dry_run {
+                let created = !verified.contains_key(&detailed_place.place_id);
+                Self::record_dry_run(stats, &detailed_place, created);
+                continue;
+            }
+            detailed_places.push(detailed_place);
+            has_details_flags.push(has_details);
+        }
+
+        let persisted =
+            Self::persist_google_places(run.pool, run.google_client, &detailed_places, run.city, true).await;
+        for ((detailed_place, has_details), persisted) in detailed_places.iter().zip(has_details_flags).zip(persisted) {
+            if let (true, Ok(persisted)) = (has_details, &persisted) {
+                if let Err(e) = PlaceRepository::mark_verified(run.pool, persisted.place.id).await {
+                    log::warn!("Could not mark {} as verified: {}", detailed_place.name, e);
+                }
+            }
+            Self::record_persist(stats, &detailed_place.name, persisted, options.verbose_changes);
+        }
+
+        if !outcome.over_budget && !options.dry_run {
+            Self::record_progress(run.pool, run.label, run.place_type, cell, results_count).await;
+        }
+        outcome
+    }
+
+    /// Mark a cell as completed for later resumes
+    /// DOCUMENTATION: A failed write only costs a re-query on resume, so it is logged
+    /// rather than failing the sync
+    async fn record_progress(
+        pool: &PgPool,
+        label: &str,
+        place_type: Option<&str>,
+        cell: &GridCell,
+        results_count: usize,
+    ) {
+        if let Err(e) = SyncProgressRepository::record_cell(pool, label, place_type, cell, results_count).await {
+            log::warn!("Could not record progress of cell {}: {}", cell.cell_id, e);
+        }
+    }
+
+    /// Fetch Place Details for a cell's new places, `concurrency` requests at a time
+    /// DOCUMENTATION: Yields each nearby place with its details result in completion
+    /// order. Only the number of in-flight requests is bounded here; request pacing is
+    /// left to the client's shared rate limiter.
+    fn fetch_details<'a>(
+        google_client: &'a dyn GooglePlacesApi,
+        places: Vec<GooglePlace>,
+        language: Option<&'a str>,
+        concurrency: usize,
+    ) -> impl Stream<Item = (GooglePlace, Result<GooglePlace, PlacesError>)> + 'a {
+        stream::iter(places)
+            .map(move |place| async move {
+                log::debug!("Fetching full details for new place: {}", place.name);
+                let options = DetailsOptions { language, ..Default::default() };
+                let details = google_client
+                    .get_place_details_with(&place.place_id, options)
+                    .await;
+                (place, details)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Add the outcome of storing one place to the sync statistics
+    fn record_persist(
+        stats: &mut SyncStats,
+        name: &str,
+        result: Result<PersistedPlace, PlacesError>,
+        verbose_changes: bool,
+    ) {
+        match result {
+            Ok(persisted) => {
+                if persisted.created {
+                    stats.places_created += 1;
+                    *stats.by_type.entry(persisted.place.type_field.clone()).or_default() += 1;
+                } else {
+                    stats.places_skipped += 1;
+                }
+                if persisted.deactivated {
+                    stats.places_deactivated += 1;
+                }
+                if !persisted.changes.is_empty() {
+                    stats.places_changed += 1;
+                    for change in &persisted.changes {
+                        *stats.changed_fields.entry(change.field.clone()).or_default() += 1;
+                    }
+                    if verbose_changes {
+                        stats.changes.push(PlaceChanges {
+                            place_id: persisted.place.id,
+                            name: persisted.place.name.clone(),
+                            changes: persisted.changes,
+                        });
+                    }
+                }
+                stats.reviews_created += persisted.reviews_created;
+                stats.photos_created += persisted.photos_created;
+            }
+            Err(e) => {
+                stats.places_failed += 1;
+                let error_msg = format!("Failed to store {}: {}", name, e);
+                log::warn!("{}", error_msg);
+                stats.errors.push(error_msg);
+            }
+        }
+    }
+
+    /// When each stored place was last verified against Place Details, by Google id
+    /// DOCUMENTATION: A failed lookup is logged and treated as "nothing stored", so
+    /// the places get details as new ones would
+    async fn stored_verification(
+        pool: &PgPool,
+        google_places: &[GooglePlace],
+    ) -> HashMap<String, Option<chrono::DateTime<Utc>>> {
+        let ids: Vec<String> = google_places.iter().map(|place| place.place_id.clone()).collect();
+        PlaceRepository::verified_at_by_google_ids(pool, &ids)
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!("Could not look up stored places: {}", e);
+                HashMap::new()
+            })
+    }
+
+    /// Count a place a dry run would have stored, with the reviews and photos
+    /// `persist_google_place` would have saved
+    fn record_dry_run(stats: &mut SyncStats, google_place: &GooglePlace, created: bool) {
+        if created {
+            stats.places_created += 1;
+            let place_type = GooglePlacesClient::map_google_type_to_internal(&google_place.types);
+            *stats.by_type.entry(place_type).or_default() += 1;
+        } else {
+            stats.places_skipped += 1;
+        }
+        stats.reviews_created += google_place
+            .reviews
+            .as_ref()
+            .map_or(0, |reviews| reviews.iter().filter(|review| review.rating.is_some()).count() as u32);
+        stats.photos_created += google_place.photos.as_ref().map_or(0, |photos| photos.len() as u32);
+    }
+
+    /// Search one grid cell, retrying transient Google failures
+    /// DOCUMENTATION: Network and UNKNOWN_ERROR failures are retried up to CELL_RETRIES
+    /// times with a doubling pause; any other error is returned straight away so the
+    /// caller can skip the cell or abort the sync.
+    async fn search_cell(
+        google_client: &dyn GooglePlacesApi,
+        search: &NearbySearchParams,
+        retry_delay: Duration,
+    ) -> Result<NearbyPages, PlacesError> {
+        let mut delay = retry_delay;
+        let mut attempt = 0;
+        loop {
+            match google_client.nearby_search_pages(search, SYNC_PAGES_PER_CELL).await {
+                Err(e)
+                    if attempt < CELL_RETRIES
+                        && e.google_error().is_some_and(GoogleApiError::is_transient) =>
+                {
+                    attempt += 1;
+                    log::warn!(
+                        "Transient error for cell ({}, {}), retry {}/{} in {:?}: {}",
+                        search.latitude,
+                        search.longitude,
+                        attempt,
+                        CELL_RETRIES,
+                        delay,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Search one grid cell, backing off when Google reports OVER_QUERY_LIMIT
+    /// DOCUMENTATION: The quota window often resets within minutes, so the same cell
+    /// is searched again after waiting `quota_backoff` (doubled each time, at most
+    /// `quota_max_wait`), up to `quota_backoff_retries` times. Each wait is recorded in
+    /// the cell's stats; the last error is returned once the retries are used up
+    async fn search_cell_with_backoff(
+        run: &CellRun<'_>,
+        search: &NearbySearchParams,
+        cell: &GridCell,
+        stats: &mut SyncStats,
+    ) -> Result<NearbyPages, PlacesError> {
+        let options = run.options;
+        let mut attempt = 0;
+        loop {
+            match Self::search_cell(run.google_client, search, options.cell_retry_delay).await {
+                Err(e)
+                    if attempt < options.quota_backoff_retries
+                        && e.google_error().is_some_and(GoogleApiError::is_quota) =>
+                {
+                    attempt += 1;
+                    let wait = options.quota_wait(attempt);
+                    log::warn!(
+                        "Quota exceeded for cell {}, backing off {:?} ({}/{}): {}",
+                        cell.cell_id,
+                        wait,
+                        attempt,
+                        options.quota_backoff_retries,
+                        e
+                    );
+                    stats.quota_backoffs.push(QuotaBackoff {
+                        cell_id: cell.cell_id.clone(),
+                        attempt,
+                        wait_seconds: wait.as_secs(),
+                        error: e.to_string(),
+                        at: Utc::now().to_rfc3339(),
+                    });
+                    tokio::time::sleep(wait).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Persist a Google place together with its reviews and photos
+    /// DOCUMENTATION: Shared by the grid sync and on-demand imports. Review and photo
+    /// failures are logged and skipped; only the place upsert itself is fatal.
+    pub async fn persist_google_place(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        google_place: &GooglePlace,
+        city: &str,
+    ) -> Result<PersistedPlace, PlacesError> {
+        // Convert to CreatePlaceRequest and upsert into database
+        let create_req = GooglePlacesClient::to_create_request(google_place, city);
+        let upserted = PlaceRepository::upsert_google_place(pool, &create_req).await?;
+
+        log::debug!("Upserted place: {}", create_req.name);
+
+        Ok(Self::store_children(pool, google_client, google_place, upserted).await)
+    }
+
+    /// Persist a batch of Google places, with their reviews and photos when `with_children`
+    /// DOCUMENTATION: Used by the grid sync for each cell. The places are written with
+    /// one `PlaceRepository::bulk_upsert` statement; if it fails (or the batch repeats
+    /// a place), every place falls back to a single upsert so one bad row only fails
+    /// itself. Places whose coordinates look swapped for the city are rejected per row
+    /// (see `detect_swapped_coordinates`). Results are in input order and count exactly
+    /// as the per-place path.
+    /// Without children only the place rows are refreshed (fresh places whose details
+    /// were not fetched again)
+    pub async fn persist_google_places(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        google_places: &[GooglePlace],
+        city: &str,
+        with_children: bool,
+    ) -> Vec<Result<PersistedPlace, PlacesError>> {
+        if google_places.is_empty() {
+            return Vec::new();
+        }
+
+        // A row with swapped coordinates fails on its own; the rest are written together
+        let mut results: Vec<Option<Result<PersistedPlace, PlacesError>>> = Vec::with_capacity(google_places.len());
+        let mut accepted = Vec::with_capacity(google_places.len());
+        let mut requests = Vec::with_capacity(google_places.len());
+        for (index, google_place) in google_places.iter().enumerate() {
+            let request = GooglePlacesClient::to_create_request(google_place, city);
+            match detect_swapped_coordinates(request.location, Some(&request.city)) {
+                Some(hint) => results.push(Some(Err(PlacesError::ValidationError(hint)))),
+                None => {
+                    results.push(None);
+                    accepted.push(index);
+                    requests.push(request);
+                }
+            }
+        }
+
+        match PlaceRepository::bulk_upsert(pool, &requests).await {
+            Ok(upserted) => {
+                for (&index, upserted) in accepted.iter().zip(upserted) {
+                    results[index] = Some(Ok(if with_children {
+                        Self::store_children(pool, google_client, &google_places[index], upserted).await
+                    } else {
+                        upserted.into()
+                    }));
+                }
+            }
+            Err(e) => {
+                log::warn!(
+                    "Bulk upsert of {} places failed, storing them one by one: {}",
+                    requests.len(),
+                    e
+                );
+                for (&index, request) in accepted.iter().zip(&requests) {
+                    results[index] = Some(if with_children {
+                        Self::persist_google_place(pool, google_client, &google_places[index], city).await
+                    } else {
+                        PlaceRepository::upsert_google_place(pool, request).await.map(PersistedPlace::from)
+                    });
+                }
+            }
+        }
+        // Every slot is filled: rejected above, written or failed just now
+        results.into_iter().flatten().collect()
+    }
+
+    /// Store the reviews and photos of an upserted place
+    async fn store_children(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        google_place: &GooglePlace,
+        upserted: UpsertedPlace,
+    ) -> PersistedPlace {
+        let mut persisted = PersistedPlace::from(upserted);
+        persisted.reviews_created = Self::store_reviews(pool, persisted.place.id, google_place).await;
+        persisted.photos_created = Self::store_photos(pool, google_client, persisted.place.id, google_place).await;
+        persisted
+    }
+
+    /// Store the rated reviews of a Google place, returning how many were written
+    /// DOCUMENTATION: Reviews are keyed by a hash of place, author and text (see
+    /// `google_review_source_id`), so repeated syncs update them in place. Rows still
+    /// keyed by the old `{place}_{time}` id are moved over. Failures are logged and skipped
+    pub async fn store_reviews(pool: &PgPool, place_id: Uuid, google_place: &GooglePlace) -> u32 {
+        let mut created = 0;
+        // Save reviews (if available)
+        if let Some(ref reviews) = google_place.reviews {
+            for review in reviews {
+                if let Some(rating) = review.rating {
+                    let review_req = CreateReviewRequest {
+                        place_id,
+                        source: "google".to_string(),
+                        source_id: Some(google_review_source_id(
+                            &google_place.place_id,
+                            review.author_name.as_deref(),
+                            review.text.as_deref(),
+                            review.time,
+                        )),
+                        author: review.author_name.clone(),
+                        rating: rating as f32,
+                        text: review.text.clone(),
+                        posted_at: review
+                            .time
+                            .and_then(|t| Utc.timestamp_opt(t, 0).single())
+                            .unwrap_or_else(|| Utc::now()),
+                        is_verified: Some(false),
+                        has_photo: review.profile_photo_url.is_some().then_some(true),
+                        language: review.text_language().map(str::to_string),
+                        legacy_source_id: Some(legacy_google_review_source_id(
+                            &google_place.place_id,
+                            review.time,
+                        )),
+                    };
+
+                    match ReviewRepository::create_review(pool, &review_req).await {
+                        Ok(_) => {
+                            created += 1;
+                        }
+                        Err(e) => {
+                            log::warn!("Failed to save review for {}: {}", google_place.name, e);
+                        }
+                    }
+                }
+            }
+        }
+        created
+    }
+
+    /// Store the photos of a Google place, returning how many were written
+    /// DOCUMENTATION: The first photo becomes primary. Photos are keyed by their Google
+    /// reference, so repeated syncs update them in place. Failures are logged and skipped
+    pub async fn store_photos(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        place_id: Uuid,
+        google_place: &GooglePlace,
+    ) -> u32 {
+        let mut created = 0;
+        // Save photos (if available)
+        if let Some(ref photos) = google_place.photos {
+            for (idx, photo) in photos.iter().enumerate() {
+                // Construct Google Places Photo URLs (legacy references or v1 photo names)
+                let photo_url = google_client.get_photo_url(&photo.photo_reference, Some(800));
+                let thumbnail_url = google_client.get_photo_thumbnail_url(&photo.photo_reference);
+
+                let photo_req = CreatePhotoRequest {
+                    place_id,
+                    source: "google".to_string(),
+                    source_photo_reference: Some(photo.photo_reference.clone()),
+                    photo_url,
+                    thumbnail_url: Some(thumbnail_url),
+                    width: photo.width,
+                    height: photo.height,
+                    attribution: photo
+                        .html_attributions
+                        .as_ref()
+                        .and_then(|attrs| attrs.first().cloned()),
+                    is_primary: Some(idx == 0), // First photo is primary
+                    display_order: Some(idx as i32),
+                };
+
+                match PhotoRepository::create_photo(pool, &photo_req).await {
+                    Ok(_) => {
+                        created += 1;
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to save photo for {}: {}", google_place.name, e);
+                    }
+                }
+            }
+        }
+        created
+    }
+
+    /// Synchronize places for multiple cities
+    /// DOCUMENTATION: Batch sync operation for multiple cities. Up to `concurrency`
+    /// cities are synced at once; they share the client's rate limiter, so only the
+    /// waiting overlaps, not the request rate. Each city keeps its own request budget
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `google_client` - Google Places API client
+    /// * `cities` - List of city names to sync
+    /// * `place_type` - Optional place type filter
+    /// * `options` - Concurrency tuning
+    /// * `concurrency` - Cities synced at once
+    ///
+    /// # Returns
+    /// Vector of SyncStats, one per city, in the order of `cities`
+    pub async fn sync_cities(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        cities: &[String],
+        place_type: Option<&str>,
+        options: &SyncOptions,
+        concurrency: usize,
+    ) -> Vec<SyncStats> {
+        // Owned names keep the future Send for callers that spawn it (batch sync jobs)
+        stream::iter(cities.iter().cloned())
+            .map(|city| async move {
+                log::info!("Starting sync for city: {}", city);
+
+                match Self::sync_city(pool, google_client, &city, place_type, None, None, None, options).await {
+                    Ok(stats) => stats,
+                    Err(e) => {
+                        log::error!("Failed to sync city {}: {}", city, e);
+
+                        let mut stats = SyncStats::new(city.clone());
+                        stats.errors.push(format!("Sync failed: {}", e));
+                        stats.complete(0);
+                        stats
+                    }
+                }
+            })
+            // `buffered` yields in input order whatever order the cities finish in
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Get sync summary across multiple city syncs
+    /// DOCUMENTATION: Aggregates statistics from multiple sync operations
+    ///
+    /// # Arguments
+    /// * `stats_list` - List of sync statistics from multiple operations
+    /// * `max_cell_stats` - Cap on the combined `cells` list, as for one city
+    ///   (SYNC_MAX_CELL_STATS); the first cities' cells are kept
+    ///
+    /// # Returns
+    /// Aggregated SyncStats representing the total
+    pub fn aggregate_stats(stats_list: &[SyncStats], max_cell_stats: usize) -> SyncStats {
+        let mut aggregated = SyncStats::new("Multiple Cities".to_string());
+
+        for stats in stats_list {
+            aggregated.api_requests += stats.api_requests;
+            aggregated.places_retrieved += stats.places_retrieved;
+            aggregated.cells_processed += stats.cells_processed;
+            aggregated.cells_skipped_resume += stats.cells_skipped_resume;
+            aggregated.cells_empty += stats.cells_empty;
+            aggregated.cells_subdivided += stats.cells_subdivided;
+            aggregated.cells_skipped_outside_polygon += stats.cells_skipped_outside_polygon;
+            aggregated.places_created += stats.places_created;
+            aggregated.places_skipped += stats.places_skipped;
+            aggregated.details_skipped += stats.details_skipped;
+            aggregated.places_failed += stats.places_failed;
+            aggregated.places_deactivated += stats.places_deactivated;
+            aggregated.places_changed += stats.places_changed;
+            aggregated.cells_remaining += stats.cells_remaining;
+            aggregated.budget_exhausted |= stats.budget_exhausted;
+            aggregated.duration_seconds += stats.duration_seconds;
+            for (place_type, created) in &stats.by_type {
+                *aggregated.by_type.entry(place_type.clone()).or_default() += created;
+            }
+            let room = max_cell_stats.saturating_sub(aggregated.cells.len());
+            aggregated.cells.extend(stats.cells.iter().take(room).cloned());
+            for (field, changed) in &stats.changed_fields {
+                *aggregated.changed_fields.entry(field.clone()).or_default() += changed;
+            }
+            aggregated.changes.extend(stats.changes.iter().cloned());
+            aggregated.quota_backoffs.extend(stats.quota_backoffs.clone());
+            aggregated.errors.extend(stats.errors.clone());
+        }
+
+        aggregated.completed_at = Some(Utc::now().to_rfc3339());
+        aggregated
+    }
+
+    /// Update existing places with fresh data from Google
+    /// DOCUMENTATION: Picks up to `limit` active Google places, least recently verified
+    /// first (`older_than` skips places verified more recently), and fetches Place
+    /// Details for each one in turn; pacing is done by the client's shared rate
+    /// limiter. Rating, rating count, business status, opening hours, phone and website
+    /// are overwritten and the place is marked verified. Places Google no longer knows
+    /// (NOT_FOUND) are deactivated. Quota and key errors stop the run.
+    /// In the photos and reviews modes only that part of Place Details is requested and
+    /// upserted; the place row is left alone and the place stays due for a full refresh
+    pub async fn refresh_existing_places(
+        pool: &PgPool,
+        google_client: &dyn GooglePlacesApi,
+        city: Option<&str>,
+        limit: u32,
+        older_than: Option<chrono::Duration>,
+        mode: RefreshMode,
+    ) -> Result<RefreshStats, PlacesError> {
+        let start_time = Instant::now();
+        let mut stats = RefreshStats::for_mode(city.map(str::to_string), mode);
+        let options = DetailsOptions { fields: mode.details_fields(), ..Default::default() };
+
+        let verified_before = older_than.and_then(|age| Utc::now().checked_sub_signed(age));
+        let candidates =
+            PlaceRepository::list_for_refresh(pool, city, verified_before, i64::from(limit)).await?;
+
+        log::info!(
+            "Refreshing {} places{} ({} mode)",
+            candidates.len(),
+            city.map(|c| format!(" in {}", c)).unwrap_or_default(),
+            mode.as_str()
+        );
+
+        for candidate in candidates {
+            stats.places_checked += 1;
+            stats.api_requests += 1;
+
+            match google_client.get_place_details_with(&candidate.google_place_id, options).await {
+                Ok(google_place) if mode == RefreshMode::Photos => {
+                    let created = Self::store_photos(pool, google_client, candidate.id, &google_place).await;
+                    stats.photos_created = Some(stats.photos_created.unwrap_or(0) + created);
+                    stats.places_refreshed += 1;
+                    stats.cities.insert(candidate.city);
+                }
+                Ok(google_place) if mode == RefreshMode::Reviews => {
+                    let created = Self::store_reviews(pool, candidate.id, &google_place).await;
+                    stats.reviews_created = Some(stats.reviews_created.unwrap_or(0) + created);
+                    stats.places_refreshed += 1;
+                    stats.cities.insert(candidate.city);
+                }
+                Ok(google_place) => {
+                    let update = GooglePlacesClient::to_create_request(&google_place, &candidate.city);
+                    match PlaceRepository::refresh_google_fields(pool, candidate.id, &update).await {
+                        Ok(true) => {
+                            stats.places_refreshed += 1;
+                            stats.cities.insert(candidate.city);
+                        }
+                        Ok(false) => {
+                            log::info!("{} ({}) is permanently closed, deactivated", candidate.name, candidate.google_place_id);
+                            stats.places_deactivated += 1;
+                            stats.cities.insert(candidate.city);
+                        }
+                        Err(e) => {
+                            stats.places_failed += 1;
+                            stats.errors.push(format!("Failed to update {}: {}", candidate.name, e));
+                        }
+                    }
+                }
+                Err(e) if e.is_not_found() => {
+                    log::info!("{} ({}) no longer exists on Google, deactivating", candidate.name, candidate.google_place_id);
+                    match PlaceRepository::delete_place(pool, candidate.id).await {
+                        Ok(_) => {
+                            stats.places_deactivated += 1;
+                            stats.cities.insert(candidate.city);
+                        }
+                        Err(e) => {
+                            stats.places_failed += 1;
+                            stats.errors.push(format!("Failed to deactivate {}: {}", candidate.name, e));
+                        }
+                    }
+                }
+                Err(e) => {
+                    stats.places_failed += 1;
+                    stats.errors.push(format!("Details failed for {}: {}", candidate.name, e));
 
-        Err(PlacesError::InternalError)
+                    // Quota and key problems fail every remaining place the same way
+                    if e.google_error().is_some_and(GoogleApiError::is_fatal) {
+                        log::error!("Stopping refresh: {}", e);
+                        break;
+                    }
+                }
+            }
+        }
+
+        stats.duration_seconds = start_time.elapsed().as_secs();
+        stats.completed_at = Some(Utc::now().to_rfc3339());
+
+        log::info!(
+            "Refresh completed: {} refreshed, {} deactivated, {} failed in {}s",
+            stats.places_refreshed,
+            stats.places_deactivated,
+            stats.places_failed,
+            stats.duration_seconds
+        );
+
+        Ok(stats)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{
+        fixture_places, insert_test_place, lazy_pool, nearby_body, test_pool, MockGooglePlacesClient, MockHttpServer,
+    };
 
     #[test]
     fn test_sync_stats_creation() {
@@ -442,13 +1812,43 @@ mod tests {
 
     #[test]
     fn test_aggregate_stats() {
+        let cells = |prefix: &str, count: usize| -> Vec<CellStat> {
+            (0..count)
+                .map(|i| CellStat {
+                    cell_id: format!("{}_{}", prefix, i),
+                    retrieved: 1,
+                    created: 1,
+                    skipped: 0,
+                    failed: 0,
+                })
+                .collect()
+        };
         let stats1 = SyncStats {
             city: "Madrid".to_string(),
             api_requests: 10,
             places_retrieved: 100,
+            cells_processed: 12,
+            cells_skipped_resume: 4,
+            cells_empty: 3,
+            cells_subdivided: 2,
+            cells_skipped_outside_polygon: 5,
             places_created: 80,
             places_skipped: 15,
+            details_skipped: 10,
             places_failed: 5,
+            places_deactivated: 1,
+            places_changed: 3,
+            reviews_created: 0,
+            photos_created: 0,
+            dry_run: false,
+            budget_exhausted: false,
+            cells_remaining: 0,
+            samples: Vec::new(),
+            by_type: HashMap::new(),
+            cells: cells("madrid", 3),
+            changed_fields: HashMap::new(),
+            changes: Vec::new(),
+            quota_backoffs: Vec::new(),
             errors: vec!["Error 1".to_string()],
             duration_seconds: 60,
             started_at: Utc::now().to_rfc3339(),
@@ -459,23 +1859,1038 @@ mod tests {
             city: "Barcelona".to_string(),
             api_requests: 8,
             places_retrieved: 80,
+            cells_processed: 9,
+            cells_skipped_resume: 0,
+            cells_empty: 1,
+            cells_subdivided: 0,
+            cells_skipped_outside_polygon: 1,
             places_created: 70,
             places_skipped: 8,
+            details_skipped: 6,
             places_failed: 2,
+            places_deactivated: 2,
+            places_changed: 1,
+            reviews_created: 0,
+            photos_created: 0,
+            dry_run: false,
+            budget_exhausted: false,
+            cells_remaining: 0,
+            samples: Vec::new(),
+            by_type: HashMap::new(),
+            cells: cells("barcelona", 2),
+            changed_fields: HashMap::new(),
+            changes: Vec::new(),
+            quota_backoffs: Vec::new(),
             errors: vec!["Error 2".to_string()],
             duration_seconds: 50,
             started_at: Utc::now().to_rfc3339(),
             completed_at: Some(Utc::now().to_rfc3339()),
         };
 
-        let aggregated = SyncService::aggregate_stats(&[stats1, stats2]);
+        let aggregated = SyncService::aggregate_stats(&[stats1.clone(), stats2.clone()], 4);
 
         assert_eq!(aggregated.api_requests, 18);
         assert_eq!(aggregated.places_retrieved, 180);
+        assert_eq!(aggregated.cells_processed, 21);
+        assert_eq!(aggregated.cells_skipped_resume, 4);
+        assert_eq!(aggregated.cells_empty, 4);
+        assert_eq!(aggregated.cells_subdivided, 2);
+        assert_eq!(aggregated.cells_skipped_outside_polygon, 6);
         assert_eq!(aggregated.places_created, 150);
         assert_eq!(aggregated.places_skipped, 23);
+        assert_eq!(aggregated.details_skipped, 16);
         assert_eq!(aggregated.places_failed, 7);
+        assert_eq!(aggregated.places_deactivated, 3);
+        assert_eq!(aggregated.places_changed, 4);
         assert_eq!(aggregated.duration_seconds, 110);
         assert_eq!(aggregated.errors.len(), 2);
+        // The summed cell list is capped like a single city's
+        let ids: Vec<&str> = aggregated.cells.iter().map(|cell| cell.cell_id.as_str()).collect();
+        assert_eq!(ids, ["madrid_0", "madrid_1", "madrid_2", "barcelona_0"]);
+        assert_eq!(SyncService::aggregate_stats(&[stats1, stats2], 10).cells.len(), 5);
+    }
+
+    #[test]
+    fn test_record_persist_counts_changed_fields() {
+        let place: Place = serde_json::from_value(serde_json::json!({
+            "id": Uuid::nil(),
+            "name": "Bodega",
+            "type": "bar",
+            "longitude": -0.8891,
+            "latitude": 41.6488,
+            "city": "Zaragoza",
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        let persisted = |changes: &[(&str, f64, f64)]| PersistedPlace {
+            place: place.clone(),
+            created: false,
+            deactivated: false,
+            reviews_created: 0,
+            photos_created: 0,
+            changes: changes
+                .iter()
+                .map(|(field, old, new)| FieldChange {
+                    field: field.to_string(),
+                    old: serde_json::json!(old),
+                    new: serde_json::json!(new),
+                })
+                .collect(),
+        };
+
+        let mut stats = SyncStats::new("Zaragoza".to_string());
+        SyncService::record_persist(&mut stats, "Bodega", Ok(persisted(&[("google_rating", 4.3, 4.1)])), false);
+        SyncService::record_persist(&mut stats, "Bodega", Ok(persisted(&[])), false);
+        assert_eq!(stats.places_skipped, 2);
+        assert_eq!(stats.places_changed, 1);
+        assert_eq!(stats.changed_fields["google_rating"], 1);
+        assert!(stats.changes.is_empty());
+
+        let changes = [("google_rating", 4.1, 4.0), ("google_rating_count", 10.0, 12.0)];
+        SyncService::record_persist(&mut stats, "Bodega", Ok(persisted(&changes)), true);
+        assert_eq!(stats.places_changed, 2);
+        assert_eq!(stats.changed_fields["google_rating"], 2);
+        assert_eq!(stats.changed_fields["google_rating_count"], 1);
+        assert_eq!(stats.changes.len(), 1);
+        assert_eq!(stats.changes[0].changes.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_sync_counts_empty_cells() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&[])]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        // Empty cells never touch the database, so a lazy pool is never connected
+        let pool = lazy_pool();
+
+        let stats = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), None, &SyncOptions::default())
+            .await
+            .unwrap();
+
+        assert!(stats.cells_empty > 0);
+        assert_eq!(stats.cells_empty as usize, server.hits());
+        assert_eq!(stats.api_requests, stats.cells_empty);
+        assert_eq!(stats.places_retrieved, 0);
+        assert!(stats.errors.is_empty());
+
+        // A second run is answered from the client's cache and costs no requests
+        let again = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), None, &SyncOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(again.cells_empty, stats.cells_empty);
+        assert_eq!(again.api_requests, 0);
+        assert_eq!(stats.cells_empty as usize, server.hits());
+    }
+
+    fn one_cell_bounds() -> CityBounds {
+        CityBounds {
+            name: "bbox:41.64,-0.89,41.66,-0.87".to_string(),
+            min_lat: 41.64,
+            max_lat: 41.66,
+            min_lng: -0.89,
+            max_lng: -0.87,
+            cell_size_km: 10.0,
+            radius_m: 5000,
+            timezone: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_counts_without_writing() {
+        let client = MockGooglePlacesClient::from_fixtures();
+        // Any write would fail on the lazy pool and show up as places_failed
+        let pool = lazy_pool();
+        let options = SyncOptions { dry_run: true, ..SyncOptions::default() };
+
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert!(stats.dry_run);
+        assert_eq!(stats.cells_processed, 1);
+        assert_eq!(stats.places_retrieved, 2);
+        assert_eq!(stats.places_created, 2);
+        assert_eq!(stats.places_failed, 0);
+        assert!(stats.errors.is_empty());
+        // One nearby page plus details for both new places, as in a real sync
+        assert_eq!(client.call_count("details"), 2);
+        assert_eq!(stats.api_requests, 3);
+        assert!(stats.reviews_created > 0);
+        assert_eq!(stats.samples.len(), 1);
+        assert_eq!(stats.samples[0].places.len(), 2);
+        assert_eq!(stats.by_type.values().sum::<u32>(), 2);
+        assert_eq!(stats.cells.len(), 1);
+        assert_eq!(stats.cells[0].retrieved, 2);
+        assert_eq!(stats.cells[0].created, 2);
+
+        let client = MockGooglePlacesClient::from_fixtures();
+        let options = SyncOptions { skip_details: true, ..options };
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.places_created, 2);
+        assert_eq!(client.call_count("details"), 0);
+        assert_eq!(stats.api_requests, 1);
+    }
+
+    /// Bounds split into several 5 km cells, with the cells the sync will search
+    fn several_cell_bounds() -> (CityBounds, Vec<GridCell>) {
+        let bounds = CityBounds {
+            name: "bbox:41.60,-0.95,41.70,-0.85".to_string(),
+            min_lat: 41.60,
+            max_lat: 41.70,
+            min_lng: -0.95,
+            max_lng: -0.85,
+            cell_size_km: 5.0,
+            radius_m: 3000,
+            timezone: None,
+        };
+        let cells = GridGenerator::generate_for_bounds(&bounds, GridPattern::Square, None, None);
+        assert!(cells.len() > 1);
+        (bounds, cells)
+    }
+
+    fn no_retry_delays() -> SyncOptions {
+        SyncOptions {
+            cell_retry_delay: Duration::ZERO,
+            failed_cell_retry_delay: Duration::ZERO,
+            quota_backoff: Duration::ZERO,
+            ..SyncOptions::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_cell_succeeds_on_retry_pass() {
+        let (bounds, cells) = several_cell_bounds();
+        let failing = &cells[1];
+        // Fails the first search and both immediate retries, then recovers
+        let attempts = 1 + CELL_RETRIES as usize;
+        let client = MockGooglePlacesClient::new().with_nearby_failures(
+            failing.latitude,
+            failing.longitude,
+            attempts,
+            GoogleApiError::Network("connection reset".to_string()),
+        );
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &no_retry_delays())
+            .await
+            .unwrap();
+
+        assert!(stats.errors.is_empty(), "{:?}", stats.errors);
+        assert_eq!(stats.cells_processed as usize, cells.len());
+        assert_eq!(stats.cells_empty as usize, cells.len());
+        assert_eq!(client.call_count("nearby"), cells.len() + attempts);
+        // The retried cell is searched after every other cell
+        assert!(client.calls().last().unwrap().starts_with("nearby:"));
+    }
+
+    #[tokio::test]
+    async fn test_cells_failing_every_pass_are_reported_once() {
+        let (bounds, cells) = several_cell_bounds();
+        let failing = &cells[0];
+        let client = MockGooglePlacesClient::new().with_nearby_failures(
+            failing.latitude,
+            failing.longitude,
+            usize::MAX,
+            GoogleApiError::Unknown("try again".to_string()),
+        );
+        let options = SyncOptions { failed_cell_retries: 2, ..no_retry_delays() };
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.errors.len(), 1);
+        assert!(stats.errors[0].contains(&failing.cell_id));
+        assert_eq!(stats.cells_processed as usize, cells.len());
+        // Main pass plus two retry passes, each with its immediate retries
+        assert_eq!(client.call_count("nearby"), cells.len() - 1 + 3 * (1 + CELL_RETRIES as usize));
+    }
+
+    #[tokio::test]
+    async fn test_quota_errors_skip_the_retry_pass() {
+        let (bounds, cells) = several_cell_bounds();
+        let client = MockGooglePlacesClient::new().with_nearby_failures(
+            cells[0].latitude,
+            cells[0].longitude,
+            1,
+            GoogleApiError::OverQueryLimit("quota".to_string()),
+        );
+        let options = SyncOptions { quota_backoff_retries: 0, ..no_retry_delays() };
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(client.call_count("nearby"), 1);
+        assert_eq!(stats.errors.len(), 1);
+        assert!(stats.errors[0].contains("quota"));
+    }
+
+    #[tokio::test]
+    async fn test_quota_backoff_retries_the_cell_once_the_limit_clears() {
+        let (bounds, cells) = several_cell_bounds();
+        // The limiter rejects the first cell twice, then lets it through
+        let client = MockGooglePlacesClient::new().with_nearby_failures(
+            cells[0].latitude,
+            cells[0].longitude,
+            2,
+            GoogleApiError::OverQueryLimit("quota".to_string()),
+        );
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &no_retry_delays())
+            .await
+            .unwrap();
+
+        assert!(stats.errors.is_empty(), "{:?}", stats.errors);
+        assert_eq!(stats.cells_processed as usize, cells.len());
+        assert_eq!(client.call_count("nearby"), cells.len() + 2);
+        assert_eq!(stats.quota_backoffs.len(), 2);
+        assert!(stats.quota_backoffs.iter().all(|backoff| backoff.cell_id == cells[0].cell_id));
+        assert_eq!(stats.quota_backoffs[1].attempt, 2);
+        // The same cell is searched again before any other
+        assert!(client.calls()[..3].iter().all(|call| call == "nearby:first"));
+    }
+
+    #[tokio::test]
+    async fn test_quota_backoff_gives_up_and_stops_the_sync() {
+        let (bounds, cells) = several_cell_bounds();
+        let client = MockGooglePlacesClient::new().with_nearby_failures(
+            cells[0].latitude,
+            cells[0].longitude,
+            usize::MAX,
+            GoogleApiError::OverQueryLimit("quota".to_string()),
+        );
+        let options = SyncOptions { quota_backoff_retries: 2, ..no_retry_delays() };
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(client.call_count("nearby"), 3);
+        assert_eq!(stats.quota_backoffs.len(), 2);
+        assert_eq!(stats.errors.len(), 1);
+        assert!(stats.errors[0].contains("quota"));
+    }
+
+    #[test]
+    fn test_quota_backoff_waits_double_up_to_the_max() {
+        let options = SyncOptions::default();
+        let waits: Vec<u64> = (1..=4).map(|attempt| options.quota_wait(attempt).as_secs()).collect();
+        assert_eq!(waits, [30, 60, 120, 120]);
+
+        let options = SyncOptions { quota_max_wait: Duration::from_secs(45), ..SyncOptions::default() };
+        assert_eq!(options.quota_wait(1).as_secs(), 30);
+        assert_eq!(options.quota_wait(2).as_secs(), 45);
+        assert_eq!(options.quota_wait(40), Duration::from_secs(45));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_cells_match_the_serial_totals() {
+        let (bounds, cells) = several_cell_bounds();
+        let sync = |cell_concurrency: usize| {
+            let bounds = bounds.clone();
+            let failing = cells[1].clone();
+            async move {
+                // Every cell finds the same two places; one cell fails once
+                let client = MockGooglePlacesClient::from_fixtures().with_nearby_failures(
+                    failing.latitude,
+                    failing.longitude,
+                    1 + CELL_RETRIES as usize,
+                    GoogleApiError::Network("connection reset".to_string()),
+                );
+                let options = SyncOptions { dry_run: true, cell_concurrency, ..no_retry_delays() };
+                let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &options)
+                    .await
+                    .unwrap();
+                (stats, client.call_count("nearby"), client.call_count("details"))
+            }
+        };
+
+        let (serial, serial_nearby, serial_details) = sync(1).await;
+        let (concurrent, concurrent_nearby, concurrent_details) = sync(cells.len()).await;
+
+        assert!(serial.errors.is_empty(), "{:?}", serial.errors);
+        assert!(concurrent.errors.is_empty(), "{:?}", concurrent.errors);
+        assert_eq!(concurrent.cells_processed as usize, cells.len());
+        assert_eq!(concurrent.cells_processed, serial.cells_processed);
+        assert_eq!(concurrent.api_requests, serial.api_requests);
+        assert_eq!(concurrent.places_retrieved, serial.places_retrieved);
+        assert_eq!(concurrent.places_created, serial.places_created);
+        assert_eq!(concurrent.places_skipped, serial.places_skipped);
+        assert_eq!(concurrent.details_skipped, serial.details_skipped);
+        assert_eq!(concurrent.reviews_created, serial.reviews_created);
+        assert_eq!(concurrent.photos_created, serial.photos_created);
+        assert_eq!(concurrent.samples.len(), serial.samples.len());
+        // Each place gets its details once per run, whichever cell finds it first
+        assert_eq!(concurrent_details, 2);
+        assert_eq!(serial_details, 2);
+        assert_eq!(concurrent_nearby, serial_nearby);
+    }
+
+    #[tokio::test]
+    async fn test_cell_stats_are_capped() {
+        let (bounds, cells) = several_cell_bounds();
+        let client = MockGooglePlacesClient::from_fixtures();
+        let options = SyncOptions { dry_run: true, max_cell_stats: 2, ..no_retry_delays() };
+
+        let stats = SyncService::sync_bounds(&lazy_pool(), &client, &bounds, "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.cells_processed as usize, cells.len());
+        assert_eq!(stats.cells.len(), 2);
+        assert_eq!(stats.cells[0].cell_id, cells[0].cell_id);
+        // The first cell creates both places, later cells find them again
+        assert_eq!(stats.cells[0].created, 2);
+        assert_eq!(stats.cells[1].created, 0);
+        assert_eq!(stats.cells[1].skipped, 2);
+        assert_eq!(stats.by_type.values().sum::<u32>(), stats.places_created);
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["cells"].as_array().unwrap().len(), 2);
+        assert!(json["by_type"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_cities_keep_the_request_order() {
+        let client = MockGooglePlacesClient::new();
+        let cities = ["Madrid", "Atlantis", "Zaragoza"].map(str::to_string);
+        // Dry runs skip the progress writes the lazy pool would time out on
+        let options = SyncOptions { dry_run: true, ..no_retry_delays() };
+
+        let serial = SyncService::sync_cities(&lazy_pool(), &client, &cities, None, &options, 1).await;
+        let concurrent = SyncService::sync_cities(&lazy_pool(), &client, &cities, None, &options, 3).await;
+
+        let names = |stats: &[SyncStats]| stats.iter().map(|stats| stats.city.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&concurrent), names(&serial));
+        assert_eq!(concurrent[0].city.to_lowercase(), "madrid");
+        // The unknown city fails on its own without stopping the others
+        assert!(!concurrent[1].errors.is_empty());
+        assert!(concurrent[2].errors.is_empty(), "{:?}", concurrent[2].errors);
+
+        let serial = SyncService::aggregate_stats(&serial, options.max_cell_stats);
+        let concurrent = SyncService::aggregate_stats(&concurrent, options.max_cell_stats);
+        assert_eq!(concurrent.cells_processed, serial.cells_processed);
+        assert_eq!(concurrent.api_requests, serial.api_requests);
+        assert_eq!(concurrent.errors.len(), serial.errors.len());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_cells_share_the_request_budget() {
+        let client = MockGooglePlacesClient::new();
+        let options = SyncOptions { max_api_requests: 5, cell_concurrency: 4, ..SyncOptions::default() };
+
+        let stats = SyncService::sync_city(&lazy_pool(), &client, "Zaragoza", None, Some(10.0), Some(5000), None, &options)
+            .await
+            .unwrap();
+
+        // Cells wait for the pages the others did not use: same stop as a serial run
+        assert_eq!(client.call_count("nearby"), 3);
+        assert_eq!(stats.api_requests, 3);
+        assert_eq!(stats.cells_processed, 3);
+        assert!(stats.budget_exhausted);
+    }
+
+    #[tokio::test]
+    async fn test_budget_stops_before_a_cell_it_cannot_afford() {
+        let client = MockGooglePlacesClient::new();
+        let pool = lazy_pool();
+        let options = SyncOptions { max_api_requests: 5, ..SyncOptions::default() };
+
+        let stats = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), None, &options)
+            .await
+            .unwrap();
+
+        // Each empty cell costs one request; a fourth cell could need 3 more than the 2 left
+        assert_eq!(client.call_count("nearby"), 3);
+        assert_eq!(stats.cells_processed, 3);
+        assert!(stats.budget_exhausted);
+        assert!(stats.cells_remaining > 0);
+        assert!(stats.errors.iter().any(|e| e.contains("budget of 5 exhausted")));
+    }
+
+    #[tokio::test]
+    async fn test_budget_limits_details_calls() {
+        let places = (0..5)
+            .map(|i| serde_json::from_value(serde_json::json!({
+                "place_id": format!("budget-{}", i),
+                "name": format!("Place {}", i),
+                "types": ["restaurant"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }))
+            .unwrap())
+            .collect();
+        let client = MockGooglePlacesClient::new().with_nearby_page(places);
+        let pool = lazy_pool();
+        let options = SyncOptions { max_api_requests: 3, dry_run: true, ..SyncOptions::default() };
+
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(client.call_count("details"), 2);
+        assert_eq!(stats.api_requests, 3);
+        assert_eq!(stats.places_created, 2);
+        assert!(stats.budget_exhausted);
+        assert_eq!(stats.cells_remaining, 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_aborts_on_request_denied() {
+        let denied = serde_json::json!({
+            "status": "REQUEST_DENIED",
+            "error_message": "The provided API key is invalid."
+        })
+        .to_string();
+        let server = MockHttpServer::start_json(vec![denied]).await;
+        let client = GooglePlacesClient::new("bad_key".to_string()).with_base_url(&server.base_url);
+        let pool = lazy_pool();
+
+        let stats = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), None, &SyncOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(server.hits(), 1, "every other cell would fail the same way");
+        assert_eq!(stats.errors.len(), 1);
+        assert!(stats.errors[0].contains("The provided API key is invalid."));
+    }
+
+    #[tokio::test]
+    async fn test_search_cell_retries_only_transient_errors() {
+        let unknown = serde_json::json!({ "status": "UNKNOWN_ERROR" }).to_string();
+        let server = MockHttpServer::start_json(vec![unknown, nearby_body(&["a"])]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let search = NearbySearchParams::new(41.65, -0.88, 1000);
+
+        let pages = SyncService::search_cell(&client, &search, Duration::ZERO).await.unwrap();
+        assert_eq!(pages.outcome.into_places().len(), 1);
+        assert_eq!(server.hits(), 2);
+
+        let invalid = serde_json::json!({ "status": "INVALID_REQUEST" }).to_string();
+        let server = MockHttpServer::start_json(vec![invalid]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+
+        let result = SyncService::search_cell(&client, &search, Duration::ZERO).await;
+        assert!(matches!(
+            result.unwrap_err().google_error(),
+            Some(GoogleApiError::InvalidRequest(_))
+        ));
+        assert_eq!(server.hits(), 1);
+    }
+
+    /// Mock whose every Nearby Search returns three full pages (60 places)
+    fn saturated_client() -> MockGooglePlacesClient {
+        (0..MAX_NEARBY_PAGES).fold(MockGooglePlacesClient::new(), |client, page| {
+            client.with_nearby_page(
+                (0..20)
+                    .map(|i| serde_json::from_value(serde_json::json!({
+                        "place_id": format!("p{}-{}", page, i),
+                        "name": format!("Place {}-{}", page, i),
+                        "types": ["restaurant"],
+                        "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+                    }))
+                    .unwrap())
+                    .collect(),
+            )
+        })
+    }
+
+    /// Search queued cells like `sync_city` does, without persisting anything;
+    /// returns (cells searched, cells subdivided)
+    async fn drain_queue(client: &dyn GooglePlacesApi, options: &SyncOptions) -> (usize, usize) {
+        let root = GridCell {
+            latitude: 41.65,
+            longitude: -0.88,
+            radius: 1000,
+            cell_id: "Zaragoza-1".to_string(),
+        };
+        let mut queue = CellQueue::new(vec![root], options);
+        let (mut searched, mut subdivided) = (0, 0);
+        while let Some(cell) = queue.pop() {
+            searched += 1;
+            let search = NearbySearchParams::new(cell.latitude, cell.longitude, cell.radius);
+            let places = SyncService::search_cell(client, &search, Duration::ZERO)
+                .await
+                .unwrap()
+                .outcome
+                .into_places();
+            if places.len() >= SATURATED_CELL_RESULTS && queue.subdivide(&cell) {
+                subdivided += 1;
+            }
+        }
+        (searched, subdivided)
+    }
+
+    #[tokio::test]
+    async fn test_saturated_cells_subdivide_down_to_min_radius() {
+        let client = saturated_client();
+        // 1000 m -> 708 m -> 501 m; a further split (355 m) would fall below 400 m
+        let options = SyncOptions { min_cell_radius_m: 400, max_cells: 1000, ..SyncOptions::default() };
+
+        let (searched, subdivided) = drain_queue(&client, &options).await;
+
+        assert_eq!(subdivided, 1 + 4);
+        assert_eq!(searched, 1 + 4 + 16);
+        assert_eq!(client.call_count("nearby"), searched * MAX_NEARBY_PAGES as usize);
+    }
+
+    #[tokio::test]
+    async fn test_subdivision_respects_cell_cap() {
+        let client = saturated_client();
+        let options = SyncOptions { min_cell_radius_m: 1, max_cells: 10, ..SyncOptions::default() };
+
+        let (searched, subdivided) = drain_queue(&client, &options).await;
+
+        // The root and the first child split (1 + 4 + 4 cells); a third split would make 13
+        assert_eq!(subdivided, 2);
+        assert_eq!(searched, 9);
+    }
+
+    #[tokio::test]
+    async fn test_sync_rejects_grid_above_cell_cap() {
+        let server = MockHttpServer::start_json(vec![nearby_body(&[])]).await;
+        let client = GooglePlacesClient::new("test_key".to_string()).with_base_url(&server.base_url);
+        let pool = lazy_pool();
+        let options = SyncOptions { max_cells: 1, ..SyncOptions::default() };
+
+        let result = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), None, &options).await;
+
+        assert!(matches!(result, Err(PlacesError::InvalidInput(msg)) if msg.contains("above the limit of 1")));
+        assert_eq!(server.hits(), 0);
+    }
+
+    /// Fixture client whose details requests sleep and record the peak number in flight
+    struct SlowDetails {
+        inner: MockGooglePlacesClient,
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl GooglePlacesApi for SlowDetails {
+        async fn nearby_search_page(
+            &self,
+            search: &NearbySearchParams,
+            page_token: Option<&str>,
+        ) -> Result<crate::services::NearbyPage, PlacesError> {
+            self.inner.nearby_search_page(search, page_token).await
+        }
+
+        async fn get_place_details_with(
+            &self,
+            place_id: &str,
+            options: DetailsOptions<'_>,
+        ) -> Result<GooglePlace, PlacesError> {
+            use std::sync::atomic::Ordering;
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            if place_id == "missing" {
+                return Err(GoogleApiError::NotFound(place_id.to_string()).into());
+            }
+            self.inner
+                .get_place_details_with("ChIJ2QdR5bEUWQ0RkN_n1cAQFh4", options)
+                .await
+        }
+
+        async fn autocomplete(
+            &self,
+            input: &str,
+            session_token: Option<&str>,
+            location_bias: Option<&crate::services::LocationBias>,
+            region: Option<&str>,
+        ) -> Result<Vec<crate::services::AutocompletePrediction>, PlacesError> {
+            self.inner
+                .autocomplete(input, session_token, location_bias, region)
+                .await
+        }
+
+        async fn find_place(
+            &self,
+            input: &str,
+            input_type: crate::services::FindPlaceInputType,
+        ) -> Result<Vec<crate::services::FindPlaceCandidate>, PlacesError> {
+            self.inner.find_place(input, input_type).await
+        }
+
+        async fn text_search(
+            &self,
+            query: &str,
+            location_bias: Option<&crate::services::LocationBias>,
+        ) -> Result<Vec<GooglePlace>, PlacesError> {
+            self.inner.text_search(query, location_bias).await
+        }
+
+        fn get_photo_url(&self, photo_reference: &str, max_width: Option<i32>) -> String {
+            self.inner.get_photo_url(photo_reference, max_width)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_details_is_bounded_and_yields_every_place() {
+        let client = SlowDetails {
+            inner: MockGooglePlacesClient::from_fixtures(),
+            in_flight: Default::default(),
+            peak: Default::default(),
+        };
+        let mut places: Vec<GooglePlace> = (0..12)
+            .map(|i| serde_json::from_value(serde_json::json!({
+                "place_id": format!("p{}", i),
+                "name": format!("Place {}", i),
+                "types": ["restaurant"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } }
+            }))
+            .unwrap())
+            .collect();
+        places[3].place_id = "missing".to_string();
+
+        let results: Vec<_> = SyncService::fetch_details(&client, places, Some("es"), 4)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 12);
+        assert_eq!(results.iter().filter(|(_, details)| details.is_err()).count(), 1);
+        assert_eq!(client.peak.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_falls_back_to_one_result_per_place() {
+        // The lazy pool has no database: the batch fails, then every place on its own
+        let client = MockGooglePlacesClient::from_fixtures();
+        let places = fixture_places();
+        let results = SyncService::persist_google_places(&lazy_pool(), &client, &places, "Zaragoza", true).await;
+
+        assert_eq!(results.len(), places.len());
+        let mut stats = SyncStats::new("Zaragoza".to_string());
+        for (place, result) in places.iter().zip(results) {
+            SyncService::record_persist(&mut stats, &place.name, result, false);
+        }
+        assert_eq!(stats.places_failed as usize, places.len());
+        assert_eq!(stats.places_created + stats.places_skipped, 0);
+        assert!(SyncService::persist_google_places(&lazy_pool(), &client, &[], "Zaragoza", true).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejects_swapped_coordinates_per_row() {
+        let client = MockGooglePlacesClient::from_fixtures();
+        let mut places = fixture_places();
+        for place in &mut places {
+            let location = &mut place.geometry.location;
+            std::mem::swap(&mut location.lat, &mut location.lng);
+        }
+
+        // Nothing is left to write, so the lazy pool is never used
+        let results = SyncService::persist_google_places(&lazy_pool(), &client, &places, "Zaragoza", true).await;
+        assert_eq!(results.len(), places.len());
+        for result in results {
+            match result {
+                Err(PlacesError::ValidationError(hint)) => assert!(hint.contains("did you mean"), "{}", hint),
+                other => panic!("expected a swapped-coordinate rejection, got {:?}", other.map(|p| p.place.id)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_batched_persist_counts_like_per_place_persist() {
+        let pool = test_pool().await;
+        let client = MockGooglePlacesClient::from_fixtures();
+        let run = Uuid::new_v4();
+        // Same places under per-path Google ids so both paths start from nothing
+        let copies = |path: &str| -> Vec<GooglePlace> {
+            fixture_places()
+                .into_iter()
+                .map(|place| GooglePlace { place_id: format!("{}_{}_{}", place.place_id, path, run), ..place })
+                .collect()
+        };
+        let (per_place, batched) = (copies("row"), copies("batch"));
+
+        for pass in 0..2 {
+            let mut expected = SyncStats::new("Zaragoza".to_string());
+            for place in &per_place {
+                let persisted = SyncService::persist_google_place(&pool, &client, place, "Zaragoza").await;
+                SyncService::record_persist(&mut expected, &place.name, persisted, false);
+            }
+            let mut actual = SyncStats::new("Zaragoza".to_string());
+            let results = SyncService::persist_google_places(&pool, &client, &batched, "Zaragoza", true).await;
+            for (place, persisted) in batched.iter().zip(results) {
+                SyncService::record_persist(&mut actual, &place.name, persisted, false);
+            }
+
+            assert_eq!(actual.places_created, expected.places_created, "pass {}", pass);
+            assert_eq!(actual.places_skipped, expected.places_skipped, "pass {}", pass);
+            assert_eq!(actual.places_failed, 0);
+            assert_eq!(actual.by_type, expected.by_type);
+            assert_eq!(actual.places_changed, expected.places_changed);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_sync_city_against_mock_client() {
+        let pool = test_pool().await;
+        let fixture_ids = ["ChIJ2QdR5bEUWQ0RkN_n1cAQFh4", "ChIJx8vE3bAUWQ0RmO3Kp7WcVXs"];
+        sqlx::query("DELETE FROM places WHERE google_place_id = ANY($1)")
+            .bind(&fixture_ids[..])
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let client = MockGooglePlacesClient::from_fixtures();
+        let stats = SyncService::sync_city(&pool, &client, "Zaragoza", None, Some(10.0), Some(5000), Some("es"), &SyncOptions::default())
+            .await
+            .unwrap();
+
+        // Every cell returns both fixture places: the first cell creates them (fetching
+        // details once each), later cells find them stored and skip details
+        let cells = client.call_count("nearby") as u32;
+        assert!(cells > 1);
+        assert_eq!(stats.places_retrieved, 2 * cells);
+        assert_eq!(stats.places_created, 2);
+        assert_eq!(stats.places_skipped, 2 * (cells - 1));
+        assert_eq!(stats.details_skipped, 2 * (cells - 1));
+        assert_eq!(stats.places_failed, 0);
+        assert_eq!(client.call_count("details"), 2);
+        // The second fixture place has no details, so only one details call is billed
+        assert_eq!(stats.api_requests, cells + 1);
+        assert!(stats.reviews_created > 0);
+        assert_eq!(stats.photos_created, 1);
+        assert!(stats.errors.is_empty());
+
+        let stored = PlaceRepository::get_by_google_place_id(&pool, fixture_ids[0])
+            .await
+            .unwrap();
+        assert_eq!(stored.name, "El Tubo Tapas");
+        assert_eq!(stored.city, "Zaragoza");
+        // Only the place stored from Place Details counts as verified
+        assert!(stored.last_verified_at.is_some());
+        let without_details = PlaceRepository::get_by_google_place_id(&pool, fixture_ids[1]).await.unwrap();
+        assert!(without_details.last_verified_at.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_sync_skips_details_for_fresh_places() {
+        let pool = test_pool().await;
+        let fresh_id = "ChIJ2QdR5bEUWQ0RkN_n1cAQFh4";
+        let stale_id = "ChIJx8vE3bAUWQ0RmO3Kp7WcVXs";
+        sqlx::query("DELETE FROM places WHERE google_place_id = ANY($1)")
+            .bind(&[fresh_id, stale_id][..])
+            .execute(&pool)
+            .await
+            .unwrap();
+        let fresh = insert_test_place(&pool, fresh_id).await;
+        let stale = insert_test_place(&pool, stale_id).await;
+        PlaceRepository::mark_verified(&pool, fresh).await.unwrap();
+        sqlx::query("UPDATE places SET last_verified_at = NOW() - INTERVAL '40 days' WHERE id = $1")
+            .bind(stale)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let client = MockGooglePlacesClient::from_fixtures();
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &SyncOptions::default())
+            .await
+            .unwrap();
+
+        // Only the place verified 40 days ago (older than the 30-day default) is re-fetched
+        assert_eq!(client.calls(), vec!["nearby:first".to_string(), format!("details:{}", stale_id)]);
+        assert_eq!(stats.details_skipped, 1);
+        assert_eq!(stats.places_skipped, 2);
+        assert_eq!(stats.places_created, 0);
+
+        // With a zero-day window every stored place is re-fetched
+        let client = MockGooglePlacesClient::from_fixtures();
+        let options = SyncOptions { details_refresh_days: 0, ..SyncOptions::default() };
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &options)
+            .await
+            .unwrap();
+        assert_eq!(client.call_count("details"), 2);
+        assert_eq!(stats.details_skipped, 0);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_sync_refreshes_fresh_places_from_nearby_results() {
+        let pool = test_pool().await;
+        let google_id = format!("fresh_refresh_{}", Uuid::new_v4());
+        let id = insert_test_place(&pool, &google_id).await;
+        sqlx::query("UPDATE places SET google_rating = 3.1, phone = '976 000 000' WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        PlaceRepository::mark_verified(&pool, id).await.unwrap();
+
+        let mut nearby = fixture_places().remove(0);
+        nearby.place_id = google_id.clone();
+        nearby.rating = Some(4.7);
+        nearby.business_status = Some("CLOSED_PERMANENTLY".to_string());
+        let client = MockGooglePlacesClient::new().with_nearby_page(vec![nearby]);
+        let stats = SyncService::sync_bounds(&pool, &client, &one_cell_bounds(), "Zaragoza", None, None, None, None, &SyncOptions::default())
+            .await
+            .unwrap();
+
+        // No details call, but the row follows the nearby result
+        assert_eq!(client.call_count("details"), 0);
+        assert_eq!(stats.details_skipped, 1);
+        assert_eq!(stats.places_deactivated, 1);
+        let stored = PlaceRepository::fetch_by_google_place_id(&pool, &google_id, true).await.unwrap();
+        assert_eq!(stored.google_rating, Some(4.7));
+        assert_eq!(stored.is_active, Some(false));
+        // Details-only fields the nearby result lacks are kept
+        assert_eq!(stored.phone.as_deref(), Some("976 000 000"));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_resumed_sync_only_searches_remaining_cells() {
+        let pool = test_pool().await;
+        SyncProgressRepository::clear(&pool, "Zaragoza").await.unwrap();
+
+        // Every cell comes back empty, so nothing but progress is written
+        let client = MockGooglePlacesClient::new();
+        let first = SyncService::sync_city(&pool, &client, "Zaragoza", Some("bar"), Some(10.0), Some(5000), None, &SyncOptions::default())
+            .await
+            .unwrap();
+        let cells = client.call_count("nearby");
+        assert!(cells > 2);
+        assert_eq!(first.cells_processed as usize, cells);
+        assert_eq!(first.cells_skipped_resume, 0);
+
+        // Simulate an interruption: forget the last two cells
+        sqlx::query(
+            "DELETE FROM sync_cell_progress WHERE city = 'Zaragoza' AND place_type = 'bar' \
+             AND cell_id = ANY($1)",
+        )
+        .bind(vec![format!("Zaragoza-{}", cells - 1), format!("Zaragoza-{}", cells)])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let client = MockGooglePlacesClient::new();
+        let options = SyncOptions { resume: true, ..SyncOptions::default() };
+        let resumed = SyncService::sync_city(&pool, &client, "Zaragoza", Some("bar"), Some(10.0), Some(5000), None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(client.call_count("nearby"), 2);
+        assert_eq!(resumed.cells_processed, 2);
+        assert_eq!(resumed.cells_skipped_resume as usize, cells - 2);
+
+        // Another place type keeps its own progress
+        let client = MockGooglePlacesClient::new();
+        SyncService::sync_city(&pool, &client, "Zaragoza", Some("cafe"), Some(10.0), Some(5000), None, &options)
+            .await
+            .unwrap();
+        assert_eq!(client.call_count("nearby"), cells);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_refresh_updates_known_places_and_deactivates_missing_ones() {
+        let pool = test_pool().await;
+        let known_id = "ChIJ2QdR5bEUWQ0RkN_n1cAQFh4";
+        let missing_id = format!("refresh_missing_{}", uuid::Uuid::new_v4());
+        sqlx::query("DELETE FROM places WHERE google_place_id = $1")
+            .bind(known_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let known = insert_test_place(&pool, known_id).await;
+        let missing = insert_test_place(&pool, &missing_id).await;
+
+        // A city of their own keeps other test places out of the run
+        let city = format!("Refresh-{}", uuid::Uuid::new_v4());
+        sqlx::query("UPDATE places SET city = $1 WHERE id = ANY($2)")
+            .bind(&city)
+            .bind(vec![known, missing])
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let client = MockGooglePlacesClient::from_fixtures();
+        let stats = SyncService::refresh_existing_places(&pool, &client, Some(&city.to_uppercase()), 10, None, RefreshMode::Full)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.places_checked, 2);
+        assert_eq!(stats.api_requests, 2);
+        assert_eq!(stats.places_refreshed, 1);
+        assert_eq!(stats.places_deactivated, 1);
+        assert_eq!(stats.places_failed, 0);
+        assert!(stats.cities.contains(&city));
+
+        let refreshed = PlaceRepository::get_by_id(&pool, known).await.unwrap();
+        assert!(refreshed.google_rating.is_some());
+        assert!(refreshed.last_verified_at.is_some());
+        assert_eq!(refreshed.name, format!("Test {}", known_id), "only Google's volatile fields change");
+        let (active,): (bool,) = sqlx::query_as("SELECT is_active FROM places WHERE id = $1")
+            .bind(missing)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(!active);
+
+        // The refreshed place was just verified and the missing one is inactive
+        let client = MockGooglePlacesClient::from_fixtures();
+        let stats = SyncService::refresh_existing_places(&pool, &client, Some(&city), 10, Some(chrono::Duration::days(1)), RefreshMode::Full)
+            .await
+            .unwrap();
+        assert_eq!(stats.places_checked, 0);
+        assert_eq!(client.call_count("details"), 0);
+    }
+
+    #[test]
+    fn test_refresh_stats_report_only_the_mode_counters() {
+        let full = serde_json::to_value(RefreshStats::new(None)).unwrap();
+        assert_eq!(full["mode"], "full");
+        assert!(full.get("photos_created").is_none());
+        assert!(full.get("reviews_created").is_none());
+
+        let photos = serde_json::to_value(RefreshStats::for_mode(None, RefreshMode::Photos)).unwrap();
+        assert_eq!(photos["mode"], "photos");
+        assert_eq!(photos["photos_created"], 0);
+        assert!(photos.get("reviews_created").is_none());
+
+        let reviews: RefreshMode = serde_json::from_str("\"reviews\"").unwrap();
+        assert_eq!(reviews.details_fields(), DetailsFields::Reviews);
+        assert_eq!(RefreshMode::default().details_fields(), DetailsFields::Full);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a migrated PostGIS database (TEST_DATABASE_URL)"]
+    async fn test_photos_refresh_leaves_the_place_row_alone() {
+        let pool = test_pool().await;
+        let known_id = "ChIJ2QdR5bEUWQ0RkN_n1cAQFh4";
+        sqlx::query("DELETE FROM places WHERE google_place_id = $1")
+            .bind(known_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let known = insert_test_place(&pool, known_id).await;
+        let city = format!("Photos-{}", uuid::Uuid::new_v4());
+        sqlx::query("UPDATE places SET city = $1 WHERE id = $2")
+            .bind(&city)
+            .bind(known)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let client = MockGooglePlacesClient::from_fixtures();
+        let stats = SyncService::refresh_existing_places(&pool, &client, Some(&city), 10, None, RefreshMode::Photos)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.places_refreshed, 1);
+        assert!(stats.photos_created.is_some());
+        assert!(stats.reviews_created.is_none());
+        let place = PlaceRepository::get_by_id(&pool, known).await.unwrap();
+        assert!(place.google_rating.is_none(), "photos mode does not touch the place row");
+        assert!(place.last_verified_at.is_none());
     }
 }
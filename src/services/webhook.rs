@@ -0,0 +1,205 @@
+// src/services/webhook.rs
+// DOCUMENTATION: Sync completion webhooks
+// PURPOSE: Notify an external URL when a sync job finishes, signing the payload so the
+// receiver can check it came from this service
+
+use crate::config::Config;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::time::Duration;
+
+/// Header carrying `sha256=<hex HMAC-SHA256 of the body>` when WEBHOOK_SECRET is set
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Auphere-Signature";
+
+/// Attempts per notification (the first one plus retries on 5xx and network errors)
+pub const MAX_WEBHOOK_ATTEMPTS: u32 = 3;
+
+/// Pause before each retry
+const WEBHOOK_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Whole-request timeout for one webhook attempt
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a webhook notification, kept on the sync job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub url: String,
+    pub delivered: bool,
+    pub attempts: u32,
+    /// Last failure (status or transport error) when not delivered
+    pub error: Option<String>,
+}
+
+/// Whether `url` looks like an absolute http(s) URL
+pub fn is_http_url(url: &str) -> bool {
+    let url = url.trim();
+    ["http://", "https://"]
+        .iter()
+        .any(|scheme| {
+            url.len() > scheme.len()
+                && url.get(..scheme.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        })
+}
+
+/// Hex HMAC-SHA256 of `body` keyed with `secret`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Posts JSON notifications with retries and an optional signature
+/// DOCUMENTATION: 5xx responses and transport errors are retried up to
+/// MAX_WEBHOOK_ATTEMPTS; 4xx responses are not, the receiver rejected the payload.
+/// Failures are returned in the WebhookDelivery, never as errors
+pub struct WebhookSender {
+    client: Client,
+    secret: Option<String>,
+    retry_delay: Duration,
+}
+
+impl WebhookSender {
+    /// Create a sender signing with `secret` when given
+    pub fn new(secret: Option<String>) -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(WEBHOOK_TIMEOUT)
+                .build()
+                .expect("HTTP client configuration is static and valid"),
+            secret,
+            retry_delay: WEBHOOK_RETRY_DELAY,
+        }
+    }
+
+    /// Sender signing with WEBHOOK_SECRET
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.webhook_secret.clone())
+    }
+
+    /// Override the pause between attempts (tests use zero)
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// POST `payload` as JSON to `url`
+    pub async fn send<T: Serialize>(&self, url: &str, payload: &T) -> WebhookDelivery {
+        let mut delivery = WebhookDelivery {
+            url: url.to_string(),
+            delivered: false,
+            attempts: 0,
+            error: None,
+        };
+
+        let body = match serde_json::to_vec(payload) {
+            Ok(body) => body,
+            Err(e) => {
+                delivery.error = Some(format!("Could not serialize payload: {}", e));
+                return delivery;
+            }
+        };
+
+        while delivery.attempts < MAX_WEBHOOK_ATTEMPTS {
+            if delivery.attempts > 0 {
+                tokio::time::sleep(self.retry_delay).await;
+            }
+            delivery.attempts += 1;
+
+            let mut request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone());
+            if let Some(secret) = &self.secret {
+                request = request.header(WEBHOOK_SIGNATURE_HEADER, format!("sha256={}", sign(secret, &body)));
+            }
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    delivery.delivered = true;
+                    delivery.error = None;
+                    return delivery;
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    delivery.error = Some(format!("Webhook responded with HTTP {}", status.as_u16()));
+                    if !status.is_server_error() {
+                        break;
+                    }
+                }
+                Err(e) => delivery.error = Some(format!("Webhook request failed: {}", e)),
+            }
+        }
+
+        log::warn!(
+            "Webhook {} not delivered after {} attempt(s): {}",
+            url,
+            delivery.attempts,
+            delivery.error.as_deref().unwrap_or("unknown error")
+        );
+        delivery
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MockHttpServer;
+
+    fn sender(secret: Option<&str>) -> WebhookSender {
+        WebhookSender::new(secret.map(str::to_string)).with_retry_delay(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_sign_matches_hmac_sha256() {
+        assert_eq!(
+            sign("key", b"The quick brown fox jumps over the lazy dog"),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn test_is_http_url() {
+        assert!(is_http_url("https://hooks.example.com/sync"));
+        assert!(is_http_url("HTTP://localhost:9000"));
+        assert!(!is_http_url("https://"));
+        assert!(!is_http_url("ftp://example.com"));
+        assert!(!is_http_url("example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_are_retried() {
+        let server = MockHttpServer::start(vec![
+            (500, "{}".to_string()),
+            (503, "{}".to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+
+        let delivery = sender(Some("secret")).send(&server.base_url, &serde_json::json!({ "ok": true })).await;
+
+        assert!(delivery.delivered);
+        assert_eq!(delivery.attempts, 3);
+        assert!(delivery.error.is_none());
+        assert_eq!(server.hits(), 3);
+        assert!(server.requests()[0].starts_with("POST "));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_and_exhausted_retries_are_reported() {
+        let rejected = MockHttpServer::start(vec![(400, "{}".to_string())]).await;
+        let delivery = sender(None).send(&rejected.base_url, &serde_json::json!({})).await;
+        assert!(!delivery.delivered);
+        assert_eq!(delivery.attempts, 1);
+        assert!(delivery.error.unwrap().contains("400"));
+
+        let failing = MockHttpServer::start(vec![(502, "{}".to_string())]).await;
+        let delivery = sender(None).send(&failing.base_url, &serde_json::json!({})).await;
+        assert!(!delivery.delivered);
+        assert_eq!(delivery.attempts, MAX_WEBHOOK_ATTEMPTS);
+        assert_eq!(failing.hits(), MAX_WEBHOOK_ATTEMPTS as usize);
+    }
+}
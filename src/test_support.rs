@@ -0,0 +1,536 @@
+// src/test_support.rs
+// DOCUMENTATION: Shared helpers for unit tests
+// PURPOSE: Minimal HTTP server and fixture-backed client standing in for the
+// Google Places API, plus access to an optional PostGIS test database for
+// `#[ignore]`d DB tests
+
+use crate::errors::{GoogleApiError, PlacesError};
+use crate::services::google_places_v1::{V1Place, V1SearchResponse};
+use crate::services::{
+    AutocompletePrediction, DetailsOptions, FindPlaceCandidate, FindPlaceInputType, GooglePlace,
+    GooglePlacesApi, LocationBias, NearbyOutcome, NearbyPage, NearbySearchParams,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Canned response: (status, extra headers, body)
+pub(crate) type MockResponse = (u16, Vec<(&'static str, String)>, String);
+
+/// Canned HTTP server for exercising the Google client without network access
+/// DOCUMENTATION: Serves the configured responses in order (the last one repeats)
+/// and records every request line so tests can assert on paths and query strings
+pub(crate) struct MockHttpServer {
+    /// Base URL to pass to the client under test (e.g. http://127.0.0.1:PORT)
+    pub base_url: String,
+    hits: Arc<AtomicUsize>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockHttpServer {
+    /// Start a server answering with JSON bodies and HTTP 200
+    pub async fn start_json(bodies: Vec<String>) -> Self {
+        Self::start(bodies.into_iter().map(|body| (200, body)).collect()).await
+    }
+
+    /// Start a server answering with the given (status, body) pairs
+    pub async fn start(responses: Vec<(u16, String)>) -> Self {
+        Self::start_with_headers(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, Vec::new(), body))
+                .collect(),
+        )
+        .await
+    }
+
+    /// Start a server answering with (status, extra headers, body) triples
+    /// DOCUMENTATION: Content-Type defaults to application/json unless given
+    pub async fn start_with_headers(responses: Vec<MockResponse>) -> Self {
+        assert!(!responses.is_empty(), "mock server needs at least one response");
+
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server");
+        let base_url = format!("http://{}", listener.local_addr().expect("local addr"));
+        let hits = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let server_hits = hits.clone();
+        let server_requests = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let index = server_hits.fetch_add(1, Ordering::SeqCst);
+                let (status, headers, body) = responses[index.min(responses.len() - 1)].clone();
+                let requests = server_requests.clone();
+
+                tokio::spawn(async move {
+                    let mut buffer = Vec::new();
+                    let mut chunk = [0u8; 1024];
+                    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut chunk).await {
+                            Ok(0) | Err(_) => break,
+                            Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+                        }
+                    }
+
+                    let request = String::from_utf8_lossy(&buffer);
+                    if let Some(line) = request.lines().next() {
+                        requests.lock().unwrap().push(line.to_string());
+                    }
+
+                    let mut head = format!("HTTP/1.1 {} MOCK\r\n", status);
+                    if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-type")) {
+                        head.push_str("Content-Type: application/json\r\n");
+                    }
+                    for (name, value) in &headers {
+                        head.push_str(&format!("{}: {}\r\n", name, value));
+                    }
+                    let response = format!(
+                        "{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        head,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+
+        Self {
+            base_url,
+            hits,
+            requests,
+        }
+    }
+
+    /// Number of requests received so far
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+
+    /// Request lines received so far (e.g. "GET /nearbysearch/json?... HTTP/1.1")
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// Nearby Search body with the given places (status OK) or ZERO_RESULTS when empty
+pub(crate) fn nearby_body(place_ids: &[&str]) -> String {
+    if place_ids.is_empty() {
+        return serde_json::json!({ "status": "ZERO_RESULTS", "results": [] }).to_string();
+    }
+
+    let results: Vec<serde_json::Value> = place_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            serde_json::json!({
+                "place_id": id,
+                "name": format!("Place {}", i),
+                "types": ["restaurant"],
+                "geometry": { "location": { "lat": 41.65, "lng": -0.88 } },
+                "vicinity": "Zaragoza"
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "status": "OK", "results": results }).to_string()
+}
+
+/// In-process Google Places API serving canned places
+/// DOCUMENTATION: Every Nearby Search (whatever the cell) returns the configured pages
+/// in order, linked by "page-N" tokens; details are looked up by place id. Each call
+/// is recorded as "operation:argument" so tests can assert on what the services asked for.
+pub(crate) struct MockGooglePlacesClient {
+    nearby_pages: Vec<Vec<GooglePlace>>,
+    details: HashMap<String, GooglePlace>,
+    /// Searches centered on (lat, lng) fail with the error while the count lasts
+    nearby_failures: Mutex<Vec<(f64, f64, usize, GoogleApiError)>>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockGooglePlacesClient {
+    /// Mock without places: Nearby Search is empty and details are NotFound
+    pub fn new() -> Self {
+        Self {
+            nearby_pages: Vec::new(),
+            details: HashMap::new(),
+            nearby_failures: Mutex::new(Vec::new()),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Mock serving the v1 fixtures in src/services/fixtures (two places in Zaragoza,
+    /// full details for the first one)
+    pub fn from_fixtures() -> Self {
+        let details: V1Place = serde_json::from_str(include_str!(
+            "services/fixtures/v1_place_details_restaurant.json"
+        ))
+        .expect("details fixture");
+
+        Self::new()
+            .with_nearby_page(fixture_places())
+            .with_details(GooglePlace::from(details))
+    }
+
+    /// Append a Nearby Search page
+    pub fn with_nearby_page(mut self, places: Vec<GooglePlace>) -> Self {
+        self.nearby_pages.push(places);
+        self
+    }
+
+    /// Serve `place` for details requests on its place id
+    pub fn with_details(mut self, place: GooglePlace) -> Self {
+        self.details.insert(place.place_id.clone(), place);
+        self
+    }
+
+    /// Fail the next `times` searches centered on (latitude, longitude) with `error`
+    pub fn with_nearby_failures(self, latitude: f64, longitude: f64, times: usize, error: GoogleApiError) -> Self {
+        self.nearby_failures.lock().unwrap().push((latitude, longitude, times, error));
+        self
+    }
+
+    /// Calls received so far, e.g. ["nearby:first", "details:ChIJ..."]
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Number of calls received for one operation ("nearby", "details", ...)
+    pub fn call_count(&self, operation: &str) -> usize {
+        let prefix = format!("{}:", operation);
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|call| call.starts_with(&prefix))
+            .count()
+    }
+
+    fn record(&self, operation: &str, argument: &str) {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", operation, argument));
+    }
+
+    /// Known places whose name contains `input` (case-insensitive)
+    fn matching(&self, input: &str) -> Vec<GooglePlace> {
+        let needle = input.trim().to_lowercase();
+        let mut places: Vec<GooglePlace> = self
+            .nearby_pages
+            .iter()
+            .flatten()
+            .chain(self.details.values())
+            .filter(|place| place.name.to_lowercase().contains(&needle))
+            .cloned()
+            .collect();
+        places.sort_by(|a, b| a.place_id.cmp(&b.place_id));
+        places.dedup_by(|a, b| a.place_id == b.place_id);
+        places
+    }
+}
+
+#[async_trait]
+impl GooglePlacesApi for MockGooglePlacesClient {
+    async fn nearby_search_page(
+        &self,
+        search: &NearbySearchParams,
+        page_token: Option<&str>,
+    ) -> Result<NearbyPage, PlacesError> {
+        self.record("nearby", page_token.unwrap_or("first"));
+
+        if page_token.is_none() {
+            let mut failures = self.nearby_failures.lock().unwrap();
+            let failure = failures.iter_mut().find(|(lat, lng, times, _)| {
+                *times > 0 && (lat - search.latitude).abs() < 1e-9 && (lng - search.longitude).abs() < 1e-9
+            });
+            if let Some((_, _, times, error)) = failure {
+                *times -= 1;
+                return Err(error.clone().into());
+            }
+        }
+
+        let index = match page_token {
+            None => 0,
+            Some(token) => token
+                .strip_prefix("page-")
+                .and_then(|n| n.parse::<usize>().ok())
+                .ok_or_else(|| GoogleApiError::InvalidRequest(format!("Unknown page token {}", token)))?,
+        };
+
+        let places = match self.nearby_pages.get(index) {
+            Some(places) if !places.is_empty() => places.clone(),
+            _ => {
+                return Ok(NearbyPage {
+                    outcome: NearbyOutcome::Empty,
+                    next_page_token: None,
+                    cached: false,
+                })
+            }
+        };
+        let places = places
+            .into_iter()
+            .map(|place| GooglePlace {
+                language: search.language.clone().or(place.language),
+                ..place
+            })
+            .collect();
+
+        Ok(NearbyPage {
+            outcome: NearbyOutcome::Results(places),
+            next_page_token: (index + 1 < self.nearby_pages.len())
+                .then(|| format!("page-{}", index + 1)),
+            cached: false,
+        })
+    }
+
+    async fn get_place_details_with(
+        &self,
+        place_id: &str,
+        options: DetailsOptions<'_>,
+    ) -> Result<GooglePlace, PlacesError> {
+        self.record("details", place_id);
+
+        let place = self
+            .details
+            .get(place_id)
+            .cloned()
+            .ok_or_else(|| GoogleApiError::NotFound(format!("Place not found: {}", place_id)))?;
+        Ok(GooglePlace {
+            language: options.language.map(str::to_string).or(place.language.clone()),
+            ..place
+        })
+    }
+
+    async fn autocomplete(
+        &self,
+        input: &str,
+        _session_token: Option<&str>,
+        _location_bias: Option<&LocationBias>,
+        _region: Option<&str>,
+    ) -> Result<Vec<AutocompletePrediction>, PlacesError> {
+        self.record("autocomplete", input);
+
+        Ok(self
+            .matching(input)
+            .into_iter()
+            .map(|place| AutocompletePrediction {
+                description: place.vicinity.clone().unwrap_or_else(|| place.name.clone()),
+                place_id: place.place_id,
+                types: place.types,
+            })
+            .collect())
+    }
+
+    async fn find_place(
+        &self,
+        input: &str,
+        _input_type: FindPlaceInputType,
+    ) -> Result<Vec<FindPlaceCandidate>, PlacesError> {
+        self.record("find", input);
+
+        Ok(self
+            .matching(input)
+            .into_iter()
+            .map(|place| FindPlaceCandidate {
+                place_id: place.place_id,
+                name: Some(place.name),
+                formatted_address: place.formatted_address,
+            })
+            .collect())
+    }
+
+    async fn text_search(
+        &self,
+        query: &str,
+        _location_bias: Option<&LocationBias>,
+    ) -> Result<Vec<GooglePlace>, PlacesError> {
+        self.record("text", query);
+        Ok(self.matching(query))
+    }
+
+    fn get_photo_url(&self, photo_reference: &str, max_width: Option<i32>) -> String {
+        format!(
+            "https://photos.test/{}?maxwidth={}",
+            photo_reference,
+            max_width.unwrap_or(800)
+        )
+    }
+}
+
+/// Connect to the database named by TEST_DATABASE_URL
+/// DOCUMENTATION: Database tests are `#[ignore]`d by default; run them with
+/// `TEST_DATABASE_URL=postgres://... cargo test -- --ignored` against a migrated schema
+pub(crate) async fn test_pool() -> sqlx::PgPool {
+    let url = std::env::var("TEST_DATABASE_URL")
+        .expect("TEST_DATABASE_URL must point at a migrated PostGIS database");
+    sqlx::postgres::PgPoolOptions::new()
+        .max_connections(10)
+        .connect(&url)
+        .await
+        .expect("connect to test database")
+}
+
+/// Places of the v1 Nearby Search fixture (two places in Zaragoza)
+pub(crate) fn fixture_places() -> Vec<GooglePlace> {
+    let search: V1SearchResponse = serde_json::from_str(include_str!(
+        "services/fixtures/v1_search_nearby_restaurant.json"
+    ))
+    .expect("search fixture");
+    search.places.into_iter().map(GooglePlace::from).collect()
+}
+
+/// Insert a throwaway place and return its id
+pub(crate) async fn insert_test_place(pool: &sqlx::PgPool, google_place_id: &str) -> uuid::Uuid {
+    let request: crate::models::CreatePlaceRequest = serde_json::from_value(serde_json::json!({
+        "name": format!("Test {}", google_place_id),
+        "type": "restaurant",
+        "location": [-0.8891, 41.6488],
+        "city": "Zaragoza",
+        "google_place_id": google_place_id,
+        "main_categories": []
+    }))
+    .expect("test place request");
+
+    crate::db::PlaceRepository::create_place(pool, &request)
+        .await
+        .expect("insert test place")
+        .id
+}
+
+/// Configuration with test-friendly defaults (no Google key, caches off)
+pub(crate) fn test_config() -> crate::config::Config {
+    crate::config::Config {
+        database_url: "postgres://unused@localhost/unused".to_string(),
+        server_address: "127.0.0.1".to_string(),
+        server_port: 0,
+        environment: "test".to_string(),
+        log_level: "warn".to_string(),
+        google_places_api_key: String::new(),
+        google_places_base_url: "http://127.0.0.1:9".to_string(),
+        google_connect_timeout_ms: 2_000,
+        google_request_timeout_ms: 10_000,
+        google_default_language: "es".to_string(),
+        google_requests_per_second: 10,
+        google_region_bias: String::new(),
+        google_api_version: "legacy".to_string(),
+        google_places_v1_base_url: "http://127.0.0.1:9".to_string(),
+        google_price_nearby_search: 32.0,
+        google_price_text_search: 32.0,
+        google_price_place_details: 17.0,
+        google_price_photo: 7.0,
+        google_price_autocomplete: 2.83,
+        google_price_find_place: 17.0,
+        google_price_geocoding: 5.0,
+        photo_proxy_base_url: String::new(),
+        photo_url_cache_ttl_seconds: 600,
+        photo_max_bytes: 5 * 1024 * 1024,
+        sync_details_concurrency: 5,
+        sync_max_cell_concurrency: 4,
+        sync_max_city_concurrency: 3,
+        sync_min_cell_radius_m: 150,
+        sync_max_cells: 500,
+        sync_max_bbox_area_km2: 2000.0,
+        sync_resume_window_hours: 24,
+        sync_max_api_requests: 10_000,
+        sync_failed_cell_retries: 1,
+        sync_failed_cell_retry_delay_seconds: 30,
+        sync_quota_backoff_retries: 3,
+        sync_quota_max_wait_seconds: 120,
+        sync_max_cell_stats: 500,
+        sync_max_jobs: 100,
+        sync_max_concurrent_jobs: 1,
+        webhook_url: None,
+        webhook_secret: None,
+        sync_schedule_enabled: false,
+        sync_schedule_interval_hours: 24,
+        sync_schedule_cities: Vec::new(),
+        sync_schedule_limit: 100,
+        sync_stale_after_days: 30,
+        cities_config_path: None,
+        geocoding_fallback_enabled: false,
+        admin_token: "test-admin-token".to_string(),
+        db_max_connections: 1,
+        db_connection_timeout: 1,
+        search_cache_enabled: false,
+        search_cache_ttl_seconds: 60,
+        search_fuzzy_threshold: 0.3,
+        search_language: "spanish".to_string(),
+        featured_min_rating: 4.0,
+        featured_min_rating_count: 20,
+        cache_backend: "memory".to_string(),
+        redis_url: None,
+        cache_search_ttl_seconds: 3_600,
+        cache_cleanup_interval_seconds: 300,
+        cache_max_entries: 1_000,
+        cache_details_ttl_seconds: 3_600,
+        cache_empty_results_ttl_seconds: 600,
+        cache_autocomplete_ttl_seconds: 300,
+        details_freshness_hours: 24,
+        on_demand_import_enabled: true,
+        on_demand_imports_per_minute: 30,
+        env_errors: Vec::new(),
+    }
+}
+
+thread_local! {
+    static SQL_STATEMENTS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Logger counting the statements sqlx reports on the "sqlx::query" target
+struct SqlStatementLogger;
+
+impl log::Log for SqlStatementLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "sqlx::query"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            SQL_STATEMENTS.with(|count| count.set(count.get() + 1));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Counter of the SQL statements run on the calling thread since `start`
+/// DOCUMENTATION: sqlx logs every statement at debug level; a process-wide logger
+/// (installed once) counts them per thread so parallel tests do not mix. Only
+/// meaningful on a current-thread runtime (`#[tokio::test]`), where the queries
+/// run on the test thread
+pub(crate) struct SqlStatementCounter {
+    start: usize,
+}
+
+impl SqlStatementCounter {
+    pub(crate) fn start() -> Self {
+        static INSTALL: std::sync::Once = std::sync::Once::new();
+        INSTALL.call_once(|| {
+            log::set_logger(&SqlStatementLogger).expect("no other logger in tests");
+            log::set_max_level(log::LevelFilter::Debug);
+        });
+        Self { start: SQL_STATEMENTS.with(std::cell::Cell::get) }
+    }
+
+    /// Statements run since `start`
+    pub(crate) fn count(&self) -> usize {
+        SQL_STATEMENTS.with(std::cell::Cell::get) - self.start
+    }
+}
+
+/// Pool that never connects; for code paths that must not touch the database
+/// DOCUMENTATION: Code paths that tolerate a failing lookup (e.g. stored city bounds)
+/// see the error quickly instead of waiting out the default acquire timeout
+pub(crate) fn lazy_pool() -> sqlx::PgPool {
+    sqlx::postgres::PgPoolOptions::new()
+        .acquire_timeout(std::time::Duration::from_millis(200))
+        .connect_lazy("postgres://unused@localhost/unused")
+        .expect("lazy pool")
+}